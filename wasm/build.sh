@@ -10,7 +10,17 @@ echo ""
 # Build Rust
 echo "🦀 Building Rust..."
 cd rust
-wasm-pack build --target nodejs --release
+if [ "$THREADS" = "1" ]; then
+  # Multithreaded browser build (Web Workers + SharedArrayBuffer); needs nightly Rust
+  RUSTFLAGS="-C target-feature=+atomics,+bulk-memory" \
+    rustup run nightly wasm-pack build --target web --release --out-dir pkg-threads \
+    -- --features threads -Z build-std=panic_abort,std
+elif [ "$SIMD" = "1" ]; then
+  # Vectorized comparison loops (requires a runtime with WebAssembly SIMD)
+  RUSTFLAGS="-C target-feature=+simd128" wasm-pack build --target nodejs --release -- --features simd
+else
+  wasm-pack build --target nodejs --release
+fi
 cd ..
 echo "✅ Rust built"
 echo ""
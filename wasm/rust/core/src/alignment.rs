@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+use crate::comparison::compare_regions;
+use crate::fft::{match_counts, match_counts_scratch_bytes};
+use crate::kmer::kmers;
+use crate::packed::{count_packed_mismatches, PackedSequence};
+use crate::{parallel, simd};
+
+// ============================================================================
+// Offset Search
+// ============================================================================
+/// Best ungapped placement of seq2 against seq1 (positions are 0-based).
+pub struct Alignment {
+  pub offset1: usize,
+  pub offset2: usize,
+  pub length: usize,
+  pub mismatches: usize,
+  pub identity: f64,
+}
+
+impl Alignment {
+  pub fn mask(&self, bytes1: &[u8], bytes2: &[u8]) -> Vec<u8> {
+    let region1 = &bytes1[self.offset1..self.offset1 + self.length];
+    let region2 = &bytes2[self.offset2..self.offset2 + self.length];
+    compare_regions(region1, region2).0
+  }
+
+  fn none() -> Self {
+    Alignment { offset1: 0, offset2: 0, length: 0, mismatches: i32::MAX as usize, identity: 0.0 }
+  }
+
+  /// Identity wins unless within 1%, in which case the longer overlap wins
+  fn is_better_than(&self, best: &Alignment) -> bool {
+    self.identity > best.identity + 0.01
+      || ((self.identity - best.identity).abs() < 0.01 && self.length > best.length)
+  }
+}
+
+/// Columns a placement must overlap; at least one, so the scan never scores an empty placement
+fn min_overlap_len(len1: usize, len2: usize, min_sequence_overlap_pct: f64) -> i32 {
+  (((len1.min(len2) as f64) * min_sequence_overlap_pct).ceil() as i32).max(1)
+}
+
+/// Score the placement where seq2 starts `offset` bases into seq1 (negative: seq1 starts inside seq2)
+fn evaluate_offset(bytes1: &[u8], bytes2: &[u8], offset: i32) -> Alignment {
+  let start1 = if offset > 0 { offset as usize } else { 0 };
+  let start2 = if offset < 0 { (-offset) as usize } else { 0 };
+  let overlap_len = (bytes1.len() - start1).min(bytes2.len() - start2);
+  
+  let mismatches = simd::count_mismatches(&bytes1[start1..start1 + overlap_len], &bytes2[start2..start2 + overlap_len]);
+  
+  Alignment {
+    offset1: start1,
+    offset2: start2,
+    length: overlap_len,
+    mismatches,
+    identity: 1.0 - (mismatches as f64) / (overlap_len as f64),
+  }
+}
+
+/// `evaluate_offset` for two packed sequences
+fn evaluate_packed_offset(packed1: &PackedSequence, packed2: &PackedSequence, offset: i32) -> Alignment {
+  let start1 = if offset > 0 { offset as usize } else { 0 };
+  let start2 = if offset < 0 { (-offset) as usize } else { 0 };
+  let overlap_len = (packed1.len() - start1).min(packed2.len() - start2);
+  let mismatches = count_packed_mismatches(packed1, start1, packed2, start2, overlap_len);
+  
+  Alignment {
+    offset1: start1,
+    offset2: start2,
+    length: overlap_len,
+    mismatches,
+    identity: 1.0 - (mismatches as f64) / (overlap_len as f64),
+  }
+}
+
+/// Scores one offset: FFT match counts for large inputs, packed words when both inputs
+/// are plain ACGT, bytes otherwise
+pub enum OffsetScorer<'a> {
+  Counts { counts: Vec<usize>, len1: usize, len2: usize },
+  Packed(PackedSequence, PackedSequence),
+  Bytes(&'a [u8], &'a [u8]),
+}
+
+impl<'a> OffsetScorer<'a> {
+  pub fn new(bytes1: &'a [u8], bytes2: &'a [u8]) -> Self {
+    // Exact counts for every offset, so the scan picks the same winner either way
+    if (bytes1.len() as u64) * (bytes2.len() as u64) > FFT_MIN_WORK {
+      return OffsetScorer::Counts { counts: match_counts(bytes1, bytes2), len1: bytes1.len(), len2: bytes2.len() };
+    }
+    Self::direct(bytes1, bytes2)
+  }
+  
+  fn direct(bytes1: &'a [u8], bytes2: &'a [u8]) -> Self {
+    match (PackedSequence::pack(bytes1), PackedSequence::pack(bytes2)) {
+      (Some(packed1), Some(packed2)) => OffsetScorer::Packed(packed1, packed2),
+      _ => OffsetScorer::Bytes(bytes1, bytes2),
+    }
+  }
+  
+  pub fn score(&self, offset: i32) -> Alignment {
+    match self {
+      OffsetScorer::Counts { counts, len1, len2 } => {
+        alignment_from_matches(*len1, *len2, offset, counts[(offset + *len2 as i32 - 1) as usize])
+      }
+      OffsetScorer::Packed(packed1, packed2) => evaluate_packed_offset(packed1, packed2, offset),
+      OffsetScorer::Bytes(bytes1, bytes2) => evaluate_offset(bytes1, bytes2, offset),
+    }
+  }
+}
+
+/// Same placement as `evaluate_offset`, built from a precomputed match count
+fn alignment_from_matches(len1: usize, len2: usize, offset: i32, matches: usize) -> Alignment {
+  let start1 = if offset > 0 { offset as usize } else { 0 };
+  let start2 = if offset < 0 { (-offset) as usize } else { 0 };
+  let overlap_len = (len1 - start1).min(len2 - start2);
+  let mismatches = overlap_len - matches;
+  
+  Alignment {
+    offset1: start1,
+    offset2: start2,
+    length: overlap_len,
+    mismatches,
+    identity: 1.0 - (mismatches as f64) / (overlap_len as f64),
+  }
+}
+
+// Above this many base comparisons the exhaustive scan switches to FFT match counts
+const FFT_MIN_WORK: u64 = 1 << 22;
+const SCAN_CHUNK: i32 = 1024;
+
+pub fn find_best_alignment(bytes1: &[u8], bytes2: &[u8], min_sequence_overlap_pct: f64) -> Alignment {
+  let scorer = OffsetScorer::new(bytes1, bytes2);
+  select_best_alignment(bytes1.len(), bytes2.len(), min_sequence_overlap_pct, |&offset| scorer.score(offset))
+}
+
+/// `find_best_alignment`, but stopping at the first offset (in scan order) whose identity
+/// reaches `good_enough_identity` rather than only at a perfect match
+pub fn find_good_enough_alignment(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  min_sequence_overlap_pct: f64,
+  good_enough_identity: f64,
+) -> Alignment {
+  let scorer = OffsetScorer::new(bytes1, bytes2);
+  let score = |&offset: &i32| scorer.score(offset);
+  let mut scan = OffsetScan::new(bytes1.len(), bytes2.len(), min_sequence_overlap_pct).good_enough(good_enough_identity);
+  while !scan.scan_chunk(&score) {}
+  scan.best
+}
+
+/// Estimated peak heap bytes of the exhaustive search plus the mask (both lengths non-zero)
+pub fn exhaustive_scratch_bytes(len1: usize, len2: usize) -> usize {
+  let search = if (len1 as u64) * (len2 as u64) > FFT_MIN_WORK {
+    match_counts_scratch_bytes(len1, len2)
+  } else {
+    (len1 + len2) / 4 + 16
+  };
+  search + len1.min(len2)
+}
+
+/// Estimated heap bytes of a `match_profile` and its computation
+pub fn profile_scratch_bytes(len1: usize, len2: usize) -> usize {
+  exhaustive_scratch_bytes(len1, len2) + (len1 + len2) * std::mem::size_of::<usize>()
+}
+
+/// Estimated heap bytes of a `SeedIndex` over `len1` bases (position plus hash-table share)
+pub fn seeded_scratch_bytes(len1: usize, len2: usize) -> usize {
+  len1 * (std::mem::size_of::<usize>() + 24) + len1.min(len2)
+}
+
+/// Match count for every offset (indexed by `offset + len2 - 1`), for callers that
+/// re-run the offset search with different overlap requirements
+pub fn match_profile(bytes1: &[u8], bytes2: &[u8]) -> Vec<usize> {
+  if (bytes1.len() as u64) * (bytes2.len() as u64) > FFT_MIN_WORK {
+    return match_counts(bytes1, bytes2);
+  }
+  let len2 = bytes2.len() as i32;
+  let scorer = OffsetScorer::direct(bytes1, bytes2);
+  (-(len2 - 1)..bytes1.len() as i32)
+    .map(|offset| {
+      let alignment = scorer.score(offset);
+      alignment.length - alignment.mismatches
+    })
+    .collect()
+}
+
+/// The exhaustive search over a precomputed `match_profile`
+pub fn find_best_alignment_in_profile(len1: usize, len2: usize, profile: &[usize], min_sequence_overlap_pct: f64) -> Alignment {
+  select_best_alignment(len1, len2, min_sequence_overlap_pct, |&offset| {
+    alignment_from_matches(len1, len2, offset, profile[(offset + len2 as i32 - 1) as usize])
+  })
+}
+
+fn select_best_alignment<F>(len1: usize, len2: usize, min_sequence_overlap_pct: f64, score: F) -> Alignment
+where
+  F: Fn(&i32) -> Alignment + Sync + Send,
+{
+  let mut scan = OffsetScan::new(len1, len2, min_sequence_overlap_pct);
+  while !scan.scan_chunk(&score) {}
+  scan.best
+}
+
+/// Resumable exhaustive search over offsets; offsets are scored a chunk at a time (in
+/// parallel with the `threads` feature) and then considered strictly in ascending order
+pub struct OffsetScan {
+  min_overlap: i32,
+  first: i32,
+  next: i32,
+  last: i32,
+  good_enough_identity: f64,
+  finished: bool,
+  pub best: Alignment,
+}
+
+impl OffsetScan {
+  pub fn new(len1: usize, len2: usize, min_sequence_overlap_pct: f64) -> Self {
+    let min_overlap = min_overlap_len(len1, len2, min_sequence_overlap_pct);
+    let (len1, len2) = (len1 as i32, len2 as i32);
+    OffsetScan {
+      min_overlap,
+      first: -len2 + min_overlap,
+      next: -len2 + min_overlap,
+      last: len1 - min_overlap,
+      good_enough_identity: f64::INFINITY,
+      finished: false,
+      best: Alignment::none(),
+    }
+  }
+  
+  /// Also stop at the first candidate whose identity reaches `identity`
+  pub fn good_enough(mut self, identity: f64) -> Self {
+    self.good_enough_identity = identity;
+    self
+  }
+  
+  /// Share of the offset range scanned so far (1.0 once finished)
+  pub fn fraction_done(&self) -> f64 {
+    if self.finished || self.next > self.last {
+      return 1.0;
+    }
+    (self.next - self.first) as f64 / (self.last - self.first + 1) as f64
+  }
+  
+  /// Score the next chunk of offsets; returns true once the search is complete
+  pub fn scan_chunk<F>(&mut self, score: &F) -> bool
+  where
+    F: Fn(&i32) -> Alignment + Sync + Send,
+  {
+    if self.finished || self.next > self.last {
+      self.finished = true;
+      return true;
+    }
+    
+    let chunk_end = (self.next + SCAN_CHUNK - 1).min(self.last);
+    let offsets: Vec<i32> = (self.next..=chunk_end).collect();
+    self.next = chunk_end + 1;
+    for candidate in parallel::map_ordered(&offsets, score) {
+      if (candidate.length as i32) < self.min_overlap {
+        continue;
+      }
+      
+      let done = candidate.mismatches == 0 || candidate.identity >= self.good_enough_identity;
+      if candidate.is_better_than(&self.best) {
+        self.best = candidate;
+      }
+      
+      if done {
+        self.finished = true;
+        return true;
+      }
+    }
+    self.next > self.last
+  }
+}
+
+// ============================================================================
+// Seeded Offset Search
+// ============================================================================
+// Fast approximate alternative to the exhaustive scan: shared k-mers vote for
+// the diagonal (offset) they imply, and only the most-voted diagonals are
+// scored exactly. Falls back to the exhaustive scan when nothing seeds.
+const SEED_K: usize = 11;
+const SEED_MAX_OCCURRENCES: usize = 64; // Ignore repetitive k-mers
+const SEED_CANDIDATES: usize = 8;
+
+/// k-mer positions in seq1, reusable across seeded searches against it
+pub struct SeedIndex {
+  positions: HashMap<u64, Vec<usize>>,
+}
+
+impl SeedIndex {
+  pub fn new(bytes1: &[u8]) -> Self {
+    let mut positions: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (pos, kmer, _) in kmers(bytes1, SEED_K) {
+      positions.entry(kmer).or_default().push(pos);
+    }
+    SeedIndex { positions }
+  }
+  
+  pub fn align(&self, bytes1: &[u8], bytes2: &[u8], min_sequence_overlap_pct: f64) -> Alignment {
+    if bytes1.len() < SEED_K || bytes2.len() < SEED_K {
+      return find_best_alignment(bytes1, bytes2, min_sequence_overlap_pct);
+    }
+    let min_overlap = min_overlap_len(bytes1.len(), bytes2.len(), min_sequence_overlap_pct);
+    
+    let mut votes: HashMap<i32, usize> = HashMap::new();
+    for (pos2, kmer, _) in kmers(bytes2, SEED_K) {
+      if let Some(positions) = self.positions.get(&kmer) {
+        if positions.len() > SEED_MAX_OCCURRENCES {
+          continue;
+        }
+        for &pos1 in positions {
+          *votes.entry(pos1 as i32 - pos2 as i32).or_default() += 1;
+        }
+      }
+    }
+    
+    // Highest vote first; offset breaks ties so the order is deterministic
+    let mut diagonals: Vec<(i32, usize)> = votes.into_iter().collect();
+    diagonals.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    
+    let mut best = Alignment::none();
+    let mut scored = 0;
+    for &(offset, _) in &diagonals {
+      let candidate = evaluate_offset(bytes1, bytes2, offset);
+      if (candidate.length as i32) < min_overlap {
+        continue;
+      }
+      if candidate.is_better_than(&best) {
+        best = candidate;
+      }
+      scored += 1;
+      if scored == SEED_CANDIDATES {
+        break;
+      }
+    }
+    
+    if scored == 0 {
+      return find_best_alignment(bytes1, bytes2, min_sequence_overlap_pct);
+    }
+    best
+  }
+}
+
+pub fn find_seeded_alignment(bytes1: &[u8], bytes2: &[u8], min_sequence_overlap_pct: f64) -> Alignment {
+  SeedIndex::new(bytes1).align(bytes1, bytes2, min_sequence_overlap_pct)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Reproducible pseudo-random bases
+  fn random_bases(len: usize, mut state: u64) -> Vec<u8> {
+    (0..len)
+      .map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        b"ACGT"[(state >> 33) as usize % 4]
+      })
+      .collect()
+  }
+
+  /// seq1[500..1700] with a substitution every 50 bases
+  fn planted_pair() -> (Vec<u8>, Vec<u8>) {
+    let seq1 = random_bases(2100, 1);
+    let mut seq2 = seq1[500..1700].to_vec();
+    for i in (0..seq2.len()).step_by(50) {
+      seq2[i] = if seq2[i] == b'A' { b'C' } else { b'A' };
+    }
+    (seq1, seq2)
+  }
+
+  #[test]
+  fn every_scorer_gives_the_same_placement_at_every_offset() {
+    let seq1 = random_bases(2100, 2);
+    let seq2 = random_bases(2100, 3);
+    let counts = OffsetScorer::new(&seq1, &seq2);
+    assert!(matches!(counts, OffsetScorer::Counts { .. }));
+    let packed = OffsetScorer::direct(&seq1, &seq2);
+    assert!(matches!(packed, OffsetScorer::Packed(..)));
+    let bytes = OffsetScorer::Bytes(&seq1, &seq2);
+    for offset in -2099..2100 {
+      let expected = bytes.score(offset);
+      for alignment in [counts.score(offset), packed.score(offset)] {
+        assert_eq!(
+          (alignment.offset1, alignment.offset2, alignment.length, alignment.mismatches),
+          (expected.offset1, expected.offset2, expected.length, expected.mismatches),
+          "offset {}",
+          offset
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn a_zero_overlap_requirement_still_scans_only_overlapping_offsets() {
+    // Large enough for the FFT match counts
+    let (seq1, seq2) = (random_bases(3000, 4), random_bases(3000, 5));
+    assert!(matches!(OffsetScorer::new(&seq1, &seq2), OffsetScorer::Counts { .. }));
+    let profile = match_profile(&seq1, &seq2);
+    for alignment in [
+      find_best_alignment(&seq1, &seq2, 0.0),
+      find_best_alignment_in_profile(seq1.len(), seq2.len(), &profile, 0.0),
+    ] {
+      assert!(alignment.length > 0 && alignment.identity.is_finite());
+    }
+  }
+
+  #[test]
+  fn searches_find_the_planted_placement() {
+    let (seq1, seq2) = planted_pair();
+    for alignment in [
+      find_best_alignment(&seq1, &seq2, 0.5),
+      find_seeded_alignment(&seq1, &seq2, 0.5),
+      find_best_alignment_in_profile(seq1.len(), seq2.len(), &match_profile(&seq1, &seq2), 0.5),
+    ] {
+      assert_eq!((alignment.offset1, alignment.offset2, alignment.length), (500, 0, 1200));
+      assert_eq!(alignment.mismatches, 24);
+    }
+  }
+}
@@ -0,0 +1,269 @@
+use std::fs;
+use std::process::ExitCode;
+
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{nucleotide_comparison, protein_comparison};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::normalize::{genbank_origin, Normalization};
+use gene_compare_core::observer::Observer;
+use gene_compare_core::scoring::Scoring;
+use gene_compare_core::translation::{ReadingFrames, StartCodons, Translation};
+use gene_compare_core::{
+  CODON_SIZE, DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+  DEFAULT_SEGMENT_WINDOW_LENGTH,
+};
+
+// ============================================================================
+// gene-compare
+// ============================================================================
+// Batch front end for the same comparison the web UI runs: sequences are read
+// from FASTA the way lib/parser.js does, compared with the lib/constants.js
+// defaults, and the result JSON is byte-identical to compare_sequences_full /
+// compare_proteins_full.
+
+const USAGE: &str = "usage: gene-compare <seq1.fa> <seq2.fa> [--protein] [--json <out.json>] [--verbose]
+                    [--window <bases>] [--min-identity <0-1>] [--min-significant <0-1>] [--min-overlap <0-1>]
+                    [--frames <frame1>,<frame2>] [--cds1 <start>-<end> --cds2 <start>-<end>] [--start-codons <ATG,GTG,...>] [--kozak]
+                    [--selenocysteine] [--resolve-ambiguous-codons]";
+
+struct Args {
+  seq1: String,
+  seq2: String,
+  protein: bool,
+  json: Option<String>,
+  verbose: bool,
+  window: usize,
+  min_identity: f64,
+  min_significant: f64,
+  min_overlap: f64,
+  frames: ReadingFrames,
+  translation: Translation,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+  let mut files = Vec::new();
+  let mut parsed = Args {
+    seq1: String::new(),
+    seq2: String::new(),
+    protein: false,
+    json: None,
+    verbose: false,
+    window: DEFAULT_SEGMENT_WINDOW_LENGTH,
+    min_identity: DEFAULT_MIN_IDENTITY,
+    min_significant: DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+    min_overlap: DEFAULT_MIN_SEQUENCE_OVERLAP_PCT,
+    frames: ReadingFrames::default(),
+    translation: Translation::default(),
+  };
+  let (mut frames, mut cds1, mut cds2) = ((None, None), None, None);
+  let mut start_codons = StartCodons::default();
+  let mut kozak = false;
+  while let Some(arg) = args.next() {
+    let mut value = |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+    match arg.as_str() {
+      "--protein" => parsed.protein = true,
+      "--verbose" => parsed.verbose = true,
+      "--kozak" => kozak = true,
+      "--selenocysteine" => parsed.translation.selenocysteine = true,
+      "--resolve-ambiguous-codons" => parsed.translation.resolve_ambiguity = true,
+      "--json" => parsed.json = Some(value("--json")?),
+      "--window" => parsed.window = number(&value("--window")?, "--window")?,
+      "--min-identity" => parsed.min_identity = number(&value("--min-identity")?, "--min-identity")?,
+      "--min-significant" => parsed.min_significant = number(&value("--min-significant")?, "--min-significant")?,
+      "--min-overlap" => parsed.min_overlap = number(&value("--min-overlap")?, "--min-overlap")?,
+      "--frames" => {
+        let (frame1, frame2) = pair(&value("--frames")?, ',', "--frames")?;
+        if frame1 >= CODON_SIZE || frame2 >= CODON_SIZE {
+          return Err("--frames expects frames 0-2".to_string());
+        }
+        frames = (Some(frame1), Some(frame2));
+      }
+      "--cds1" => cds1 = Some(pair(&value("--cds1")?, '-', "--cds1")?),
+      "--cds2" => cds2 = Some(pair(&value("--cds2")?, '-', "--cds2")?),
+      "--start-codons" => {
+        let codons = value("--start-codons")?;
+        start_codons = StartCodons::parse(&codons).ok_or_else(|| format!("--start-codons: invalid codon list {:?}", codons))?;
+      }
+      "-h" | "--help" => return Err(String::new()),
+      flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+      _ => files.push(arg),
+    }
+  }
+  match (cds1, cds2) {
+    (Some(cds1), Some(cds2)) => parsed.frames = ReadingFrames::Cds { cds1, cds2 },
+    (None, None) => parsed.frames = ReadingFrames::Search { frame1: frames.0, frame2: frames.1, start_codons, kozak },
+    _ => return Err("--cds1 and --cds2 go together".to_string()),
+  }
+  let [seq1, seq2]: [String; 2] = files.try_into().map_err(|_| "expected two FASTA files".to_string())?;
+  parsed.seq1 = seq1;
+  parsed.seq2 = seq2;
+  Ok(parsed)
+}
+
+fn number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, String> {
+  value.parse().map_err(|_| format!("{} expects a number, got {:?}", name, value))
+}
+
+/// Two numbers separated by `separator`, such as "0,2" or "120-1450"
+fn pair(value: &str, separator: char, name: &str) -> Result<(usize, usize), String> {
+  let (first, second) = value
+    .split_once(separator)
+    .ok_or_else(|| format!("{} expects two numbers separated by '{}', got {:?}", name, separator, value))?;
+  Ok((number(first, name)?, number(second, name)?))
+}
+
+/// Sequence of a FASTA file as lib/parser.js reads it: every line not starting with '>',
+/// joined and trimmed. GenBank flat files are read from their ORIGIN section, uppercased.
+fn read_fasta(path: &str) -> Result<String, String> {
+  let data = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+  if genbank_origin(data.as_bytes()).is_some() {
+    let genbank = Normalization { genbank: true, uppercase: true, ..Normalization::default() };
+    let (sequence, _) = genbank.apply(data.as_bytes());
+    if sequence.is_empty() {
+      return Err(format!("{}: no sequence data found in GenBank ORIGIN section", path));
+    }
+    return Ok(String::from_utf8_lossy(&sequence).into_owned());
+  }
+  let sequence: String = data.split('\n').filter(|line| !line.starts_with('>')).collect();
+  let sequence = sequence.trim();
+  if sequence.is_empty() {
+    return Err(format!("{}: no sequence data found in FASTA", path));
+  }
+  Ok(sequence.to_string())
+}
+
+/// Sends diagnostic messages to stderr with `--verbose`
+struct StderrLog(bool);
+
+impl Observer for StderrLog {
+  fn log(&mut self, message: &str) {
+    if self.0 {
+      eprintln!("{}", message);
+    }
+  }
+}
+
+fn run(args: Args) -> Result<(), String> {
+  let seq1 = read_fasta(&args.seq1)?;
+  let seq2 = read_fasta(&args.seq2)?;
+  let mut log = StderrLog(args.verbose);
+
+  let (_, nucleotide) = nucleotide_comparison(
+    seq1.as_bytes(),
+    seq2.as_bytes(),
+    args.window,
+    args.min_identity,
+    args.min_significant,
+    args.min_overlap,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    &mut log,
+  );
+  let placement: serde_json::Value = serde_json::from_str(&nucleotide).map_err(|e| e.to_string())?;
+  if let Some(error) = placement["error"].as_str() {
+    return Err(error.to_string());
+  }
+
+  let output = if args.protein {
+    let field = |name: &str| placement[name].as_i64().unwrap_or(0) as i32;
+    let protein = protein_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      field("offset1"),
+      field("offset2"),
+      field("length"),
+      args.window / CODON_SIZE,
+      args.min_identity,
+      args.min_significant,
+      &args.frames,
+      &args.translation,
+      &ResultFormat::default(),
+      &Scoring::default(),
+      BlockDetection::default(),
+      &mut log,
+    );
+    format!(r#"{{"nucleotide":{},"protein":{}}}"#, nucleotide, protein)
+  } else {
+    format!(r#"{{"nucleotide":{}}}"#, nucleotide)
+  };
+
+  match args.json {
+    Some(path) => fs::write(&path, output).map_err(|e| format!("{}: {}", path, e)),
+    None => {
+      println!("{}", output);
+      Ok(())
+    }
+  }
+}
+
+fn main() -> ExitCode {
+  let args = match parse_args(std::env::args().skip(1)) {
+    Ok(args) => args,
+    Err(message) => {
+      if !message.is_empty() {
+        eprintln!("gene-compare: {}", message);
+      }
+      eprintln!("{}", USAGE);
+      return ExitCode::from(2);
+    }
+  };
+  match run(args) {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(message) => {
+      eprintln!("gene-compare: {}", message);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn args(line: &str) -> Result<Args, String> {
+    parse_args(line.split_whitespace().map(str::to_string))
+  }
+
+  #[test]
+  fn options_override_the_defaults() {
+    let parsed = args("a.fa --protein b.fa --window 30 --min-identity 0.8 --frames 1,2 --selenocysteine").ok().unwrap();
+    assert_eq!((parsed.seq1.as_str(), parsed.seq2.as_str(), parsed.protein), ("a.fa", "b.fa", true));
+    assert_eq!((parsed.window, parsed.min_identity, parsed.min_overlap), (30, 0.8, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT));
+    assert!(matches!(parsed.frames, ReadingFrames::Search { frame1: Some(1), frame2: Some(2), kozak: false, .. }));
+    assert!(parsed.translation.selenocysteine);
+
+    let cds = args("a.fa b.fa --cds1 0-300 --cds2 12-312").ok().unwrap();
+    assert!(cds.frames == ReadingFrames::Cds { cds1: (0, 300), cds2: (12, 312) });
+  }
+
+  #[test]
+  fn bad_arguments_are_reported() {
+    assert_eq!(args("a.fa").err().unwrap(), "expected two FASTA files");
+    assert_eq!(args("a.fa b.fa --cds1 0-300").err().unwrap(), "--cds1 and --cds2 go together");
+    assert_eq!(args("a.fa b.fa --frames 0,3").err().unwrap(), "--frames expects frames 0-2");
+    assert_eq!(args("a.fa b.fa --window").err().unwrap(), "--window needs a value");
+    assert_eq!(args("a.fa b.fa --bogus").err().unwrap(), "unknown option --bogus");
+    assert_eq!(args("--help").err().unwrap(), "");
+  }
+
+  #[test]
+  fn a_run_writes_both_comparisons() {
+    let dir = std::env::temp_dir().join(format!("gene-compare-cli-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    let seq = "ATGGCTAGCTAGGATCCGATCGATCGGCTAGCTTACGATCGATCGTAGCTAGCTAGCATCGATCGACTGACTAA";
+    fs::write(path("seq1.fa"), format!(">seq1 test\n{}\n{}\n", &seq[..40], &seq[40..])).unwrap();
+    fs::write(path("seq2.fa"), format!(">seq2\n{}\n", seq)).unwrap();
+    fs::write(path("empty.fa"), ">nothing\n").unwrap();
+
+    let line = format!("{} {} --protein --window 9 --json {}", path("seq1.fa"), path("seq2.fa"), path("out.json"));
+    run(args(&line).ok().unwrap()).unwrap();
+    let output: serde_json::Value = serde_json::from_str(&fs::read_to_string(path("out.json")).unwrap()).unwrap();
+    assert_eq!(output["nucleotide"]["identity"], 1.0);
+    assert_eq!(output["protein"]["identity"], 1.0);
+
+    let line = format!("{} {}", path("empty.fa"), path("seq2.fa"));
+    assert_eq!(run(args(&line).ok().unwrap()).err().unwrap(), format!("{}: no sequence data found in FASTA", path("empty.fa")));
+    fs::remove_dir_all(&dir).unwrap();
+  }
+}
@@ -0,0 +1,338 @@
+use crate::symbols::{GAP, MISMATCH};
+
+// ============================================================================
+// Conserved Blocks
+// ============================================================================
+// A mask is scanned in fixed windows; consecutive windows at or above the
+// identity threshold merge into a block, and blocks much shorter than the
+// longest one are dropped. Fixed windows toggle at window boundaries, so a
+// block's ends move by a whole window with a single mismatch; the hysteresis
+// method instead follows the identity of the window centered on each column,
+// entering a block at the identity threshold and leaving it only below a lower
+// exit threshold, so boundaries sit where the profile crosses them. Either
+// way a block may start or end on a mismatch; trimmed blocks start and end on
+// identical columns, ready to use as primer or probe targets.
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum BlockMethod {
+  /// Consecutive fixed windows at or above the threshold
+  #[default]
+  Windows,
+  /// Columns of the smoothed identity profile from where it reaches the threshold until it
+  /// drops below `exit_identity`
+  Hysteresis { exit_identity: f64 },
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct BlockDetection {
+  pub method: BlockMethod,
+  /// Drop the mismatch and gap columns at the ends of each block
+  pub trim_edges: bool,
+}
+
+#[derive(Clone)]
+pub struct ConservedBlock {
+  pub start: usize,
+  pub end: usize,
+  pub length: usize,
+  pub sequence: Vec<u8>,
+}
+
+pub fn count_mismatches_in_mask(mask: &[u8]) -> usize {
+  mask.iter().filter(|&&b| b == b'?').count()
+}
+
+/// Identity of the mask in `bins` equal slices (fewer for a shorter mask, one column each),
+/// for an overview of a long alignment at a fixed resolution
+pub fn overview_identity(mask: &[u8], bins: usize) -> Vec<f64> {
+  let bins = bins.min(mask.len());
+  (0..bins)
+    .map(|bin| {
+      let slice = &mask[bin * mask.len() / bins..(bin + 1) * mask.len() / bins];
+      1.0 - count_mismatches_in_mask(slice) as f64 / slice.len() as f64
+    })
+    .collect()
+}
+
+/// Window of an alignment where differences cluster, 0-based half-open in the mask
+#[derive(Clone, Copy, PartialEq)]
+pub struct DivergentWindow {
+  pub start: usize,
+  pub end: usize,
+  /// Mismatch and gap columns
+  pub differences: usize,
+  pub identity: f64,
+}
+
+/// The `count` non-overlapping `window`-column windows of the mask with the most mismatches
+/// and gaps, most divergent (then leftmost) first; windows without a difference are left
+/// out, and a mask shorter than the window is a single window
+pub fn divergent_windows(mask: &[u8], window: usize, count: usize) -> Vec<DivergentWindow> {
+  let window = window.clamp(1, mask.len().max(1));
+  if mask.is_empty() || count == 0 {
+    return Vec::new();
+  }
+  let mut differences = vec![0; mask.len() + 1];
+  for (i, &c) in mask.iter().enumerate() {
+    differences[i + 1] = differences[i] + usize::from(c == MISMATCH || c == GAP);
+  }
+  let mut starts: Vec<usize> = (0..=mask.len() - window).collect();
+  starts.sort_by_key(|&start| (std::cmp::Reverse(differences[start + window] - differences[start]), start));
+  let mut chosen: Vec<DivergentWindow> = Vec::new();
+  for start in starts {
+    let found = differences[start + window] - differences[start];
+    if found == 0 || chosen.len() == count {
+      break;
+    }
+    if chosen.iter().all(|other| start >= other.end || start + window <= other.start) {
+      let identity = 1.0 - found as f64 / window as f64;
+      chosen.push(DivergentWindow { start, end: start + window, differences: found, identity });
+    }
+  }
+  chosen
+}
+
+/// Maximal runs of columns, 0-based half-open, where the `window` centered on each column
+/// reaches `min_identity` (mismatches and gaps count against it), with their ends moved to
+/// the nearest divergent column and kept from `min_length` columns: sustained conservation
+/// between divergent stretches, as in the domains of a protein comparison
+pub fn conserved_segments(mask: &[u8], window: usize, min_identity: f64, min_length: usize) -> Vec<(usize, usize)> {
+  let identical = |c: u8| c != MISMATCH && c != GAP;
+  let mut matches = vec![0; mask.len() + 1];
+  for (i, &c) in mask.iter().enumerate() {
+    matches[i + 1] = matches[i] + usize::from(identical(c));
+  }
+  let half = window.max(1) / 2;
+  let conserved = |i: usize| {
+    let (start, end) = (i.saturating_sub(half), (i + half + 1).min(mask.len()));
+    (matches[end] - matches[start]) as f64 / (end - start) as f64 >= min_identity
+  };
+  let mut segments = Vec::new();
+  let mut i = 0;
+  while i < mask.len() {
+    if !conserved(i) {
+      i += 1;
+      continue;
+    }
+    let run_start = i;
+    while i < mask.len() && conserved(i) {
+      i += 1;
+    }
+    // The window reaches past the run's ends, so they stop short of the divergence by up
+    // to half a window: extend them over the identical columns left there
+    let floor = segments.last().map_or(0, |&(_, end)| end).max(run_start.saturating_sub(half));
+    let ceiling = (i + half).min(mask.len());
+    let mut start = (run_start..i).find(|&j| identical(mask[j])).unwrap_or(i).max(floor);
+    if start == run_start {
+      while start > floor && identical(mask[start - 1]) {
+        start -= 1;
+      }
+    }
+    let mut end = (start..i).rev().find(|&j| identical(mask[j])).map_or(start, |j| j + 1);
+    if end == i {
+      while end < ceiling && identical(mask[end]) {
+        end += 1;
+      }
+    }
+    if end > start && end - start >= min_length.max(1) {
+      segments.push((start, end));
+    }
+  }
+  segments
+}
+
+/// Conserved blocks as `detection` says; `find_conserved_blocks` for untrimmed fixed windows
+pub fn find_blocks(
+  mask: &[u8],
+  window_size: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  detection: BlockDetection,
+) -> Vec<ConservedBlock> {
+  let blocks = match detection.method {
+    BlockMethod::Windows => find_conserved_blocks(mask, window_size, min_identity, min_significant_length_group),
+    BlockMethod::Hysteresis { exit_identity } => {
+      let blocks = hysteresis_blocks(mask, window_size, min_identity, exit_identity.min(min_identity));
+      significant_blocks(blocks, min_significant_length_group)
+    }
+  };
+  if detection.trim_edges { trim_block_edges(blocks) } else { blocks }
+}
+
+/// `blocks` without their leading and trailing mismatch and gap columns, and without those
+/// left empty
+pub fn trim_block_edges(blocks: Vec<ConservedBlock>) -> Vec<ConservedBlock> {
+  let identical = |c: &u8| *c != MISMATCH && *c != GAP;
+  blocks
+    .into_iter()
+    .filter_map(|block| {
+      let lead = block.sequence.iter().position(identical)?;
+      let tail = block.sequence.iter().rposition(identical)? + 1;
+      let (start, end) = (block.start + lead, block.start + tail);
+      Some(ConservedBlock { start, end, length: end - start, sequence: block.sequence[lead..tail].to_vec() })
+    })
+    .collect()
+}
+
+/// Blocks of the identity profile over the `window` centered on each column (mismatches
+/// and gaps count against it): a block opens at a column reaching `enter` and closes
+/// before the first column below `exit`
+fn hysteresis_blocks(mask: &[u8], window: usize, enter: f64, exit: f64) -> Vec<ConservedBlock> {
+  let mut matches = vec![0; mask.len() + 1];
+  for (i, &c) in mask.iter().enumerate() {
+    matches[i + 1] = matches[i] + usize::from(c != MISMATCH && c != GAP);
+  }
+  let half = window.max(1) / 2;
+  let profile = |i: usize| {
+    let (start, end) = (i.saturating_sub(half), (i + half + 1).min(mask.len()));
+    (matches[end] - matches[start]) as f64 / (end - start) as f64
+  };
+  let block = |start: usize, end: usize| ConservedBlock {
+    start,
+    end,
+    length: end - start,
+    sequence: mask[start..end].to_vec(),
+  };
+  let mut blocks = Vec::new();
+  let mut open = None;
+  for i in 0..mask.len() {
+    let identity = profile(i);
+    match open {
+      None if identity >= enter => open = Some(i),
+      Some(start) if identity < exit => {
+        blocks.push(block(start, i));
+        open = None;
+      }
+      _ => {}
+    }
+  }
+  if let Some(start) = open {
+    blocks.push(block(start, mask.len()));
+  }
+  blocks
+}
+
+pub fn find_conserved_blocks(mask: &[u8], window_size: usize, min_identity: f64, min_significant_length_group: f64) -> Vec<ConservedBlock> {
+  let window_size = window_size.max(1);
+  let mut blocks = Vec::new();
+  let mut current_block = Vec::new();
+  let mut block_start = 0;
+  let mut in_block = false;
+  
+  let mut i = 0;
+  while i < mask.len() {
+    let end = (i + window_size).min(mask.len());
+    let window = &mask[i..end];
+    // A short final window is judged over the last full window, so one column's match or
+    // mismatch does not decide the tail alone
+    let judged =
+      if window.len() < window_size && mask.len() >= window_size { &mask[mask.len() - window_size..] } else { window };
+    let mismatches = count_mismatches_in_mask(judged);
+    let identity = 1.0 - (mismatches as f64) / (judged.len() as f64);
+    
+    if identity >= min_identity {
+      if !in_block {
+        block_start = i;
+        in_block = true;
+      }
+      current_block.extend_from_slice(window);
+    } else {
+      if !current_block.is_empty() {
+        blocks.push(ConservedBlock {
+          start: block_start,
+          end: block_start + current_block.len(),
+          length: current_block.len(),
+          sequence: current_block.clone(),
+        });
+        current_block.clear();
+        in_block = false;
+      }
+    }
+    i += window_size;
+  }
+  
+  // Save final block
+  if !current_block.is_empty() {
+    blocks.push(ConservedBlock {
+      start: block_start,
+      end: block_start + current_block.len(),
+      length: current_block.len(),
+      sequence: current_block,
+    });
+  }
+  
+  significant_blocks(blocks, min_significant_length_group)
+}
+
+/// Without the blocks much shorter than the longest
+fn significant_blocks(blocks: Vec<ConservedBlock>, min_significant_length_group: f64) -> Vec<ConservedBlock> {
+  if blocks.len() > 1 {
+    let max_length = blocks.iter().map(|b| b.length).max().unwrap_or(0);
+    let min_significant = (max_length as f64 * min_significant_length_group) as usize;
+    let filtered: Vec<_> = blocks.iter().filter(|b| b.length >= min_significant).cloned().collect();
+    if !filtered.is_empty() {
+      return filtered;
+    }
+  }
+  
+  blocks
+}
+
+pub fn blocks_to_json(blocks: &[ConservedBlock]) -> String {
+  let parts: Vec<String> = blocks.iter().map(|b| {
+    format!(
+      r#"{{"start":{},"end":{},"length":{},"sequence":"{}"}}"#,
+      b.start, b.end, b.length,
+      String::from_utf8_lossy(&b.sequence)
+    )
+  }).collect();
+  format!("[{}]", parts.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hysteresis(exit_identity: f64) -> BlockDetection {
+    BlockDetection { method: BlockMethod::Hysteresis { exit_identity }, trim_edges: false }
+  }
+
+  fn spans(blocks: &[ConservedBlock]) -> Vec<(usize, usize)> {
+    blocks.iter().map(|block| (block.start, block.end)).collect()
+  }
+
+  #[test]
+  fn hysteresis_blocks_end_where_the_profile_crosses_the_thresholds() {
+    let mask = [vec![b'A'; 30], vec![MISMATCH; 10], vec![b'A'; 30]].concat();
+    let blocks = find_blocks(&mask, 10, 0.8, 0.0, hysteresis(0.5));
+    assert_eq!(spans(&blocks), [(0, 30), (43, 70)]);
+  }
+
+  #[test]
+  fn hysteresis_holds_a_block_open_across_a_dip_above_the_exit_threshold() {
+    let mask = [vec![b'A'; 30], b"?A?A?A".to_vec(), vec![b'A'; 30]].concat();
+    assert_eq!(spans(&find_blocks(&mask, 10, 0.9, 0.0, hysteresis(0.5))), [(0, 66)]);
+    assert_eq!(spans(&find_blocks(&mask, 10, 0.9, 0.0, hysteresis(0.9))), [(0, 27), (38, 66)]);
+  }
+
+  #[test]
+  fn trimmed_blocks_start_and_end_on_identical_columns() {
+    let block = |start: usize, sequence: &[u8]| ConservedBlock {
+      start,
+      end: start + sequence.len(),
+      length: sequence.len(),
+      sequence: sequence.to_vec(),
+    };
+    let trimmed = trim_block_edges(vec![block(10, b"?-AC?GT-?"), block(40, b"?-?")]);
+    assert_eq!(spans(&trimmed), [(12, 17)]);
+    assert_eq!(trimmed[0].sequence, b"AC?GT");
+  }
+
+  #[test]
+  fn window_blocks_can_be_trimmed() {
+    let mask = [b"?".to_vec(), vec![b'A'; 19]].concat();
+    let detection = BlockDetection { trim_edges: true, ..BlockDetection::default() };
+    assert_eq!(spans(&find_conserved_blocks(&mask, 10, 0.8, 0.0)), [(0, 20)]);
+    assert_eq!(spans(&find_blocks(&mask, 10, 0.8, 0.0, detection)), [(1, 20)]);
+  }
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use crate::alignment::Alignment;
+use crate::primers::gc_fraction;
+use crate::CODON_SIZE;
+
+// ============================================================================
+// Codon-Optimized Comparison
+// ============================================================================
+// A synthetic gene recoded for expression is meant to differ from the natural
+// CDS at the nucleotide level and nowhere else: every codon swapped for a
+// synonymous one. Nucleotide placement is pointless there, so the proteins are
+// placed first, each aligned codon pair compared, and the recoding summarised
+// as a shift in codon usage.
+
+pub type Codon = [u8; 3];
+
+/// An aligned codon pair that differs
+pub struct CodonDifference {
+  /// Residue positions in each protein
+  pub residue1: usize,
+  pub residue2: usize,
+  /// Codon starts within each CDS
+  pub nt1: usize,
+  pub nt2: usize,
+  pub codon1: Codon,
+  pub codon2: Codon,
+  pub aa1: u8,
+  pub aa2: u8,
+  pub synonymous: bool,
+  /// 1-based positions within the codon
+  pub changed_bases: Vec<usize>,
+}
+
+/// A synonymous change from one codon to another and how often it occurs
+pub struct CodonSwap {
+  pub from: Codon,
+  pub to: Codon,
+  pub aa: u8,
+  pub count: usize,
+}
+
+pub struct RecodingComparison {
+  pub differences: Vec<CodonDifference>,
+  pub synonymous: usize,
+  /// Differing codons over the aligned codons
+  pub changed_codon_fraction: f64,
+  /// Identical bases over the aligned codons
+  pub nucleotide_identity: f64,
+  pub gc1: f64,
+  pub gc2: f64,
+  pub gc3_1: f64,
+  pub gc3_2: f64,
+  /// Counts of each unambiguous codon
+  pub usage1: BTreeMap<Codon, usize>,
+  pub usage2: BTreeMap<Codon, usize>,
+  pub usage_distance: f64,
+  /// Most frequent first
+  pub swaps: Vec<CodonSwap>,
+  pub protein_identity: f64,
+  /// Same length, placed end to end, every residue equal
+  pub protein_identical: bool,
+}
+
+fn is_codon(codon: &[u8]) -> bool {
+  codon.iter().all(|c| matches!(c, b'A' | b'C' | b'G' | b'T'))
+}
+
+fn codon(bytes: &[u8]) -> Codon {
+  [bytes[0], bytes[1], bytes[2]]
+}
+
+fn codon_usage(cds: &[u8]) -> BTreeMap<Codon, usize> {
+  let mut usage = BTreeMap::new();
+  for bytes in cds.chunks_exact(CODON_SIZE).filter(|bytes| is_codon(bytes)) {
+    *usage.entry(codon(bytes)).or_insert(0) += 1;
+  }
+  usage
+}
+
+/// Half the summed difference of the two codon frequency tables: 0 for the same usage,
+/// 1 for no codon in common
+fn usage_distance(usage1: &BTreeMap<Codon, usize>, usage2: &BTreeMap<Codon, usize>) -> f64 {
+  let (total1, total2) = (usage1.values().sum::<usize>(), usage2.values().sum::<usize>());
+  if total1 == 0 || total2 == 0 {
+    return 0.0;
+  }
+  let frequency = |usage: &BTreeMap<Codon, usize>, total: usize, codon| {
+    usage.get(codon).copied().unwrap_or(0) as f64 / total as f64
+  };
+  let codons = usage1.keys().chain(usage2.keys().filter(|codon| !usage1.contains_key(*codon)));
+  codons.map(|codon| (frequency(usage1, total1, codon) - frequency(usage2, total2, codon)).abs()).sum::<f64>() / 2.0
+}
+
+/// G and C at the third position of each codon
+fn gc3(cds: &[u8]) -> f64 {
+  let thirds: Vec<u8> = cds.chunks_exact(CODON_SIZE).map(|codon| codon[2]).collect();
+  gc_fraction(&thirds)
+}
+
+/// Compare two upper-case CDSs codon by codon along `alignment`, a placement of their
+/// translations `aa1` and `aa2`
+pub fn compare_recoding(cds1: &[u8], cds2: &[u8], aa1: &[u8], aa2: &[u8], alignment: &Alignment) -> RecodingComparison {
+  let mut differences = Vec::new();
+  let (mut synonymous, mut matching_bases) = (0, 0);
+  let mut swaps: BTreeMap<(Codon, Codon), (u8, usize)> = BTreeMap::new();
+  for column in 0..alignment.length {
+    let (residue1, residue2) = (alignment.offset1 + column, alignment.offset2 + column);
+    let (nt1, nt2) = (residue1 * CODON_SIZE, residue2 * CODON_SIZE);
+    let (codon1, codon2) = (&cds1[nt1..nt1 + CODON_SIZE], &cds2[nt2..nt2 + CODON_SIZE]);
+    let changed_bases: Vec<usize> = (0..CODON_SIZE).filter(|&i| codon1[i] != codon2[i]).map(|i| i + 1).collect();
+    matching_bases += CODON_SIZE - changed_bases.len();
+    if changed_bases.is_empty() {
+      continue;
+    }
+    let is_synonymous = aa1[residue1] == aa2[residue2];
+    if is_synonymous {
+      synonymous += 1;
+      if is_codon(codon1) && is_codon(codon2) {
+        swaps.entry((codon(codon1), codon(codon2))).or_insert((aa1[residue1], 0)).1 += 1;
+      }
+    }
+    differences.push(CodonDifference {
+      residue1,
+      residue2,
+      nt1,
+      nt2,
+      codon1: codon(codon1),
+      codon2: codon(codon2),
+      aa1: aa1[residue1],
+      aa2: aa2[residue2],
+      synonymous: is_synonymous,
+      changed_bases,
+    });
+  }
+
+  let (usage1, usage2) = (codon_usage(cds1), codon_usage(cds2));
+  let mut swaps: Vec<CodonSwap> = swaps.into_iter().map(|((from, to), (aa, count))| CodonSwap { from, to, aa, count }).collect();
+  swaps.sort_by_key(|swap| std::cmp::Reverse(swap.count));
+
+  let aligned = alignment.length.max(1) as f64;
+  RecodingComparison {
+    changed_codon_fraction: differences.len() as f64 / aligned,
+    differences,
+    synonymous,
+    nucleotide_identity: matching_bases as f64 / (aligned * CODON_SIZE as f64),
+    gc1: gc_fraction(cds1),
+    gc2: gc_fraction(cds2),
+    gc3_1: gc3(cds1),
+    gc3_2: gc3(cds2),
+    usage_distance: usage_distance(&usage1, &usage2),
+    usage1,
+    usage2,
+    swaps,
+    protein_identity: (alignment.length - alignment.mismatches) as f64 / aligned,
+    protein_identical: alignment.mismatches == 0 && aa1.len() == aa2.len() && alignment.length == aa1.len(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn end_to_end(length: usize, mismatches: usize) -> Alignment {
+    Alignment { offset1: 0, offset2: 0, length, mismatches, identity: (length - mismatches) as f64 / length as f64 }
+  }
+
+  #[test]
+  fn a_synonymous_recoding_shifts_codon_usage_only() {
+    let comparison = compare_recoding(b"ATGCTGCTGAAA", b"ATGCTCCTCAAG", b"MLLK", b"MLLK", &end_to_end(4, 0));
+    assert!(comparison.protein_identical);
+    assert_eq!(comparison.synonymous, 3);
+    assert_eq!(comparison.differences.iter().map(|d| d.residue1).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(comparison.differences[0].changed_bases, vec![3]);
+    assert_eq!((comparison.changed_codon_fraction, comparison.nucleotide_identity), (0.75, 0.75));
+    assert_eq!((comparison.gc3_1, comparison.gc3_2), (0.75, 1.0));
+    assert_eq!(comparison.usage_distance, 0.75);
+
+    let swaps: Vec<(&Codon, &Codon, u8, usize)> = comparison.swaps.iter().map(|s| (&s.from, &s.to, s.aa, s.count)).collect();
+    assert_eq!(swaps, vec![(b"CTG", b"CTC", b'L', 2), (b"AAA", b"AAG", b'K', 1)]);
+  }
+
+  #[test]
+  fn a_residue_change_is_not_synonymous() {
+    let comparison = compare_recoding(b"ATGAAA", b"ATGAGA", b"MK", b"MR", &end_to_end(2, 1));
+    assert!(!comparison.protein_identical);
+    assert_eq!(comparison.synonymous, 0);
+    assert!(comparison.swaps.is_empty());
+    let difference = &comparison.differences[0];
+    assert_eq!((difference.nt1, difference.aa1, difference.aa2, difference.synonymous), (3, b'K', b'R', false));
+    assert_eq!(comparison.protein_identity, 0.5);
+  }
+}
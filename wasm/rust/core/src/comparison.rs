@@ -0,0 +1,1100 @@
+use crate::alignment::{exhaustive_scratch_bytes, find_best_alignment, Alignment};
+use crate::blocks::{find_blocks, BlockDetection, ConservedBlock};
+use crate::diagnostics::{Diagnostics, Level};
+use crate::fingerprint::with_fingerprint;
+use crate::kozak::{kozak_start, KozakContext, MAX_KOZAK_SCORE};
+use crate::format::ResultFormat;
+use crate::limits::{check_memory, EXHAUSTIVE_TOO_LARGE};
+use crate::observer::Observer;
+use crate::polya::poly_a_tail;
+use crate::scoring::{blosum62, Scoring};
+use crate::symbols::{GAP, MISMATCH};
+use crate::translation::{frameshift_gaps, Frameshift, ReadingFrames, Translation};
+use crate::{parallel, simd, CODON_SIZE};
+
+// ============================================================================
+// Comparison Core
+// ============================================================================
+// Builds the JSON result objects. The wasm exports are thin wrappers around
+// these, so native callers get byte-identical output for the same inputs.
+
+pub fn compare_regions(seq1: &[u8], seq2: &[u8]) -> (Vec<u8>, usize) {
+  let len = seq1.len().min(seq2.len());
+  let mut mask = Vec::with_capacity(len);
+  let mismatches = simd::fill_mask(seq1, seq2, b'?', &mut mask);
+  
+  (mask, mismatches)
+}
+
+// ============================================================================
+// Nucleotide Comparison
+// ============================================================================
+/// Best placement, mask, conserved blocks, and JSON result of a nucleotide comparison;
+/// returns the identity alongside the JSON
+#[allow(clippy::too_many_arguments)]
+pub fn nucleotide_comparison(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  observer: &mut dyn Observer,
+) -> (f64, String) {
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return (0.0, empty_nucleotide_result(format));
+  }
+  if let Err(exceeded) = check_memory(exhaustive_scratch_bytes(bytes1.len(), bytes2.len()), EXHAUSTIVE_TOO_LARGE) {
+    return (0.0, exceeded.to_json());
+  }
+  
+  observer.report("offsetScan", 0.0);
+  let alignment = find_best_alignment(bytes1, bytes2, min_sequence_overlap_pct);
+  observer.report("offsetScan", 1.0);
+  let json = nucleotide_result_json(
+    bytes1,
+    bytes2,
+    &alignment,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    format,
+    scoring,
+    BlockDetection::default(),
+    observer,
+  );
+  (alignment.identity, json)
+}
+
+/// Result for an empty input sequence, in `format`'s coordinate system and mask symbols
+pub fn empty_nucleotide_result(format: &ResultFormat) -> String {
+  with_fingerprint(format!(
+    r#"{{"mask":"","mismatches":0,"length":0,"identity":0,"fullLengthIdentity":0,"queryCoverage":0,"targetCoverage":0,"score":0,"bitScore":null,"evalue":null,"truncated":true,"offset1":0,"offset2":0,"coordinateSystem":"{}"{},"overhangs":null,"conservedBlocks":[],"diagnostics":[{{"level":"warning","code":"emptySequence","message":"One or both sequences are empty","data":{{}}}}]}}"#,
+    format.coordinates.name(),
+    format.symbols.json_field(),
+  ))
+}
+
+/// Mask, conserved blocks, and JSON for an alignment that has already been found
+#[allow(clippy::too_many_arguments)]
+pub fn nucleotide_result_json(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  // Build mask
+  observer.report("mask", 0.0);
+  let mask = alignment.mask(bytes1, bytes2);
+  observer.report("mask", 1.0);
+  
+  mask_result_json(
+    bytes1,
+    bytes2,
+    alignment,
+    &mask,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    format,
+    scoring,
+    blocks,
+    observer,
+  )
+}
+
+/// Conserved blocks and JSON for an alignment whose mask has already been built
+#[allow(clippy::too_many_arguments)]
+pub fn mask_result_json(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  mask: &[u8],
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  // Find conserved blocks
+  observer.report("blocks", 0.0);
+  let blocks = find_blocks(mask, segment_window_length, min_identity, min_significant_length_group, blocks);
+  observer.report("blocks", 1.0);
+  
+  let (len1, len2) = (bytes1.len(), bytes2.len());
+  let truncated = len1 != len2 || alignment.offset1 != 0 || alignment.offset2 != 0;
+  
+  let mut diagnostics = Diagnostics::default();
+  if truncated {
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "truncated",
+      format!(
+        "Comparison limited to the {}-base overlap at seq1[{}], seq2[{}] (sequence lengths {} and {})",
+        alignment.length, alignment.offset1, alignment.offset2, len1, len2
+      ),
+      format!(
+        r#"{{"offset1":{},"offset2":{},"length":{},"length1":{},"length2":{}}}"#,
+        alignment.offset1, alignment.offset2, alignment.length, len1, len2
+      ),
+    );
+  }
+  
+  let (tail1, tail2) = (poly_a_tail(bytes1), poly_a_tail(bytes2));
+  if tail1 > 0 || tail2 > 0 {
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "polyATail",
+      format!(
+        "Poly-A tails of {} and {} bases at the sequence ends; trim them if their lengths skew the overlap",
+        tail1, tail2
+      ),
+      format!(r#"{{"length1":{},"length2":{}}}"#, tail1, tail2),
+    );
+  }
+  
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let score = scoring.nucleotide_score(region1, region2, mask);
+  let aligned = alignment.length > 0;
+  let bit_score = scoring.nucleotide_statistics().filter(|_| aligned).map(|statistics| statistics.bit_score(score));
+  let evalue = scoring
+    .nucleotide_composition_statistics(bytes1, bytes2)
+    .filter(|_| aligned)
+    .map(|statistics| statistics.evalue(score, len1, len2));
+  
+  let styled = format.symbols.render(mask, region1, region2);
+  with_fingerprint(format!(
+    r#"{{"mask":"{}","mismatches":{},"length":{},"identity":{},{},"score":{},"bitScore":{},"evalue":{},"truncated":{},"offset1":{},"offset2":{},"coordinateSystem":"{}"{},"overhangs":{{"seq1":{},"seq2":{}}},"conservedBlocks":{},"diagnostics":{}}}"#,
+    String::from_utf8_lossy(&styled),
+    alignment.mismatches,
+    alignment.length,
+    alignment.identity,
+    coverage_fields(alignment.length, alignment.mismatches, len1, len2),
+    score,
+    optional_f64(bit_score),
+    optional_f64(evalue),
+    truncated,
+    format.coordinates.start(alignment.offset1),
+    format.coordinates.start(alignment.offset2),
+    format.coordinates.name(),
+    format.symbols.json_field(),
+    overhang_json(bytes1, alignment.offset1, alignment.length),
+    overhang_json(bytes2, alignment.offset2, alignment.length),
+    styled_blocks_json(&blocks, &styled, format),
+    diagnostics.to_json()
+  ))
+}
+
+/// Identity over the whole of seq1 (the query) rather than the overlap, and the share of each
+/// sequence the overlap covers: 100% identity over a 40% overlap is 40% full-length identity
+fn coverage_fields(length: usize, mismatches: usize, len1: usize, len2: usize) -> String {
+  let fraction = |part: usize, whole: usize| if whole == 0 { 0.0 } else { part as f64 / whole as f64 };
+  format!(
+    r#""fullLengthIdentity":{},"queryCoverage":{},"targetCoverage":{}"#,
+    fraction(length - mismatches.min(length), len1),
+    fraction(length, len1),
+    fraction(length, len2)
+  )
+}
+
+/// The parts of a sequence before and after its aligned region (`offset`, `length`)
+fn overhang_json(bytes: &[u8], offset: usize, length: usize) -> String {
+  let (five_prime, three_prime) = (&bytes[..offset], &bytes[offset + length..]);
+  let json_string = |part: &[u8]| serde_json::to_string(&String::from_utf8_lossy(part)).unwrap_or_else(|_| "\"\"".to_string());
+  format!(
+    r#"{{"fivePrimeLength":{},"fivePrime":{},"threePrimeLength":{},"threePrime":{}}}"#,
+    five_prime.len(),
+    json_string(five_prime),
+    three_prime.len(),
+    json_string(three_prime)
+  )
+}
+
+// ============================================================================
+// Protein Comparison
+// ============================================================================
+/// Reading-frame search, translation, mask, conserved blocks, and JSON result of a protein
+/// comparison over the nucleotide region placed by a nucleotide comparison; `frames` can pin
+/// the frames or give the coding regions instead, and `translation` sets the codon rules
+#[allow(clippy::too_many_arguments)]
+pub fn protein_comparison(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  frames: &ReadingFrames,
+  translation: &Translation,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  let mut diagnostics = Diagnostics::default();
+  
+  let combinations: Vec<(usize, usize)> = match *frames {
+    ReadingFrames::Search { frame1, frame2, .. } => {
+      let tried = |pinned: Option<usize>| pinned.map_or(0..CODON_SIZE, |frame| frame..frame + 1);
+      tried(frame1).flat_map(|frame1| tried(frame2).map(move |frame2| (frame1, frame2))).collect()
+    }
+    ReadingFrames::Cds { .. } => Vec::new(),
+  };
+  let searching = frames.method() == "search";
+  
+  let mut start1 = None;
+  let mut start2 = None;
+  let mut inferred_frames = None;
+  let mut kozak = (None, None);
+  if let ReadingFrames::Search { frame1, frame2, start_codons, kozak: by_context } = *frames {
+    // Find start codons (mRNA sequences include the 5' UTR, so they don't start at codon boundaries);
+    // only uppercase codons count, so lowercase input keeps its existing frame-detection notes
+    let find_start_codon = |seq: &[u8]| -> Option<usize> {
+      seq.windows(CODON_SIZE).position(|codon| codon.iter().all(u8::is_ascii_uppercase) && start_codons.contains(codon))
+    };
+    
+    if by_context {
+      let (site1, site2) = (kozak_start(bytes1, &start_codons), kozak_start(bytes2, &start_codons));
+      (start1, start2) = (site1.map(|(start, _)| start), site2.map(|(start, _)| start));
+      kozak = (site1.map(|(_, context)| context), site2.map(|(_, context)| context));
+      let shown = |context: Option<KozakContext>| {
+        context.map_or("none".to_string(), |context| format!("{} ({}/{})", context.strength.name(), context.score, MAX_KOZAK_SCORE))
+      };
+      let json = |context: Option<KozakContext>| context.map_or("null".to_string(), |context| context.to_json());
+      diagnostics.push(
+        observer,
+        Level::Info,
+        "kozakContext",
+        format!("Start codons chosen by Kozak context: seq1 {}, seq2 {}", shown(kozak.0), shown(kozak.1)),
+        format!(r#"{{"seq1":{},"seq2":{}}}"#, json(kozak.0), json(kozak.1)),
+      );
+    } else {
+      start1 = find_start_codon(bytes1);
+      start2 = find_start_codon(bytes2);
+    }
+    
+    if !searching {
+      let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| v.to_string());
+      let shown = |value: Option<usize>| value.map_or("any".to_string(), |v| format!("+{}", v));
+      diagnostics.push(
+        observer,
+        Level::Info,
+        "framesPinned",
+        format!("Reading frames pinned by the caller: seq1 {}, seq2 {}", shown(frame1), shown(frame2)),
+        format!(r#"{{"frame1":{},"frame2":{}}}"#, optional(frame1), optional(frame2)),
+      );
+    }
+    
+    if let (Some(s1), Some(s2)) = (start1, start2) {
+      let frame1 = ((nuc_offset1 - s1 as i32) % CODON_SIZE as i32 + CODON_SIZE as i32) % CODON_SIZE as i32;
+      let frame2 = ((nuc_offset2 - s2 as i32) % CODON_SIZE as i32 + CODON_SIZE as i32) % CODON_SIZE as i32;
+      inferred_frames = Some((frame1, frame2));
+      
+      diagnostics.push(
+        observer,
+        Level::Info,
+        "startCodons",
+        format!("Found start codons: seq1 at position {}, seq2 at position {}", s1, s2),
+        format!(r#"{{"seq1":{},"seq2":{}}}"#, s1, s2),
+      );
+      diagnostics.push(
+        observer,
+        Level::Info,
+        "inferredFrames",
+        format!("Inferred frames relative to CDS from alignment offsets seq1[{}], seq2[{}]: seq1 +{}, seq2 +{}", nuc_offset1, nuc_offset2, frame1, frame2),
+        format!(r#"{{"offset1":{},"offset2":{},"frame1":{},"frame2":{}}}"#, nuc_offset1, nuc_offset2, frame1, frame2),
+      );
+      
+      if frame1 != frame2 {
+        let action = if searching { "searching all 9 frame combinations" } else { "keeping the pinned frames" };
+        diagnostics.push(
+          observer,
+          Level::Warning,
+          "frameBroken",
+          format!("Nucleotide alignment broke the reading frame; {}", action),
+          format!(r#"{{"frame1":{},"frame2":{}}}"#, frame1, frame2),
+        );
+      }
+    } else {
+      let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| v.to_string());
+      let action = if searching { "trying all 9 reading frame combinations" } else { "using the pinned frames" };
+      diagnostics.push(
+        observer,
+        Level::Warning,
+        "startCodonMissing",
+        format!("Start codon not found in one or both sequences; {}", action),
+        format!(r#"{{"seq1":{},"seq2":{}}}"#, optional(start1), optional(start2)),
+      );
+    }
+  }
+  
+  // Find best reading frame (combinations are translated in parallel with the `threads` feature)
+  observer.report("translation", 0.0);
+  let mut best_frame1: usize = 0;
+  let mut best_frame2: usize = 0;
+  let mut best_identity: f64 = 0.0;
+  let mut best_aa1: Vec<u8> = Vec::new();
+  let mut best_aa2: Vec<u8> = Vec::new();
+  let mut adjusted_offset1 = nuc_offset1 as usize;
+  let mut adjusted_offset2 = nuc_offset2 as usize;
+  
+  let candidates = parallel::map_ordered(&combinations, |&(frame1, frame2)| {
+    let start1 = (nuc_offset1 as usize) + frame1;
+    let start2 = (nuc_offset2 as usize) + frame2;
+    let adjusted_len = ((nuc_length as usize).saturating_sub(frame1))
+      .min((nuc_length as usize).saturating_sub(frame2));
+    
+    if adjusted_len < aa_segment_window_length * CODON_SIZE {
+      return None;
+    }
+    
+    let end1 = (start1 + adjusted_len).min(bytes1.len());
+    let end2 = (start2 + adjusted_len).min(bytes2.len());
+    
+    if start1 >= bytes1.len() || start2 >= bytes2.len() {
+      return None;
+    }
+    
+    let region1 = &bytes1[start1..end1];
+    let region2 = &bytes2[start2..end2];
+    
+    let aa1 = translation.translate(region1);
+    let aa2 = translation.translate(region2);
+    
+    let min_len = aa1.len().min(aa2.len());
+    if min_len == 0 {
+      return None;
+    }
+    
+    let (_, mismatches) = compare_regions(&aa1[..min_len], &aa2[..min_len]);
+    let identity = 1.0 - (mismatches as f64) / (min_len as f64);
+    Some((frame1, frame2, identity, aa1, aa2))
+  });
+  
+  // Every combination's identity (null when it was too short to translate), and the winner's
+  // lead over the runner-up, so a close call between frames is visible
+  let tried: Vec<(usize, usize, Option<f64>)> = combinations
+    .iter()
+    .zip(&candidates)
+    .map(|(&(frame1, frame2), candidate)| (frame1, frame2, candidate.as_ref().map(|candidate| candidate.2)))
+    .collect();
+  
+  for (frame1, frame2, identity, aa1, aa2) in candidates.into_iter().flatten() {
+    // Match JS behavior: use > (strictly greater) so first frame with best identity wins
+    // When frames are checked in order (0,0), (0,1), (0,2), (1,0), etc., the first one
+    // that achieves the best identity will be selected and subsequent equal identities won't replace it
+    if identity > best_identity {
+      best_identity = identity;
+      best_frame1 = frame1;
+      best_frame2 = frame2;
+      best_aa1 = aa1;
+      best_aa2 = aa2;
+    }
+  }
+  adjusted_offset1 += best_frame1;
+  adjusted_offset2 += best_frame2;
+  if tried.len() > 1 {
+    let evaluated = || tried.iter().filter_map(|&(frame1, frame2, identity)| Some((frame1, frame2, identity?)));
+    let runner_up = evaluated()
+      .filter(|&(frame1, frame2, _)| (frame1, frame2) != (best_frame1, best_frame2))
+      .max_by(|a, b| a.2.total_cmp(&b.2).then(b.0.cmp(&a.0)).then(b.1.cmp(&a.1)));
+    let margin = runner_up.map(|(_, _, identity)| best_identity - identity);
+    let combinations: Vec<String> = tried
+      .iter()
+      .map(|&(frame1, frame2, identity)| {
+        format!(
+          r#"{{"frame1":{},"frame2":{},"identity":{}}}"#,
+          frame1,
+          frame2,
+          identity.map_or("null".to_string(), |identity| identity.to_string())
+        )
+      })
+      .collect();
+    let message = match runner_up {
+      Some((frame1, frame2, identity)) => format!(
+        "Frame search: seq1 +{}, seq2 +{} at identity {:.3}, next best seq1 +{}, seq2 +{} at {:.3}",
+        best_frame1, best_frame2, best_identity, frame1, frame2, identity
+      ),
+      None if evaluated().next().is_some() => {
+        format!("Frame search: only seq1 +{}, seq2 +{} could be compared", best_frame1, best_frame2)
+      }
+      None => "Frame search: no frame combination was long enough to compare".to_string(),
+    };
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "frameSearch",
+      message,
+      format!(
+        r#"{{"combinations":[{}],"margin":{}}}"#,
+        combinations.join(","),
+        margin.map_or("null".to_string(), |margin| margin.to_string())
+      ),
+    );
+  }
+  
+  if let ReadingFrames::Cds { cds1, cds2 } = *frames {
+    // Annotated coding regions are translated whole; ends past the sequence are cut to it
+    let clamp = |(start, end): (usize, usize), len: usize| (start.min(len), end.clamp(start.min(len), len));
+    let (range1, range2) = (clamp(cds1, bytes1.len()), clamp(cds2, bytes2.len()));
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "cdsGiven",
+      format!("Translating the given CDSs: seq1[{}..{}], seq2[{}..{}]", range1.0, range1.1, range2.0, range2.1),
+      format!(r#"{{"cds1":[{},{}],"cds2":[{},{}]}}"#, range1.0, range1.1, range2.0, range2.1),
+    );
+    if (range1, range2) != (cds1, cds2) {
+      diagnostics.push(
+        observer,
+        Level::Warning,
+        "cdsOutOfRange",
+        "A given CDS extends past the end of its sequence; translating up to the end".to_string(),
+        format!(r#"{{"cds1":[{},{}],"cds2":[{},{}]}}"#, cds1.0, cds1.1, cds2.0, cds2.1),
+      );
+    }
+    best_aa1 = translation.translate(&bytes1[range1.0..range1.1]);
+    best_aa2 = translation.translate(&bytes2[range2.0..range2.1]);
+    let min_len = best_aa1.len().min(best_aa2.len());
+    if min_len > 0 {
+      let (_, mismatches) = compare_regions(&best_aa1[..min_len], &best_aa2[..min_len]);
+      best_identity = 1.0 - (mismatches as f64) / (min_len as f64);
+    }
+    (adjusted_offset1, adjusted_offset2) = (range1.0, range2.0);
+    (best_frame1, best_frame2) = (range1.0 % CODON_SIZE, range2.0 % CODON_SIZE);
+  }
+  
+  observer.report("translation", 1.0);
+  
+  // Compare best amino acid sequences
+  observer.report("mask", 0.0);
+  let length = best_aa1.len().min(best_aa2.len());
+  let (mask, mismatches) = if length > 0 {
+    compare_regions(&best_aa1[..length], &best_aa2[..length])
+  } else {
+    (Vec::new(), 0)
+  };
+  observer.report("mask", 1.0);
+  
+  // Use best_identity from the loop (matches JS behavior)
+  let identity = best_identity;
+  
+  // Find conserved blocks on amino acids
+  observer.report("blocks", 0.0);
+  let blocks = find_blocks(&mask, aa_segment_window_length, min_identity, min_significant_length_group, blocks);
+  observer.report("blocks", 1.0);
+  
+  // Log best alignment
+  diagnostics.push(
+    observer,
+    Level::Info,
+    "bestFrames",
+    format!("Best protein alignment: seq1 +{}, seq2 +{}", best_frame1, best_frame2),
+    format!(r#"{{"frame1":{},"frame2":{},"identity":{}}}"#, best_frame1, best_frame2, identity),
+  );
+  if best_aa1.len() != best_aa2.len() {
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "truncated",
+      format!("Protein comparison truncated to the shorter translation ({} of {} and {} residues)", length, best_aa1.len(), best_aa2.len()),
+      format!(r#"{{"length":{},"length1":{},"length2":{}}}"#, length, best_aa1.len(), best_aa2.len()),
+    );
+  }
+  
+  // The frame-detection notes, for callers that don't install a logger
+  let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| v.to_string());
+  let frame_detection = format!(
+    r#"{{"method":"{}","startCodon1":{},"startCodon2":{},"kozak1":{},"kozak2":{},"inferredFrame1":{},"inferredFrame2":{},"frameBroken":{}}}"#,
+    frames.method(),
+    optional(start1),
+    optional(start2),
+    kozak.0.map_or("null".to_string(), |context| context.to_json()),
+    kozak.1.map_or("null".to_string(), |context| context.to_json()),
+    optional(inferred_frames.map(|(f1, _)| f1 as usize)),
+    optional(inferred_frames.map(|(_, f2)| f2 as usize)),
+    inferred_frames.is_some_and(|(f1, f2)| f1 != f2)
+  );
+  
+  let score = scoring.protein_score(&best_aa1[..length], &best_aa2[..length], &mask);
+  let bit_score = scoring.protein_statistics().filter(|_| length > 0).map(|statistics| statistics.bit_score(score));
+  let evalue = scoring
+    .protein_composition_statistics(&best_aa1, &best_aa2)
+    .filter(|_| length > 0)
+    .map(|statistics| statistics.evalue(score, best_aa1.len(), best_aa2.len()));
+  
+  let mismatch_codons =
+    mismatch_codons_json(bytes1, bytes2, &mask, &best_aa1, &best_aa2, (adjusted_offset1, adjusted_offset2), format);
+
+  // Gaps in pre-aligned input that break the codons of the translated regions
+  let translated = |bytes: &[u8], offset: usize, aa: &[u8]| -> Vec<Frameshift> {
+    let end = (offset + aa.len() * CODON_SIZE).min(bytes.len());
+    frameshift_gaps(bytes.get(offset..end).unwrap_or_default())
+  };
+  let frameshifts =
+    [translated(bytes1, adjusted_offset1, &best_aa1), translated(bytes2, adjusted_offset2, &best_aa2)];
+  if frameshifts.iter().any(|found| !found.is_empty()) {
+    diagnostics.push(
+      observer,
+      Level::Warning,
+      "frameshiftGaps",
+      format!(
+        "Gaps that are not whole codons shift the reading frame: {} in seq1, {} in seq2",
+        frameshifts[0].len(),
+        frameshifts[1].len()
+      ),
+      format!(r#"{{"seq1":{},"seq2":{}}}"#, frameshifts[0].len(), frameshifts[1].len()),
+    );
+  }
+  let frameshifts = frameshifts_json(&frameshifts, [adjusted_offset1, adjusted_offset2], format);
+  
+  let styled = format.symbols.render(&mask, &best_aa1[..length], &best_aa2[..length]);
+  with_fingerprint(format!(
+    r#"{{"aa1":"{}","aa2":"{}","mask":"{}","mismatches":{},"length":{},"identity":{},{},"score":{},"bitScore":{},"evalue":{},"truncated":{},"offset1":{},"offset2":{},"coordinateSystem":"{}"{},"frame1":{},"frame2":{},"conservedBlocks":{},"mismatchCodons":{},"substitutions":{},"indels":{},"frameshifts":{},"stopCodons":{},"frameDetection":{},"diagnostics":{}}}"#,
+    String::from_utf8_lossy(&best_aa1),
+    String::from_utf8_lossy(&best_aa2),
+    String::from_utf8_lossy(&styled),
+    mismatches,
+    length,
+    identity,
+    // Coverage of the nucleotide inputs by the compared codons
+    coverage_fields(length * CODON_SIZE, mismatches * CODON_SIZE, bytes1.len(), bytes2.len()),
+    score,
+    optional_f64(bit_score),
+    optional_f64(evalue),
+    best_aa1.len() != best_aa2.len(),
+    format.coordinates.start(adjusted_offset1 / CODON_SIZE),
+    format.coordinates.start(adjusted_offset2 / CODON_SIZE),
+    format.coordinates.name(),
+    format.symbols.json_field(),
+    best_frame1,
+    best_frame2,
+    styled_blocks_json(&blocks, &styled, format),
+    mismatch_codons,
+    substitutions_json(&mask, &best_aa1, &best_aa2),
+    indels_json(&best_aa1, &best_aa2, length, format),
+    frameshifts,
+    stop_codons_json(&best_aa1, &best_aa2, Some((adjusted_offset1, adjusted_offset2)), format),
+    frame_detection,
+    diagnostics.to_json()
+  ))
+}
+
+// ============================================================================
+// Direct Protein Comparison
+// ============================================================================
+/// Best placement, mask, conserved blocks, and JSON result of two amino-acid sequences
+/// compared as given (e.g. from protein FASTA), in the protein result shape; `frame1`,
+/// `frame2`, `mismatchCodons`, and `frameDetection` are null since nothing is translated
+#[allow(clippy::too_many_arguments)]
+pub fn protein_direct_comparison(
+  aa1: &[u8],
+  aa2: &[u8],
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  let mut diagnostics = Diagnostics::default();
+  let alignment = match place_proteins(aa1, aa2, min_sequence_overlap_pct, &mut diagnostics, observer) {
+    Ok(alignment) => alignment,
+    Err(exceeded) => return exceeded,
+  };
+  placed_protein_json(
+    aa1,
+    aa2,
+    &alignment,
+    None,
+    "",
+    diagnostics,
+    aa_segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    format,
+    scoring,
+    blocks,
+    observer,
+  )
+}
+
+/// Best ungapped placement of two amino-acid sequences; an empty one places nothing and is
+/// noted, and a search over the memory limit returns its error JSON
+fn place_proteins(
+  aa1: &[u8],
+  aa2: &[u8],
+  min_sequence_overlap_pct: f64,
+  diagnostics: &mut Diagnostics,
+  observer: &mut dyn Observer,
+) -> Result<Alignment, String> {
+  if aa1.is_empty() || aa2.is_empty() {
+    diagnostics.push(
+      observer,
+      Level::Warning,
+      "emptySequence",
+      "One or both sequences are empty".to_string(),
+      "{}".to_string(),
+    );
+    return Ok(Alignment { offset1: 0, offset2: 0, length: 0, mismatches: 0, identity: 0.0 });
+  }
+  check_memory(exhaustive_scratch_bytes(aa1.len(), aa2.len()), EXHAUSTIVE_TOO_LARGE).map_err(|exceeded| exceeded.to_json())?;
+  observer.report("offsetScan", 0.0);
+  let alignment = find_best_alignment(aa1, aa2, min_sequence_overlap_pct);
+  observer.report("offsetScan", 1.0);
+  Ok(alignment)
+}
+
+/// Mask, conserved blocks, and protein-shaped JSON for a placement of two amino-acid
+/// sequences; `extra_fields` (each with a leading comma) go before `diagnostics`
+#[allow(clippy::too_many_arguments)]
+fn placed_protein_json(
+  aa1: &[u8],
+  aa2: &[u8],
+  alignment: &Alignment,
+  frame2: Option<usize>,
+  extra_fields: &str,
+  mut diagnostics: Diagnostics,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  let truncated = aa1.len() != aa2.len() || alignment.offset1 != 0 || alignment.offset2 != 0;
+  if truncated && alignment.length > 0 {
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "truncated",
+      format!(
+        "Comparison limited to the {}-residue overlap at seq1[{}], seq2[{}] (sequence lengths {} and {})",
+        alignment.length, alignment.offset1, alignment.offset2, aa1.len(), aa2.len()
+      ),
+      format!(
+        r#"{{"offset1":{},"offset2":{},"length":{},"length1":{},"length2":{}}}"#,
+        alignment.offset1, alignment.offset2, alignment.length, aa1.len(), aa2.len()
+      ),
+    );
+  }
+  
+  let region1 = &aa1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &aa2[alignment.offset2..alignment.offset2 + alignment.length];
+  observer.report("mask", 0.0);
+  let (mask, mismatches) = compare_regions(region1, region2);
+  observer.report("mask", 1.0);
+  
+  observer.report("blocks", 0.0);
+  let blocks = find_blocks(&mask, aa_segment_window_length, min_identity, min_significant_length_group, blocks);
+  observer.report("blocks", 1.0);
+  
+  // Like an empty nucleotide result, no alignment has no bit score or E-value
+  let score = scoring.protein_score(region1, region2, &mask);
+  let aligned = alignment.length > 0;
+  let bit_score = scoring.protein_statistics().filter(|_| aligned).map(|statistics| statistics.bit_score(score));
+  let evalue = scoring
+    .protein_composition_statistics(aa1, aa2)
+    .filter(|_| aligned)
+    .map(|statistics| statistics.evalue(score, aa1.len(), aa2.len()));
+  
+  let styled = format.symbols.render(&mask, region1, region2);
+  let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| v.to_string());
+  with_fingerprint(format!(
+    r#"{{"aa1":"{}","aa2":"{}","mask":"{}","mismatches":{},"length":{},"identity":{},{},"score":{},"bitScore":{},"evalue":{},"truncated":{},"offset1":{},"offset2":{},"coordinateSystem":"{}"{},"frame1":null,"frame2":{},"conservedBlocks":{},"mismatchCodons":null,"substitutions":{},"indels":{},"frameshifts":[],"stopCodons":{},"frameDetection":null{},"diagnostics":{}}}"#,
+    String::from_utf8_lossy(region1),
+    String::from_utf8_lossy(region2),
+    String::from_utf8_lossy(&styled),
+    mismatches,
+    alignment.length,
+    alignment.identity,
+    coverage_fields(alignment.length, mismatches, aa1.len(), aa2.len()),
+    score,
+    optional_f64(bit_score),
+    optional_f64(evalue),
+    truncated,
+    format.coordinates.start(alignment.offset1),
+    format.coordinates.start(alignment.offset2),
+    format.coordinates.name(),
+    format.symbols.json_field(),
+    optional(frame2),
+    styled_blocks_json(&blocks, &styled, format),
+    substitutions_json(&mask, region1, region2),
+    indels_json(region1, region2, alignment.length, format),
+    stop_codons_json(region1, region2, None, format),
+    extra_fields,
+    diagnostics.to_json()
+  ))
+}
+
+// ============================================================================
+// Protein Against mRNA
+// ============================================================================
+/// Comparison of a protein (seq1) with the translations of an mRNA (seq2) in its three
+/// forward frames, keeping the frame whose best placement has the highest identity (the
+/// first on ties), in the direct protein result shape with `frame2` set. `encodingRegion`
+/// gives the mRNA bases that encode the aligned residues and the share of the protein they
+/// cover (null, like `frame2`, when nothing aligns); `frameIdentities` the best identity in
+/// each frame
+#[allow(clippy::too_many_arguments)]
+pub fn protein_mrna_comparison(
+  protein: &[u8],
+  mrna: &[u8],
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  translation: &Translation,
+  format: &ResultFormat,
+  scoring: &Scoring,
+  blocks: BlockDetection,
+  observer: &mut dyn Observer,
+) -> String {
+  observer.report("translation", 0.0);
+  let translations: Vec<Vec<u8>> = (0..CODON_SIZE).map(|frame| translation.translate(&mrna[frame.min(mrna.len())..])).collect();
+  observer.report("translation", 1.0);
+  
+  // An empty input is placed (and noted) once rather than per frame
+  let mut diagnostics = Diagnostics::default();
+  let frames = if protein.is_empty() || translations[0].is_empty() { 1 } else { CODON_SIZE };
+  let mut placements = Vec::with_capacity(frames);
+  for translated in &translations[..frames] {
+    match place_proteins(protein, translated, min_sequence_overlap_pct, &mut diagnostics, observer) {
+      Ok(alignment) => placements.push(alignment),
+      Err(exceeded) => return exceeded,
+    }
+  }
+  let mut best = 0;
+  for (frame, alignment) in placements.iter().enumerate() {
+    if alignment.identity > placements[best].identity {
+      best = frame;
+    }
+  }
+  let alignment = &placements[best];
+  
+  let start = best + alignment.offset2 * CODON_SIZE;
+  let end = start + alignment.length * CODON_SIZE;
+  let coverage = if protein.is_empty() { 0.0 } else { alignment.length as f64 / protein.len() as f64 };
+  if alignment.length > 0 {
+    diagnostics.push(
+      observer,
+      Level::Info,
+      "encodingFrame",
+      format!(
+        "Protein residues {}..{} are encoded by mRNA[{}..{}] in frame +{} (identity {})",
+        alignment.offset1,
+        alignment.offset1 + alignment.length,
+        start,
+        end,
+        best,
+        alignment.identity
+      ),
+      format!(r#"{{"frame":{},"start":{},"end":{},"identity":{}}}"#, best, start, end, alignment.identity),
+    );
+  }
+  let identities: Vec<String> =
+    (0..CODON_SIZE).map(|frame| placements.get(frame).map_or(0.0, |alignment| alignment.identity).to_string()).collect();
+  let encoding_region = if alignment.length > 0 {
+    format!(
+      r#"{{"start":{},"end":{},"frame":{},"coverage":{}}}"#,
+      format.coordinates.start(start),
+      format.coordinates.end(end),
+      best,
+      coverage
+    )
+  } else {
+    "null".to_string()
+  };
+  let extra_fields = format!(r#","encodingRegion":{},"frameIdentities":[{}]"#, encoding_region, identities.join(","));
+  placed_protein_json(
+    protein,
+    &translations[best],
+    alignment,
+    (alignment.length > 0).then_some(best),
+    &extra_fields,
+    diagnostics,
+    aa_segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    format,
+    scoring,
+    blocks,
+    observer,
+  )
+}
+
+/// Each amino-acid mismatch with the codons behind it: its position in the protein mask, and
+/// where each codon starts in its nucleotide sequence (`starts` are where the translations begin)
+fn mismatch_codons_json(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  mask: &[u8],
+  aa1: &[u8],
+  aa2: &[u8],
+  starts: (usize, usize),
+  format: &ResultFormat,
+) -> String {
+  let codon = |bytes: &[u8], start: usize| {
+    let codon = &bytes[start.min(bytes.len())..(start + CODON_SIZE).min(bytes.len())];
+    serde_json::to_string(&String::from_utf8_lossy(codon)).unwrap_or_default()
+  };
+  let parts: Vec<String> = mask
+    .iter()
+    .enumerate()
+    .filter(|&(_, &m)| m == MISMATCH)
+    .map(|(i, _)| {
+      let (start1, start2) = (starts.0 + i * CODON_SIZE, starts.1 + i * CODON_SIZE);
+      format!(
+        r#"{{"position":{},"aa1":"{}","aa2":"{}","codon1Start":{},"codon1":{},"codon2Start":{},"codon2":{}}}"#,
+        format.coordinates.start(i),
+        aa1[i] as char,
+        aa2[i] as char,
+        format.coordinates.start(start1),
+        codon(bytes1, start1),
+        format.coordinates.start(start2),
+        codon(bytes2, start2)
+      )
+    })
+    .collect();
+  format!("[{}]", parts.join(","))
+}
+
+/// Where each protein terminates: the mask column of its first stop (`column1`/`column2`,
+/// null without one), the stop codon's start in the nucleotide sequence for translations
+/// from `starts` (`codon1Start`/`codon2Start`), whether both stop in the same column
+/// (`homologous`), and how many columns later seq2 stops than seq1 (`shift`)
+fn stop_codons_json(aa1: &[u8], aa2: &[u8], starts: Option<(usize, usize)>, format: &ResultFormat) -> String {
+  let (stop1, stop2) = (aa1.iter().position(|&aa| aa == b'*'), aa2.iter().position(|&aa| aa == b'*'));
+  let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| format.coordinates.start(v).to_string());
+  let codon_start = |stop: Option<usize>, start: Option<usize>| optional(stop.zip(start).map(|(i, s)| s + i * CODON_SIZE));
+  let shift = stop1.zip(stop2).map_or("null".to_string(), |(stop1, stop2)| (stop2 as i64 - stop1 as i64).to_string());
+  format!(
+    r#"{{"column1":{},"column2":{},"codon1Start":{},"codon2Start":{},"homologous":{},"shift":{}}}"#,
+    optional(stop1),
+    optional(stop2),
+    codon_start(stop1, starts.map(|starts| starts.0)),
+    codon_start(stop2, starts.map(|starts| starts.1)),
+    stop1.is_some() && stop1 == stop2,
+    shift
+  )
+}
+
+/// Tally of the amino-acid replacements behind the mismatches, most frequent first (then in
+/// residue order), each marked conservative when BLOSUM62 scores the pair above zero; gap
+/// columns are indels, not substitutions
+fn substitutions_json(mask: &[u8], aa1: &[u8], aa2: &[u8]) -> String {
+  let mut counts: Vec<((u8, u8), usize)> = Vec::new();
+  for (i, _) in mask.iter().enumerate().filter(|&(i, &m)| m == MISMATCH && aa1[i] != GAP && aa2[i] != GAP) {
+    let pair = (aa1[i], aa2[i]);
+    match counts.iter_mut().find(|(counted, _)| *counted == pair) {
+      Some((_, count)) => *count += 1,
+      None => counts.push((pair, 1)),
+    }
+  }
+  counts.sort_by(|(pair1, count1), (pair2, count2)| count2.cmp(count1).then(pair1.cmp(pair2)));
+  let parts: Vec<String> = counts
+    .iter()
+    .map(|&((from, to), count)| {
+      let score = blosum62(from, to);
+      format!(
+        r#"{{"from":"{}","to":"{}","count":{},"blosum62":{},"conservative":{}}}"#,
+        from as char,
+        to as char,
+        count,
+        score,
+        score > 0
+      )
+    })
+    .collect();
+  format!("[{}]", parts.join(","))
+}
+
+/// In-frame insertions (residues only in seq2, where seq1 has '-') and deletions (residues
+/// only in seq1) over the first `length` columns, as runs of gap residues; the translation
+/// of pre-aligned input gives '-' for each codon-sized gap
+fn indels_json(aa1: &[u8], aa2: &[u8], length: usize, format: &ResultFormat) -> String {
+  let mut parts = Vec::new();
+  let mut i = 0;
+  while i < length {
+    let (kind, present) = match (aa1[i] == GAP, aa2[i] == GAP) {
+      (true, false) => ("insertion", aa2),
+      (false, true) => ("deletion", aa1),
+      _ => {
+        i += 1;
+        continue;
+      }
+    };
+    let gapped = if kind == "insertion" { aa1 } else { aa2 };
+    let start = i;
+    while i < length && gapped[i] == GAP && present[i] != GAP {
+      i += 1;
+    }
+    parts.push(format!(
+      r#"{{"type":"{}","position":{},"length":{},"residues":"{}"}}"#,
+      kind,
+      format.coordinates.start(start),
+      i - start,
+      String::from_utf8_lossy(&present[start..i])
+    ));
+  }
+  format!("[{}]", parts.join(","))
+}
+
+/// Frameshifting gap runs of each sequence's translated region (from `offsets`): `sequence`
+/// (1 or 2), nucleotide `position` and `length`, and the protein `column` of the codon the
+/// run starts in
+fn frameshifts_json(frameshifts: &[Vec<Frameshift>; 2], offsets: [usize; 2], format: &ResultFormat) -> String {
+  let parts: Vec<String> = frameshifts
+    .iter()
+    .zip(offsets)
+    .enumerate()
+    .flat_map(|(index, (found, offset))| {
+      found.iter().map(move |frameshift| {
+        format!(
+          r#"{{"sequence":{},"position":{},"length":{},"column":{}}}"#,
+          index + 1,
+          format.coordinates.start(offset + frameshift.position),
+          frameshift.length,
+          format.coordinates.start(frameshift.position / CODON_SIZE)
+        )
+      })
+    })
+    .collect();
+  format!("[{}]", parts.join(","))
+}
+
+fn optional_f64(value: Option<f64>) -> String {
+  value.map_or("null".to_string(), js_number)
+}
+
+/// `v` as JavaScript's `JSON.stringify` writes it: exponent notation below 1e-6 and from
+/// 1e21 (E-values reach both), and `null` for infinities and NaN, so results stay valid JSON
+/// identical to their parsed-and-restringified form
+fn js_number(v: f64) -> String {
+  if !v.is_finite() {
+    "null".to_string()
+  } else if v != 0.0 && (v.abs() < 1e-6 || v.abs() >= 1e21) {
+    let s = format!("{:e}", v);
+    if s.contains("e-") { s } else { s.replace('e', "e+") }
+  } else {
+    v.to_string()
+  }
+}
+
+/// Conserved blocks as JSON, with each block's sequence cut from the styled mask
+fn styled_blocks_json(blocks: &[ConservedBlock], styled: &[u8], format: &ResultFormat) -> String {
+  let parts: Vec<String> = blocks
+    .iter()
+    .map(|b| {
+      format!(
+        r#"{{"start":{},"end":{},"length":{},"sequence":"{}"}}"#,
+        format.coordinates.start(b.start),
+        format.coordinates.end(b.end),
+        b.length,
+        String::from_utf8_lossy(&styled[b.start..b.end])
+      )
+    })
+    .collect();
+  format!("[{}]", parts.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+    DEFAULT_SEGMENT_WINDOW_LENGTH,
+  };
+  use crate::format::CoordinateSystem;
+  use serde_json::Value;
+
+  /// Reproducible pseudo-random bases
+  fn random_bases(len: usize, mut seed: u64) -> Vec<u8> {
+    (0..len)
+      .map(|_| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        b"ACGT"[(seed >> 33) as usize % 4]
+      })
+      .collect()
+  }
+
+  fn parse(json: &str) -> Value {
+    serde_json::from_str(json).expect("result is valid JSON")
+  }
+
+  #[test]
+  fn js_number_writes_null_for_non_finite_values() {
+    assert_eq!(js_number(f64::INFINITY), "null");
+    assert_eq!(js_number(f64::NAN), "null");
+    assert_eq!(js_number(1e-7), "1e-7");
+    assert_eq!(js_number(1e21), "1e+21");
+  }
+
+  #[test]
+  fn unrelated_sequences_give_valid_json() {
+    let (seq1, seq2) = (random_bases(2000, 1), random_bases(2000, 2));
+    let (_, json) = nucleotide_comparison(
+      &seq1,
+      &seq2,
+      DEFAULT_SEGMENT_WINDOW_LENGTH,
+      DEFAULT_MIN_IDENTITY,
+      DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+      DEFAULT_MIN_SEQUENCE_OVERLAP_PCT,
+      &ResultFormat::default(),
+      &Scoring::default(),
+      &mut (),
+    );
+    let result = parse(&json);
+    assert!(result["evalue"].is_null() || result["evalue"].as_f64().is_some_and(f64::is_finite));
+  }
+
+  #[test]
+  fn protein_result_without_aligned_residues_has_no_statistics() {
+    let seq = random_bases(300, 3);
+    let json = protein_comparison(
+      &seq,
+      &seq,
+      400,
+      0,
+      300,
+      DEFAULT_SEGMENT_WINDOW_LENGTH / CODON_SIZE,
+      DEFAULT_MIN_IDENTITY,
+      DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+      &ReadingFrames::default(),
+      &Translation::default(),
+      &ResultFormat::default(),
+      &Scoring::default(),
+      BlockDetection::default(),
+      &mut (),
+    );
+    let result = parse(&json);
+    assert_eq!(result["length"], 0);
+    assert!(result["bitScore"].is_null() && result["evalue"].is_null());
+  }
+
+  #[test]
+  fn empty_result_states_the_requested_coordinate_system() {
+    let format = ResultFormat { coordinates: CoordinateSystem::OneBased, ..ResultFormat::default() };
+    let result = parse(&empty_nucleotide_result(&format));
+    assert_eq!(result["coordinateSystem"], "1-based-inclusive");
+    assert_eq!(parse(&empty_nucleotide_result(&ResultFormat::default()))["coordinateSystem"], "0-based-half-open");
+  }
+}
@@ -0,0 +1,100 @@
+use crate::symbols::{GAP, MISMATCH};
+
+// ============================================================================
+// Low-Complexity Regions
+// ============================================================================
+// Poly-A/poly-T tracts, microsatellites, and other repetitive stretches match
+// between unrelated sequences far more often than chance, so two unrelated
+// 3' UTRs can look 70% identical. Identity adjusted for composition leaves
+// out the columns where either base lies in a homopolymer run or a window
+// that DUST (Morgulis et al. 2006, as in NCBI's dustmasker) scores as low
+// complexity: triplets repeated within 64 bases, scored as the sum over
+// triplets of c * (c - 1) / 2 divided by the triplets in the window minus one.
+
+/// Shortest single-base run counted as a homopolymer
+pub const MIN_HOMOPOLYMER_RUN: usize = 8;
+/// dustmasker's default window and score threshold
+pub const DUST_WINDOW: usize = 64;
+pub const DUST_THRESHOLD: f64 = 20.0;
+
+fn triplet_index(triplet: &[u8]) -> Option<usize> {
+  triplet.iter().try_fold(0, |index, &c| {
+    let base = match c.to_ascii_uppercase() {
+      b'A' => 0,
+      b'C' => 1,
+      b'G' => 2,
+      b'T' | b'U' => 3,
+      _ => return None,
+    };
+    Some(index * 4 + base)
+  })
+}
+
+/// Positions of `seq` inside a homopolymer run of at least `MIN_HOMOPOLYMER_RUN` bases
+/// (case-insensitive, N and gaps excluded) or a `DUST_WINDOW` window scoring above
+/// `DUST_THRESHOLD`
+pub fn low_complexity_mask(seq: &[u8]) -> Vec<bool> {
+  let mut low = vec![false; seq.len()];
+  let mut start = 0;
+  for end in 1..=seq.len() {
+    if end == seq.len() || !seq[end].eq_ignore_ascii_case(&seq[start]) {
+      let base = seq[start].to_ascii_uppercase();
+      if end - start >= MIN_HOMOPOLYMER_RUN && base != b'N' && base != GAP {
+        low[start..end].fill(true);
+      }
+      start = end;
+    }
+  }
+
+  // Sliding DUST score: a triplet entering with c copies already present adds c pairs
+  if seq.len() >= DUST_WINDOW {
+    let triplets: Vec<Option<usize>> = seq.windows(3).map(triplet_index).collect();
+    let per_window = DUST_WINDOW - 2;
+    let mut counts = [0usize; 64];
+    let (mut pairs, mut present) = (0, 0);
+    for (i, triplet) in triplets.iter().enumerate() {
+      if let Some(t) = *triplet {
+        pairs += counts[t];
+        counts[t] += 1;
+        present += 1;
+      }
+      if i >= per_window {
+        if let Some(t) = triplets[i - per_window] {
+          counts[t] -= 1;
+          pairs -= counts[t];
+          present -= 1;
+        }
+      }
+      if i + 1 >= per_window && present > 1 && pairs as f64 / (present - 1) as f64 > DUST_THRESHOLD {
+        let window_start = i + 1 - per_window;
+        low[window_start..window_start + DUST_WINDOW].fill(true);
+      }
+    }
+  }
+  low
+}
+
+/// Identity over the columns of an alignment whose bases are outside both sequences'
+/// low-complexity regions
+pub struct AdjustedIdentity {
+  pub identity: f64,
+  pub columns: usize,
+  pub excluded: usize,
+}
+
+/// Composition-adjusted identity of the aligned `mask` columns, where column `i` holds
+/// `seq1[offset1 + i]` and `seq2[offset2 + i]`; gap columns count as differences, and the
+/// identity is 0 when every column is excluded
+pub fn adjusted_identity(seq1: &[u8], seq2: &[u8], offsets: (usize, usize), mask: &[u8]) -> AdjustedIdentity {
+  let (low1, low2) = (low_complexity_mask(seq1), low_complexity_mask(seq2));
+  let (mut columns, mut identical) = (0, 0);
+  for (i, &m) in mask.iter().enumerate() {
+    if low1[offsets.0 + i] || low2[offsets.1 + i] {
+      continue;
+    }
+    columns += 1;
+    identical += usize::from(m != MISMATCH && m != GAP);
+  }
+  let identity = if columns == 0 { 0.0 } else { identical as f64 / columns as f64 };
+  AdjustedIdentity { identity, columns, excluded: mask.len() - columns }
+}
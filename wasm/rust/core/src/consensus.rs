@@ -0,0 +1,238 @@
+use std::ops::Range;
+
+use crate::alignment::Alignment;
+use crate::iupac::{base_set, reverse_complement, set_to_code};
+
+// ============================================================================
+// Consensus of an Aligned Pair
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConsensusPolicy {
+  /// Mismatching columns become the IUPAC code covering both bases
+  Iupac,
+  /// Mismatching columns become N (two reads never have a strict majority)
+  Majority,
+}
+
+impl ConsensusPolicy {
+  /// "majority" (any case) or IUPAC for anything else
+  pub fn parse(policy: &str) -> Self {
+    match policy.to_ascii_lowercase().as_str() {
+      "majority" => ConsensusPolicy::Majority,
+      _ => ConsensusPolicy::Iupac,
+    }
+  }
+}
+
+pub fn consensus_base(a: u8, b: u8, policy: ConsensusPolicy) -> u8 {
+  let (a, b) = (a.to_ascii_uppercase(), b.to_ascii_uppercase());
+  if a == b {
+    return a;
+  }
+  match policy {
+    ConsensusPolicy::Iupac => match (base_set(a), base_set(b)) {
+      (0, _) | (_, 0) => b'N',
+      (set_a, set_b) => set_to_code(set_a | set_b),
+    },
+    ConsensusPolicy::Majority => b'N',
+  }
+}
+
+/// Upper-case consensus of two sequences placed by `alignment`; columns covered by only one
+/// take its base, so the consensus spans the union of both
+pub fn pair_consensus(bytes1: &[u8], bytes2: &[u8], alignment: &Alignment, policy: ConsensusPolicy) -> Vec<u8> {
+  // Leading overhang comes from whichever read starts earlier
+  let mut result = Vec::with_capacity(bytes1.len().max(bytes2.len()) + alignment.offset1.max(alignment.offset2));
+  result.extend_from_slice(&bytes1[..alignment.offset1]);
+  result.extend_from_slice(&bytes2[..alignment.offset2]);
+
+  for i in 0..alignment.length {
+    result.push(consensus_base(bytes1[alignment.offset1 + i], bytes2[alignment.offset2 + i], policy));
+  }
+
+  // Trailing overhang comes from whichever read extends further
+  result.extend_from_slice(&bytes1[alignment.offset1 + alignment.length..]);
+  result.extend_from_slice(&bytes2[alignment.offset2 + alignment.length..]);
+
+  result.make_ascii_uppercase();
+  result
+}
+
+// ============================================================================
+// Forward / Reverse Read Consensus
+// ============================================================================
+// Verifying a clone by Sanger sequencing usually means one read from each
+// end. The reverse read is reverse-complemented (its qualities reversed) and
+// placed against the forward one; where they disagree, the much better call
+// wins, and otherwise the column becomes the IUPAC code covering both.
+
+/// Phred difference at which the better of two conflicting calls is taken outright
+/// (a tenfold lower error probability)
+const QUALITY_MARGIN: u8 = 10;
+/// Cap on the quality of a column both reads agree on
+const MAX_CONSENSUS_QUALITY: u8 = 60;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Resolution {
+  Forward,
+  Reverse,
+  Ambiguous,
+}
+
+impl Resolution {
+  pub fn name(self) -> &'static str {
+    match self {
+      Resolution::Forward => "forward",
+      Resolution::Reverse => "reverse",
+      Resolution::Ambiguous => "ambiguous",
+    }
+  }
+}
+
+/// A column the two reads call differently
+pub struct Conflict {
+  /// 0-based position in the consensus
+  pub position: usize,
+  pub forward: u8,
+  pub forward_quality: Option<u8>,
+  /// The reverse read's call, complemented into the forward orientation
+  pub reverse: u8,
+  pub reverse_quality: Option<u8>,
+  pub resolution: Resolution,
+}
+
+pub struct ReadPairConsensus {
+  pub bases: Vec<u8>,
+  /// One per base: reads agreeing add up (capped at 60), a resolved conflict keeps the
+  /// difference, an ambiguous one is 0
+  pub qualities: Vec<u8>,
+  /// Where the reverse read starts in the consensus; negative when it starts first
+  pub reverse_start: i64,
+  pub overlap: usize,
+  pub conflicts: Vec<Conflict>,
+}
+
+/// Consensus of a forward read and a reverse read of the same template, in the forward
+/// read's orientation. `forward_quals` / `reverse_quals` are the reads' Phred scores as
+/// called, either empty or one per base; the pair is placed by `place`, whose error ends
+/// the search.
+pub fn read_pair_consensus<E>(
+  forward: &[u8],
+  forward_quals: &[u8],
+  reverse: &[u8],
+  reverse_quals: &[u8],
+  place: impl FnOnce(&[u8], &[u8]) -> Result<Alignment, E>,
+) -> Result<ReadPairConsensus, E> {
+  let forward = forward.to_ascii_uppercase();
+  let reverse = reverse_complement(reverse);
+  let quality = |quals: &[u8], i: usize| quals.get(i).copied();
+  let reverse_quals: Vec<u8> = reverse_quals.iter().rev().copied().collect();
+
+  let alignment = if forward.is_empty() || reverse.is_empty() { None } else { Some(place(&forward, &reverse)?) };
+  let (offset1, offset2, length) = alignment.map_or((0, 0, 0), |alignment| (alignment.offset1, alignment.offset2, alignment.length));
+
+  let (mut bases, mut qualities, mut conflicts) = (Vec::new(), Vec::new(), Vec::new());
+  let single = |read: &[u8], quals: &[u8], range: Range<usize>, bases: &mut Vec<u8>, qualities: &mut Vec<u8>| {
+    bases.extend_from_slice(&read[range.clone()]);
+    qualities.extend(range.map(|i| quality(quals, i).unwrap_or(0)));
+  };
+  // Leading overhang from whichever read starts earlier
+  single(&forward, forward_quals, 0..offset1, &mut bases, &mut qualities);
+  single(&reverse, &reverse_quals, 0..offset2, &mut bases, &mut qualities);
+  for column in 0..length {
+    let (i, j) = (offset1 + column, offset2 + column);
+    let (a, b) = (forward[i], reverse[j]);
+    let (qa, qb) = (quality(forward_quals, i), quality(&reverse_quals, j));
+    if base_set(a) == base_set(b) {
+      bases.push(a);
+      qualities.push(qa.unwrap_or(0).saturating_add(qb.unwrap_or(0)).min(MAX_CONSENSUS_QUALITY));
+      continue;
+    }
+    let (base, base_quality, resolution) = match (qa, qb) {
+      (Some(qa), Some(qb)) if qa >= qb.saturating_add(QUALITY_MARGIN) => (a, qa - qb, Resolution::Forward),
+      (Some(qa), Some(qb)) if qb >= qa.saturating_add(QUALITY_MARGIN) => (b, qb - qa, Resolution::Reverse),
+      _ => (consensus_base(a, b, ConsensusPolicy::Iupac), 0, Resolution::Ambiguous),
+    };
+    conflicts.push(Conflict {
+      position: bases.len(),
+      forward: a,
+      forward_quality: qa,
+      reverse: b,
+      reverse_quality: qb,
+      resolution,
+    });
+    bases.push(base);
+    qualities.push(base_quality);
+  }
+  // Trailing overhang from whichever read extends further
+  single(&forward, forward_quals, offset1 + length..forward.len(), &mut bases, &mut qualities);
+  single(&reverse, &reverse_quals, offset2 + length..reverse.len(), &mut bases, &mut qualities);
+
+  Ok(ReadPairConsensus {
+    bases,
+    qualities,
+    reverse_start: offset1 as i64 - offset2 as i64,
+    overlap: length,
+    conflicts,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::alignment::find_best_alignment;
+
+  #[test]
+  fn pair_consensus_spans_both_reads() {
+    let alignment = Alignment { offset1: 2, offset2: 0, length: 6, mismatches: 1, identity: 5.0 / 6.0 };
+    let (bytes1, bytes2) = (b"ttACGTAC", b"ACGAACgg");
+    assert_eq!(pair_consensus(bytes1, bytes2, &alignment, ConsensusPolicy::parse("iupac")), b"TTACGWACGG");
+    assert_eq!(pair_consensus(bytes1, bytes2, &alignment, ConsensusPolicy::parse("Majority")), b"TTACGNACGG");
+  }
+
+  #[test]
+  fn unknown_bases_make_an_n() {
+    assert_eq!(consensus_base(b'a', b'A', ConsensusPolicy::Iupac), b'A');
+    assert_eq!(consensus_base(b'C', b'G', ConsensusPolicy::Iupac), b'S');
+    assert_eq!(consensus_base(b'C', b'-', ConsensusPolicy::Iupac), b'N');
+  }
+
+  /// Forward read of template `[0, 30)` and reverse read of `[10, 40)`; the reverse read
+  /// calls template position 20 (an A) as G
+  fn read_pair() -> (Vec<u8>, Vec<u8>) {
+    let template = b"GATTACAGCCTTAGGCATCGATCCGGTACGTTAGCAAGTC";
+    let mut reverse = reverse_complement(&template[10..40]);
+    reverse[19] = b'C';
+    (template[..30].to_vec(), reverse)
+  }
+
+  fn place(a: &[u8], b: &[u8]) -> Result<Alignment, ()> {
+    Ok(find_best_alignment(a, b, 0.5))
+  }
+
+  #[test]
+  fn the_much_better_call_wins_a_conflict() {
+    let (forward, reverse) = read_pair();
+    let consensus = read_pair_consensus(&forward, &[40; 30], &reverse, &[20; 30], place).ok().unwrap();
+    assert_eq!(consensus.bases, b"GATTACAGCCTTAGGCATCGATCCGGTACGTTAGCAAGTC");
+    assert_eq!((consensus.reverse_start, consensus.overlap), (10, 20));
+    assert_eq!(&consensus.qualities[..10], &[40; 10]);
+    assert_eq!(consensus.qualities[20], 20);
+    assert_eq!(consensus.qualities[21], 60);
+    assert_eq!(&consensus.qualities[30..], &[20; 10]);
+
+    assert_eq!(consensus.conflicts.len(), 1);
+    let conflict = &consensus.conflicts[0];
+    assert_eq!((conflict.position, conflict.forward, conflict.reverse), (20, b'A', b'G'));
+    assert_eq!(conflict.resolution, Resolution::Forward);
+  }
+
+  #[test]
+  fn an_unresolved_conflict_is_ambiguous() {
+    let (forward, reverse) = read_pair();
+    let consensus = read_pair_consensus(&forward, &[], &reverse, &[], place).ok().unwrap();
+    assert_eq!(consensus.bases[20], b'R');
+    assert_eq!(consensus.qualities[20], 0);
+    assert_eq!(consensus.conflicts[0].resolution.name(), "ambiguous");
+  }
+}
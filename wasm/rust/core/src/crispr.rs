@@ -0,0 +1,170 @@
+use crate::alignment::Alignment;
+use crate::iupac::{base_set, reverse_complement};
+use crate::pattern::{find_pattern, PatternHit};
+
+// ============================================================================
+// CRISPR Guide Sites
+// ============================================================================
+// A Cas9 guide targets a protospacer only when a PAM follows it on the same
+// strand: NGG for SpCas9, read 5' to 3' right after the protospacer. The
+// nuclease cuts 3 bases upstream of the PAM. Edits that repair a cut usually
+// change the PAM or the protospacer so the guide no longer targets the edited
+// allele, which is what comparing the two alleles checks.
+
+/// The PAM of SpCas9
+pub const DEFAULT_PAM: &[u8] = b"NGG";
+
+/// Bases between the cut and the PAM
+const CUT_DISTANCE: usize = 3;
+
+pub struct Guide {
+  /// The protospacer the guide pairs with, as DNA
+  pub protospacer: Vec<u8>,
+  /// IUPAC PAM following the protospacer
+  pub pam: Vec<u8>,
+}
+
+impl Guide {
+  /// A guide from its spacer (RNA or DNA, case-insensitive) and PAM (IUPAC, `DEFAULT_PAM`
+  /// when empty). `None` when the spacer has a base other than A/C/G/T/U, is shorter than the
+  /// cut distance, or the PAM is not IUPAC
+  pub fn new(spacer: &str, pam: &str) -> Option<Guide> {
+    let dna = |text: &str| -> Vec<u8> {
+      text.trim().bytes().map(|c| if c.eq_ignore_ascii_case(&b'U') { b'T' } else { c.to_ascii_uppercase() }).collect()
+    };
+    let (protospacer, pam) = (dna(spacer), dna(pam));
+    let pam = if pam.is_empty() { DEFAULT_PAM.to_vec() } else { pam };
+    if protospacer.len() < CUT_DISTANCE
+      || !protospacer.iter().all(|c| matches!(c, b'A' | b'C' | b'G' | b'T'))
+      || pam.iter().any(|&c| base_set(c) == 0)
+    {
+      return None;
+    }
+    Some(Guide { protospacer, pam })
+  }
+
+  fn target(&self) -> Vec<u8> {
+    [self.protospacer.as_slice(), &self.pam].concat()
+  }
+
+  /// Protospacer and PAM matches in `seq` on either strand with at most `max_mismatches`
+  /// mismatches, all of them in the protospacer, by position
+  pub fn find_sites(&self, seq: &[u8], max_mismatches: usize) -> Vec<GuideSite> {
+    let (spacer, pam) = (self.protospacer.len(), self.pam.len());
+    let reverse_pam = reverse_complement(&self.pam);
+    find_pattern(seq, &self.target(), max_mismatches)
+      .into_iter()
+      .filter_map(|hit| {
+        let pam_start = if hit.reverse { hit.position } else { hit.position + spacer };
+        let expected = if hit.reverse { &reverse_pam } else { &self.pam };
+        matches_exactly(&seq[pam_start..pam_start + pam], expected).then(|| {
+          let cut = if hit.reverse { pam_start + pam + CUT_DISTANCE } else { pam_start - CUT_DISTANCE };
+          GuideSite { hit, pam_start, cut, length: spacer + pam }
+        })
+      })
+      .collect()
+  }
+
+  /// PAMs in `seq` on either strand, whatever precedes them
+  pub fn find_pams(&self, seq: &[u8]) -> Vec<PatternHit> {
+    find_pattern(seq, &self.pam, 0)
+  }
+}
+
+fn matches_exactly(window: &[u8], pattern: &[u8]) -> bool {
+  window.iter().zip(pattern).all(|(&base, &code)| {
+    let base = base_set(base);
+    base != 0 && base & !base_set(code) == 0
+  })
+}
+
+pub struct GuideSite {
+  pub hit: PatternHit,
+  /// Start of the PAM on the top strand
+  pub pam_start: usize,
+  /// Top-strand position the cut falls before
+  pub cut: usize,
+  /// Protospacer and PAM together
+  pub length: usize,
+}
+
+/// A PAM present on one strand at a column of the alignment in only one of the sequences
+pub struct PamChange {
+  /// Column of the PAM's top-strand start
+  pub column: usize,
+  pub reverse: bool,
+  /// Whether seq1 has it (destroyed) rather than seq2 (created)
+  pub in_seq1: bool,
+  /// Mismatching columns over the PAM
+  pub mismatches: Vec<usize>,
+}
+
+impl PamChange {
+  pub fn change(&self) -> &'static str {
+    if self.in_seq1 { "destroyed" } else { "created" }
+  }
+}
+
+/// PAMs created or destroyed by the differences over the placement `alignment`, by column
+pub fn pam_changes(bytes1: &[u8], bytes2: &[u8], alignment: &Alignment, guide: &Guide) -> Vec<PamChange> {
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let (pams1, pams2) = (guide.find_pams(region1), guide.find_pams(region2));
+  let has = |pams: &[PatternHit], pam: &PatternHit| {
+    pams.iter().any(|other| (other.position, other.reverse) == (pam.position, pam.reverse))
+  };
+  let mut changes: Vec<PamChange> = pams1
+    .iter()
+    .filter(|pam| !has(&pams2, pam))
+    .map(|pam| (pam, true))
+    .chain(pams2.iter().filter(|pam| !has(&pams1, pam)).map(|pam| (pam, false)))
+    .map(|(pam, in_seq1)| {
+      let columns = pam.position..pam.position + guide.pam.len();
+      let mismatches = columns.filter(|&i| !region1[i].eq_ignore_ascii_case(&region2[i])).collect();
+      PamChange { column: pam.position, reverse: pam.reverse, in_seq1, mismatches }
+    })
+    .collect();
+  changes.sort_by_key(|change| (change.column, change.reverse));
+  changes
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SPACER: &str = "GACUCAUAAAGAUGAGACUC";
+
+  /// The protospacer and a TGG PAM between AT flanks
+  fn target() -> Vec<u8> {
+    [&b"AT"[..], SPACER.replace('U', "T").as_bytes(), b"TGG", b"AT"].concat()
+  }
+
+  #[test]
+  fn sites_need_the_pam_on_the_same_strand() {
+    let guide = Guide::new(SPACER, "").unwrap();
+    assert_eq!(guide.pam, DEFAULT_PAM);
+    let site = |seq: &[u8]| guide.find_sites(seq, 0).iter().map(|site| (site.hit.reverse, site.pam_start, site.cut, site.length)).collect::<Vec<_>>();
+    assert_eq!(site(&target()), vec![(false, 22, 19, 23)]);
+    assert_eq!(site(&reverse_complement(&target())), vec![(true, 2, 8, 23)]);
+
+    // A mismatch in the PAM is never tolerated, one in the protospacer is
+    let mut broken_pam = target();
+    broken_pam[24] = b'A';
+    assert!(guide.find_sites(&broken_pam, 1).is_empty());
+    let mut mismatched = target();
+    mismatched[5] = b'A';
+    assert_eq!(guide.find_sites(&mismatched, 1)[0].hit.mismatches, 1);
+    assert!(Guide::new("GAC", "XYZ").is_none());
+  }
+
+  #[test]
+  fn an_edited_pam_is_destroyed() {
+    let guide = Guide::new(SPACER, "NGG").unwrap();
+    let mut edited = target();
+    edited[24] = b'A';
+    let alignment = Alignment { offset1: 0, offset2: 0, length: edited.len(), mismatches: 1, identity: 26.0 / 27.0 };
+    let changes = pam_changes(&target(), &edited, &alignment, &guide);
+    let found: Vec<(usize, bool, &str, Vec<usize>)> = changes.iter().map(|change| (change.column, change.reverse, change.change(), change.mismatches.clone())).collect();
+    assert_eq!(found, vec![(22, false, "destroyed", vec![24])]);
+  }
+}
@@ -0,0 +1,61 @@
+use crate::observer::Observer;
+
+// ============================================================================
+// Diagnostics
+// ============================================================================
+// Notes about decisions the comparison made (start codons, inferred reading
+// frames, truncation) are collected into a `diagnostics` array of
+// {level, code, message, data} objects in the result. Each one is also sent to
+// the observer's log, so a console view of the run reads the same.
+
+#[derive(Clone, Copy)]
+pub(crate) enum Level {
+  Info,
+  Warning,
+}
+
+impl Level {
+  fn name(self) -> &'static str {
+    match self {
+      Level::Info => "info",
+      Level::Warning => "warning",
+    }
+  }
+}
+
+struct Diagnostic {
+  level: Level,
+  code: &'static str,
+  message: String,
+  /// JSON object with the values behind the message
+  data: String,
+}
+
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+  entries: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+  pub(crate) fn push(&mut self, observer: &mut dyn Observer, level: Level, code: &'static str, message: String, data: String) {
+    observer.log(&format!("[{}] {}: {}", level.name(), code, message));
+    self.entries.push(Diagnostic { level, code, message, data });
+  }
+
+  pub(crate) fn to_json(&self) -> String {
+    let parts: Vec<String> = self
+      .entries
+      .iter()
+      .map(|d| {
+        format!(
+          r#"{{"level":"{}","code":"{}","message":{},"data":{}}}"#,
+          d.level.name(),
+          d.code,
+          serde_json::to_string(&d.message).unwrap_or_else(|_| "\"\"".to_string()),
+          d.data
+        )
+      })
+      .collect();
+    format!("[{}]", parts.join(","))
+  }
+}
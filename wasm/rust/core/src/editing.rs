@@ -0,0 +1,235 @@
+// ============================================================================
+// Edited-Allele Classification
+// ============================================================================
+// An amplicon across a Cas9 cut comes back unedited, repaired by homology-
+// directed repair (HDR) from the donor template, or with a small indel left
+// by non-homologous end joining (NHEJ). The amplicon keeps both primer ends,
+// so the differences from the reference are modelled as substitutions plus at
+// most one indel: the length difference, placed at the split that keeps the
+// most bases matching on both sides. The same model of the expected HDR
+// amplicon gives the intended edits, and the outcome follows from how many of
+// them the edited amplicon carries.
+
+/// An insertion (`length` > 0, the `inserted` bases before reference `position`) or a
+/// deletion (`length` < 0, of the reference bases from `position`)
+#[derive(Clone, PartialEq)]
+pub struct IndelEdit {
+  pub position: usize,
+  pub length: isize,
+  pub inserted: Vec<u8>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Substitution {
+  /// Position in the reference
+  pub position: usize,
+  pub reference: u8,
+  pub edited: u8,
+}
+
+/// Differences of one amplicon from the reference
+#[derive(Clone, Default)]
+pub struct EditModel {
+  pub indel: Option<IndelEdit>,
+  pub substitutions: Vec<Substitution>,
+}
+
+impl EditModel {
+  pub fn is_empty(&self) -> bool {
+    self.indel.is_none() && self.substitutions.is_empty()
+  }
+}
+
+/// Matches of `a[i]` with `b[i + shift]` over `i` from each start to the end of `a` (or
+/// of `b`), so `counts[i]` is the number of matching pairs from `i` on
+fn suffix_matches(a: &[u8], b: &[u8], shift: usize) -> Vec<usize> {
+  let len = a.len().min(b.len().saturating_sub(shift));
+  let mut counts = vec![0; a.len() + 1];
+  for i in (0..a.len()).rev() {
+    counts[i] = counts[i + 1] + usize::from(i < len && a[i] == b[i + shift]);
+  }
+  counts
+}
+
+/// `reference` against `edited` (both uppercase) as substitutions and at most one indel; with
+/// an `anchor`, ties between indel placements go to the one nearest it, otherwise to the leftmost
+pub fn edit_model(reference: &[u8], edited: &[u8], anchor: Option<usize>) -> EditModel {
+  let (shorter, longer, deletion) =
+    if edited.len() <= reference.len() { (edited, reference, true) } else { (reference, edited, false) };
+  let size = longer.len() - shorter.len();
+  let mut model = EditModel::default();
+  let mut split = shorter.len();
+  if size > 0 {
+    // Shorter's bases before the split face longer's at the same index, the rest face
+    // longer's `size` further on
+    let after = suffix_matches(shorter, longer, size);
+    // The split is the indel's reference position either way; ranked by matches, then by
+    // nearness to the anchor (0 for all without one, leaving the leftmost)
+    let distance = |k: usize| anchor.map_or(0, |anchor| k.abs_diff(anchor));
+    let (mut before, mut best) = (0, (0, 0));
+    for k in 0..=shorter.len() {
+      let score = before + after[k];
+      if k == 0 || score > best.0 || (score == best.0 && distance(k) < distance(best.1)) {
+        best = (score, k);
+      }
+      if k < shorter.len() {
+        before += usize::from(shorter[k] == longer[k]);
+      }
+    }
+    split = best.1;
+    model.indel = Some(if deletion {
+      IndelEdit { position: split, length: -(size as isize), inserted: Vec::new() }
+    } else {
+      IndelEdit { position: split, length: size as isize, inserted: edited[split..split + size].to_vec() }
+    });
+  }
+  for (i, &base) in shorter.iter().enumerate() {
+    let j = if i < split { i } else { i + size };
+    let (reference_index, reference_base, edited_base) =
+      if deletion { (j, longer[j], base) } else { (i, base, longer[j]) };
+    if reference_base != edited_base {
+      let substitution = Substitution { position: reference_index, reference: reference_base, edited: edited_base };
+      model.substitutions.push(substitution);
+    }
+  }
+  model
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EditOutcome {
+  Unedited,
+  PerfectHdr,
+  PartialHdr,
+  NhejIndel,
+  Substitution,
+}
+
+impl EditOutcome {
+  pub fn name(self) -> &'static str {
+    match self {
+      EditOutcome::Unedited => "unedited",
+      EditOutcome::PerfectHdr => "perfectHdr",
+      EditOutcome::PartialHdr => "partialHdr",
+      EditOutcome::NhejIndel => "nhejIndel",
+      EditOutcome::Substitution => "substitution",
+    }
+  }
+}
+
+pub struct EditClassification {
+  pub outcome: EditOutcome,
+  /// The edited amplicon's differences inside the window
+  pub edited: EditModel,
+  /// The expected HDR amplicon's differences: the intended edits
+  pub intended: EditModel,
+  /// Intended edits the edited amplicon carries, of `intended_total`
+  pub intended_present: usize,
+  pub intended_total: usize,
+  /// Differences left out for lying outside the window
+  pub outside_window: usize,
+}
+
+impl EditClassification {
+  pub fn is_intended(&self, substitution: &Substitution) -> bool {
+    self.intended.substitutions.contains(substitution)
+  }
+}
+
+/// Outcome of an edit from the `reference` amplicon, the `edited` one, and the `expected`
+/// amplicon after perfect HDR (empty without a donor template), all case-insensitive. With
+/// a `cut_site`, differences that are not intended edits count only within `window` bases
+/// of it, as in CRISPResso's quantification window
+pub fn classify_edit(
+  reference: &[u8],
+  edited: &[u8],
+  expected: &[u8],
+  cut_site: Option<usize>,
+  window: usize,
+) -> EditClassification {
+  let (reference, edited, expected) =
+    (reference.to_ascii_uppercase(), edited.to_ascii_uppercase(), expected.to_ascii_uppercase());
+  let intended = if expected.is_empty() { EditModel::default() } else { edit_model(&reference, &expected, cut_site) };
+  let mut model = edit_model(&reference, &edited, cut_site);
+
+  let in_window = |position: usize| cut_site.is_none_or(|cut| position.abs_diff(cut) <= window);
+  let before = model.substitutions.len() + usize::from(model.indel.is_some());
+  model
+    .substitutions
+    .retain(|substitution| intended.substitutions.contains(substitution) || in_window(substitution.position));
+  if model.indel.as_ref().is_some_and(|indel| Some(indel) != intended.indel.as_ref() && !in_window(indel.position)) {
+    model.indel = None;
+  }
+  let outside_window = before - model.substitutions.len() - usize::from(model.indel.is_some());
+
+  let carried = intended.substitutions.iter().filter(|substitution| model.substitutions.contains(substitution)).count();
+  let intended_present = carried + usize::from(intended.indel.is_some() && intended.indel == model.indel);
+  let intended_total = intended.substitutions.len() + usize::from(intended.indel.is_some());
+  let exact = intended_present == intended_total
+    && model.substitutions.len() == intended.substitutions.len()
+    && model.indel == intended.indel;
+  let outcome = if model.is_empty() {
+    EditOutcome::Unedited
+  } else if intended_total > 0 && exact {
+    EditOutcome::PerfectHdr
+  } else if intended_present > 0 {
+    EditOutcome::PartialHdr
+  } else if model.indel.is_some() {
+    EditOutcome::NhejIndel
+  } else {
+    EditOutcome::Substitution
+  };
+  EditClassification { outcome, edited: model, intended, intended_present, intended_total, outside_window }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const REFERENCE: &[u8] = b"ACGTACGTTGCAAGCTTACG";
+
+  /// `REFERENCE` with each (position, base) substituted
+  fn substituted(changes: &[(usize, u8)]) -> Vec<u8> {
+    let mut seq = REFERENCE.to_vec();
+    for &(position, base) in changes {
+      seq[position] = base;
+    }
+    seq
+  }
+
+  #[test]
+  fn an_indel_is_placed_where_the_most_bases_match() {
+    let deleted = [&REFERENCE[..8], &REFERENCE[10..]].concat();
+    let model = edit_model(REFERENCE, &deleted, None);
+    assert!(model.indel == Some(IndelEdit { position: 8, length: -2, inserted: Vec::new() }));
+    assert!(model.substitutions.is_empty());
+
+    let inserted = [&REFERENCE[..8], b"GGG", &REFERENCE[8..]].concat();
+    let model = edit_model(REFERENCE, &inserted, Some(8));
+    assert!(model.indel == Some(IndelEdit { position: 8, length: 3, inserted: b"GGG".to_vec() }));
+  }
+
+  #[test]
+  fn outcomes_follow_the_intended_edits() {
+    let expected = substituted(&[(5, b'G'), (12, b'T')]);
+    let outcome = |edited: &[u8]| classify_edit(REFERENCE, edited, &expected, Some(10), 3);
+    assert!(outcome(REFERENCE).outcome == EditOutcome::Unedited);
+    assert!(outcome(&expected.to_ascii_lowercase()).outcome == EditOutcome::PerfectHdr);
+
+    let partial = outcome(&substituted(&[(5, b'G')]));
+    assert_eq!(partial.outcome.name(), "partialHdr");
+    assert_eq!((partial.intended_present, partial.intended_total), (1, 2));
+    assert!(partial.is_intended(&partial.edited.substitutions[0]));
+
+    let nhej = outcome(&[&REFERENCE[..8], &REFERENCE[10..]].concat());
+    assert_eq!(nhej.outcome.name(), "nhejIndel");
+    assert_eq!(outcome(&substituted(&[(11, b'T')])).outcome.name(), "substitution");
+  }
+
+  #[test]
+  fn differences_outside_the_window_are_left_out() {
+    let classification = classify_edit(REFERENCE, &substituted(&[(18, b'T')]), b"", Some(10), 3);
+    assert_eq!(classification.outcome.name(), "unedited");
+    assert_eq!(classification.outside_window, 1);
+    assert_eq!(classify_edit(REFERENCE, &substituted(&[(18, b'T')]), b"", None, 3).outcome.name(), "substitution");
+  }
+}
@@ -0,0 +1,90 @@
+use crate::alignment::Alignment;
+
+// ============================================================================
+// Exon Annotations
+// ============================================================================
+// Exons are passed around as flat [start, end, start, end, ...] arrays of
+// 0-based half-open coordinates, which map directly onto a JS Uint32Array.
+
+fn parse_gff_exon(line: &str) -> Option<(u32, u32)> {
+  let cols: Vec<&str> = line.split('\t').collect();
+  if cols.len() < 9 || cols[2] != "exon" {
+    return None;
+  }
+  let start: u32 = cols[3].trim().parse().ok()?;
+  let end: u32 = cols[4].trim().parse().ok()?;
+  // GFF is 1-based inclusive
+  Some((start.checked_sub(1)?, end))
+}
+
+fn parse_genbank_exon(line: &str) -> Option<(u32, u32)> {
+  let mut parts = line.split_whitespace();
+  if parts.next()? != "exon" {
+    return None;
+  }
+  let location = parts.next()?;
+  let location = location.trim_start_matches("complement(").trim_end_matches(')');
+  let (start, end) = location.split_once("..")?;
+  let start: u32 = start.trim_start_matches('<').parse().ok()?;
+  let end: u32 = end.trim_start_matches('>').parse().ok()?;
+  // GenBank locations are 1-based inclusive
+  Some((start.checked_sub(1)?, end))
+}
+
+/// Extract exon coordinates from GFF3 `exon` rows or GenBank `exon` feature lines.
+/// Returns flat 0-based half-open [start, end, ...] pairs in file order.
+pub fn parse_exon_annotations(text: &str) -> Vec<u32> {
+  text
+    .lines()
+    .filter_map(|line| parse_gff_exon(line).or_else(|| parse_genbank_exon(line)))
+    .flat_map(|(start, end)| [start, end])
+    .collect()
+}
+
+/// Exon pairs clamped to a sequence of `seq_len` bases
+pub fn exon_ranges(exons: &[u32], seq_len: usize) -> Vec<(usize, usize)> {
+  exons
+    .chunks_exact(2)
+    .map(|pair| {
+      let start = (pair[0] as usize).min(seq_len);
+      let end = (pair[1] as usize).clamp(start, seq_len);
+      (start, end)
+    })
+    .collect()
+}
+
+// ============================================================================
+// Exon-by-Exon Comparison
+// ============================================================================
+pub struct ExonComparison {
+  pub range1: Option<(usize, usize)>,
+  pub range2: Option<(usize, usize)>,
+  /// Placement of the pair, when both exons are present and non-empty
+  pub alignment: Option<Alignment>,
+}
+
+/// Exon N of seq1 against exon N of seq2 for every annotated exon, each pair placed
+/// independently by `place`; the first error it gives ends the comparison
+pub fn compare_exons<E>(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  exons1: &[u32],
+  exons2: &[u32],
+  mut place: impl FnMut(&[u8], &[u8]) -> Result<Alignment, E>,
+) -> Result<Vec<ExonComparison>, E> {
+  let ranges1 = exon_ranges(exons1, bytes1.len());
+  let ranges2 = exon_ranges(exons2, bytes2.len());
+  let count = ranges1.len().max(ranges2.len());
+  let mut exons = Vec::with_capacity(count);
+
+  for i in 0..count {
+    let range1 = ranges1.get(i).copied();
+    let range2 = ranges2.get(i).copied();
+    let alignment = match (range1, range2) {
+      (Some((s1, e1)), Some((s2, e2))) if s1 < e1 && s2 < e2 => Some(place(&bytes1[s1..e1], &bytes2[s2..e2])?),
+      _ => None,
+    };
+    exons.push(ExonComparison { range1, range2, alignment });
+  }
+  Ok(exons)
+}
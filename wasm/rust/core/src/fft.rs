@@ -0,0 +1,152 @@
+// ============================================================================
+// FFT Cross-Correlation
+// ============================================================================
+// Exact per-offset match counts for two byte sequences in O(A·N log N), where
+// A is the number of symbols shared by both inputs. Each symbol's indicator
+// sequences are correlated in the frequency domain; by linearity the spectra
+// are summed and transformed back once.
+
+#[derive(Clone, Copy)]
+struct Complex {
+  re: f64,
+  im: f64,
+}
+
+impl Complex {
+  const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+
+  fn mul(self, other: Complex) -> Complex {
+    Complex {
+      re: self.re * other.re - self.im * other.im,
+      im: self.re * other.im + self.im * other.re,
+    }
+  }
+}
+
+/// In-place iterative radix-2 FFT; `data.len()` must be a power of two
+fn fft(data: &mut [Complex], inverse: bool) {
+  let n = data.len();
+  let mut j = 0;
+  for i in 1..n {
+    let mut bit = n >> 1;
+    while j & bit != 0 {
+      j ^= bit;
+      bit >>= 1;
+    }
+    j |= bit;
+    if i < j {
+      data.swap(i, j);
+    }
+  }
+
+  let mut len = 2;
+  while len <= n {
+    let angle = 2.0 * std::f64::consts::PI / len as f64 * if inverse { 1.0 } else { -1.0 };
+    let step = Complex { re: angle.cos(), im: angle.sin() };
+    for chunk in data.chunks_exact_mut(len) {
+      let mut w = Complex { re: 1.0, im: 0.0 };
+      let (lo, hi) = chunk.split_at_mut(len / 2);
+      for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+        let t = b.mul(w);
+        *b = Complex { re: a.re - t.re, im: a.im - t.im };
+        *a = Complex { re: a.re + t.re, im: a.im + t.im };
+        w = w.mul(step);
+      }
+    }
+    len <<= 1;
+  }
+
+  if inverse {
+    for value in data.iter_mut() {
+      value.re /= n as f64;
+      value.im /= n as f64;
+    }
+  }
+}
+
+/// Heap bytes `match_counts` allocates for inputs of these lengths
+pub(crate) fn match_counts_scratch_bytes(len_a: usize, len_b: usize) -> usize {
+  let out_len = len_a + len_b - 1;
+  3 * out_len.next_power_of_two() * std::mem::size_of::<Complex>() + out_len * std::mem::size_of::<usize>()
+}
+
+/// Number of identical positions for every placement of `b` against `a`.
+/// Entry `offset + b.len() - 1` holds the count where b starts `offset` bases into a
+/// (offset runs from `-(b.len() - 1)` to `a.len() - 1`).
+pub(crate) fn match_counts(a: &[u8], b: &[u8]) -> Vec<usize> {
+  let out_len = a.len() + b.len() - 1;
+  let n = out_len.next_power_of_two();
+
+  let mut present_a = [false; 256];
+  let mut present_b = [false; 256];
+  a.iter().for_each(|&c| present_a[c as usize] = true);
+  b.iter().for_each(|&c| present_b[c as usize] = true);
+
+  let mut total = vec![Complex::ZERO; n];
+  let mut spectrum_a = vec![Complex::ZERO; n];
+  let mut spectrum_b = vec![Complex::ZERO; n];
+  for symbol in 0..256 {
+    if !(present_a[symbol] && present_b[symbol]) {
+      continue;
+    }
+    spectrum_a.fill(Complex::ZERO);
+    spectrum_b.fill(Complex::ZERO);
+    for (i, &c) in a.iter().enumerate() {
+      if c as usize == symbol {
+        spectrum_a[i].re = 1.0;
+      }
+    }
+    // b is reversed so that convolution computes correlation
+    for (i, &c) in b.iter().rev().enumerate() {
+      if c as usize == symbol {
+        spectrum_b[i].re = 1.0;
+      }
+    }
+    fft(&mut spectrum_a, false);
+    fft(&mut spectrum_b, false);
+    for ((t, x), y) in total.iter_mut().zip(&spectrum_a).zip(&spectrum_b) {
+      let p = x.mul(*y);
+      t.re += p.re;
+      t.im += p.im;
+    }
+  }
+
+  fft(&mut total, true);
+  total[..out_len].iter().map(|c| c.re.round().max(0.0) as usize).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Matches at every placement, counted base by base
+  fn naive_counts(a: &[u8], b: &[u8]) -> Vec<usize> {
+    (-(b.len() as i64 - 1)..a.len() as i64)
+      .map(|offset| {
+        (0..b.len() as i64)
+          .filter(|&j| {
+            let i = offset + j;
+            i >= 0 && (i as usize) < a.len() && a[i as usize] == b[j as usize]
+          })
+          .count()
+      })
+      .collect()
+  }
+
+  #[test]
+  fn match_counts_equal_the_naive_scan() {
+    let mut state = 7u64;
+    let mut random = |len: usize| -> Vec<u8> {
+      (0..len)
+        .map(|_| {
+          state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+          b"ACGTN"[(state >> 33) as usize % 5]
+        })
+        .collect()
+    };
+    for (len_a, len_b) in [(1, 1), (37, 5), (5, 37), (300, 211)] {
+      let (a, b) = (random(len_a), random(len_b));
+      assert_eq!(match_counts(&a, &b), naive_counts(&a, &b), "{} x {}", len_a, len_b);
+    }
+  }
+}
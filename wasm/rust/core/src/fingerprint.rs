@@ -0,0 +1,41 @@
+// ============================================================================
+// Result Fingerprints
+// ============================================================================
+// Every nucleotide and protein result ends with a `fingerprint`: the 64-bit
+// FNV-1a hash of the result JSON as emitted without that field, in hex.
+// Results are built with fixed field order, no whitespace, and the shortest
+// round-trip formatting of floats, so the same inputs give byte-identical JSON
+// (and fingerprint) on every platform and in every build configuration
+// (wasm or native, `simd`, `threads`). Regression pipelines can diff
+// fingerprints instead of whole results.
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a over `bytes`; fixed by design, unlike `std`'s hashers
+pub fn fingerprint(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &b| (hash ^ b as u64).wrapping_mul(FNV_PRIME))
+}
+
+/// Append the `fingerprint` field to a result object
+pub fn with_fingerprint(mut json: String) -> String {
+  let hash = fingerprint(json.as_bytes());
+  json.pop(); // Closing brace
+  json.push_str(&format!(r#","fingerprint":"{:016x}"}}"#, hash));
+  json
+}
+
+/// Add `fields` (encoded `"name":value` pairs) to a fingerprinted result and fingerprint
+/// it again; results without a fingerprint (errors) are returned unchanged
+pub fn extend_result(mut json: String, fields: &str) -> String {
+  match json.rfind(r#","fingerprint":""#) {
+    Some(at) if !fields.is_empty() => {
+      json.truncate(at);
+      json.push(',');
+      json.push_str(fields);
+      json.push('}');
+      with_fingerprint(json)
+    }
+    _ => json,
+  }
+}
@@ -0,0 +1,56 @@
+use crate::symbols::MaskSymbols;
+
+// ============================================================================
+// Result Format
+// ============================================================================
+// How a result is written out, as opposed to what is compared: the mask
+// symbols and the coordinate system of its positions. Neither changes the
+// alignment, identity, or blocks found. Each result names its coordinate
+// system in `coordinateSystem`.
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum CoordinateSystem {
+  /// Offsets and block starts index the first residue from 0; block ends are one past the last
+  #[default]
+  ZeroBased,
+  /// Offsets and block starts number the first residue from 1; block ends are the last residue
+  /// (NCBI and GenBank style)
+  OneBased,
+}
+
+impl CoordinateSystem {
+  pub fn name(self) -> &'static str {
+    match self {
+      CoordinateSystem::ZeroBased => "0-based-half-open",
+      CoordinateSystem::OneBased => "1-based-inclusive",
+    }
+  }
+
+  /// Position of a range's first residue, given as a 0-based index
+  pub fn start(self, start: usize) -> usize {
+    match self {
+      CoordinateSystem::ZeroBased => start,
+      CoordinateSystem::OneBased => start + 1,
+    }
+  }
+
+  /// 0-based index of a range's first residue given in this system (the inverse of `start`)
+  pub fn start_index(self, start: i32) -> i32 {
+    match self {
+      CoordinateSystem::ZeroBased => start,
+      CoordinateSystem::OneBased => start - 1,
+    }
+  }
+
+  /// Position of a range's end, given as a 0-based exclusive end; the same number is the
+  /// 1-based position of the last residue
+  pub fn end(self, end: usize) -> usize {
+    end
+  }
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct ResultFormat {
+  pub symbols: MaskSymbols,
+  pub coordinates: CoordinateSystem,
+}
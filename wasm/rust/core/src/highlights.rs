@@ -0,0 +1,83 @@
+use crate::blocks::ConservedBlock;
+use crate::format::ResultFormat;
+use crate::symbols::{GAP, MISMATCH};
+
+// ============================================================================
+// Highlight Ranges
+// ============================================================================
+// Front ends colour the aligned columns by what they hold. Rather than have
+// them rescan the mask on every frame, the columns are classified once and
+// merged into runs, with the conserved blocks as a second layer of ranges
+// over the same columns.
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HighlightKind {
+  Match,
+  Mismatch,
+  /// Either sequence has a gap ('-')
+  Gap,
+  /// Either sequence is soft-masked (lowercase) or unknown (N) at the column
+  Masked,
+  /// A conserved block; overlaps the column runs
+  Block,
+}
+
+impl HighlightKind {
+  pub fn name(self) -> &'static str {
+    match self {
+      HighlightKind::Match => "match",
+      HighlightKind::Mismatch => "mismatch",
+      HighlightKind::Gap => "gap",
+      HighlightKind::Masked => "masked",
+      HighlightKind::Block => "block",
+    }
+  }
+}
+
+/// Columns `start..end` of the alignment (0-based, half-open, like block positions)
+pub struct HighlightRange {
+  pub start: usize,
+  pub end: usize,
+  pub kind: HighlightKind,
+}
+
+impl HighlightRange {
+  pub fn to_json(&self, format: &ResultFormat) -> String {
+    format!(
+      r#"{{"start":{},"end":{},"kind":"{}"}}"#,
+      format.coordinates.start(self.start),
+      format.coordinates.end(self.end),
+      self.kind.name()
+    )
+  }
+}
+
+fn column_kind(a: u8, b: u8, symbol: u8) -> HighlightKind {
+  let masked = |c: u8| c.is_ascii_lowercase() || c == b'N';
+  if a == GAP || b == GAP {
+    HighlightKind::Gap
+  } else if masked(a) || masked(b) {
+    HighlightKind::Masked
+  } else if symbol == MISMATCH {
+    HighlightKind::Mismatch
+  } else {
+    HighlightKind::Match
+  }
+}
+
+/// The aligned columns as runs of one kind, plus one range per conserved block, sorted by
+/// start (a block sorts after the column run starting with it); `region1` and `region2` are
+/// the aligned regions the mask and blocks were built from
+pub fn highlight_ranges(region1: &[u8], region2: &[u8], mask: &[u8], blocks: &[ConservedBlock]) -> Vec<HighlightRange> {
+  let mut ranges: Vec<HighlightRange> = Vec::new();
+  for (column, (&symbol, (&a, &b))) in mask.iter().zip(region1.iter().zip(region2)).enumerate() {
+    let kind = column_kind(a, b, symbol);
+    match ranges.last_mut() {
+      Some(last) if last.kind == kind => last.end = column + 1,
+      _ => ranges.push(HighlightRange { start: column, end: column + 1, kind }),
+    }
+  }
+  ranges.extend(blocks.iter().map(|block| HighlightRange { start: block.start, end: block.end, kind: HighlightKind::Block }));
+  ranges.sort_by_key(|range| (range.start, range.kind));
+  ranges
+}
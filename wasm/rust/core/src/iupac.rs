@@ -0,0 +1,47 @@
+// ============================================================================
+// IUPAC Nucleotide Codes
+// ============================================================================
+// Each code is represented as a 4-bit set of the bases it may stand for:
+// A=1, C=2, G=4, T=8 (U is treated as T).
+
+const IUPAC_CODES: &[u8; 16] = b"-ACMGRSVTWYHKDBN";
+
+pub fn base_set(c: u8) -> u8 {
+  match c.to_ascii_uppercase() {
+    b'A' => 0b0001,
+    b'C' => 0b0010,
+    b'G' => 0b0100,
+    b'T' | b'U' => 0b1000,
+    b'M' => 0b0011,
+    b'R' => 0b0101,
+    b'W' => 0b1001,
+    b'S' => 0b0110,
+    b'Y' => 0b1010,
+    b'K' => 0b1100,
+    b'V' => 0b0111,
+    b'H' => 0b1011,
+    b'D' => 0b1101,
+    b'B' => 0b1110,
+    b'N' => 0b1111,
+    _ => 0,
+  }
+}
+
+pub fn set_to_code(set: u8) -> u8 {
+  IUPAC_CODES[(set & 0b1111) as usize]
+}
+
+/// The set of the complementary bases (A<->T, C<->G): R (A/G) becomes Y (C/T)
+pub fn complement_set(set: u8) -> u8 {
+  (set & 0b0001) << 3 | (set & 0b0010) << 1 | (set & 0b0100) >> 1 | (set & 0b1000) >> 3
+}
+
+/// Reverse complement of a sequence of IUPAC codes (uppercase); other characters become '-'
+pub fn reverse_complement(seq: &[u8]) -> Vec<u8> {
+  seq.iter().rev().map(|&c| set_to_code(complement_set(base_set(c)))).collect()
+}
+
+/// Whether `seq` reads the same on both strands (GAATTC does, and so does GANTC)
+pub fn is_palindromic(seq: &[u8]) -> bool {
+  seq.iter().zip(seq.iter().rev()).all(|(&a, &b)| base_set(a) == complement_set(base_set(b)))
+}
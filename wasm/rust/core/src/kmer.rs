@@ -0,0 +1,61 @@
+// ============================================================================
+// 2-bit k-mer Encoding
+// ============================================================================
+// k-mers up to 32 bases are packed into a u64 (A=0, C=1, G=2, T=3). Windows
+// containing anything other than ACGT/U are skipped.
+
+pub const MAX_K: usize = 32;
+
+pub fn base_code(c: u8) -> Option<u64> {
+  match c {
+    b'A' | b'a' => Some(0),
+    b'C' | b'c' => Some(1),
+    b'G' | b'g' => Some(2),
+    b'T' | b't' | b'U' | b'u' => Some(3),
+    _ => None,
+  }
+}
+
+/// Rolling (position, forward code, reverse-complement code) for every valid k-mer
+pub fn kmers(seq: &[u8], k: usize) -> Vec<(usize, u64, u64)> {
+  let mut out = Vec::new();
+  if k == 0 || k > MAX_K || seq.len() < k {
+    return out;
+  }
+  let mask = if k == MAX_K { u64::MAX } else { (1u64 << (2 * k)) - 1 };
+  let shift = 2 * (k as u64 - 1);
+  let mut forward = 0u64;
+  let mut reverse = 0u64;
+  let mut valid = 0;
+  for (i, &c) in seq.iter().enumerate() {
+    match base_code(c) {
+      Some(code) => {
+        forward = ((forward << 2) | code) & mask;
+        reverse = (reverse >> 2) | ((3 - code) << shift);
+        valid += 1;
+      }
+      None => valid = 0,
+    }
+    if valid >= k {
+      out.push((i + 1 - k, forward, reverse));
+    }
+  }
+  out
+}
+
+/// Strand-independent code: the smaller of a k-mer and its reverse complement
+pub fn canonical_kmers(seq: &[u8], k: usize) -> impl Iterator<Item = u64> {
+  kmers(seq, k).into_iter().map(|(_, forward, reverse)| forward.min(reverse))
+}
+
+pub fn decode_kmer(code: u64, k: usize) -> String {
+  (0..k)
+    .rev()
+    .map(|i| match (code >> (2 * i)) & 0b11 {
+      0 => 'A',
+      1 => 'C',
+      2 => 'G',
+      _ => 'T',
+    })
+    .collect()
+}
@@ -0,0 +1,103 @@
+use crate::translation::StartCodons;
+use crate::CODON_SIZE;
+
+// ============================================================================
+// Kozak Context
+// ============================================================================
+// Ribosomes scan an mRNA from its 5' cap and usually start at the first start
+// codon in a good context, gccRccAUGG (Kozak 1987): a purine at -3 and a G at
+// +4 matter most, the GCCGCC run upstream much less. An upstream ATG in a weak
+// context is mostly skipped, so taking the first ATG as the CDS start picks
+// uORFs in long 5' UTRs. Scoring the context lets CDS inference skip them.
+
+/// Consensus bases at -6..-1 (before the start codon); -3 is scored separately
+const UPSTREAM_CONSENSUS: &[u8; 6] = b"GCCRCC";
+/// Points for a purine at -3 and a G at +4, the two positions that decide strength
+const MINUS_THREE_POINTS: u32 = 3;
+const PLUS_FOUR_POINTS: u32 = 2;
+/// Highest possible score: both key positions plus the five other upstream consensus bases
+pub const MAX_KOZAK_SCORE: u32 = MINUS_THREE_POINTS + PLUS_FOUR_POINTS + 5;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KozakStrength {
+  /// Neither a purine at -3 nor a G at +4
+  Weak,
+  /// One of the two
+  Adequate,
+  /// Both
+  Strong,
+}
+
+impl KozakStrength {
+  pub fn name(self) -> &'static str {
+    match self {
+      KozakStrength::Weak => "weak",
+      KozakStrength::Adequate => "adequate",
+      KozakStrength::Strong => "strong",
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct KozakContext {
+  /// 0 to `MAX_KOZAK_SCORE`
+  pub score: u32,
+  pub strength: KozakStrength,
+}
+
+impl KozakContext {
+  pub fn to_json(&self) -> String {
+    format!(r#"{{"score":{},"maxScore":{},"strength":"{}"}}"#, self.score, MAX_KOZAK_SCORE, self.strength.name())
+  }
+}
+
+/// Context of the start codon at `start` (case-insensitive); positions before the
+/// sequence start or past its end count as mismatches
+pub fn kozak_context(seq: &[u8], start: usize) -> KozakContext {
+  let base = |offset: isize| {
+    let pos = start as isize + offset;
+    (pos >= 0).then(|| seq.get(pos as usize).map(u8::to_ascii_uppercase)).flatten()
+  };
+  let purine = matches!(base(-3), Some(b'A' | b'G'));
+  let g_plus_four = base(CODON_SIZE as isize) == Some(b'G');
+
+  let mut score = 0;
+  if purine {
+    score += MINUS_THREE_POINTS;
+  }
+  if g_plus_four {
+    score += PLUS_FOUR_POINTS;
+  }
+  for (i, &consensus) in UPSTREAM_CONSENSUS.iter().enumerate() {
+    let offset = i as isize - UPSTREAM_CONSENSUS.len() as isize;
+    if offset != -3 && base(offset) == Some(consensus) {
+      score += 1;
+    }
+  }
+
+  let strength = match (purine, g_plus_four) {
+    (true, true) => KozakStrength::Strong,
+    (false, false) => KozakStrength::Weak,
+    _ => KozakStrength::Adequate,
+  };
+  KozakContext { score, strength }
+}
+
+/// The start codon a scanning ribosome would most likely use: the first one of the
+/// strongest context found in `seq`, with its context
+pub fn kozak_start(seq: &[u8], start_codons: &StartCodons) -> Option<(usize, KozakContext)> {
+  let mut best: Option<(usize, KozakContext)> = None;
+  for pos in 0..seq.len().saturating_sub(CODON_SIZE - 1) {
+    if !start_codons.contains(&seq[pos..pos + CODON_SIZE]) {
+      continue;
+    }
+    let context = kozak_context(seq, pos);
+    if best.is_none_or(|(_, best)| context.strength > best.strength) {
+      best = Some((pos, context));
+      if context.strength == KozakStrength::Strong {
+        break;
+      }
+    }
+  }
+  best
+}
@@ -0,0 +1,58 @@
+//! Ungapped nucleotide and protein comparison of two gene sequences: offset
+//! search, translation and reading-frame selection, and conserved-block
+//! detection. This crate has no wasm-bindgen dependencies; `dna-wasm-rust`
+//! wraps it for the browser and Node.
+
+pub mod alignment;
+pub mod blocks;
+pub mod codon_optimized;
+pub mod comparison;
+pub mod complexity;
+pub mod consensus;
+pub mod crispr;
+mod diagnostics;
+pub mod editing;
+pub mod exons;
+mod fft;
+pub mod fingerprint;
+pub mod format;
+pub mod highlights;
+pub mod iupac;
+pub mod kmer;
+pub mod kozak;
+pub mod limits;
+pub mod mirna;
+pub mod motif;
+pub mod msa;
+pub mod normalize;
+pub mod observer;
+pub mod ortholog;
+mod packed;
+mod parallel;
+pub mod pattern;
+pub mod plasmid;
+pub mod polya;
+pub mod primers;
+pub mod profile;
+pub mod quality;
+pub mod rearrangement;
+pub mod regions;
+pub mod restriction;
+pub mod scoring;
+mod simd;
+pub mod sketch;
+pub mod spectrum;
+pub mod suffix;
+pub mod symbols;
+pub mod translation;
+pub mod tree;
+pub mod variants;
+
+// ============================================================================
+// Constants
+// ============================================================================
+pub const CODON_SIZE: usize = 3; // Fundamental: 3 nucleotides = 1 codon (does not change)
+pub const DEFAULT_MIN_SEQUENCE_OVERLAP_PCT: f64 = 0.5; // Matches MIN_SEQUENCE_OVERLAP_PCT in lib/constants.js
+pub const DEFAULT_SEGMENT_WINDOW_LENGTH: usize = 66; // Matches SEGMENT_WINDOW_LENGTH in lib/constants.js
+pub const DEFAULT_MIN_IDENTITY: f64 = 0.67; // Matches MIN_IDENTITY in lib/constants.js
+pub const DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP: f64 = 0.15; // Matches MIN_SIGNIFICANT_LENGTH_GROUP in lib/constants.js
@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ============================================================================
+// Memory Limits
+// ============================================================================
+// Before allocating its working buffers a comparison estimates how much heap
+// it needs; above the configured cap it returns an error object instead of
+// growing WASM memory until the instance traps with an opaque OOM.
+
+/// Default cap, well inside the 4 GiB a wasm32 instance can address
+const DEFAULT_MEMORY_LIMIT: usize = 1 << 30;
+
+static MEMORY_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_MEMORY_LIMIT);
+
+/// Set the scratch-memory cap in bytes for a single comparison (0 removes the cap)
+pub fn set_memory_limit(bytes: usize) {
+  MEMORY_LIMIT.store(bytes, Ordering::Relaxed);
+}
+
+/// Current scratch-memory cap in bytes (0 means unlimited)
+pub fn memory_limit() -> usize {
+  MEMORY_LIMIT.load(Ordering::Relaxed)
+}
+
+pub struct LimitExceeded {
+  message: &'static str,
+  pub required: usize,
+  pub limit: usize,
+}
+
+impl LimitExceeded {
+  pub fn to_json(&self) -> String {
+    format!(
+      r#"{{"error":"{}","code":"memoryLimit","requiredBytes":{},"limitBytes":{}}}"#,
+      self.message, self.required, self.limit
+    )
+  }
+}
+
+pub const EXHAUSTIVE_TOO_LARGE: &str = "sequences too large for the exhaustive offset search; try seeded mode (Comparator.compare_seeded) or raise set_memory_limit";
+pub const SEEDED_TOO_LARGE: &str = "sequences too large for seeded mode; raise set_memory_limit";
+pub const SUFFIX_ARRAY_TOO_LARGE: &str = "sequence too large to index; raise set_memory_limit";
+
+/// Ok when `required` bytes fit under the cap
+pub fn check_memory(required: usize, message: &'static str) -> Result<(), LimitExceeded> {
+  let limit = memory_limit();
+  if limit != 0 && required > limit {
+    return Err(LimitExceeded { message, required, limit });
+  }
+  Ok(())
+}
@@ -0,0 +1,208 @@
+use crate::alignment::Alignment;
+use crate::iupac::reverse_complement;
+
+// ============================================================================
+// microRNA Seed Sites
+// ============================================================================
+// A miRNA finds its targets mostly through its seed, nucleotides 2-8 from the
+// 5' end, pairing with a 3'UTR. Read 5' to 3' on the mRNA, a site is the
+// reverse complement of the seed, optionally preceded by a match to
+// nucleotide 8 and followed by an A across from nucleotide 1. The canonical
+// site types, from most to least effective (Bartel 2009), are 8mer, 7mer-m8,
+// 7mer-A1, and 6mer; every site contains the 6mer core pairing with
+// nucleotides 2-7, which is where sites are placed and compared.
+
+/// Length of the 6mer core (nucleotides 2-7)
+const CORE_LENGTH: usize = 6;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SiteType {
+  SixMer,
+  SevenMerA1,
+  SevenMerM8,
+  EightMer,
+}
+
+impl SiteType {
+  pub fn name(self) -> &'static str {
+    match self {
+      SiteType::SixMer => "6mer",
+      SiteType::SevenMerA1 => "7mer-A1",
+      SiteType::SevenMerM8 => "7mer-m8",
+      SiteType::EightMer => "8mer",
+    }
+  }
+
+  /// Bases before the core the site covers (the nucleotide-8 match), and after it (the A)
+  fn flanks(self) -> (usize, usize) {
+    match self {
+      SiteType::SixMer => (0, 0),
+      SiteType::SevenMerA1 => (0, 1),
+      SiteType::SevenMerM8 => (1, 0),
+      SiteType::EightMer => (1, 1),
+    }
+  }
+}
+
+pub struct Seed {
+  pub name: String,
+  /// Nucleotides 2-8 as DNA (nucleotide 8 missing when only a 6-base seed was given)
+  pub seed: Vec<u8>,
+}
+
+impl Seed {
+  /// A seed from a mature miRNA (8 or more bases: nucleotides 2-8 are used) or from the
+  /// seed itself (the 7 bases 2-8, or the 6 bases 2-7); RNA or DNA, case-insensitive. `None`
+  /// for anything shorter or with a base other than A/C/G/T/U
+  pub fn new(name: &str, sequence: &str) -> Option<Seed> {
+    let bases: Vec<u8> = sequence.bytes().map(|c| if c.eq_ignore_ascii_case(&b'U') { b'T' } else { c.to_ascii_uppercase() }).collect();
+    if !bases.iter().all(|c| matches!(c, b'A' | b'C' | b'G' | b'T')) {
+      return None;
+    }
+    let seed = match bases.len() {
+      0..CORE_LENGTH => return None,
+      6 | 7 => bases,
+      _ => bases[1..8].to_vec(),
+    };
+    Some(Seed { name: name.to_string(), seed })
+  }
+
+  /// Sites in `utr` (case-insensitive, U read as T) by core position, each of its best type
+  pub fn find_sites(&self, utr: &[u8]) -> Vec<SeedSite> {
+    let utr: Vec<u8> = utr.iter().map(|&c| if c.eq_ignore_ascii_case(&b'U') { b'T' } else { c.to_ascii_uppercase() }).collect();
+    let core = reverse_complement(&self.seed[..CORE_LENGTH]);
+    let position_eight = self.seed.get(CORE_LENGTH).map(|&c| reverse_complement(&[c])[0]);
+    utr
+      .windows(CORE_LENGTH)
+      .enumerate()
+      .filter(|(_, window)| *window == core.as_slice())
+      .map(|(core_start, _)| {
+        let m8 = core_start > 0 && position_eight == Some(utr[core_start - 1]);
+        let a1 = utr.get(core_start + CORE_LENGTH) == Some(&b'A');
+        let site_type = match (m8, a1) {
+          (true, true) => SiteType::EightMer,
+          (true, false) => SiteType::SevenMerM8,
+          (false, true) => SiteType::SevenMerA1,
+          (false, false) => SiteType::SixMer,
+        };
+        SeedSite { core_start, site_type }
+      })
+      .collect()
+  }
+}
+
+/// Seeds from a list of mature miRNA or seed sequences separated by commas, spaces, or
+/// newlines, each optionally named as `name=SEQUENCE` (otherwise named by its sequence);
+/// entries that are not valid seeds are returned separately
+pub fn parse_seeds(list: &str) -> (Vec<Seed>, Vec<String>) {
+  let (mut seeds, mut invalid) = (Vec::new(), Vec::new());
+  for entry in list.split(|c: char| c == ',' || c.is_whitespace()).filter(|entry| !entry.is_empty()) {
+    let (name, sequence) = entry.split_once('=').unwrap_or((entry, entry));
+    match Seed::new(name, sequence) {
+      Some(seed) => seeds.push(seed),
+      None => invalid.push(entry.to_string()),
+    }
+  }
+  (seeds, invalid)
+}
+
+pub struct SeedSite {
+  /// Start of the 6mer core
+  pub core_start: usize,
+  pub site_type: SiteType,
+}
+
+impl SeedSite {
+  /// The bases the site covers, 0-based half-open
+  pub fn span(&self) -> (usize, usize) {
+    let (before, after) = self.site_type.flanks();
+    (self.core_start - before, self.core_start + CORE_LENGTH + after)
+  }
+}
+
+/// A seed site that differs between the two sequences at the same column: created (only seq2
+/// has it), destroyed (only seq1), or changed type
+pub struct SeedSiteChange<'a> {
+  pub seed: &'a Seed,
+  /// Column of the core in the alignment
+  pub column: usize,
+  pub type1: Option<SiteType>,
+  pub type2: Option<SiteType>,
+  /// Mismatching columns over the larger of the two sites
+  pub mismatches: Vec<usize>,
+}
+
+impl SeedSiteChange<'_> {
+  pub fn change(&self) -> &'static str {
+    match (self.type1, self.type2) {
+      (None, _) => "created",
+      (_, None) => "destroyed",
+      _ => "changed",
+    }
+  }
+}
+
+/// Seed sites created, destroyed, or changed in type by the differences over the placement
+/// `alignment`, by column and seed
+pub fn seed_site_changes<'a>(bytes1: &[u8], bytes2: &[u8], alignment: &Alignment, seeds: &'a [Seed]) -> Vec<SeedSiteChange<'a>> {
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let mut changes = Vec::new();
+  for seed in seeds {
+    let (sites1, sites2) = (seed.find_sites(region1), seed.find_sites(region2));
+    let mut columns: Vec<usize> = sites1.iter().chain(&sites2).map(|site| site.core_start).collect();
+    columns.sort_unstable();
+    columns.dedup();
+    for column in columns {
+      let find = |sites: &[SeedSite]| sites.iter().find(|site| site.core_start == column).map(|site| site.site_type);
+      let (type1, type2) = (find(&sites1), find(&sites2));
+      if type1 == type2 {
+        continue;
+      }
+      let widest = type1.max(type2).unwrap_or(SiteType::SixMer);
+      let (start, end) = SeedSite { core_start: column, site_type: widest }.span();
+      let mismatches = (start..end.min(region1.len()))
+        .filter(|&i| !region1[i].eq_ignore_ascii_case(&region2[i]))
+        .collect();
+      changes.push(SeedSiteChange { seed, column, type1, type2, mismatches });
+    }
+  }
+  changes.sort_by(|a, b| (a.column, &a.seed.name).cmp(&(b.column, &b.seed.name)));
+  changes
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const LET_7A: &str = "UGAGGUAGUAGGUUGUAUAGUU";
+
+  #[test]
+  fn seeds_come_from_mature_mirnas_or_the_seed_itself() {
+    let (seeds, invalid) = parse_seeds(&format!("let-7a={}, GAGGUAG,\nGAGGTA XYZ", LET_7A));
+    assert_eq!(invalid, vec!["XYZ"]);
+    assert_eq!(seeds.iter().map(|seed| seed.seed.as_slice()).collect::<Vec<_>>(), vec![&b"GAGGTAG"[..], b"GAGGTAG", b"GAGGTA"]);
+    assert_eq!(seeds[0].name, "let-7a");
+    assert!(Seed::new("short", "GAGGT").is_none());
+  }
+
+  #[test]
+  fn sites_take_their_best_type() {
+    let seed = Seed::new("let-7a", LET_7A).unwrap();
+    let sites = seed.find_sites(b"CTACCTCAGGTACCTCTT");
+    let found: Vec<(usize, &str, (usize, usize))> = sites.iter().map(|site| (site.core_start, site.site_type.name(), site.span())).collect();
+    assert_eq!(found, vec![(1, "8mer", (0, 8)), (10, "6mer", (10, 16))]);
+    // Without nucleotide 8 no site can be m8
+    assert_eq!(Seed::new("core", "GAGGTA").unwrap().find_sites(b"CTACCTCA")[0].site_type.name(), "7mer-A1");
+  }
+
+  #[test]
+  fn changes_are_created_destroyed_or_retyped() {
+    let seeds = [Seed::new("let-7a", LET_7A).unwrap()];
+    let alignment = Alignment { offset1: 0, offset2: 0, length: 18, mismatches: 2, identity: 16.0 / 18.0 };
+    let changes = seed_site_changes(b"CTACCTCAGGTACCTCTT", b"CTACCTCGGGTACGTCTT", &alignment, &seeds);
+    let found: Vec<(usize, &str, Vec<usize>)> = changes.iter().map(|change| (change.column, change.change(), change.mismatches.clone())).collect();
+    assert_eq!(found, vec![(1, "changed", vec![7]), (10, "destroyed", vec![13])]);
+    assert!(changes[0].type2 == Some(SiteType::SevenMerM8));
+  }
+}
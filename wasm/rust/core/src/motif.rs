@@ -0,0 +1,264 @@
+use crate::alignment::Alignment;
+
+// ============================================================================
+// Position Weight Matrices
+// ============================================================================
+// Matrices are read from JASPAR (`A [ 4 19 0 ... ]` rows, with or without
+// letters and brackets) or MEME (`letter-probability matrix:` blocks) text.
+// Each column becomes log2-odds against a uniform background after a small
+// pseudocount, and hits are windows whose score reaches a fraction of the way
+// from the matrix's lowest to its highest possible score, both strands.
+
+/// Frequency added to each base of a column so unseen bases don't score minus infinity
+const PSEUDO_FREQUENCY: f64 = 0.01;
+/// Relative score a window needs to count as a hit when the caller gives none
+pub const DEFAULT_MOTIF_THRESHOLD: f64 = 0.85;
+
+pub struct Motif {
+  pub id: String,
+  pub name: String,
+  /// Log2-odds of A, C, G, T per position
+  pub weights: Vec<[f64; 4]>,
+}
+
+fn base_index(c: u8) -> Option<usize> {
+  match c.to_ascii_uppercase() {
+    b'A' => Some(0),
+    b'C' => Some(1),
+    b'G' => Some(2),
+    b'T' | b'U' => Some(3),
+    _ => None,
+  }
+}
+
+impl Motif {
+  /// Counts or frequencies per position (A, C, G, T) to log-odds; `None` for an empty matrix
+  /// or a column without counts
+  fn from_columns(id: &str, name: &str, columns: &[[f64; 4]]) -> Option<Motif> {
+    let mut weights = Vec::with_capacity(columns.len());
+    for column in columns {
+      let total: f64 = column.iter().sum();
+      if total <= 0.0 || column.iter().any(|&count| count < 0.0) {
+        return None;
+      }
+      weights.push(column.map(|count| ((count / total + PSEUDO_FREQUENCY) / (1.0 + 4.0 * PSEUDO_FREQUENCY) / 0.25).log2()));
+    }
+    (!weights.is_empty()).then(|| Motif { id: id.to_string(), name: name.to_string(), weights })
+  }
+
+  pub fn width(&self) -> usize {
+    self.weights.len()
+  }
+
+  fn min_score(&self) -> f64 {
+    self.weights.iter().map(|column| column.iter().cloned().fold(f64::INFINITY, f64::min)).sum()
+  }
+
+  fn max_score(&self) -> f64 {
+    self.weights.iter().map(|column| column.iter().cloned().fold(f64::NEG_INFINITY, f64::max)).sum()
+  }
+
+  /// Score of `window` (one base per position) on the given strand, scaled to 0-1 between
+  /// the lowest and highest possible scores; `None` if it has a base other than A/C/G/T/U
+  pub fn relative_score(&self, window: &[u8], reverse: bool) -> Option<f64> {
+    let mut score = 0.0;
+    for (i, column) in self.weights.iter().enumerate() {
+      score += if reverse {
+        // The reverse strand reads the complement backwards: position i pairs with the
+        // window's base from the other end
+        column[3 - base_index(window[window.len() - 1 - i])?]
+      } else {
+        column[base_index(window[i])?]
+      };
+    }
+    let (min, max) = (self.min_score(), self.max_score());
+    Some(if max > min { (score - min) / (max - min) } else { 1.0 })
+  }
+
+  /// Windows of `seq` scoring at least `threshold` on either strand, by position
+  pub fn scan(&self, seq: &[u8], threshold: f64) -> Vec<MotifHit> {
+    let mut hits = Vec::new();
+    for (position, window) in seq.windows(self.width()).enumerate() {
+      for reverse in [false, true] {
+        if let Some(score) = self.relative_score(window, reverse).filter(|&score| score >= threshold) {
+          hits.push(MotifHit { position, reverse, score });
+        }
+      }
+    }
+    hits
+  }
+}
+
+pub struct MotifHit {
+  pub position: usize,
+  pub reverse: bool,
+  /// Relative score, 0-1
+  pub score: f64,
+}
+
+/// Motifs in JASPAR or MEME text; matrices that cannot be read are skipped. JASPAR matrices
+/// take their id and name from the preceding `>` header, MEME ones from their `MOTIF` line
+pub fn parse_motifs(text: &str) -> Vec<Motif> {
+  if text.contains("letter-probability matrix") {
+    parse_meme(text)
+  } else {
+    parse_jaspar(text)
+  }
+}
+
+fn numbers(line: &str) -> Vec<f64> {
+  line
+    .split(|c: char| c.is_whitespace() || c == '[' || c == ']' || c == ',')
+    .filter_map(|token| token.parse().ok())
+    .collect()
+}
+
+fn parse_jaspar(text: &str) -> Vec<Motif> {
+  let mut motifs = Vec::new();
+  let (mut id, mut name) = (String::new(), String::new());
+  let mut rows: Vec<Vec<f64>> = Vec::new();
+  let mut flush = |id: &str, name: &str, rows: &mut Vec<Vec<f64>>| {
+    if rows.len() == 4 && rows.iter().all(|row| row.len() == rows[0].len()) {
+      let columns: Vec<[f64; 4]> = (0..rows[0].len()).map(|i| [rows[0][i], rows[1][i], rows[2][i], rows[3][i]]).collect();
+      motifs.extend(Motif::from_columns(id, name, &columns));
+    }
+    rows.clear();
+  };
+  for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+    if let Some(header) = line.strip_prefix('>') {
+      flush(&id, &name, &mut rows);
+      let mut parts = header.split_whitespace();
+      id = parts.next().unwrap_or_default().to_string();
+      name = parts.collect::<Vec<_>>().join(" ");
+    } else {
+      let row = numbers(line);
+      if !row.is_empty() {
+        rows.push(row);
+      }
+      if rows.len() == 4 {
+        flush(&id, &name, &mut rows);
+      }
+    }
+  }
+  flush(&id, &name, &mut rows);
+  motifs
+}
+
+fn parse_meme(text: &str) -> Vec<Motif> {
+  let mut motifs = Vec::new();
+  let (mut id, mut name) = (String::new(), String::new());
+  let mut lines = text.lines().map(str::trim).peekable();
+  while let Some(line) = lines.next() {
+    if let Some(rest) = line.strip_prefix("MOTIF") {
+      let mut parts = rest.split_whitespace();
+      id = parts.next().unwrap_or_default().to_string();
+      name = parts.collect::<Vec<_>>().join(" ");
+    } else if line.starts_with("letter-probability matrix") {
+      let width = line
+        .split_once("w=")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|width| width.parse::<usize>().ok());
+      let mut columns = Vec::new();
+      while let Some(row) = lines.peek().map(|line| numbers(line)).filter(|row| row.len() == 4) {
+        columns.push([row[0], row[1], row[2], row[3]]);
+        lines.next();
+      }
+      if width.is_none_or(|width| width == columns.len()) {
+        motifs.extend(Motif::from_columns(&id, &name, &columns));
+      }
+    }
+  }
+  motifs
+}
+
+/// A motif hit in one sequence's aligned region that the other sequence lacks at the same
+/// column and strand
+pub struct MotifChange<'a> {
+  pub motif: &'a Motif,
+  pub column: usize,
+  pub reverse: bool,
+  /// Whether seq1 has the hit (lost in seq2) rather than seq2 (gained)
+  pub in_seq1: bool,
+  /// Relative scores of the window in seq1 and seq2 (`None` over an ambiguous base)
+  pub score1: Option<f64>,
+  pub score2: Option<f64>,
+  /// Mismatching columns inside the window
+  pub mismatches: Vec<usize>,
+}
+
+/// Motif hits gained or lost over the placement `alignment`, by column and motif; hits that
+/// run past the overlap are left out
+pub fn motif_changes<'a>(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  motifs: &'a [Motif],
+  threshold: f64,
+) -> Vec<MotifChange<'a>> {
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let mut changes = Vec::new();
+  for motif in motifs {
+    let (hits1, hits2) = (motif.scan(region1, threshold), motif.scan(region2, threshold));
+    let key = |hit: &MotifHit| (hit.position, hit.reverse);
+    for (hits, others, in_seq1) in [(&hits1, &hits2, true), (&hits2, &hits1, false)] {
+      for hit in hits.iter().filter(|hit| !others.iter().any(|other| key(other) == key(hit))) {
+        let columns = hit.position..hit.position + motif.width();
+        let mismatches = columns.clone().filter(|&column| !region1[column].eq_ignore_ascii_case(&region2[column])).collect();
+        changes.push(MotifChange {
+          motif,
+          column: hit.position,
+          reverse: hit.reverse,
+          in_seq1,
+          score1: motif.relative_score(&region1[columns.clone()], hit.reverse),
+          score2: motif.relative_score(&region2[columns], hit.reverse),
+          mismatches,
+        });
+      }
+    }
+  }
+  changes.sort_by(|a, b| (a.column, &a.motif.id, !a.in_seq1).cmp(&(b.column, &b.motif.id, !b.in_seq1)));
+  changes
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const JASPAR: &str = ">MA0001.1 CAGT box\nA [ 0 10 0 0 ]\nC [ 10 0 0 0 ]\nG [ 0 0 10 0 ]\nT [ 0 0 0 10 ]\n";
+  const MEME: &str = "MEME version 4\n\nMOTIF M1 CAGT-box\nletter-probability matrix: alength= 4 w= 4\n0 1 0 0\n1 0 0 0\n0 0 1 0\n0 0 0 1\n";
+
+  #[test]
+  fn both_formats_read_the_same_matrix() {
+    let (jaspar, meme) = (parse_motifs(JASPAR), parse_motifs(MEME));
+    assert_eq!((jaspar.len(), meme.len()), (1, 1));
+    assert_eq!((jaspar[0].id.as_str(), jaspar[0].name.as_str()), ("MA0001.1", "CAGT box"));
+    assert_eq!((meme[0].id.as_str(), meme[0].name.as_str()), ("M1", "CAGT-box"));
+    assert_eq!(jaspar[0].weights, meme[0].weights);
+    // A width that disagrees with the rows is not a matrix
+    assert!(parse_motifs(&MEME.replace("w= 4", "w= 5")).is_empty());
+  }
+
+  #[test]
+  fn hits_are_found_on_both_strands() {
+    let motif = &parse_motifs(JASPAR)[0];
+    assert_eq!(motif.relative_score(b"CAGT", false), Some(1.0));
+    assert_eq!(motif.relative_score(b"CANT", false), None);
+    let hits = motif.scan(b"CAGTTACTG", DEFAULT_MOTIF_THRESHOLD);
+    let found: Vec<(usize, bool)> = hits.iter().map(|hit| (hit.position, hit.reverse)).collect();
+    assert_eq!(found, vec![(0, false), (5, true)]);
+  }
+
+  #[test]
+  fn a_mutated_site_is_lost_in_seq2() {
+    let motifs = parse_motifs(JASPAR);
+    let alignment = Alignment { offset1: 1, offset2: 0, length: 8, mismatches: 1, identity: 7.0 / 8.0 };
+    let changes = motif_changes(b"GTTCAGTTT", b"TTCAATTT", &alignment, &motifs, DEFAULT_MOTIF_THRESHOLD);
+    assert_eq!(changes.len(), 1);
+    let change = &changes[0];
+    assert_eq!((change.column, change.reverse, change.in_seq1), (2, false, true));
+    assert_eq!(change.score1, Some(1.0));
+    assert!(change.score2.unwrap() < DEFAULT_MOTIF_THRESHOLD);
+    assert_eq!(change.mismatches, vec![4]);
+  }
+}
@@ -0,0 +1,240 @@
+use crate::alignment::Alignment;
+use crate::symbols::GAP;
+
+// ============================================================================
+// Center-Star Multiple Comparison
+// ============================================================================
+// The crate's aligner is ungapped, so the multiple alignment is built by
+// placing every sequence at its best offset against a center sequence (the one
+// with the highest summed identity to all others). Columns are center
+// coordinates extended to cover every overhang; uncovered cells are '-'.
+// Pairs are placed by the caller's `place`, which picks the offset search and
+// can refuse a pair (e.g. over a memory limit) with its own error.
+
+pub struct MultiAlignment {
+  pub center: usize,
+  /// Column of each sequence's first base, relative to the first column
+  pub starts: Vec<usize>,
+  pub identities: Vec<f64>,
+  /// Gap-padded rows, all `columns` long
+  pub rows: Vec<Vec<u8>>,
+  pub columns: usize,
+}
+
+impl MultiAlignment {
+  pub fn column(&self, col: usize) -> impl Iterator<Item = u8> + '_ {
+    self.rows.iter().map(move |row| row[col])
+  }
+
+  /// Conservation, entropy, residue frequencies, and consensus of every column
+  pub fn column_profile(&self) -> ColumnProfile {
+    let n = self.rows.len();
+    let max_bits = max_entropy(&self.rows);
+    let mut profile = ColumnProfile::default();
+    for col in 0..self.columns {
+      let counts = column_counts(self.column(col));
+      let (base, count) = column_majority(&counts);
+      let bits = column_entropy(&counts);
+      profile.conservation.push(count as f64 / n as f64);
+      profile.entropy.push(bits);
+      profile.information_content.push(max_bits - bits);
+      profile.frequencies.push(column_frequencies(&counts));
+      profile.consensus.push(base);
+      profile.mask.push(if count == n { base } else { b'?' });
+    }
+    profile
+  }
+}
+
+/// Every sequence placed against the center by `place`; the first error `place` gives for a
+/// pair of non-empty sequences ends the search
+pub fn center_star_alignment<E>(
+  seqs: &[&[u8]],
+  mut place: impl FnMut(&[u8], &[u8]) -> Result<Alignment, E>,
+) -> Result<MultiAlignment, E> {
+  let n = seqs.len();
+
+  // Pairwise placements: shift[i][j] = position in i where j's first base lands
+  let mut identity = vec![vec![0.0; n]; n];
+  let mut shift = vec![vec![0i64; n]; n];
+  for i in 0..n {
+    identity[i][i] = 1.0;
+    for j in (i + 1)..n {
+      if seqs[i].is_empty() || seqs[j].is_empty() {
+        continue;
+      }
+      let alignment = place(seqs[i], seqs[j])?;
+      let s = alignment.offset1 as i64 - alignment.offset2 as i64;
+      identity[i][j] = alignment.identity;
+      identity[j][i] = alignment.identity;
+      shift[i][j] = s;
+      shift[j][i] = -s;
+    }
+  }
+
+  // First sequence wins ties so results are stable for identical inputs
+  let mut center = 0;
+  let mut best_sum = f64::MIN;
+  for (i, row) in identity.iter().enumerate() {
+    let sum: f64 = row.iter().sum();
+    if sum > best_sum {
+      best_sum = sum;
+      center = i;
+    }
+  }
+
+  let first = (0..n).map(|j| shift[center][j]).min().unwrap_or(0);
+  let starts: Vec<usize> = (0..n).map(|j| (shift[center][j] - first) as usize).collect();
+  let columns = (0..n).map(|j| starts[j] + seqs[j].len()).max().unwrap_or(0);
+
+  let rows = (0..n)
+    .map(|j| {
+      let mut row = vec![GAP; columns];
+      row[starts[j]..starts[j] + seqs[j].len()].copy_from_slice(seqs[j]);
+      row
+    })
+    .collect();
+
+  Ok(MultiAlignment { center, starts, identities: identity[center].clone(), rows, columns })
+}
+
+/// Residue counts in a column (gaps excluded), in order of first appearance
+fn column_counts(column: impl Iterator<Item = u8>) -> Vec<(u8, usize)> {
+  let mut counts: Vec<(u8, usize)> = Vec::new();
+  for c in column.filter(|&c| c != GAP) {
+    match counts.iter_mut().find(|(b, _)| *b == c) {
+      Some(entry) => entry.1 += 1,
+      None => counts.push((c, 1)),
+    }
+  }
+  counts
+}
+
+/// Most frequent residue and its count; ties resolve to N.
+fn column_majority(counts: &[(u8, usize)]) -> (u8, usize) {
+  let best = counts.iter().map(|&(_, n)| n).max().unwrap_or(0);
+  let mut leaders = counts.iter().filter(|&&(_, n)| n == best);
+  match (leaders.next(), leaders.next()) {
+    (Some(&(base, n)), None) => (base, n),
+    (Some(_), Some(_)) => (b'N', best),
+    _ => (GAP, 0),
+  }
+}
+
+// ============================================================================
+// Column Entropy
+// ============================================================================
+/// Per-column statistics of a `MultiAlignment`, as parallel vectors
+#[derive(Default)]
+pub struct ColumnProfile {
+  /// Fraction of sequences carrying the majority residue
+  pub conservation: Vec<f64>,
+  /// Shannon entropy in bits over the residues present
+  pub entropy: Vec<f64>,
+  /// Maximum entropy of the alphabet less the column's entropy
+  pub information_content: Vec<f64>,
+  /// Share of each residue present, sorted by residue
+  pub frequencies: Vec<Vec<(u8, f64)>>,
+  /// Majority residue (N on ties)
+  pub consensus: Vec<u8>,
+  /// The residue where every sequence agrees, '?' elsewhere
+  pub mask: Vec<u8>,
+}
+
+/// Shannon entropy in bits over the residues present in a column
+fn column_entropy(counts: &[(u8, usize)]) -> f64 {
+  let total: usize = counts.iter().map(|&(_, n)| n).sum();
+  if total == 0 {
+    return 0.0;
+  }
+  counts
+    .iter()
+    .map(|&(_, n)| {
+      let p = n as f64 / total as f64;
+      p * (1.0 / p).log2()
+    })
+    .sum()
+}
+
+/// Maximum possible entropy: 2 bits for nucleotides, log2(20) for anything else
+fn max_entropy(rows: &[Vec<u8>]) -> f64 {
+  let nucleotide = rows.iter().flatten().all(|&c| c == GAP || b"ACGTUNacgtun".contains(&c));
+  if nucleotide { 2.0 } else { 20f64.log2() }
+}
+
+fn column_frequencies(counts: &[(u8, usize)]) -> Vec<(u8, f64)> {
+  let total: usize = counts.iter().map(|&(_, n)| n).sum();
+  let mut sorted = counts.to_vec();
+  sorted.sort_unstable();
+  sorted.iter().map(|&(c, n)| (c, n as f64 / total as f64)).collect()
+}
+
+// ============================================================================
+// All-vs-All Identity Matrix
+// ============================================================================
+/// Symmetric identity matrix over every pair (diagonal is 1, empty sequences score 0), each
+/// placed by `place`; the first error it gives ends the search
+pub fn identity_matrix<E>(
+  seqs: &[&[u8]],
+  mut place: impl FnMut(&[u8], &[u8]) -> Result<Alignment, E>,
+) -> Result<Vec<Vec<f64>>, E> {
+  let n = seqs.len();
+  let mut matrix = vec![vec![0.0; n]; n];
+  for i in 0..n {
+    matrix[i][i] = 1.0;
+    for j in (i + 1)..n {
+      if seqs[i].is_empty() || seqs[j].is_empty() {
+        continue;
+      }
+      let alignment = place(seqs[i], seqs[j])?;
+      matrix[i][j] = alignment.identity;
+      matrix[j][i] = alignment.identity;
+    }
+  }
+  Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::alignment::find_best_alignment;
+
+  fn place(a: &[u8], b: &[u8]) -> Result<Alignment, ()> {
+    Ok(find_best_alignment(a, b, 0.5))
+  }
+
+  #[test]
+  fn rows_are_padded_onto_the_center_columns() {
+    let full = b"GATTACAGCCTTAGGC";
+    let inner = &full[3..13];
+    let mut variant = full.to_vec();
+    variant[8] = b'T';
+    let msa = center_star_alignment(&[inner, full, &variant], place).ok().unwrap();
+
+    assert_eq!(msa.center, 1);
+    assert_eq!(msa.starts, vec![3, 0, 0]);
+    assert_eq!(msa.columns, full.len());
+    assert_eq!(msa.rows[0], b"---TACAGCCTTA---");
+    assert_eq!(msa.identities[1], 1.0);
+
+    let profile = msa.column_profile();
+    assert_eq!(profile.mask[4], b'A');
+    assert_eq!(profile.mask[0], b'?');
+    assert_eq!(profile.consensus[8], b'C');
+    assert!((profile.conservation[8] - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(profile.entropy[4], 0.0);
+    assert_eq!(profile.information_content[4], 2.0);
+  }
+
+  #[test]
+  fn a_refused_pair_ends_the_search() {
+    let seqs: [&[u8]; 3] = [b"ACGT", b"", b"ACGA"];
+    let matrix = identity_matrix(&seqs, place).ok().unwrap();
+    assert_eq!(matrix[0][1], 0.0);
+    assert_eq!(matrix[0][2], 0.75);
+    assert_eq!(matrix[1][1], 1.0);
+
+    let refuse = |_: &[u8], _: &[u8]| Err::<Alignment, _>("too large");
+    assert_eq!(center_star_alignment(&seqs, refuse).err(), Some("too large"));
+  }
+}
@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::polya::poly_a_tail;
+
+// ============================================================================
+// Input Normalization
+// ============================================================================
+// Comparison is byte-exact: 'a' and 'A' are different residues, and a space or
+// line number in pasted text shifts every following position. Callers that
+// compare text as users paste it (lowercase, GenBank ORIGIN blocks split into
+// numbered lines of ten, RNA with U for T) run it through `Normalization`
+// first. GenBank pastes often include the whole record, so `genbank` also
+// cuts the text down to its ORIGIN section before dropping the coordinates.
+// Every step is off by default, so normalized and raw comparisons are
+// distinguishable, and each step reports how many residues it changed. Offsets
+// in results refer to the normalized sequences.
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Normalization {
+  /// Convert lowercase letters to uppercase
+  pub uppercase: bool,
+  /// Remove spaces, tabs, and line breaks
+  pub strip_whitespace: bool,
+  /// Remove digits (the coordinates at the start of GenBank ORIGIN lines)
+  pub strip_digits: bool,
+  /// Replace U with T (and u with t, unless also uppercasing)
+  pub u_to_t: bool,
+  /// Keep only the ORIGIN section of a pasted GenBank record (see `genbank_origin`) and
+  /// drop its coordinates and spacing, as `strip_digits` and `strip_whitespace` would
+  pub genbank: bool,
+  /// Remove a poly-A tail (see `poly_a_tail`) after the other steps. Not part of `all`:
+  /// it changes what is compared, not just how the input is written
+  pub trim_poly_a: bool,
+}
+
+/// What `Normalization::apply` changed in one sequence
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct NormalizationReport {
+  pub original_length: usize,
+  pub length: usize,
+  pub uppercased: usize,
+  pub whitespace_removed: usize,
+  pub digits_removed: usize,
+  pub uracil_replaced: usize,
+  /// GenBank record text outside the ORIGIN section, in bytes
+  pub genbank_removed: usize,
+  /// Length of the poly-A tail that was trimmed
+  pub poly_a_trimmed: usize,
+}
+
+/// Byte range of the sequence section of a GenBank record: the lines after the `ORIGIN`
+/// line up to the `//` terminator (or the end of the text); `None` without an ORIGIN line
+pub fn genbank_origin(text: &[u8]) -> Option<Range<usize>> {
+  let mut start = None;
+  let mut line_start = 0;
+  for line in text.split_inclusive(|&c| c == b'\n') {
+    let line_end = line_start + line.len();
+    match start {
+      None if line.starts_with(b"ORIGIN") => start = Some(line_end),
+      Some(start) if line.trim_ascii_start().starts_with(b"//") => return Some(start..line_start),
+      _ => {}
+    }
+    line_start = line_end;
+  }
+  start.map(|start| start..text.len())
+}
+
+impl Normalization {
+  /// Every input-cleaning step enabled
+  pub fn all() -> Self {
+    Normalization {
+      uppercase: true,
+      strip_whitespace: true,
+      strip_digits: true,
+      u_to_t: true,
+      genbank: true,
+      trim_poly_a: false,
+    }
+  }
+
+  /// The steps that apply to amino-acid text: U is selenocysteine, not uracil, and runs of
+  /// A are alanines, not a poly-A tail
+  pub fn for_protein(self) -> Self {
+    Normalization { u_to_t: false, trim_poly_a: false, ..self }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.uppercase || self.strip_whitespace || self.strip_digits || self.u_to_t || self.genbank || self.trim_poly_a
+  }
+
+  /// Normalized form of `seq`; borrowed when no step changes anything
+  pub fn apply<'a>(&self, seq: &'a [u8]) -> (Cow<'a, [u8]>, NormalizationReport) {
+    let mut report = NormalizationReport { original_length: seq.len(), length: seq.len(), ..NormalizationReport::default() };
+    if !self.is_enabled() {
+      return (Cow::Borrowed(seq), report);
+    }
+
+    let section = if self.genbank { genbank_origin(seq).unwrap_or(0..seq.len()) } else { 0..seq.len() };
+    report.genbank_removed = seq.len() - section.len();
+    let strip_whitespace = self.strip_whitespace || self.genbank;
+    let strip_digits = self.strip_digits || self.genbank;
+
+    let mut out = Vec::with_capacity(section.len());
+    for &c in &seq[section] {
+      if strip_whitespace && c.is_ascii_whitespace() {
+        report.whitespace_removed += 1;
+        continue;
+      }
+      if strip_digits && c.is_ascii_digit() {
+        report.digits_removed += 1;
+        continue;
+      }
+      let mut c = c;
+      if self.uppercase && c.is_ascii_lowercase() {
+        c = c.to_ascii_uppercase();
+        report.uppercased += 1;
+      }
+      if self.u_to_t && c.eq_ignore_ascii_case(&b'U') {
+        c = if c == b'U' { b'T' } else { b't' };
+        report.uracil_replaced += 1;
+      }
+      out.push(c);
+    }
+
+    if self.trim_poly_a {
+      report.poly_a_trimmed = poly_a_tail(&out);
+      out.truncate(out.len() - report.poly_a_trimmed);
+    }
+
+    report.length = out.len();
+    if out == seq {
+      return (Cow::Borrowed(seq), report);
+    }
+    (Cow::Owned(out), report)
+  }
+}
+
+impl NormalizationReport {
+  pub fn changed(&self) -> bool {
+    self.length != self.original_length || self.uppercased + self.uracil_replaced > 0
+  }
+
+  pub fn to_json(&self) -> String {
+    format!(
+      r#"{{"originalLength":{},"length":{},"uppercased":{},"whitespaceRemoved":{},"digitsRemoved":{},"uracilReplaced":{},"genbankRemoved":{},"polyATrimmed":{}}}"#,
+      self.original_length,
+      self.length,
+      self.uppercased,
+      self.whitespace_removed,
+      self.digits_removed,
+      self.uracil_replaced,
+      self.genbank_removed,
+      self.poly_a_trimmed
+    )
+  }
+}
@@ -0,0 +1,16 @@
+// ============================================================================
+// Progress and Log Observer
+// ============================================================================
+// Long-running comparisons report their phases ("offsetScan", "translation",
+// "mask", "blocks") and diagnostic messages to an observer. Both default to
+// doing nothing; `()` is the silent observer.
+
+pub trait Observer {
+  /// `fraction` (0-1) of `phase` is done
+  fn report(&mut self, _phase: &'static str, _fraction: f64) {}
+
+  /// A diagnostic message formatted for a console, e.g. "[info] truncated: ..."
+  fn log(&mut self, _message: &str) {}
+}
+
+impl Observer for () {}
@@ -0,0 +1,136 @@
+use crate::alignment::Alignment;
+use crate::msa::center_star_alignment;
+use crate::symbols::GAP;
+
+// ============================================================================
+// Ortholog Panel
+// ============================================================================
+// Orthologs from different species drift apart at synonymous sites long
+// before their proteins do, so a panel is compared translated: the query
+// against every ortholog for the ranking, and all of them together for the
+// columns the whole panel conserves.
+
+pub struct Record {
+  pub id: String,
+  pub description: String,
+  pub sequence: String,
+}
+
+/// Records of a multi-FASTA text, each named by its header's first word; sequence lines
+/// before the first header are ignored
+pub fn parse_fasta(text: &str) -> Vec<Record> {
+  let mut records: Vec<Record> = Vec::new();
+  for line in text.lines().map(str::trim) {
+    if let Some(header) = line.strip_prefix('>') {
+      let (id, description) = header.trim().split_once(char::is_whitespace).unwrap_or((header.trim(), ""));
+      records.push(Record { id: id.to_string(), description: description.trim().to_string(), sequence: String::new() });
+    } else if let Some(record) = records.last_mut() {
+      record.sequence.push_str(line);
+    }
+  }
+  records
+}
+
+pub struct Panel {
+  /// Best placement of each ortholog against the query; none for an empty ortholog
+  pub placements: Vec<Option<Alignment>>,
+  /// Ortholog indices by identity to the query, then coverage of the query, then input order
+  pub order: Vec<usize>,
+  /// Pairwise identity matrix with the query first; 0 on the diagonal for an empty protein
+  pub identities: Vec<Vec<f64>>,
+  /// One column per query residue: the residue where every protein is present and agrees,
+  /// '?' elsewhere
+  pub shared_mask: Vec<u8>,
+}
+
+impl Panel {
+  /// Share of the query the ortholog's placement covers
+  pub fn coverage(&self, index: usize, query_length: usize) -> f64 {
+    self.placements[index].as_ref().map_or(0.0, |a| a.length as f64 / query_length as f64)
+  }
+
+  pub fn identity(&self, index: usize) -> f64 {
+    self.placements[index].as_ref().map_or(0.0, |a| a.identity)
+  }
+}
+
+/// Compare a non-empty query protein (`proteins[0]`) with the orthologs after it, every pair
+/// placed by `place`; the first error it gives ends the comparison
+pub fn compare_panel<E>(
+  proteins: &[Vec<u8>],
+  mut place: impl FnMut(&[u8], &[u8]) -> Result<Alignment, E>,
+) -> Result<Panel, E> {
+  let query = &proteins[0];
+  let placements = proteins[1..]
+    .iter()
+    .map(|ortholog| (!ortholog.is_empty()).then(|| place(query, ortholog)).transpose())
+    .collect::<Result<Vec<_>, E>>()?;
+
+  let rows: Vec<&[u8]> = proteins.iter().map(Vec::as_slice).collect();
+  let msa = center_star_alignment(&rows, &mut place)?;
+  let mut identities = vec![vec![0.0; rows.len()]; rows.len()];
+  for i in 0..rows.len() {
+    identities[i][i] = if rows[i].is_empty() { 0.0 } else { 1.0 };
+    for j in (i + 1)..rows.len() {
+      if !rows[i].is_empty() && !rows[j].is_empty() {
+        identities[i][j] = place(rows[i], rows[j])?.identity;
+        identities[j][i] = identities[i][j];
+      }
+    }
+  }
+  let query_start = msa.starts[0];
+  let shared_mask = (query_start..query_start + query.len())
+    .map(|column| {
+      let residue = msa.rows[0][column];
+      if msa.column(column).all(|other| other == residue && other != GAP) { residue } else { b'?' }
+    })
+    .collect();
+
+  let mut panel = Panel { placements, order: Vec::new(), identities, shared_mask };
+  let mut order: Vec<usize> = (0..panel.placements.len()).collect();
+  let coverage = |index| panel.coverage(index, query.len());
+  order.sort_by(|&a, &b| panel.identity(b).total_cmp(&panel.identity(a)).then(coverage(b).total_cmp(&coverage(a))).then(a.cmp(&b)));
+  panel.order = order;
+  Ok(panel)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::alignment::find_best_alignment;
+
+  const QUERY: &[u8] = b"MKTAYIAKQRQISFVKSH";
+
+  fn place(a: &[u8], b: &[u8]) -> Result<Alignment, ()> {
+    Ok(find_best_alignment(a, b, 0.5))
+  }
+
+  #[test]
+  fn records_are_named_by_their_first_header_word() {
+    let records = parse_fasta("ignored\n>human  TP53 tumor protein\nMEEP\nQSDP\n>mouse\nMTAM\n");
+    let parsed: Vec<(&str, &str, &str)> = records.iter().map(|r| (r.id.as_str(), r.description.as_str(), r.sequence.as_str())).collect();
+    assert_eq!(parsed, vec![("human", "TP53 tumor protein", "MEEPQSDP"), ("mouse", "", "MTAM")]);
+  }
+
+  #[test]
+  fn orthologs_rank_by_identity_then_coverage() {
+    let mut substituted = QUERY.to_vec();
+    substituted[5] = b'W';
+    let proteins = vec![QUERY.to_vec(), substituted, QUERY[2..16].to_vec(), QUERY.to_vec(), Vec::new()];
+    let panel = compare_panel(&proteins, place).ok().unwrap();
+
+    assert_eq!(panel.order, vec![2, 1, 0, 3]);
+    assert_eq!(panel.coverage(1, QUERY.len()), 14.0 / 18.0);
+    assert_eq!(panel.identity(0), 17.0 / 18.0);
+    assert!(panel.placements[3].is_none());
+    assert_eq!(panel.identities[4][4], 0.0);
+    assert_eq!((panel.identities[0][3], panel.identities[1][0]), (1.0, 17.0 / 18.0));
+    // The empty ortholog is present nowhere
+    assert!(panel.shared_mask.iter().all(|&c| c == b'?'));
+
+    let panel = compare_panel(&proteins[..3], place).ok().unwrap();
+    let mut expected = b"??TAYIAKQRQISFVK??".to_vec();
+    expected[5] = b'?';
+    assert_eq!(panel.shared_mask, expected);
+  }
+}
@@ -0,0 +1,96 @@
+// ============================================================================
+// 2-bit Packed Sequences
+// ============================================================================
+// 32 bases per u64 (A=0, C=1, G=2, T=3, base i in bits 2·(i mod 32)). Packing
+// only applies to sequences made purely of uppercase ACGT, where comparing
+// codes is exactly equivalent to comparing bytes; anything else (N, IUPAC
+// codes, lowercase) stays on the byte path so results never change.
+
+const BASES_PER_WORD: usize = 32;
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+
+pub struct PackedSequence {
+  words: Vec<u64>,
+  len: usize,
+}
+
+impl PackedSequence {
+  pub(crate) fn pack(seq: &[u8]) -> Option<PackedSequence> {
+    let mut words = vec![0u64; seq.len().div_ceil(BASES_PER_WORD)];
+    for (i, &c) in seq.iter().enumerate() {
+      let code = match c {
+        b'A' => 0,
+        b'C' => 1,
+        b'G' => 2,
+        b'T' => 3,
+        _ => return None,
+      };
+      words[i / BASES_PER_WORD] |= code << (2 * (i % BASES_PER_WORD));
+    }
+    Some(PackedSequence { words, len: seq.len() })
+  }
+
+  pub(crate) fn len(&self) -> usize {
+    self.len
+  }
+
+  /// 32 bases starting at `pos` (positions past the end read as A)
+  fn word_at(&self, pos: usize) -> u64 {
+    let index = pos / BASES_PER_WORD;
+    let shift = 2 * (pos % BASES_PER_WORD);
+    let low = self.words.get(index).copied().unwrap_or(0) >> shift;
+    if shift == 0 {
+      return low;
+    }
+    low | (self.words.get(index + 1).copied().unwrap_or(0) << (64 - shift))
+  }
+}
+
+/// Mismatches between `len` bases of `a` from `start_a` and of `b` from `start_b`
+pub(crate) fn count_packed_mismatches(a: &PackedSequence, start_a: usize, b: &PackedSequence, start_b: usize, len: usize) -> usize {
+  let mut mismatches = 0;
+  let mut done = 0;
+  while done < len {
+    let diff = a.word_at(start_a + done) ^ b.word_at(start_b + done);
+    // One bit per differing base
+    let mut per_base = (diff | (diff >> 1)) & LOW_BITS;
+    let remaining = len - done;
+    if remaining < BASES_PER_WORD {
+      per_base &= (1u64 << (2 * remaining)) - 1;
+    }
+    mismatches += per_base.count_ones() as usize;
+    done += BASES_PER_WORD;
+  }
+  mismatches
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn packed_mismatches_equal_the_byte_count() {
+    let mut state = 11u64;
+    let mut random = |len: usize| -> Vec<u8> {
+      (0..len)
+        .map(|_| {
+          state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+          b"ACGT"[(state >> 33) as usize % 4]
+        })
+        .collect()
+    };
+    let (a, b) = (random(150), random(150));
+    let (packed_a, packed_b) = (PackedSequence::pack(&a).unwrap(), PackedSequence::pack(&b).unwrap());
+    for (start_a, start_b, len) in [(0, 0, 150), (3, 40, 64), (31, 33, 1), (100, 0, 50), (17, 90, 0)] {
+      let expected = (0..len).filter(|&i| a[start_a + i] != b[start_b + i]).count();
+      assert_eq!(count_packed_mismatches(&packed_a, start_a, &packed_b, start_b, len), expected);
+    }
+  }
+
+  #[test]
+  fn only_uppercase_acgt_is_packed() {
+    assert!(PackedSequence::pack(b"ACGTACGT").is_some());
+    assert!(PackedSequence::pack(b"ACGN").is_none());
+    assert!(PackedSequence::pack(b"acgt").is_none());
+  }
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// Optional Parallelism
+// ============================================================================
+// With the `threads` feature, independent work items are spread across the
+// global rayon pool (in the browser, Web Workers started by the wasm crate's
+// init_thread_pool). Results are always returned in input order so every
+// caller's selection logic, and hence its output, is identical to the
+// single-threaded build.
+
+#[cfg(feature = "threads")]
+pub(crate) fn map_ordered<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+  use rayon::prelude::*;
+  items.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "threads"))]
+pub(crate) fn map_ordered<T, R>(items: &[T], f: impl Fn(&T) -> R) -> Vec<R> {
+  items.iter().map(f).collect()
+}
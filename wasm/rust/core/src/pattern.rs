@@ -0,0 +1,174 @@
+use crate::iupac::{base_set, is_palindromic, reverse_complement};
+
+// ============================================================================
+// Pattern Search
+// ============================================================================
+// Probes, guides, and recognition sites are IUPAC patterns searched on both
+// strands. A sequence base matches a pattern position when it is one of the
+// position's bases, so an N in the sequence only matches an N in the pattern
+// and a probe over an ambiguous call counts it as a mismatch.
+
+pub struct PatternHit {
+  /// Start of the hit on the top strand
+  pub position: usize,
+  /// The reverse complement of the pattern matched (never for palindromic patterns)
+  pub reverse: bool,
+  pub mismatches: usize,
+}
+
+/// Hits of `pattern` in `seq` (both case-insensitive) on either strand with at most
+/// `max_mismatches` mismatching positions, by position; an empty pattern has none
+pub fn find_pattern(seq: &[u8], pattern: &[u8], max_mismatches: usize) -> Vec<PatternHit> {
+  if pattern.is_empty() {
+    return Vec::new();
+  }
+  let mut hits = strand_hits(seq, pattern, max_mismatches, false);
+  if !is_palindromic(pattern) {
+    hits.extend(strand_hits(seq, &reverse_complement(pattern), max_mismatches, true));
+    hits.sort_by_key(|hit| (hit.position, hit.reverse));
+  }
+  hits
+}
+
+fn strand_hits(seq: &[u8], pattern: &[u8], max_mismatches: usize, reverse: bool) -> Vec<PatternHit> {
+  let sets: Vec<u8> = pattern.iter().map(|&c| base_set(c)).collect();
+  seq
+    .windows(pattern.len())
+    .enumerate()
+    .filter_map(|(position, window)| {
+      let mut mismatches = 0;
+      for (&base, &set) in window.iter().zip(&sets) {
+        let base = base_set(base);
+        if base == 0 || base & !set != 0 {
+          mismatches += 1;
+          if mismatches > max_mismatches {
+            return None;
+          }
+        }
+      }
+      Some(PatternHit { position, reverse, mismatches })
+    })
+    .collect()
+}
+
+// ============================================================================
+// Approximate Matching
+// ============================================================================
+// Hamming-distance search with bit-parallel shift-and: bit i of the state for
+// j mismatches is set when the needle's first i + 1 characters end at the
+// current haystack position with at most j mismatches. A needle longer than a
+// machine word is compared window by window instead.
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Start positions in `haystack` where `needle` matches with at most `max_mismatches`
+/// differing characters (ASCII case-insensitive, no IUPAC), each with its mismatch count,
+/// by position; an empty needle has none
+pub fn approximate_matches(needle: &[u8], haystack: &[u8], max_mismatches: usize) -> Vec<(usize, usize)> {
+  let m = needle.len();
+  if m == 0 || m > haystack.len() {
+    return Vec::new();
+  }
+  let max_mismatches = max_mismatches.min(m);
+  if m > WORD_BITS {
+    return haystack
+      .windows(m)
+      .enumerate()
+      .filter_map(|(position, window)| {
+        let mismatches = window.iter().zip(needle).filter(|(a, b)| !a.eq_ignore_ascii_case(b)).count();
+        (mismatches <= max_mismatches).then_some((position, mismatches))
+      })
+      .collect();
+  }
+
+  let mut masks = [0u64; 256];
+  for (i, &c) in needle.iter().enumerate() {
+    masks[c.to_ascii_uppercase() as usize] |= 1 << i;
+    masks[c.to_ascii_lowercase() as usize] |= 1 << i;
+  }
+  let last = 1u64 << (m - 1);
+  let mut states = vec![0u64; max_mismatches + 1];
+  let mut hits = Vec::new();
+  for (end, &c) in haystack.iter().enumerate() {
+    let mask = masks[c as usize];
+    let mut previous = 0;
+    for state in states.iter_mut() {
+      let shifted = (*state << 1) | 1;
+      // Match this character, or spend a mismatch on it from the state with one fewer
+      let next = (shifted & mask) | previous;
+      previous = shifted;
+      *state = next;
+    }
+    if end + 1 >= m {
+      if let Some(mismatches) = states.iter().position(|state| state & last != 0) {
+        hits.push((end + 1 - m, mismatches));
+      }
+    }
+  }
+  hits
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn naive_matches(needle: &[u8], haystack: &[u8], max_mismatches: usize) -> Vec<(usize, usize)> {
+    if needle.is_empty() || needle.len() > haystack.len() {
+      return Vec::new();
+    }
+    haystack
+      .windows(needle.len())
+      .enumerate()
+      .map(|(position, window)| (position, window.iter().zip(needle).filter(|(a, b)| !a.eq_ignore_ascii_case(b)).count()))
+      .filter(|&(_, mismatches)| mismatches <= max_mismatches)
+      .collect()
+  }
+
+  #[test]
+  fn shift_and_matches_equal_the_window_scan() {
+    let mut state = 5u64;
+    let mut random = |len: usize| -> Vec<u8> {
+      (0..len)
+        .map(|_| {
+          state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+          b"ACGTacgt"[(state >> 33) as usize % 8]
+        })
+        .collect()
+    };
+    let haystack = random(400);
+    for m in [1, 2, 8, 63, 64, 65] {
+      // A needle taken from the haystack has at least one exact hit
+      let needles = [haystack[100..100 + m].to_vec(), random(m)];
+      for needle in &needles {
+        for max_mismatches in [0, 1, 3, m] {
+          assert_eq!(
+            approximate_matches(needle, &haystack, max_mismatches),
+            naive_matches(needle, &haystack, max_mismatches),
+            "m {} max {}",
+            m,
+            max_mismatches
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn approximate_matches_of_degenerate_needles() {
+    assert!(approximate_matches(b"", b"ACGT", 1).is_empty());
+    assert!(approximate_matches(b"ACGTA", b"ACGT", 1).is_empty());
+    assert_eq!(approximate_matches(b"acg", b"TACGA", 0), [(1, 0)]);
+  }
+
+  #[test]
+  fn patterns_match_iupac_codes_on_both_strands() {
+    // GAATTC is palindromic: one hit per site
+    assert_eq!(find_pattern(b"TTGAATTCAA", b"GAATTC", 0).len(), 1);
+    // RCC matches ACC and GCC; its reverse complement GGY matches GGT
+    let hits: Vec<(usize, bool)> =
+      find_pattern(b"ACCTTGGTGCC", b"RCC", 0).iter().map(|hit| (hit.position, hit.reverse)).collect();
+    assert_eq!(hits, [(0, false), (5, true), (8, false)]);
+    // An N in the sequence counts as a mismatch
+    assert_eq!(find_pattern(b"ANC", b"ACC", 1)[0].mismatches, 1);
+  }
+}
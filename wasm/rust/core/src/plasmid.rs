@@ -0,0 +1,124 @@
+use crate::iupac::{base_set, reverse_complement};
+use crate::pattern::{find_pattern, PatternHit};
+
+// ============================================================================
+// Plasmid Insert Verification
+// ============================================================================
+// A cloned insert is a small fraction of the plasmid map it sits in, so the
+// overlap heuristic of the whole-sequence comparison rejects or misplaces it.
+// Instead the insert is searched like a probe: on both strands of the map, and
+// across the origin by searching the map with its start appended again.
+
+/// A column where the plasmid base is not one the insert allows, in insert orientation
+pub struct InsertDifference {
+  pub insert_position: usize,
+  pub plasmid_position: usize,
+  pub expected: u8,
+  /// The plasmid base on the insert's strand
+  pub found: u8,
+}
+
+pub struct InsertOccurrence {
+  pub hit: PatternHit,
+  /// 0-based exclusive end on the map; before `hit.position` where `wraps_origin`
+  pub end: usize,
+  pub wraps_origin: bool,
+  pub differences: Vec<InsertDifference>,
+}
+
+fn differences(insert: &[u8], plasmid: &[u8], hit: &PatternHit) -> Vec<InsertDifference> {
+  let len = insert.len();
+  (0..len)
+    .filter_map(|i| {
+      let (column, expected) =
+        if hit.reverse { (len - 1 - i, reverse_complement(&[insert[i]])[0]) } else { (i, insert[i]) };
+      let position = (hit.position + column) % plasmid.len();
+      let found = base_set(plasmid[position]);
+      (found == 0 || found & !base_set(expected) != 0).then(|| {
+        let found = if hit.reverse { reverse_complement(&[plasmid[position]])[0] } else { plasmid[position] };
+        InsertDifference { insert_position: i, plasmid_position: position, expected: insert[i], found }
+      })
+    })
+    .collect()
+}
+
+/// Occurrences of a non-empty `insert` no longer than the circular `plasmid` map: every
+/// placement on either strand, including across the origin, with at most `max_mismatches`
+/// differences (IUPAC codes in the insert match any of their bases), overlapping placements
+/// on a strand reduced to the one with the fewest; in map order
+pub fn find_insert(insert: &[u8], plasmid: &[u8], max_mismatches: usize) -> Vec<InsertOccurrence> {
+  let mut circular_map = plasmid.to_vec();
+  circular_map.extend_from_slice(&plasmid[..insert.len() - 1]);
+
+  let mut hits = find_pattern(&circular_map, insert, max_mismatches);
+  hits.sort_by_key(|hit| (hit.mismatches, hit.position));
+  let overlaps = |a: &PatternHit, b: &PatternHit| {
+    let distance = a.position.abs_diff(b.position);
+    a.reverse == b.reverse && distance.min(plasmid.len() - distance) < insert.len()
+  };
+  let mut occurrences: Vec<PatternHit> = Vec::new();
+  for hit in hits {
+    if !occurrences.iter().any(|kept| overlaps(kept, &hit)) {
+      occurrences.push(hit);
+    }
+  }
+  occurrences.sort_by_key(|hit| (hit.position, hit.reverse));
+
+  occurrences
+    .into_iter()
+    .map(|hit| InsertOccurrence {
+      end: (hit.position + insert.len() - 1) % plasmid.len() + 1,
+      wraps_origin: hit.position + insert.len() > plasmid.len(),
+      differences: differences(insert, plasmid, &hit),
+      hit,
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Reproducible pseudo-random bases
+  fn random_bases(len: usize, mut state: u64) -> Vec<u8> {
+    (0..len)
+      .map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        b"ACGT"[(state >> 33) as usize % 4]
+      })
+      .collect()
+  }
+
+  #[test]
+  fn an_insert_across_the_origin_is_found() {
+    let plasmid = random_bases(200, 11);
+    let mut insert = [&plasmid[190..], &plasmid[..15]].concat();
+    insert[12] = if insert[12] == b'A' { b'C' } else { b'A' };
+    let occurrences = find_insert(&insert, &plasmid, 1);
+    assert_eq!(occurrences.len(), 1);
+    let occurrence = &occurrences[0];
+    assert_eq!((occurrence.hit.position, occurrence.end, occurrence.wraps_origin, occurrence.hit.reverse), (190, 15, true, false));
+    assert_eq!(occurrence.differences.len(), 1);
+    let difference = &occurrence.differences[0];
+    assert_eq!((difference.insert_position, difference.plasmid_position), (12, 2));
+    assert_eq!((difference.expected, difference.found), (insert[12], plasmid[2]));
+    assert!(find_insert(&insert, &plasmid, 0).is_empty());
+  }
+
+  #[test]
+  fn a_reversed_insert_reports_differences_in_its_own_orientation() {
+    let plasmid = random_bases(200, 11);
+    let mut insert = reverse_complement(&plasmid[50..80]);
+    insert[0] = b'N';
+    insert[3] = if insert[3] == b'A' { b'C' } else { b'A' };
+    let occurrences = find_insert(&insert, &plasmid, 1);
+    assert_eq!(occurrences.len(), 1);
+    let occurrence = &occurrences[0];
+    assert_eq!((occurrence.hit.position, occurrence.end, occurrence.hit.reverse), (50, 80, true));
+    // The N matches anything; the substitution sits at the far end of the map's range
+    assert_eq!(occurrence.differences.len(), 1);
+    let difference = &occurrence.differences[0];
+    assert_eq!((difference.insert_position, difference.plasmid_position), (3, 76));
+    assert_eq!(difference.found, reverse_complement(&plasmid[76..77])[0]);
+  }
+}
@@ -0,0 +1,40 @@
+// ============================================================================
+// Poly-A Tails
+// ============================================================================
+// mRNA records often end in a poly-A stretch whose length varies between
+// records of the same transcript. Identical tails line up as long runs of
+// trivial matches, and tails of different lengths skew the overlap, so the
+// nucleotide result notes any tail it sees and `Normalization::trim_poly_a`
+// removes them before alignment.
+
+/// Shortest A-run at the 3' end reported as a poly-A tail
+pub const MIN_POLY_A_LENGTH: usize = 12;
+/// Non-A residues tolerated per ten residues of tail (sequencing errors, stray Ns)
+const MAX_INTERRUPTIONS_PER_TEN: usize = 1;
+
+fn is_a(c: u8) -> bool {
+  c.eq_ignore_ascii_case(&b'A')
+}
+
+/// Length of the poly-A tail at the end of `seq` (case-insensitive): the longest suffix of
+/// A-runs broken only by single non-A residues, at most one in ten, that starts with an A;
+/// 0 when shorter than `MIN_POLY_A_LENGTH`
+pub fn poly_a_tail(seq: &[u8]) -> usize {
+  let (mut tail, mut other) = (0, 0);
+  for i in (0..seq.len()).rev() {
+    let suffix = seq.len() - i;
+    if is_a(seq[i]) {
+      if other * 10 <= suffix * MAX_INTERRUPTIONS_PER_TEN {
+        tail = suffix;
+      }
+    } else {
+      // Two non-A residues in a row (or one at either end) is where the tail starts
+      let isolated = i > 0 && i + 1 < seq.len() && is_a(seq[i - 1]) && is_a(seq[i + 1]);
+      if !isolated {
+        break;
+      }
+      other += 1;
+    }
+  }
+  if tail >= MIN_POLY_A_LENGTH { tail } else { 0 }
+}
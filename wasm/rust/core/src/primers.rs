@@ -0,0 +1,342 @@
+use crate::blocks::ConservedBlock;
+use crate::format::ResultFormat;
+use crate::iupac::{base_set, complement_set, reverse_complement};
+use crate::symbols::MISMATCH;
+
+// ============================================================================
+// Primer Candidates
+// ============================================================================
+// A primer that amplifies both sequences has to match both exactly, so
+// candidates are taken from the matching columns of conserved blocks. Melting
+// temperatures use the nearest-neighbor model with the unified parameters of
+// SantaLucia (1998) and its entropy salt correction, as Primer3 does by
+// default; the other checks are the usual rules of thumb (GC content, a G or
+// C at the 3' end without a GC-rich 3' run, little self-complementarity).
+
+/// Primer lengths tried, in bases
+pub const MIN_PRIMER_LENGTH: usize = 18;
+pub const MAX_PRIMER_LENGTH: usize = 25;
+/// Accepted melting temperatures in °C; candidates closest to the optimum are kept
+const MIN_PRIMER_TM: f64 = 55.0;
+const MAX_PRIMER_TM: f64 = 65.0;
+const OPTIMAL_PRIMER_TM: f64 = 60.0;
+const MIN_PRIMER_GC: f64 = 0.4;
+const MAX_PRIMER_GC: f64 = 0.6;
+/// Longest run of bases a primer may pair with another copy of itself
+pub const MAX_SELF_COMPLEMENTARITY: usize = 4;
+/// At most this many G/C among the last five 3' bases (a stronger clamp primes off-target)
+const MAX_THREE_PRIME_GC: usize = 3;
+/// Longest self-dimer run through the 3' end, and longest hairpin stem, a primer may have
+pub const MAX_THREE_PRIME_COMPLEMENTARITY: usize = 3;
+pub const MAX_HAIRPIN_STEM: usize = 4;
+
+const GAS_CONSTANT: f64 = 1.987; // cal/(K·mol)
+const KELVIN: f64 = 273.15;
+
+/// Salt and strand concentrations of the reaction
+#[derive(Clone, Copy, PartialEq)]
+pub struct OligoConditions {
+  /// Monovalent cation (Na+) concentration, molar
+  pub sodium: f64,
+  /// Oligo strand concentration, molar
+  pub primer: f64,
+}
+
+impl Default for OligoConditions {
+  /// Primer3's defaults: 50 mM Na+, 50 nM primer
+  fn default() -> Self {
+    OligoConditions { sodium: 0.05, primer: 50e-9 }
+  }
+}
+
+/// Nearest-neighbor enthalpy (kcal/mol) and entropy (cal/(K·mol)) of the dinucleotide step
+/// `a` `b` on the top strand
+fn nearest_neighbor(a: u8, b: u8) -> (f64, f64) {
+  match (a, b) {
+    (b'A', b'A') | (b'T', b'T') => (-7.9, -22.2),
+    (b'A', b'T') => (-7.2, -20.4),
+    (b'T', b'A') => (-7.2, -21.3),
+    (b'C', b'A') | (b'T', b'G') => (-8.5, -22.7),
+    (b'G', b'T') | (b'A', b'C') => (-8.4, -22.4),
+    (b'C', b'T') | (b'A', b'G') => (-7.8, -21.0),
+    (b'G', b'A') | (b'T', b'C') => (-8.2, -22.2),
+    (b'C', b'G') => (-10.6, -27.2),
+    (b'G', b'C') => (-9.8, -24.4),
+    _ => (-8.0, -19.9), // GG/CC
+  }
+}
+
+/// Initiation enthalpy and entropy for a terminal base
+fn initiation(base: u8) -> (f64, f64) {
+  if matches!(base, b'G' | b'C') { (0.1, -2.8) } else { (2.3, 4.1) }
+}
+
+/// Melting temperature in °C of `seq` paired with its complement; `None` unless it has at
+/// least two bases, all of them A, C, G, or T (case-insensitive)
+pub fn melting_temperature(seq: &[u8], conditions: &OligoConditions) -> Option<f64> {
+  let seq: Vec<u8> = seq.iter().map(u8::to_ascii_uppercase).collect();
+  if seq.len() < 2 || !seq.iter().all(|c| matches!(c, b'A' | b'C' | b'G' | b'T')) {
+    return None;
+  }
+  let (mut enthalpy, mut entropy) = (0.0, 0.0);
+  for end in [seq[0], seq[seq.len() - 1]] {
+    let (h, s) = initiation(end);
+    enthalpy += h;
+    entropy += s;
+  }
+  for pair in seq.windows(2) {
+    let (h, s) = nearest_neighbor(pair[0], pair[1]);
+    enthalpy += h;
+    entropy += s;
+  }
+  entropy += 0.368 * (seq.len() - 1) as f64 * conditions.sodium.ln();
+  // Self-complementary strands pair with each other, so the full concentration counts
+  let strands = if reverse_complement(&seq) == seq { conditions.primer } else { conditions.primer / 4.0 };
+  Some(enthalpy * 1000.0 / (entropy + GAS_CONSTANT * strands.ln()) - KELVIN)
+}
+
+/// G and C over all bases of `seq`
+pub fn gc_fraction(seq: &[u8]) -> f64 {
+  let gc = seq.iter().filter(|c| matches!(c.to_ascii_uppercase(), b'G' | b'C')).count();
+  if seq.is_empty() { 0.0 } else { gc as f64 / seq.len() as f64 }
+}
+
+/// Longest run of consecutive base pairs between two copies of `seq` lying antiparallel at
+/// any offset (the strongest self-dimer)
+pub fn self_complementarity(seq: &[u8]) -> usize {
+  let other = reverse_complement(seq);
+  let n = seq.len();
+  let mut longest = 0;
+  for shift in 0..2 * n {
+    let mut run = 0;
+    for (i, base) in seq.iter().enumerate() {
+      let j = (i + shift).checked_sub(n).filter(|&j| j < n);
+      if j.is_some_and(|j| base.eq_ignore_ascii_case(&other[j])) {
+        run += 1;
+        longest = longest.max(run);
+      } else {
+        run = 0;
+      }
+    }
+  }
+  longest
+}
+
+/// Longest run of `self_complementarity` that includes the 3' base of one copy; dimers
+/// whose 3' end is paired are the ones polymerase extends
+pub fn three_prime_complementarity(seq: &[u8]) -> usize {
+  let other = reverse_complement(seq);
+  let n = seq.len();
+  // The 3' base of `seq` pairs with base j of `other` when both copies overlap there
+  (0..n)
+    .map(|j| {
+      (0..=j)
+        .take_while(|&back| seq[n - 1 - back].eq_ignore_ascii_case(&other[j - back]))
+        .count()
+    })
+    .max()
+    .unwrap_or(0)
+}
+
+/// Fewest unpaired bases a hairpin loop can have
+const MIN_HAIRPIN_LOOP: usize = 3;
+
+/// Longest stem `seq` can fold into: consecutive pairs closing a loop of at least
+/// `MIN_HAIRPIN_LOOP` bases
+pub fn hairpin_stem(seq: &[u8]) -> usize {
+  let pairs = |a: u8, b: u8| {
+    let (a, b) = (base_set(a), base_set(b));
+    a != 0 && a.count_ones() == 1 && complement_set(a) == b
+  };
+  let mut longest = 0;
+  for inner in 0..seq.len() {
+    for close in inner + MIN_HAIRPIN_LOOP + 1..seq.len() {
+      let stem = (0..=inner.min(seq.len() - 1 - close))
+        .take_while(|&k| pairs(seq[inner - k], seq[close + k]))
+        .count();
+      longest = longest.max(stem);
+    }
+  }
+  longest
+}
+
+/// A 3' G or C, but no more than `MAX_THREE_PRIME_GC` among the last five bases
+fn has_gc_clamp(primer: &[u8]) -> bool {
+  let is_gc = |c: &u8| matches!(c.to_ascii_uppercase(), b'G' | b'C');
+  let tail = &primer[primer.len().saturating_sub(5)..];
+  primer.last().is_some_and(is_gc) && tail.iter().filter(|&c| is_gc(c)).count() <= MAX_THREE_PRIME_GC
+}
+
+/// The primer checks `seq` fails, by name: "length", "tm" (including no Tm for non-ACGT
+/// bases), "gc", "gcClamp", "selfDimer", "threePrimeDimer", and "hairpin"
+pub fn primer_warnings(seq: &[u8], conditions: &OligoConditions) -> Vec<&'static str> {
+  let tm = melting_temperature(seq, conditions);
+  let checks = [
+    ("length", (MIN_PRIMER_LENGTH..=MAX_PRIMER_LENGTH).contains(&seq.len())),
+    ("tm", tm.is_some_and(|tm| (MIN_PRIMER_TM..=MAX_PRIMER_TM).contains(&tm))),
+    ("gc", (MIN_PRIMER_GC..=MAX_PRIMER_GC).contains(&gc_fraction(seq))),
+    ("gcClamp", has_gc_clamp(seq)),
+    ("selfDimer", self_complementarity(seq) <= MAX_SELF_COMPLEMENTARITY),
+    ("threePrimeDimer", three_prime_complementarity(seq) <= MAX_THREE_PRIME_COMPLEMENTARITY),
+    ("hairpin", hairpin_stem(seq) <= MAX_HAIRPIN_STEM),
+  ];
+  checks.iter().filter(|(_, passed)| !passed).map(|&(name, _)| name).collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PrimerDirection {
+  /// Anneals to the bottom strand; its sequence is read from seq1
+  Forward,
+  /// Anneals to the top strand; its sequence is the reverse complement
+  Reverse,
+}
+
+pub struct Primer {
+  pub direction: PrimerDirection,
+  /// 5' to 3', uppercase
+  pub sequence: Vec<u8>,
+  /// First (top-strand) base of the site in each sequence
+  pub start1: usize,
+  pub start2: usize,
+  pub tm: f64,
+  pub gc: f64,
+  pub self_complementarity: usize,
+  /// Index of the conserved block it comes from
+  pub block: usize,
+}
+
+impl Primer {
+  pub fn to_json(&self, format: &ResultFormat) -> String {
+    format!(
+      r#"{{"direction":"{}","sequence":"{}","length":{},"start1":{},"start2":{},"tm":{},"gc":{},"selfComplementarity":{},"block":{}}}"#,
+      if self.direction == PrimerDirection::Forward { "forward" } else { "reverse" },
+      String::from_utf8_lossy(&self.sequence),
+      self.sequence.len(),
+      format.coordinates.start(self.start1),
+      format.coordinates.start(self.start2),
+      (self.tm * 10.0).round() / 10.0,
+      self.gc,
+      self.self_complementarity,
+      self.block
+    )
+  }
+}
+
+/// The best forward and reverse primer (Tm closest to the optimum, then least
+/// self-complementary, then first) from each conserved block, over columns where both
+/// sequences have the same unambiguous base; `region1` is seq1's aligned region the mask and
+/// blocks were built from, and the offsets place the alignment in each sequence
+pub fn primer_candidates(
+  region1: &[u8],
+  mask: &[u8],
+  blocks: &[ConservedBlock],
+  (offset1, offset2): (usize, usize),
+  conditions: &OligoConditions,
+) -> Vec<Primer> {
+  let usable = |column: usize| mask[column] != MISMATCH && matches!(region1[column].to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T');
+  let mut primers = Vec::new();
+  for (index, block) in blocks.iter().enumerate() {
+    for direction in [PrimerDirection::Forward, PrimerDirection::Reverse] {
+      let mut best: Option<Primer> = None;
+      for start in block.start..block.end {
+        for length in MIN_PRIMER_LENGTH..=MAX_PRIMER_LENGTH {
+          let end = start + length;
+          if end > block.end || !(start..end).all(usable) {
+            break;
+          }
+          let site: Vec<u8> = region1[start..end].iter().map(u8::to_ascii_uppercase).collect();
+          let sequence = if direction == PrimerDirection::Forward { site } else { reverse_complement(&site) };
+          let gc = gc_fraction(&sequence);
+          let Some(tm) = melting_temperature(&sequence, conditions) else { continue };
+          // The cheap checks first; the complementarity ones only for what passes them
+          if !(MIN_PRIMER_TM..=MAX_PRIMER_TM).contains(&tm)
+            || !(MIN_PRIMER_GC..=MAX_PRIMER_GC).contains(&gc)
+            || !has_gc_clamp(&sequence)
+            || !primer_warnings(&sequence, conditions).is_empty()
+          {
+            continue;
+          }
+          let self_complementarity = self_complementarity(&sequence);
+          let candidate = Primer {
+            direction,
+            sequence,
+            start1: offset1 + start,
+            start2: offset2 + start,
+            tm,
+            gc,
+            self_complementarity,
+            block: index,
+          };
+          let rank = |primer: &Primer| ((primer.tm - OPTIMAL_PRIMER_TM).abs(), primer.self_complementarity);
+          if best.as_ref().is_none_or(|best| rank(&candidate) < rank(best)) {
+            best = Some(candidate);
+          }
+        }
+      }
+      primers.extend(best);
+    }
+  }
+  primers
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Reproducible pseudo-random bases
+  fn random_bases(len: usize, mut state: u64) -> Vec<u8> {
+    (0..len)
+      .map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        b"ACGT"[(state >> 33) as usize % 4]
+      })
+      .collect()
+  }
+
+  #[test]
+  fn candidates_match_both_sequences_and_pass_every_check() {
+    let region1 = random_bases(120, 7);
+    let mut mask = region1.clone();
+    mask[60] = MISMATCH;
+    let blocks = [ConservedBlock { start: 0, end: 120, length: 120, sequence: mask.clone() }];
+    let conditions = OligoConditions::default();
+    let primers = primer_candidates(&region1, &mask, &blocks, (100, 10), &conditions);
+
+    assert_eq!(primers.len(), 2);
+    assert!(primers[0].direction == PrimerDirection::Forward && primers[1].direction == PrimerDirection::Reverse);
+    for primer in &primers {
+      let start = primer.start1 - 100;
+      let site = &region1[start..start + primer.sequence.len()];
+      assert_eq!(primer.start2 - 10, start);
+      assert!(!(start..start + primer.sequence.len()).contains(&60));
+      let expected = if primer.direction == PrimerDirection::Forward { site.to_vec() } else { reverse_complement(site) };
+      assert_eq!(primer.sequence, expected);
+      assert!(primer_warnings(&primer.sequence, &conditions).is_empty());
+      assert!((MIN_PRIMER_TM..=MAX_PRIMER_TM).contains(&primer.tm));
+    }
+  }
+
+  #[test]
+  fn a_block_too_short_for_a_primer_gives_none() {
+    let region1 = random_bases(40, 7);
+    let blocks = [ConservedBlock { start: 0, end: MIN_PRIMER_LENGTH - 1, length: MIN_PRIMER_LENGTH - 1, sequence: Vec::new() }];
+    assert!(primer_candidates(&region1, &region1, &blocks, (0, 0), &OligoConditions::default()).is_empty());
+  }
+
+  #[test]
+  fn oligo_checks_flag_the_structures_they_name() {
+    let conditions = OligoConditions::default();
+    assert_eq!(melting_temperature(b"ACGTN", &conditions), None);
+    let tm = melting_temperature(b"AGCGGATAACAATTTCACAC", &conditions).unwrap();
+    let salty = melting_temperature(b"AGCGGATAACAATTTCACAC", &OligoConditions { sodium: 0.5, ..conditions }).unwrap();
+    assert_eq!((tm * 10.0).round() / 10.0, 48.6);
+    assert!(salty > tm);
+    assert_eq!(gc_fraction(b"GCat"), 0.5);
+
+    // A 5-pair stem around a four-base loop
+    assert_eq!(hairpin_stem(b"GGATCAAAAGATCC"), 5);
+    assert_eq!(three_prime_complementarity(b"AAAAAAGATC"), 4);
+    assert_eq!(self_complementarity(b"TTGAATTCTT"), 6);
+    assert_eq!(primer_warnings(b"AAAAAAGATC", &conditions), vec!["length", "tm", "gc", "threePrimeDimer"]);
+  }
+}
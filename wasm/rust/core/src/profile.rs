@@ -0,0 +1,48 @@
+use crate::alignment::Alignment;
+use crate::symbols::{GAP, MISMATCH};
+
+// ============================================================================
+// GC and Identity Profile
+// ============================================================================
+// Conservation and composition in the same windows along the best placement,
+// so a viewer can plot one against the other: low identity in GC-rich windows
+// often means sequencing trouble rather than divergence.
+
+/// Parallel per-window values; `starts` are window starts within the overlap
+#[derive(Default)]
+pub struct GcIdentityProfile {
+  pub starts: Vec<usize>,
+  pub gc1: Vec<f64>,
+  pub gc2: Vec<f64>,
+  pub identity: Vec<f64>,
+}
+
+/// G, C, and S (G or C) bases over the non-gap bases of a window
+fn gc_fraction(window: &[u8]) -> f64 {
+  let bases = window.iter().filter(|&&b| b != GAP).count();
+  let gc = window.iter().filter(|b| matches!(b.to_ascii_uppercase(), b'G' | b'C' | b'S')).count();
+  if bases == 0 { 0.0 } else { gc as f64 / bases as f64 }
+}
+
+/// Window GC content of each sequence and window identity along `alignment`, in windows of
+/// `window` columns every `step` (both at least 1); an overlap shorter than one window is a
+/// single window
+pub fn gc_identity_profile(bytes1: &[u8], bytes2: &[u8], alignment: &Alignment, window: usize, step: usize) -> GcIdentityProfile {
+  let mut profile = GcIdentityProfile::default();
+  if alignment.length == 0 {
+    return profile;
+  }
+  let mask = alignment.mask(bytes1, bytes2);
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let size = window.min(alignment.length);
+  for start in (0..=alignment.length - size).step_by(step) {
+    let columns = start..start + size;
+    let mismatches = mask[columns.clone()].iter().filter(|&&b| b == MISMATCH).count();
+    profile.starts.push(start);
+    profile.gc1.push(gc_fraction(&region1[columns.clone()]));
+    profile.gc2.push(gc_fraction(&region2[columns]));
+    profile.identity.push(1.0 - mismatches as f64 / size as f64);
+  }
+  profile
+}
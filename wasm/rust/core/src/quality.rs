@@ -0,0 +1,90 @@
+// ============================================================================
+// Quality Trimming
+// ============================================================================
+// Sanger reads are unreliable at both ends: the first few dozen bases before
+// the primer's signal settles, and the tail where peaks broaden and merge.
+// Base callers give each base a Phred quality (-10 log10 of the error
+// probability), and the ends are cut where quality stays low so that only the
+// trustworthy middle of the read is compared.
+
+/// Bases kept from a read, 0-based half-open; `start == end` when nothing is kept
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TrimRange {
+  pub start: usize,
+  pub end: usize,
+}
+
+impl TrimRange {
+  pub fn len(&self) -> usize {
+    self.end - self.start
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.start == self.end
+  }
+
+  fn offset(self, by: usize) -> TrimRange {
+    TrimRange { start: self.start + by, end: self.end + by }
+  }
+}
+
+fn mean(quals: &[u8]) -> f64 {
+  quals.iter().map(|&q| q as f64).sum::<f64>() / quals.len() as f64
+}
+
+/// Sliding-window trim: the read from the first window of `window` bases whose mean quality
+/// reaches `threshold` to the end of the last such window, then with any remaining
+/// low-quality bases cut from both ends. A window longer than the read is the whole read
+pub fn trim_by_quality(quals: &[u8], window: usize, threshold: f64) -> TrimRange {
+  let window = window.clamp(1, quals.len().max(1));
+  let passes = |i: &usize| mean(&quals[*i..*i + window]) >= threshold;
+  let windows = 0..(quals.len() + 1).saturating_sub(window);
+  let (Some(first), Some(last)) = (windows.clone().find(passes), windows.rev().find(passes)) else {
+    return TrimRange { start: 0, end: 0 };
+  };
+  trim_ends(&quals[first..last + window], threshold.ceil() as u8, |_| false).offset(first)
+}
+
+/// Cut bases from both ends while their quality is below `threshold` or `is_unknown` says
+/// they are uncalled (e.g. N)
+pub fn trim_ends(quals: &[u8], threshold: u8, is_unknown: impl Fn(usize) -> bool) -> TrimRange {
+  let bad = |i: usize| quals[i] < threshold || is_unknown(i);
+  let start = (0..quals.len()).find(|&i| !bad(i)).unwrap_or(quals.len());
+  let end = (start..quals.len()).rev().find(|&i| !bad(i)).map_or(start, |i| i + 1);
+  TrimRange { start, end }
+}
+
+/// Whether a base is uncalled: N, a gap or dot, or anything but a letter
+pub fn is_uncalled(c: u8) -> bool {
+  matches!(c.to_ascii_uppercase(), b'N' | b'-' | b'.') || !c.is_ascii_alphabetic()
+}
+
+/// Cut uncalled bases from both ends of `seq`
+pub fn trim_unknown_ends(seq: &[u8]) -> TrimRange {
+  let start = seq.iter().position(|&c| !is_uncalled(c)).unwrap_or(seq.len());
+  let end = seq.iter().rposition(|&c| !is_uncalled(c)).map_or(start, |i| i + 1);
+  TrimRange { start, end }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn low_quality_ends_are_cut() {
+    assert_eq!(trim_by_quality(&[5, 5, 30, 30, 30, 30, 5, 5], 2, 20.0), TrimRange { start: 2, end: 6 });
+    // Both windows pass on their mean, but the bases at either end are still below the threshold
+    assert_eq!(trim_by_quality(&[5, 40, 40, 5], 3, 20.0), TrimRange { start: 1, end: 3 });
+    assert!(trim_by_quality(&[5, 10, 5], 2, 20.0).is_empty());
+    assert!(trim_by_quality(&[], 10, 20.0).is_empty());
+    assert_eq!(trim_by_quality(&[30, 30], 10, 20.0).len(), 2);
+  }
+
+  #[test]
+  fn uncalled_bases_are_cut_from_the_ends_only() {
+    let seq = b"NNacNgtN-";
+    assert_eq!(trim_unknown_ends(seq), TrimRange { start: 2, end: 7 });
+    assert_eq!(trim_ends(&[40; 9], 20, |i| is_uncalled(seq[i])), TrimRange { start: 2, end: 7 });
+    assert!(trim_unknown_ends(b"N-.").is_empty());
+  }
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::kmer::{kmers, MAX_K};
+
+// ============================================================================
+// Inversion and Rearrangement Detection
+// ============================================================================
+// Exact k-mer anchors are collected on both strands of seq2 and chained along
+// their diagonal (forward: pos1 - pos2, reverse: pos1 + pos2) into segments.
+// The heaviest co-linear chain of forward segments is the backbone; remaining
+// forward segments are transpositions and reverse segments are inversions.
+
+const ANCHOR_MAX_OCCURRENCES: usize = 64; // Ignore repetitive k-mers
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Strand {
+  Forward,
+  Reverse,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SegmentKind {
+  /// On the backbone: forward, and in order in both sequences
+  Colinear,
+  /// Forward, but off the backbone
+  Transposition,
+  /// On the reverse strand
+  Inversion,
+}
+
+impl SegmentKind {
+  pub fn name(self) -> &'static str {
+    match self {
+      SegmentKind::Colinear => "colinear",
+      SegmentKind::Transposition => "transposition",
+      SegmentKind::Inversion => "inversion",
+    }
+  }
+}
+
+/// A run of anchors on one diagonal; reverse-strand segments give seq2 coordinates on the
+/// forward strand
+pub struct Segment {
+  pub strand: Strand,
+  pub start1: usize,
+  pub end1: usize,
+  pub start2: usize,
+  pub end2: usize,
+  pub kind: SegmentKind,
+}
+
+/// Merge sorted anchor positions (in seq1) on one diagonal into [start1, end1) runs
+fn chain_runs(positions: &mut [usize], k: usize) -> Vec<(usize, usize)> {
+  positions.sort_unstable();
+  let mut runs: Vec<(usize, usize)> = Vec::new();
+  for &p in positions.iter() {
+    match runs.last_mut() {
+      // Tolerate up to k bases between anchors (a single mismatch removes k anchors)
+      Some(run) if p <= run.1 + k => run.1 = run.1.max(p + k),
+      _ => runs.push((p, p + k)),
+    }
+  }
+  runs
+}
+
+fn find_segments(bytes1: &[u8], bytes2: &[u8], k: usize, min_segment_length: usize) -> Vec<Segment> {
+  let mut index: HashMap<u64, Vec<usize>> = HashMap::new();
+  for (pos, forward, _) in kmers(bytes1, k) {
+    index.entry(forward).or_default().push(pos);
+  }
+
+  // (strand, diagonal) -> anchor positions in seq1
+  let mut diagonals: BTreeMap<(u8, i64), Vec<usize>> = BTreeMap::new();
+  for (pos2, forward, reverse) in kmers(bytes2, k) {
+    for (strand, code) in [(0u8, forward), (1u8, reverse)] {
+      if let Some(positions) = index.get(&code).filter(|p| p.len() <= ANCHOR_MAX_OCCURRENCES) {
+        for &pos1 in positions {
+          let diagonal = if strand == 0 { pos1 as i64 - pos2 as i64 } else { (pos1 + pos2) as i64 };
+          diagonals.entry((strand, diagonal)).or_default().push(pos1);
+        }
+      }
+    }
+  }
+
+  let mut segments = Vec::new();
+  for ((strand, diagonal), mut positions) in diagonals {
+    for (start1, end1) in chain_runs(&mut positions, k) {
+      if end1 - start1 < min_segment_length {
+        continue;
+      }
+      let (strand, start2, end2) = if strand == 0 {
+        (Strand::Forward, (start1 as i64 - diagonal) as usize, (end1 as i64 - diagonal) as usize)
+      } else {
+        // seq2 runs backwards as seq1 advances along an anti-diagonal
+        (Strand::Reverse, (diagonal - end1 as i64 + k as i64) as usize, (diagonal - start1 as i64 + k as i64) as usize)
+      };
+      segments.push(Segment { strand, start1, end1, start2, end2, kind: SegmentKind::Colinear });
+    }
+  }
+  segments.sort_by_key(|s| (s.start1, s.start2));
+  segments
+}
+
+/// Mark the heaviest chain of forward segments ascending in both sequences as co-linear
+fn classify(segments: &mut [Segment]) {
+  let forward: Vec<usize> = (0..segments.len()).filter(|&i| segments[i].strand == Strand::Forward).collect();
+  let mut weight: Vec<usize> = vec![0; forward.len()];
+  let mut previous: Vec<Option<usize>> = vec![None; forward.len()];
+  for a in 0..forward.len() {
+    let sa = &segments[forward[a]];
+    weight[a] = sa.end1 - sa.start1;
+    for b in 0..a {
+      let sb = &segments[forward[b]];
+      if sb.end1 <= sa.start1 + (sa.end1 - sa.start1) / 2 && sb.start2 < sa.start2 && weight[b] + (sa.end1 - sa.start1) > weight[a] {
+        weight[a] = weight[b] + (sa.end1 - sa.start1);
+        previous[a] = Some(b);
+      }
+    }
+  }
+
+  for segment in segments.iter_mut() {
+    segment.kind = if segment.strand == Strand::Reverse { SegmentKind::Inversion } else { SegmentKind::Transposition };
+  }
+  let mut best = (0..forward.len()).max_by_key(|&a| (weight[a], std::cmp::Reverse(a)));
+  while let Some(a) = best {
+    segments[forward[a]].kind = SegmentKind::Colinear;
+    best = previous[a];
+  }
+}
+
+/// Segments of exact `anchor_length`-base anchors (clamped to 1..=32) shared on either
+/// strand, at least `min_segment_length` long, classified against the co-linear backbone
+pub fn find_rearrangements(bytes1: &[u8], bytes2: &[u8], anchor_length: usize, min_segment_length: usize) -> Vec<Segment> {
+  let k = anchor_length.clamp(1, MAX_K);
+  let mut segments = find_segments(bytes1, bytes2, k, min_segment_length.max(k));
+  classify(&mut segments);
+  segments
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::iupac::reverse_complement;
+
+  /// Reproducible pseudo-random bases
+  fn random_bases(len: usize, mut state: u64) -> Vec<u8> {
+    (0..len)
+      .map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        b"ACGT"[(state >> 33) as usize % 4]
+      })
+      .collect()
+  }
+
+  fn layout(segments: &[Segment]) -> Vec<(&'static str, usize, usize, usize, usize)> {
+    segments.iter().map(|s| (s.kind.name(), s.start1, s.end1, s.start2, s.end2)).collect()
+  }
+
+  #[test]
+  fn a_reversed_block_is_an_inversion() {
+    let (a, b, c) = (random_bases(150, 1), random_bases(60, 5), random_bases(150, 3));
+    let seq1 = [a.as_slice(), &b, &c].concat();
+    let seq2 = [a.as_slice(), &reverse_complement(&b), &c].concat();
+    let segments = find_rearrangements(&seq1, &seq2, 12, 30);
+    assert_eq!(layout(&segments), vec![("colinear", 0, 150, 0, 150), ("inversion", 150, 210, 150, 210), ("colinear", 210, 360, 210, 360)]);
+    assert_eq!(segments[1].strand, Strand::Reverse);
+  }
+
+  #[test]
+  fn a_moved_block_is_a_transposition() {
+    let (a, b, c) = (random_bases(150, 1), random_bases(60, 5), random_bases(150, 3));
+    let seq1 = [a.as_slice(), &b, &c].concat();
+    let seq2 = [b.as_slice(), &a, &c].concat();
+    let segments = find_rearrangements(&seq1, &seq2, 12, 30);
+    assert_eq!(layout(&segments), vec![("colinear", 0, 150, 60, 210), ("transposition", 150, 210, 0, 60), ("colinear", 210, 360, 210, 360)]);
+  }
+}
@@ -0,0 +1,88 @@
+use crate::blocks::{count_mismatches_in_mask, find_conserved_blocks, ConservedBlock};
+use crate::kozak::kozak_start;
+use crate::translation::{infer_cds, orf_from_start, StartCodons};
+
+// ============================================================================
+// UTR / CDS Partitioning
+// ============================================================================
+// The CDS is taken from seq1 (the reference) and projected onto the alignment,
+// splitting the mask into 5'UTR, CDS, and 3'UTR columns.
+
+/// A CDS in seq1 as 0-based half-open positions
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cds {
+  pub start: usize,
+  pub end: usize,
+  /// Whether it was inferred rather than given
+  pub inferred: bool,
+}
+
+/// The CDS from `cds_start`/`cds_end` (clamped to the sequence); from `cds_start` to the
+/// first in-frame stop when only the end is omitted; otherwise inferred from the first of
+/// `start_codons`, or with `kozak` the one in the strongest Kozak context
+pub fn select_cds(seq: &[u8], cds_start: Option<usize>, cds_end: Option<usize>, start_codons: &StartCodons, kozak: bool) -> Option<Cds> {
+  let cds = |(start, end), inferred| Cds { start, end, inferred };
+  match (cds_start, cds_end) {
+    (Some(start), Some(end)) => Some(cds((start.min(seq.len()), end.min(seq.len())), false)),
+    (Some(start), None) => orf_from_start(seq, start).map(|range| cds(range, false)),
+    (None, _) if kozak => kozak_start(seq, start_codons)
+      .and_then(|(start, _)| orf_from_start(seq, start))
+      .map(|range| cds(range, true)),
+    (None, _) => infer_cds(seq, start_codons).map(|range| cds(range, true)),
+  }
+}
+
+pub struct RegionStats {
+  pub name: &'static str,
+  /// Alignment columns, half-open
+  pub start: usize,
+  pub end: usize,
+  pub mismatches: usize,
+  pub identity: f64,
+  /// In alignment columns, like the unpartitioned result
+  pub blocks: Vec<ConservedBlock>,
+}
+
+fn region_stats(
+  name: &'static str,
+  mask: &[u8],
+  start: usize,
+  end: usize,
+  window_size: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> RegionStats {
+  let sub_mask = &mask[start..end];
+  let mismatches = count_mismatches_in_mask(sub_mask);
+  let identity = if sub_mask.is_empty() { 0.0 } else { 1.0 - (mismatches as f64) / (sub_mask.len() as f64) };
+
+  // Block coordinates are reported in alignment space, like the unpartitioned result
+  let mut blocks = find_conserved_blocks(sub_mask, window_size, min_identity, min_significant_length_group);
+  for block in &mut blocks {
+    block.start += start;
+    block.end += start;
+  }
+
+  RegionStats { name, start, end, mismatches, identity, blocks }
+}
+
+/// 5'UTR, CDS, and 3'UTR statistics of a mask whose first column is seq1 position `offset1`
+pub fn partition_regions(
+  mask: &[u8],
+  offset1: usize,
+  cds: Cds,
+  window_size: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> [RegionStats; 3] {
+  // Project seq1 coordinates onto alignment columns
+  let project = |pos: usize| pos.saturating_sub(offset1).min(mask.len());
+  let cds_col_start = project(cds.start);
+  let cds_col_end = project(cds.end);
+
+  [
+    region_stats("5'UTR", mask, 0, cds_col_start, window_size, min_identity, min_significant_length_group),
+    region_stats("CDS", mask, cds_col_start, cds_col_end, window_size, min_identity, min_significant_length_group),
+    region_stats("3'UTR", mask, cds_col_end, mask.len(), window_size, min_identity, min_significant_length_group),
+  ]
+}
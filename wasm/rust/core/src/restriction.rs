@@ -0,0 +1,200 @@
+use crate::alignment::Alignment;
+use crate::iupac::base_set;
+use crate::pattern::find_pattern;
+
+// ============================================================================
+// Restriction Sites
+// ============================================================================
+// Recognition sites are IUPAC patterns, found exactly on both strands by the
+// pattern search (see the pattern module). A mismatch that creates or destroys
+// a site between two alleles lets a digest tell them apart (PCR-RFLP
+// genotyping).
+
+/// Built-in enzymes as name and site, '^' marking the top-strand cut
+pub const COMMON_ENZYMES: &[(&str, &str)] = &[
+  ("AgeI", "A^CCGGT"),
+  ("AluI", "AG^CT"),
+  ("ApaI", "GGGCC^C"),
+  ("AvaII", "G^GWCC"),
+  ("BamHI", "G^GATCC"),
+  ("BglII", "A^GATCT"),
+  ("BsrGI", "T^GTACA"),
+  ("ClaI", "AT^CGAT"),
+  ("DpnII", "^GATC"),
+  ("EcoRI", "G^AATTC"),
+  ("EcoRV", "GAT^ATC"),
+  ("HaeIII", "GG^CC"),
+  ("HindIII", "A^AGCTT"),
+  ("HinfI", "G^ANTC"),
+  ("KpnI", "GGTAC^C"),
+  ("MluI", "A^CGCGT"),
+  ("MspI", "C^CGG"),
+  ("NcoI", "C^CATGG"),
+  ("NdeI", "CA^TATG"),
+  ("NheI", "G^CTAGC"),
+  ("NotI", "GC^GGCCGC"),
+  ("PstI", "CTGCA^G"),
+  ("PvuII", "CAG^CTG"),
+  ("SacI", "GAGCT^C"),
+  ("SacII", "CCGC^GG"),
+  ("SalI", "G^TCGAC"),
+  ("Sau96I", "G^GNCC"),
+  ("ScaI", "AGT^ACT"),
+  ("SmaI", "CCC^GGG"),
+  ("SpeI", "A^CTAGT"),
+  ("StyI", "C^CWWGG"),
+  ("TaqI", "T^CGA"),
+  ("XbaI", "T^CTAGA"),
+  ("XhoI", "C^TCGAG"),
+  ("XmnI", "GAANN^NNTTC"),
+];
+
+pub struct Enzyme {
+  pub name: String,
+  /// Recognition site in IUPAC codes, uppercase
+  pub site: Vec<u8>,
+  /// Top-strand cut, in bases after the start of the site
+  pub cut: usize,
+}
+
+impl Enzyme {
+  /// An enzyme from a name and a site such as "G^AATTC"; without '^' the cut is taken to be
+  /// after the site. `None` when the site is empty, has more than one '^', or has a character
+  /// that is not an IUPAC base
+  pub fn new(name: &str, site: &str) -> Option<Enzyme> {
+    let cut = site.find('^');
+    if site.matches('^').count() > 1 {
+      return None;
+    }
+    let site: Vec<u8> = site.bytes().filter(|&c| c != b'^').map(|c| c.to_ascii_uppercase()).collect();
+    if site.is_empty() || site.iter().any(|&c| base_set(c) == 0) {
+      return None;
+    }
+    let cut = cut.unwrap_or(site.len());
+    Some(Enzyme { name: name.to_string(), site, cut })
+  }
+
+  /// Sites in `seq` (case-insensitive) on both strands, by position
+  pub fn find_sites(&self, seq: &[u8]) -> Vec<Site> {
+    find_pattern(seq, &self.site, 0)
+      .into_iter()
+      .map(|hit| Site {
+        position: hit.position,
+        reverse: hit.reverse,
+        cut: if hit.reverse { hit.position + self.site.len() - self.cut } else { hit.position + self.cut },
+      })
+      .collect()
+  }
+}
+
+/// Enzymes from a list separated by commas or spaces: built-in names (case-insensitive) or
+/// custom `Name=SITE` entries; an empty list gives all built-in enzymes. Entries that are
+/// neither are returned separately
+pub fn parse_enzymes(list: &str) -> (Vec<Enzyme>, Vec<String>) {
+  let entries: Vec<&str> = list.split(|c: char| c == ',' || c.is_whitespace()).filter(|entry| !entry.is_empty()).collect();
+  if entries.is_empty() {
+    let enzymes = COMMON_ENZYMES.iter().filter_map(|(name, site)| Enzyme::new(name, site)).collect();
+    return (enzymes, Vec::new());
+  }
+  let (mut enzymes, mut unknown) = (Vec::new(), Vec::new());
+  for entry in entries {
+    let enzyme = match entry.split_once('=') {
+      Some((name, site)) => Enzyme::new(name, site),
+      None => COMMON_ENZYMES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(entry))
+        .and_then(|(name, site)| Enzyme::new(name, site)),
+    };
+    match enzyme {
+      Some(enzyme) => enzymes.push(enzyme),
+      None => unknown.push(entry.to_string()),
+    }
+  }
+  (enzymes, unknown)
+}
+
+pub struct Site {
+  pub position: usize,
+  /// Matched on the reverse strand (non-palindromic sites only)
+  pub reverse: bool,
+  /// Top-strand cut position
+  pub cut: usize,
+}
+
+impl Site {
+  pub fn strand(&self) -> char {
+    if self.reverse { '-' } else { '+' }
+  }
+}
+
+/// A site in the aligned overlap of one sequence whose counterpart in the other is not a site
+pub struct DifferentialSite<'a> {
+  pub enzyme: &'a Enzyme,
+  /// Column in the alignment
+  pub column: usize,
+  pub reverse: bool,
+  /// Whether seq1 (rather than seq2) has the site
+  pub in_seq1: bool,
+  /// Mismatching columns inside the site
+  pub mismatches: Vec<usize>,
+}
+
+/// Sites that one sequence has and the other lacks over the placement `alignment`, by column
+/// and enzyme; sites not entirely inside the overlap are left out, since the other sequence
+/// has nothing to compare them to
+pub fn differential_sites<'a>(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  enzymes: &'a [Enzyme],
+) -> Vec<DifferentialSite<'a>> {
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+  let mut differential = Vec::new();
+  for enzyme in enzymes {
+    let (sites1, sites2) = (enzyme.find_sites(region1), enzyme.find_sites(region2));
+    let key = |site: &Site| (site.position, site.reverse);
+    for (sites, others, in_seq1) in [(&sites1, &sites2, true), (&sites2, &sites1, false)] {
+      for site in sites.iter().filter(|site| !others.iter().any(|other| key(other) == key(site))) {
+        let columns = site.position..site.position + enzyme.site.len();
+        let mismatches = columns.filter(|&column| !region1[column].eq_ignore_ascii_case(&region2[column])).collect();
+        differential.push(DifferentialSite { enzyme, column: site.position, reverse: site.reverse, in_seq1, mismatches });
+      }
+    }
+  }
+  differential.sort_by(|a, b| (a.column, &a.enzyme.name, !a.in_seq1).cmp(&(b.column, &b.enzyme.name, !b.in_seq1)));
+  differential
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn enzymes_are_built_in_names_or_custom_sites() {
+    assert_eq!(parse_enzymes(" ").0.len(), COMMON_ENZYMES.len());
+    let (enzymes, unknown) = parse_enzymes("ecori, Esp=CGTCTC bogus,Bad=G^A^C");
+    let parsed: Vec<(&str, &[u8], usize)> = enzymes.iter().map(|e| (e.name.as_str(), e.site.as_slice(), e.cut)).collect();
+    assert_eq!(parsed, vec![("EcoRI", &b"GAATTC"[..], 1), ("Esp", b"CGTCTC", 6)]);
+    assert_eq!(unknown, vec!["bogus", "Bad=G^A^C"]);
+  }
+
+  #[test]
+  fn sites_are_found_once_on_each_strand_they_match() {
+    let (enzymes, _) = parse_enzymes("EcoRI Esp=CGTCTC");
+    let sites = |enzyme: &Enzyme, seq: &[u8]| enzyme.find_sites(seq).iter().map(|s| (s.position, s.strand(), s.cut)).collect::<Vec<_>>();
+    // EcoRI is palindromic; Esp is read backwards as GAGACG
+    assert_eq!(sites(&enzymes[0], b"ttgaattctt"), vec![(2, '+', 3)]);
+    assert_eq!(sites(&enzymes[1], b"ACGTCTCAGAGACGT"), vec![(1, '+', 7), (8, '-', 8)]);
+  }
+
+  #[test]
+  fn a_mismatch_inside_a_site_makes_it_differential() {
+    let (enzymes, _) = parse_enzymes("EcoRI,HindIII");
+    let alignment = Alignment { offset1: 0, offset2: 0, length: 16, mismatches: 2, identity: 14.0 / 16.0 };
+    let differential = differential_sites(b"TTGAATTCTTAAGCAT", b"TTGAAATCTTAAGCTT", &alignment, &enzymes);
+    let found: Vec<(&str, usize, bool, Vec<usize>)> =
+      differential.iter().map(|d| (d.enzyme.name.as_str(), d.column, d.in_seq1, d.mismatches.clone())).collect();
+    assert_eq!(found, vec![("EcoRI", 2, true, vec![5]), ("HindIII", 10, false, vec![14])]);
+  }
+}
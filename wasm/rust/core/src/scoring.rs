@@ -0,0 +1,474 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::symbols::{GAP, MISMATCH};
+
+// ============================================================================
+// Alignment Scores
+// ============================================================================
+// Percent identity cannot rank alignments of different lengths: 100% over 30
+// bases says less than 98% over 3,000. Results therefore also carry a raw
+// score (sum of per-column scores) and a bit score, the raw score rescaled by
+// the Karlin-Altschul parameters of the scoring system:
+//
+//   bits = (lambda * raw - ln K) / ln 2
+//
+// lambda and K are computed for the configured scores under a fixed
+// background (uniform bases; Robinson & Robinson amino-acid frequencies, as
+// BLAST uses), so bit scores from different scoring systems are comparable.
+// The statistics are those of ungapped alignments, which is what the offset
+// search produces; '-' columns in pre-aligned input only add `gap_score`.
+//
+// The E-value, the number of alignments at least this good expected between
+// random sequences of the same lengths, is K * m * n * e^(-lambda * raw) with
+// lambda and K recomputed for the residue composition of the two sequences:
+// a 60%-identity hit between two AT-rich sequences is less surprising than
+// the same hit under uniform base frequencies.
+//
+// A caller-supplied matrix replaces either set of scores for alphabets the
+// built-ins do not cover. Its statistics keep the usual backgrounds when it
+// scores all four bases or all 20 standard amino acids, and assume uniform
+// letter frequencies otherwise.
+
+#[derive(Clone, PartialEq)]
+pub enum ProteinMatrix {
+  /// NCBI BLOSUM62
+  Blosum62,
+  /// `match_score` for identical residues, `mismatch_score` otherwise
+  MatchMismatch,
+  Custom(Arc<ScoreMatrix>),
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Scoring {
+  pub match_score: i32,
+  pub mismatch_score: i32,
+  /// Nucleotide column where either sequence has a '-' gap
+  pub gap_score: i32,
+  /// Scores nucleotide pairs in place of `match_score` and `mismatch_score`
+  pub nucleotide_matrix: Option<Arc<ScoreMatrix>>,
+  pub protein_matrix: ProteinMatrix,
+  /// Amino-acid column where either sequence has a '-' gap
+  pub protein_gap_score: i32,
+}
+
+/// Defaults follow BLAST: megablast's +1/-2 for nucleotides, BLOSUM62 for proteins
+impl Default for Scoring {
+  fn default() -> Self {
+    Scoring {
+      match_score: 1,
+      mismatch_score: -2,
+      gap_score: -2,
+      nucleotide_matrix: None,
+      protein_matrix: ProteinMatrix::Blosum62,
+      protein_gap_score: -4,
+    }
+  }
+}
+
+// ============================================================================
+// Custom Matrices
+// ============================================================================
+
+/// Integer substitution scores over any alphabet of single letters (case-insensitive)
+#[derive(Clone, PartialEq)]
+pub struct ScoreMatrix {
+  letters: Vec<u8>,
+  /// Row-major, `letters.len()` squared
+  scores: Vec<i32>,
+  /// Row and column for letters outside the alphabet: X's, else N's
+  fallback: Option<usize>,
+}
+
+impl ScoreMatrix {
+  /// A matrix of `rows[i][j]` for `letters[i]` against `letters[j]`; `None` unless the rows
+  /// are square over distinct letters
+  pub fn new(letters: &[u8], rows: &[Vec<i32>]) -> Option<ScoreMatrix> {
+    let letters = letters.to_ascii_uppercase();
+    let distinct = letters.iter().enumerate().all(|(i, c)| !letters[..i].contains(c));
+    if letters.is_empty() || !distinct || rows.len() != letters.len() || rows.iter().any(|row| row.len() != letters.len())
+    {
+      return None;
+    }
+    let fallback = letters.iter().position(|&c| c == b'X').or_else(|| letters.iter().position(|&c| c == b'N'));
+    Some(ScoreMatrix { scores: rows.concat(), letters, fallback })
+  }
+
+  /// A matrix in NCBI's text format (a header of column letters, then one row per letter
+  /// starting with it; `#` comments) or as JSON, either `{"A": {"A": 4, "R": -1, ...}, ...}`
+  /// or `{"alphabet": "AR...", "scores": [[4, -1, ...], ...]}`
+  pub fn parse(text: &str) -> Option<ScoreMatrix> {
+    if text.trim_start().starts_with('{') { ScoreMatrix::from_json(text) } else { ScoreMatrix::from_ncbi(text) }
+  }
+
+  fn from_ncbi(text: &str) -> Option<ScoreMatrix> {
+    let letter = |field: &str| (field.len() == 1).then(|| field.as_bytes()[0]);
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+    let header: Vec<u8> = lines.next()?.split_whitespace().map(letter).collect::<Option<_>>()?;
+    let mut rows = vec![Vec::new(); header.len()];
+    for line in lines {
+      let mut fields = line.split_whitespace();
+      let row = letter(fields.next()?)?.to_ascii_uppercase();
+      let index = header.iter().position(|c| c.to_ascii_uppercase() == row)?;
+      rows[index] = fields.map(|field| field.parse().ok()).collect::<Option<_>>()?;
+    }
+    ScoreMatrix::new(&header, &rows)
+  }
+
+  fn from_json(text: &str) -> Option<ScoreMatrix> {
+    let value: Value = serde_json::from_str(text).ok()?;
+    let score = |value: &Value| value.as_i64().and_then(|score| i32::try_from(score).ok());
+    if let (Some(alphabet), Some(scores)) = (value["alphabet"].as_str(), value["scores"].as_array()) {
+      let rows: Vec<Vec<i32>> =
+        scores.iter().map(|row| row.as_array()?.iter().map(score).collect()).collect::<Option<_>>()?;
+      return ScoreMatrix::new(alphabet.as_bytes(), &rows);
+    }
+    let table = value.as_object()?;
+    let letters: Vec<u8> = table.keys().map(|key| (key.len() == 1).then(|| key.as_bytes()[0])).collect::<Option<_>>()?;
+    let rows: Vec<Vec<i32>> = table
+      .values()
+      .map(|row| table.keys().map(|column| row.get(column).and_then(score)).collect())
+      .collect::<Option<_>>()?;
+    ScoreMatrix::new(&letters, &rows)
+  }
+
+  pub fn letters(&self) -> &[u8] {
+    &self.letters
+  }
+
+  fn index(&self, residue: u8) -> Option<usize> {
+    let residue = residue.to_ascii_uppercase();
+    self.letters.iter().position(|&c| c == residue).or(self.fallback)
+  }
+
+  /// Score of two residues; one outside the alphabet scores as X (or N), or with the
+  /// matrix's lowest score when it has neither
+  pub fn score(&self, a: u8, b: u8) -> i32 {
+    match (self.index(a), self.index(b)) {
+      (Some(i), Some(j)) => self.scores[i * self.letters.len() + j],
+      _ => self.scores.iter().copied().min().unwrap_or(0),
+    }
+  }
+
+  /// Letters the statistics draw from, with their background frequencies
+  fn background(&self) -> (Vec<u8>, Vec<f64>) {
+    let standard = &BLOSUM_ORDER[..AMINO_ACIDS];
+    if standard.iter().all(|c| self.letters.contains(c)) {
+      return (standard.to_vec(), BACKGROUND.to_vec());
+    }
+    let letters: Vec<u8> = self.letters.iter().copied().filter(|&c| c != b'*').collect();
+    let uniform = vec![1.0 / letters.len() as f64; letters.len()];
+    (letters, uniform)
+  }
+
+  /// Parameters for these scores with letter frequencies `f1` and `f2` over `letters`
+  fn statistics(&self, letters: &[u8], f1: &[f64], f2: &[f64]) -> Option<Statistics> {
+    let mut probabilities = Vec::with_capacity(letters.len() * letters.len());
+    for (&a, &p) in letters.iter().zip(f1) {
+      for (&b, &q) in letters.iter().zip(f2) {
+        probabilities.push((self.score(a, b), p * q));
+      }
+    }
+    karlin_altschul(&probabilities)
+  }
+}
+
+/// Karlin-Altschul parameters of a scoring system
+#[derive(Clone, Copy, PartialEq)]
+pub struct Statistics {
+  pub lambda: f64,
+  pub k: f64,
+}
+
+impl Statistics {
+  pub fn bit_score(&self, raw: i64) -> f64 {
+    (self.lambda * raw as f64 - self.k.ln()) / std::f64::consts::LN_2
+  }
+
+  /// Expected number of alignments scoring at least `raw` in an m x n search space, summed in
+  /// log space so a low or negative score gives a large E-value rather than inf times zero;
+  /// scores so low that even the logarithm overflows give infinity
+  pub fn evalue(&self, raw: i64, m: usize, n: usize) -> f64 {
+    (self.k.ln() + (m as f64).ln() + (n as f64).ln() - self.lambda * raw as f64).exp()
+  }
+}
+
+const NUCLEOTIDES: &[u8; 4] = b"ACGT";
+
+/// Frequencies of `alphabet` letters in `seq` (case-insensitive, other residues ignored);
+/// `None` if none occur
+fn composition<const N: usize>(seq: &[u8], alphabet: &[u8]) -> Option<[f64; N]> {
+  frequencies(seq, &alphabet[..N]).map(|frequencies| std::array::from_fn(|i| frequencies[i]))
+}
+
+fn frequencies(seq: &[u8], alphabet: &[u8]) -> Option<Vec<f64>> {
+  let mut counts = vec![0usize; alphabet.len()];
+  for &c in seq {
+    if let Some(i) = alphabet.iter().position(|&a| a == c.to_ascii_uppercase()) {
+      counts[i] += 1;
+    }
+  }
+  let total: usize = counts.iter().sum();
+  (total > 0).then(|| counts.iter().map(|&count| count as f64 / total as f64).collect())
+}
+
+impl Scoring {
+  /// Raw score of equal-length aligned nucleotide regions and their mask
+  pub fn nucleotide_score(&self, region1: &[u8], region2: &[u8], mask: &[u8]) -> i64 {
+    mask
+      .iter()
+      .zip(region1.iter().zip(region2))
+      .map(|(&m, (&a, &b))| {
+        if a == GAP || b == GAP {
+          self.gap_score
+        } else if let Some(matrix) = &self.nucleotide_matrix {
+          matrix.score(a, b)
+        } else if m == MISMATCH {
+          self.mismatch_score
+        } else {
+          self.match_score
+        }
+      } as i64)
+      .sum()
+  }
+
+  /// Raw score of equal-length aligned amino-acid sequences and their mask
+  pub fn protein_score(&self, aa1: &[u8], aa2: &[u8], mask: &[u8]) -> i64 {
+    mask
+      .iter()
+      .zip(aa1.iter().zip(aa2))
+      .map(|(&m, (&a, &b))| {
+        if a == GAP || b == GAP {
+          self.protein_gap_score
+        } else {
+          match &self.protein_matrix {
+            ProteinMatrix::Blosum62 => blosum62(a, b),
+            ProteinMatrix::MatchMismatch if m == MISMATCH => self.mismatch_score,
+            ProteinMatrix::MatchMismatch => self.match_score,
+            ProteinMatrix::Custom(matrix) => matrix.score(a, b),
+          }
+        }
+      } as i64)
+      .sum()
+  }
+
+  /// Parameters for nucleotide scores under uniform base frequencies; `None` when the
+  /// expected score of a random column is not negative or no column scores positive (bit
+  /// scores are then undefined)
+  pub fn nucleotide_statistics(&self) -> Option<Statistics> {
+    self.nucleotide_statistics_for(&[0.25; 4], &[0.25; 4])
+  }
+
+  /// Parameters for nucleotide scores under the base composition of the two sequences
+  pub fn nucleotide_composition_statistics(&self, seq1: &[u8], seq2: &[u8]) -> Option<Statistics> {
+    let uniform = [0.25; 4];
+    let f1 = composition::<4>(seq1, NUCLEOTIDES).unwrap_or(uniform);
+    let f2 = composition::<4>(seq2, NUCLEOTIDES).unwrap_or(uniform);
+    self.nucleotide_statistics_for(&f1, &f2)
+  }
+
+  fn nucleotide_statistics_for(&self, f1: &[f64; 4], f2: &[f64; 4]) -> Option<Statistics> {
+    if let Some(matrix) = &self.nucleotide_matrix {
+      if NUCLEOTIDES.iter().all(|c| matrix.letters().contains(c)) {
+        return matrix.statistics(NUCLEOTIDES, f1, f2);
+      }
+      let (letters, background) = matrix.background();
+      return matrix.statistics(&letters, &background, &background);
+    }
+    let matched: f64 = f1.iter().zip(f2).map(|(p, q)| p * q).sum();
+    karlin_altschul(&[(self.match_score, matched), (self.mismatch_score, 1.0 - matched)])
+  }
+
+  /// Parameters for amino-acid scores under the Robinson & Robinson background, as for
+  /// `nucleotide_statistics`
+  pub fn protein_statistics(&self) -> Option<Statistics> {
+    if let ProteinMatrix::Custom(matrix) = &self.protein_matrix {
+      let (letters, background) = matrix.background();
+      return matrix.statistics(&letters, &background, &background);
+    }
+    self.protein_statistics_for(&BACKGROUND, &BACKGROUND)
+  }
+
+  /// Parameters for amino-acid scores under the residue composition of the two translations
+  pub fn protein_composition_statistics(&self, aa1: &[u8], aa2: &[u8]) -> Option<Statistics> {
+    if let ProteinMatrix::Custom(matrix) = &self.protein_matrix {
+      let (letters, background) = matrix.background();
+      let f1 = frequencies(aa1, &letters).unwrap_or_else(|| background.clone());
+      let f2 = frequencies(aa2, &letters).unwrap_or(background);
+      return matrix.statistics(&letters, &f1, &f2);
+    }
+    let f1 = composition::<AMINO_ACIDS>(aa1, BLOSUM_ORDER).unwrap_or(BACKGROUND);
+    let f2 = composition::<AMINO_ACIDS>(aa2, BLOSUM_ORDER).unwrap_or(BACKGROUND);
+    self.protein_statistics_for(&f1, &f2)
+  }
+
+  fn protein_statistics_for(&self, f1: &[f64; AMINO_ACIDS], f2: &[f64; AMINO_ACIDS]) -> Option<Statistics> {
+    let mut probabilities = Vec::with_capacity(AMINO_ACIDS * AMINO_ACIDS);
+    for (i, &p) in f1.iter().enumerate() {
+      for (j, &q) in f2.iter().enumerate() {
+        let score = match &self.protein_matrix {
+          ProteinMatrix::Blosum62 => BLOSUM62[i][j] as i32,
+          ProteinMatrix::MatchMismatch if i == j => self.match_score,
+          ProteinMatrix::MatchMismatch => self.mismatch_score,
+          ProteinMatrix::Custom(matrix) => matrix.score(BLOSUM_ORDER[i], BLOSUM_ORDER[j]),
+        };
+        probabilities.push((score, p * q));
+      }
+    }
+    karlin_altschul(&probabilities)
+  }
+}
+
+// ============================================================================
+// Karlin-Altschul Parameters
+// ============================================================================
+const LAMBDA_ITERATIONS: usize = 100;
+/// Series limits for K, as BLAST's BLAST_KARLIN_K_ITER_MAX and BLAST_KARLIN_K_SUMLIMIT_DEFAULT
+const K_MAX_TERMS: usize = 100;
+const K_TOLERANCE: f64 = 1e-4;
+
+fn gcd(a: i32, b: i32) -> i32 {
+  if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// lambda and K for integer column scores drawn with the given probabilities (Karlin &
+/// Altschul 1990). lambda is the positive root of sum(p * e^(lambda * s)) = 1; K follows
+/// from the series over sums of k column scores, as in BLAST's BlastKarlinLHtoK.
+fn karlin_altschul(probabilities: &[(i32, f64)]) -> Option<Statistics> {
+  let scores: Vec<(i32, f64)> = probabilities.iter().copied().filter(|&(_, p)| p > 0.0).collect();
+  let expected: f64 = scores.iter().map(|&(s, p)| s as f64 * p).sum();
+  let (low, high) = scores.iter().fold((0, 0), |(low, high), &(s, _)| (low.min(s), high.max(s)));
+  if expected >= 0.0 || high <= 0 || low >= 0 {
+    return None;
+  }
+
+  // lambda: f(0) = 0 and f falls then rises, so bracket the positive root and bisect
+  let f = |lambda: f64| scores.iter().map(|&(s, p)| p * (lambda * s as f64).exp()).sum::<f64>() - 1.0;
+  let mut upper = 1.0;
+  while f(upper) <= 0.0 {
+    upper *= 2.0;
+  }
+  let mut lower = 0.0;
+  for _ in 0..LAMBDA_ITERATIONS {
+    let mid = (lower + upper) / 2.0;
+    if f(mid) > 0.0 { upper = mid } else { lower = mid }
+  }
+  let lambda = (lower + upper) / 2.0;
+
+  // Relative entropy of the target frequencies p * e^(lambda * s)
+  let h: f64 = scores.iter().map(|&(s, p)| lambda * s as f64 * p * (lambda * s as f64).exp()).sum();
+
+  // Work on the lattice of scores divided by their common divisor
+  let delta = scores.iter().fold(0, |g, &(s, _)| gcd(g, s));
+  let (low, high) = (low / delta, high / delta);
+  let lambda_delta = lambda * delta as f64;
+  let span = (high - low) as usize;
+  let mut step = vec![0.0; span + 1];
+  for &(s, p) in &scores {
+    step[(s / delta - low) as usize] += p;
+  }
+
+  // sigma = sum over k of (E[e^(lambda S_k); S_k < 0] + P(S_k >= 0)) / k
+  let mut sigma = 0.0;
+  let mut sums = step.clone();
+  let mut sums_low = low as i64;
+  for k in 1..=K_MAX_TERMS {
+    let term: f64 = sums
+      .iter()
+      .enumerate()
+      .map(|(i, &p)| {
+        let s = sums_low + i as i64;
+        if s < 0 { p * (lambda_delta * s as f64).exp() } else { p }
+      })
+      .sum::<f64>()
+      / k as f64;
+    sigma += term;
+    if term < K_TOLERANCE {
+      break;
+    }
+    let mut next = vec![0.0; sums.len() + span];
+    for (i, &p) in sums.iter().enumerate().filter(|(_, &p)| p > 0.0) {
+      for (j, &q) in step.iter().enumerate() {
+        next[i + j] += p * q;
+      }
+    }
+    sums = next;
+    sums_low += low as i64;
+  }
+
+  let k = lambda_delta * (-2.0 * sigma).exp() / (h * (1.0 - (-lambda_delta).exp()));
+  Some(Statistics { lambda, k })
+}
+
+// ============================================================================
+// BLOSUM62
+// ============================================================================
+const BLOSUM_ORDER: &[u8; 24] = b"ARNDCQEGHILKMFPSTWYVBZX*";
+/// The 20 standard amino acids come first in `BLOSUM_ORDER`
+const AMINO_ACIDS: usize = 20;
+
+/// Robinson & Robinson (1991) amino-acid frequencies, in `BLOSUM_ORDER`
+const BACKGROUND: [f64; AMINO_ACIDS] = [
+  0.07805, 0.05129, 0.04487, 0.05364, 0.01925, 0.04264, 0.06295, 0.07377, 0.02199, 0.05142, 0.09019, 0.05744, 0.02243,
+  0.03856, 0.05203, 0.07120, 0.05841, 0.01330, 0.03216, 0.06441,
+];
+
+#[rustfmt::skip]
+const BLOSUM62: [[i8; 24]; 24] = [
+  //A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   B   Z   X   *
+  [ 4, -1, -2, -2,  0, -1, -1,  0, -2, -1, -1, -1, -1, -2, -1,  1,  0, -3, -2,  0, -2, -1,  0, -4], // A
+  [-1,  5,  0, -2, -3,  1,  0, -2,  0, -3, -2,  2, -1, -3, -2, -1, -1, -3, -2, -3, -1,  0, -1, -4], // R
+  [-2,  0,  6,  1, -3,  0,  0,  0,  1, -3, -3,  0, -2, -3, -2,  1,  0, -4, -2, -3,  3,  0, -1, -4], // N
+  [-2, -2,  1,  6, -3,  0,  2, -1, -1, -3, -4, -1, -3, -3, -1,  0, -1, -4, -3, -3,  4,  1, -1, -4], // D
+  [ 0, -3, -3, -3,  9, -3, -4, -3, -3, -1, -1, -3, -1, -2, -3, -1, -1, -2, -2, -1, -3, -3, -2, -4], // C
+  [-1,  1,  0,  0, -3,  5,  2, -2,  0, -3, -2,  1,  0, -3, -1,  0, -1, -2, -1, -2,  0,  3, -1, -4], // Q
+  [-1,  0,  0,  2, -4,  2,  5, -2,  0, -3, -3,  1, -2, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4], // E
+  [ 0, -2,  0, -1, -3, -2, -2,  6, -2, -4, -4, -2, -3, -3, -2,  0, -2, -2, -3, -3, -1, -2, -1, -4], // G
+  [-2,  0,  1, -1, -3,  0,  0, -2,  8, -3, -3, -1, -2, -1, -2, -1, -2, -2,  2, -3,  0,  0, -1, -4], // H
+  [-1, -3, -3, -3, -1, -3, -3, -4, -3,  4,  2, -3,  1,  0, -3, -2, -1, -3, -1,  3, -3, -3, -1, -4], // I
+  [-1, -2, -3, -4, -1, -2, -3, -4, -3,  2,  4, -2,  2,  0, -3, -2, -1, -2, -1,  1, -4, -3, -1, -4], // L
+  [-1,  2,  0, -1, -3,  1,  1, -2, -1, -3, -2,  5, -1, -3, -1,  0, -1, -3, -2, -2,  0,  1, -1, -4], // K
+  [-1, -1, -2, -3, -1,  0, -2, -3, -2,  1,  2, -1,  5,  0, -2, -1, -1, -1, -1,  1, -3, -1, -1, -4], // M
+  [-2, -3, -3, -3, -2, -3, -3, -3, -1,  0,  0, -3,  0,  6, -4, -2, -2,  1,  3, -1, -3, -3, -1, -4], // F
+  [-1, -2, -2, -1, -3, -1, -1, -2, -2, -3, -3, -1, -2, -4,  7, -1, -1, -4, -3, -2, -2, -1, -2, -4], // P
+  [ 1, -1,  1,  0, -1,  0,  0,  0, -1, -2, -2,  0, -1, -2, -1,  4,  1, -3, -2, -2,  0,  0,  0, -4], // S
+  [ 0, -1,  0, -1, -1, -1, -1, -2, -2, -1, -1, -1, -1, -2, -1,  1,  5, -2, -2,  0, -1, -1,  0, -4], // T
+  [-3, -3, -4, -4, -2, -2, -3, -2, -2, -3, -2, -3, -1,  1, -4, -3, -2, 11,  2, -3, -4, -3, -2, -4], // W
+  [-2, -2, -2, -3, -2, -1, -2, -3,  2, -1, -1, -2, -1,  3, -3, -2, -2,  2,  7, -1, -3, -2, -1, -4], // Y
+  [ 0, -3, -3, -3, -1, -2, -2, -3, -3,  3,  1, -2,  1, -1, -2, -2,  0, -3, -1,  4, -3, -2, -1, -4], // V
+  [-2, -1,  3,  4, -3,  0,  1, -1,  0, -3, -4,  0, -3, -3, -2,  0, -1, -4, -3, -3,  4,  1, -1, -4], // B
+  [-1,  0,  0,  1, -3,  3,  4, -2,  0, -3, -3,  1, -1, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4], // Z
+  [ 0, -1, -1, -1, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2,  0,  0, -2, -1, -1, -1, -1, -1, -4], // X
+  [-4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,  1], // *
+];
+
+/// Index into `BLOSUM_ORDER`; residues outside it score as X
+fn blosum_index(residue: u8) -> usize {
+  let residue = residue.to_ascii_uppercase();
+  BLOSUM_ORDER.iter().position(|&c| c == residue).unwrap_or(22)
+}
+
+/// NCBI BLOSUM62 score of two residues (case-insensitive; unknown residues score as X)
+pub fn blosum62(a: u8, b: u8) -> i32 {
+  BLOSUM62[blosum_index(a)][blosum_index(b)] as i32
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn evalue_of_a_low_score_stays_finite() {
+    let statistics = Scoring::default().nucleotide_statistics().unwrap();
+    let evalue = statistics.evalue(-300, 2000, 2000);
+    assert!(evalue.is_finite() && evalue > 1e100);
+    assert!(statistics.evalue(500, 2000, 2000) < 1e-100);
+  }
+
+  #[test]
+  fn evalue_of_an_empty_search_space_is_zero() {
+    let statistics = Scoring::default().nucleotide_statistics().unwrap();
+    assert_eq!(statistics.evalue(-10_000, 0, 2000), 0.0);
+  }
+}
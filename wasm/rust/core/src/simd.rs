@@ -0,0 +1,89 @@
+// ============================================================================
+// Byte Comparison Kernels
+// ============================================================================
+// Mismatch counting and mask building dominate the profile. With the `simd`
+// feature on a wasm32 build that enables `simd128`, 16 bytes are compared per
+// instruction; every other configuration uses the scalar loops. The vector
+// helpers return how many leading bytes they handled so the scalar loop can
+// finish the tail.
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+mod vector {
+  use core::arch::wasm32::*;
+
+  const LANES: usize = 16;
+
+  #[inline]
+  fn load(bytes: &[u8]) -> v128 {
+    assert!(bytes.len() >= LANES);
+    // SAFETY: length checked above; v128_load has no alignment requirement
+    unsafe { v128_load(bytes.as_ptr() as *const v128) }
+  }
+
+  pub(super) fn count_mismatches(a: &[u8], b: &[u8]) -> (usize, usize) {
+    let mut mismatches = 0;
+    let mut done = 0;
+    for (ca, cb) in a.chunks_exact(LANES).zip(b.chunks_exact(LANES)) {
+      let eq = u8x16_eq(load(ca), load(cb));
+      mismatches += LANES - u8x16_bitmask(eq).count_ones() as usize;
+      done += LANES;
+    }
+    (done, mismatches)
+  }
+
+  pub(super) fn fill_mask(a: &[u8], b: &[u8], symbol: u8, mask: &mut Vec<u8>) -> (usize, usize) {
+    let fill = u8x16_splat(symbol);
+    let mut out = [0u8; LANES];
+    let mut mismatches = 0;
+    let mut done = 0;
+    for (ca, cb) in a.chunks_exact(LANES).zip(b.chunks_exact(LANES)) {
+      let va = load(ca);
+      let eq = u8x16_eq(va, load(cb));
+      // SAFETY: `out` is exactly LANES bytes; v128_store has no alignment requirement
+      unsafe { v128_store(out.as_mut_ptr() as *mut v128, v128_bitselect(va, fill, eq)) };
+      mask.extend_from_slice(&out);
+      mismatches += LANES - u8x16_bitmask(eq).count_ones() as usize;
+      done += LANES;
+    }
+    (done, mismatches)
+  }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+mod vector {
+  pub(super) fn count_mismatches(_a: &[u8], _b: &[u8]) -> (usize, usize) {
+    (0, 0)
+  }
+
+  pub(super) fn fill_mask(_a: &[u8], _b: &[u8], _symbol: u8, _mask: &mut Vec<u8>) -> (usize, usize) {
+    (0, 0)
+  }
+}
+
+/// Number of differing positions over the common prefix of `a` and `b`
+pub(crate) fn count_mismatches(a: &[u8], b: &[u8]) -> usize {
+  let len = a.len().min(b.len());
+  let (done, mut mismatches) = vector::count_mismatches(&a[..len], &b[..len]);
+  for i in done..len {
+    if a[i] != b[i] {
+      mismatches += 1;
+    }
+  }
+  mismatches
+}
+
+/// Append the match mask of `a` vs `b` (the shared byte, or `symbol` on mismatch) and
+/// return the mismatch count
+pub(crate) fn fill_mask(a: &[u8], b: &[u8], symbol: u8, mask: &mut Vec<u8>) -> usize {
+  let len = a.len().min(b.len());
+  let (done, mut mismatches) = vector::fill_mask(&a[..len], &b[..len], symbol, mask);
+  for i in done..len {
+    if a[i] == b[i] {
+      mask.push(a[i]);
+    } else {
+      mask.push(symbol);
+      mismatches += 1;
+    }
+  }
+  mismatches
+}
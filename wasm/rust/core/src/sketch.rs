@@ -0,0 +1,90 @@
+use crate::kmer::canonical_kmers;
+
+// ============================================================================
+// MinHash Sketches
+// ============================================================================
+// Bottom-s sketches over canonical k-mers, compatible in spirit with Mash:
+// the Jaccard index is estimated from the smallest s hashes of the union and
+// converted to a mutation distance with the Mash formula.
+
+/// MurmurHash3 64-bit finalizer; stable across platforms
+fn mix64(mut h: u64) -> u64 {
+  h ^= h >> 33;
+  h = h.wrapping_mul(0xff51afd7ed558ccd);
+  h ^= h >> 33;
+  h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+  h ^= h >> 33;
+  h
+}
+
+/// The `size` smallest distinct hashes of a sequence's canonical k-mers (k ≤ 32), sorted
+/// ascending
+pub fn sketch(seq: &[u8], k: usize, size: usize) -> Vec<u64> {
+  let mut hashes: Vec<u64> = canonical_kmers(seq, k).map(mix64).collect();
+  hashes.sort_unstable();
+  hashes.dedup();
+  hashes.truncate(size);
+  hashes
+}
+
+pub struct SketchDistance {
+  pub jaccard: f64,
+  /// Mash distance, capped at 1
+  pub distance: f64,
+  pub shared: usize,
+  /// Hashes of the union compared
+  pub union: usize,
+}
+
+/// Compare two sketches built with the same `k` over the `min(len1, len2)` smallest hashes
+/// of their union
+pub fn sketch_distance(sketch1: &[u64], sketch2: &[u64], k: usize) -> SketchDistance {
+  let size = sketch1.len().min(sketch2.len());
+  let (mut i, mut j) = (0, 0);
+  let mut union = 0;
+  let mut shared = 0;
+  while union < size && i < sketch1.len() && j < sketch2.len() {
+    if sketch1[i] == sketch2[j] {
+      shared += 1;
+      i += 1;
+      j += 1;
+    } else if sketch1[i] < sketch2[j] {
+      i += 1;
+    } else {
+      j += 1;
+    }
+    union += 1;
+  }
+
+  let jaccard = if union == 0 { 0.0 } else { shared as f64 / union as f64 };
+  let distance = if jaccard == 0.0 {
+    1.0
+  } else {
+    (((1.0 + jaccard) / (2.0 * jaccard)).ln() / k as f64).min(1.0)
+  };
+  SketchDistance { jaccard, distance, shared, union }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::iupac::reverse_complement;
+
+  #[test]
+  fn a_sequence_and_its_reverse_complement_share_a_sketch() {
+    let seq = b"GATTACAGCCTTAGGCATCGATCCGGTACGTTAGCAAGT";
+    let forward = sketch(seq, 5, 16);
+    assert_eq!(forward.len(), 16);
+    assert!(forward.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(sketch(&reverse_complement(seq), 5, 16), forward);
+
+    let same = sketch_distance(&forward, &forward, 5);
+    assert_eq!((same.jaccard, same.distance, same.shared, same.union), (1.0, 0.0, 16, 16));
+  }
+
+  #[test]
+  fn unrelated_sketches_are_at_the_maximum_distance() {
+    let distance = sketch_distance(&sketch(&[b'A'; 30], 5, 8), &sketch(&[b'C'; 30], 5, 8), 5);
+    assert_eq!((distance.jaccard, distance.distance, distance.shared), (0.0, 1.0, 0));
+  }
+}
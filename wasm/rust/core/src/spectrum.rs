@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use crate::kmer::{canonical_kmers, kmers};
+
+// ============================================================================
+// k-mer Spectra
+// ============================================================================
+// Alignment-free comparison for sequences too divergent or rearranged for the
+// positional comparator. Spectra are ordered maps so output is deterministic.
+
+/// Count of every k-mer code in a sequence; with `canonical`, each k-mer is merged with its
+/// reverse complement
+pub fn spectrum(seq: &[u8], k: usize, canonical: bool) -> BTreeMap<u64, usize> {
+  let mut counts = BTreeMap::new();
+  if canonical {
+    for code in canonical_kmers(seq, k) {
+      *counts.entry(code).or_default() += 1;
+    }
+  } else {
+    for (_, code, _) in kmers(seq, k) {
+      *counts.entry(code).or_default() += 1;
+    }
+  }
+  counts
+}
+
+pub struct SpectrumComparison {
+  pub distinct1: usize,
+  pub distinct2: usize,
+  pub shared: usize,
+  pub jaccard: f64,
+  pub containment1: f64,
+  pub containment2: f64,
+  pub cosine_similarity: f64,
+}
+
+/// Jaccard index and containment over distinct k-mers, and cosine similarity over the
+/// count vectors
+pub fn compare_spectra(spectrum1: &BTreeMap<u64, usize>, spectrum2: &BTreeMap<u64, usize>) -> SpectrumComparison {
+  let shared = spectrum1.keys().filter(|code| spectrum2.contains_key(code)).count();
+  let union = spectrum1.len() + spectrum2.len() - shared;
+  let ratio = |num: usize, den: usize| if den == 0 { 0.0 } else { num as f64 / den as f64 };
+
+  let dot: f64 = spectrum1
+    .iter()
+    .filter_map(|(code, &c1)| spectrum2.get(code).map(|&c2| c1 as f64 * c2 as f64))
+    .sum();
+  let norm = |s: &BTreeMap<u64, usize>| s.values().map(|&c| (c * c) as f64).sum::<f64>().sqrt();
+  let (norm1, norm2) = (norm(spectrum1), norm(spectrum2));
+  let cosine = if norm1 == 0.0 || norm2 == 0.0 { 0.0 } else { dot / (norm1 * norm2) };
+
+  SpectrumComparison {
+    distinct1: spectrum1.len(),
+    distinct2: spectrum2.len(),
+    shared,
+    jaccard: ratio(shared, union),
+    containment1: ratio(shared, spectrum1.len()),
+    containment2: ratio(shared, spectrum2.len()),
+    cosine_similarity: cosine,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn canonical_spectra_merge_reverse_complements() {
+    // ACG and CGT are reverse complements of each other
+    let seq = b"ACGT";
+    assert_eq!(spectrum(seq, 3, false).values().sum::<usize>(), 2);
+    assert_eq!(spectrum(seq, 3, false).len(), 2);
+    assert_eq!(spectrum(seq, 3, true).len(), 1);
+  }
+
+  #[test]
+  fn comparison_counts_shared_and_distinct_kmers() {
+    let comparison = compare_spectra(&spectrum(b"AAAAC", 2, false), &spectrum(b"AAGG", 2, false));
+    // {AA×3, AC} against {AA, AG, GG}
+    assert_eq!((comparison.distinct1, comparison.distinct2, comparison.shared), (2, 3, 1));
+    assert_eq!(comparison.jaccard, 0.25);
+    assert_eq!(comparison.containment1, 0.5);
+    assert!((comparison.containment2 - 1.0 / 3.0).abs() < 1e-12);
+    assert!((comparison.cosine_similarity - 3.0 / (10f64.sqrt() * 3f64.sqrt())).abs() < 1e-12);
+
+    let empty = compare_spectra(&BTreeMap::new(), &BTreeMap::new());
+    assert_eq!((empty.jaccard, empty.cosine_similarity), (0.0, 0.0));
+  }
+}
@@ -0,0 +1,154 @@
+use std::ops::Range;
+
+// ============================================================================
+// Suffix Array
+// ============================================================================
+// Every suffix of a sequence in sorted order, so all occurrences of a fragment
+// sit next to each other and are found by binary search in O(m log n). Built
+// by prefix doubling: suffixes sorted by their first 2^k characters, the ranks
+// of those prefixes giving the sort key for the next 2k, until all ranks
+// differ. Bases are indexed uppercase so lowercase (soft-masked) input matches.
+
+pub struct SuffixArray {
+  text: Vec<u8>,
+  suffixes: Vec<u32>,
+}
+
+/// Bytes `SuffixArray::new` needs for a sequence of `len` bases
+pub fn suffix_array_scratch_bytes(len: usize) -> usize {
+  len * (1 + 4 * std::mem::size_of::<u32>())
+}
+
+impl SuffixArray {
+  pub fn new(text: &[u8]) -> SuffixArray {
+    let text = text.to_ascii_uppercase();
+    let n = text.len();
+    let mut suffixes: Vec<u32> = (0..n as u32).collect();
+    // Rank 0 stands for "past the end", which sorts first
+    let mut rank: Vec<u32> = text.iter().map(|&c| c as u32 + 1).collect();
+    let mut next = vec![0u32; n];
+    let (mut k, mut sorted) = (1, n < 2);
+    while !sorted {
+      let key = |i: u32| {
+        let i = i as usize;
+        (rank[i], rank.get(i + k).copied().unwrap_or(0))
+      };
+      suffixes.sort_unstable_by_key(|&i| key(i));
+      next[suffixes[0] as usize] = 1;
+      for pair in suffixes.windows(2) {
+        next[pair[1] as usize] = next[pair[0] as usize] + u32::from(key(pair[0]) != key(pair[1]));
+      }
+      std::mem::swap(&mut rank, &mut next);
+      sorted = rank[suffixes[n - 1] as usize] as usize == n;
+      k *= 2;
+    }
+    SuffixArray { text, suffixes }
+  }
+
+  pub fn len(&self) -> usize {
+    self.text.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.text.is_empty()
+  }
+
+  /// Suffix start positions in sorted order
+  pub fn suffixes(&self) -> &[u32] {
+    &self.suffixes
+  }
+
+  /// The suffixes in `range`, which share their first `depth` characters, narrowed to those
+  /// whose next character is `c`; within the range they are sorted by that character, with
+  /// suffixes that end first
+  fn narrow(&self, range: Range<usize>, depth: usize, c: u8) -> Range<usize> {
+    let next = |s: &u32| self.text.get(*s as usize + depth).copied();
+    let within = &self.suffixes[range.clone()];
+    let first = within.partition_point(|s| next(s).is_none_or(|other| other < c));
+    let last = within.partition_point(|s| next(s).is_none_or(|other| other <= c));
+    range.start + first..range.start + last
+  }
+
+  /// Where the suffixes starting with `pattern` (case-insensitive) sit in sorted order
+  fn range(&self, pattern: &[u8]) -> Range<usize> {
+    let mut range = 0..self.suffixes.len();
+    for (depth, &c) in pattern.iter().enumerate() {
+      range = self.narrow(range, depth, c.to_ascii_uppercase());
+      if range.is_empty() {
+        break;
+      }
+    }
+    range
+  }
+
+  /// Occurrences of `pattern`; an empty pattern occurs at every position
+  pub fn count(&self, pattern: &[u8]) -> usize {
+    self.range(pattern).len()
+  }
+
+  /// Start positions of every occurrence of `pattern`, ascending
+  pub fn positions(&self, pattern: &[u8]) -> Vec<usize> {
+    let mut positions: Vec<usize> = self.suffixes[self.range(pattern)].iter().map(|&s| s as usize).collect();
+    positions.sort_unstable();
+    positions
+  }
+
+  /// Length of the longest prefix of `query` that occurs, with the first position it occurs at
+  /// (`None` when not even the first character does)
+  pub fn longest_match(&self, query: &[u8]) -> (usize, Option<usize>) {
+    let mut range = 0..self.suffixes.len();
+    let mut length = 0;
+    for (depth, &c) in query.iter().enumerate() {
+      let narrowed = self.narrow(range.clone(), depth, c.to_ascii_uppercase());
+      if narrowed.is_empty() {
+        break;
+      }
+      range = narrowed;
+      length = depth + 1;
+    }
+    let first = (length > 0).then(|| self.suffixes[range].iter().map(|&s| s as usize).min()).flatten();
+    (length, first)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn naive_positions(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    (0..=text.len().saturating_sub(pattern.len()))
+      .filter(|&i| text[i..].len() >= pattern.len() && text[i..i + pattern.len()].eq_ignore_ascii_case(pattern))
+      .collect()
+  }
+
+  #[test]
+  fn suffixes_are_sorted() {
+    let text = b"GATTACAGATTACAAAGT";
+    let index = SuffixArray::new(text);
+    assert_eq!(index.len(), text.len());
+    for pair in index.suffixes().windows(2) {
+      assert!(text[pair[0] as usize..] < text[pair[1] as usize..]);
+    }
+  }
+
+  #[test]
+  fn positions_match_a_naive_search_case_insensitively() {
+    let text = b"acgtACGTnnACGTTacg";
+    let index = SuffixArray::new(text);
+    let upper = text.to_ascii_uppercase();
+    for pattern in [&b"ACG"[..], b"acgt", b"T", b"NNA", b"ACGTTACG", b"GGG", b"CGA"] {
+      assert_eq!(index.positions(pattern), naive_positions(&upper, &pattern.to_ascii_uppercase()), "{:?}", pattern);
+      assert_eq!(index.count(pattern), index.positions(pattern).len());
+    }
+    assert_eq!(index.count(b""), text.len());
+  }
+
+  #[test]
+  fn longest_match_reports_the_first_occurrence() {
+    let index = SuffixArray::new(b"TTGACCAGACCT");
+    assert_eq!(index.longest_match(b"GACCTA"), (5, Some(7)));
+    assert_eq!(index.longest_match(b"gacc"), (4, Some(2)));
+    assert_eq!(index.longest_match(b"N"), (0, None));
+    assert!(SuffixArray::new(b"").is_empty());
+  }
+}
@@ -0,0 +1,117 @@
+// ============================================================================
+// Mask Symbols
+// ============================================================================
+// Masks are built and scanned for conserved blocks with '?' at mismatches and
+// the residue at matches. Only when a result is written out are they restyled
+// with the caller's symbols, e.g. '|' at matches and '.' at mismatches for
+// BLAST-style match lines, so identity and blocks never depend on the style.
+// Restyled results name their symbols in `maskSymbols`, so readers of the JSON
+// can map the mask back.
+
+pub const MISMATCH: u8 = b'?';
+/// Gap character accepted in (pre-aligned) input sequences
+pub const GAP: u8 = b'-';
+/// Match columns of a mask mapped back from a match symbol that is '?' or '-'
+pub const MATCH: u8 = b'|';
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct MaskSymbols {
+  /// Written where the residues differ
+  pub mismatch: u8,
+  /// Written where the residues agree; `None` keeps the residue itself
+  pub matched: Option<u8>,
+  /// Written where either sequence has a gap ('-')
+  pub gap: u8,
+}
+
+impl Default for MaskSymbols {
+  fn default() -> Self {
+    MaskSymbols { mismatch: MISMATCH, matched: None, gap: GAP }
+  }
+}
+
+impl MaskSymbols {
+  /// Output form of `mask`, built over the aligned regions of the two sequences
+  pub fn render(&self, mask: &[u8], region1: &[u8], region2: &[u8]) -> Vec<u8> {
+    if *self == MaskSymbols::default() {
+      return mask.to_vec();
+    }
+    mask
+      .iter()
+      .zip(region1.iter().zip(region2))
+      .map(|(&m, (&a, &b))| {
+        if a == GAP || b == GAP {
+          self.gap
+        } else if m == MISMATCH && a != b {
+          self.mismatch
+        } else {
+          self.matched.unwrap_or(m)
+        }
+      })
+      .collect()
+  }
+  /// `,"maskSymbols":{...}` with the `mismatch`, `match` (null when residues are kept), and
+  /// `gap` symbols of a restyled result; empty for the default symbols
+  pub fn json_field(&self) -> String {
+    if *self == MaskSymbols::default() {
+      return String::new();
+    }
+    let matched = self.matched.map_or("null".to_string(), |c| format!(r#""{}""#, c as char));
+    format!(
+      r#","maskSymbols":{{"mismatch":"{}","match":{},"gap":"{}"}}"#,
+      self.mismatch as char, matched, self.gap as char
+    )
+  }
+
+  /// A mask written with these symbols in the default ones: '?' at mismatches, '-' at gaps,
+  /// and the match symbol (`MATCH` if it would read as either) or the residue elsewhere
+  pub fn canonical(&self, styled: &[u8]) -> Vec<u8> {
+    if *self == MaskSymbols::default() {
+      return styled.to_vec();
+    }
+    styled
+      .iter()
+      .map(|&c| {
+        if c == self.gap {
+          GAP
+        } else if c == self.mismatch {
+          MISMATCH
+        } else if c == MISMATCH || c == GAP {
+          MATCH
+        } else {
+          c
+        }
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn restyled_masks_map_back_to_the_default_symbols() {
+    let (region1, region2) = (b"ACGT-ACGT", b"ACCT-ACGA");
+    let mask = b"AC?T-ACG?";
+    let symbols = MaskSymbols { mismatch: b'.', matched: Some(b'|'), gap: b' ' };
+    let styled = symbols.render(mask, region1, region2);
+    assert_eq!(styled, b"||.| |||.");
+    assert_eq!(symbols.canonical(&styled), b"||?|-|||?");
+    let kept = MaskSymbols { matched: None, ..symbols };
+    assert_eq!(kept.canonical(&kept.render(mask, region1, region2)), mask);
+  }
+
+  #[test]
+  fn match_symbols_that_read_as_mismatches_or_gaps_map_to_match() {
+    let symbols = MaskSymbols { mismatch: b'x', matched: Some(b'?'), gap: b'_' };
+    assert_eq!(symbols.canonical(b"??x_"), b"||?-");
+  }
+
+  #[test]
+  fn only_restyled_results_name_their_symbols() {
+    assert_eq!(MaskSymbols::default().json_field(), "");
+    let symbols = MaskSymbols { mismatch: b'.', matched: Some(b'|'), gap: b'-' };
+    assert_eq!(symbols.json_field(), r#","maskSymbols":{"mismatch":".","match":"|","gap":"-"}"#);
+  }
+}
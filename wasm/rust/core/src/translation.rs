@@ -0,0 +1,280 @@
+use crate::iupac::base_set;
+use crate::symbols::GAP;
+use crate::CODON_SIZE;
+
+// ============================================================================
+// Codon Table
+// ============================================================================
+// Amino acid lookup table
+// Encoding: index = i1*16 + i2*4 + i3 where T=0, C=1, A=2, G=3
+// Built from JS codon table to ensure exact match
+const AMINO_ACIDS: &[u8] = b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+
+fn nuc_to_index(c: u8) -> Option<usize> {
+  match c {
+    b'T' | b't' => Some(0),
+    b'C' | b'c' => Some(1),
+    b'A' | b'a' => Some(2),
+    b'G' | b'g' => Some(3),
+    _ => None,
+  }
+}
+
+pub fn translate_codon(c1: u8, c2: u8, c3: u8) -> u8 {
+  match (nuc_to_index(c1), nuc_to_index(c2), nuc_to_index(c3)) {
+    (Some(i1), Some(i2), Some(i3)) => AMINO_ACIDS[i1 * 16 + i2 * 4 + i3],
+    // A whole gap codon in pre-aligned input is an in-frame indel and encodes nothing; a
+    // partial one is unreadable like any other ambiguous codon, and `frameshift_gaps`
+    // reports the gap run behind it
+    _ if [c1, c2, c3] == [GAP; CODON_SIZE] => GAP,
+    _ => b'X',
+  }
+}
+
+/// Translate every complete codon from the start of `seq`; ambiguous and partially gapped
+/// codons become X, and whole '-' gap codons become '-'
+pub fn translate_dna(seq: &[u8]) -> Vec<u8> {
+  let codon_count = seq.len() / 3;
+  let mut result = Vec::with_capacity(codon_count);
+  for i in 0..codon_count {
+    let pos = i * 3;
+    result.push(translate_codon(seq[pos], seq[pos + 1], seq[pos + 2]));
+  }
+  result
+}
+
+/// A run of '-' gaps in pre-aligned input that is not a whole number of codons, so the
+/// bases after it are read out of frame
+#[derive(Clone, Copy, PartialEq)]
+pub struct Frameshift {
+  pub position: usize,
+  pub length: usize,
+}
+
+/// The gap runs of `seq` whose length is not a multiple of three
+pub fn frameshift_gaps(seq: &[u8]) -> Vec<Frameshift> {
+  let mut frameshifts = Vec::new();
+  let mut i = 0;
+  while i < seq.len() {
+    if seq[i] != GAP {
+      i += 1;
+      continue;
+    }
+    let position = i;
+    while i < seq.len() && seq[i] == GAP {
+      i += 1;
+    }
+    if (i - position) % CODON_SIZE != 0 {
+      frameshifts.push(Frameshift { position, length: i - position });
+    }
+  }
+  frameshifts
+}
+
+// ============================================================================
+// Special Codons
+// ============================================================================
+// The standard table reads TGA as a stop and gives up on any codon with an
+// IUPAC ambiguity code, so a selenoprotein's in-frame UGA looks like a
+// premature stop and an ambiguous codon (X) reads the same whether it may be a
+// stop (TRA) or certainly is one (TAR). `Translation` changes both, on request.
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Translation {
+  /// Read every TGA as selenocysteine (U), for genes with a SECIS element
+  pub selenocysteine: bool,
+  /// Translate IUPAC-ambiguous codons through the codons they may stand for: the amino
+  /// acid (or stop) they all encode, B/Z/J for the D/N, E/Q, and I/L pairs, and X when
+  /// they disagree otherwise
+  pub resolve_ambiguity: bool,
+}
+
+impl Translation {
+  pub fn is_standard(&self) -> bool {
+    !self.selenocysteine && !self.resolve_ambiguity
+  }
+
+  fn exact_codon(&self, c1: u8, c2: u8, c3: u8) -> u8 {
+    match translate_codon(c1, c2, c3) {
+      b'*' if self.selenocysteine && [c1, c2, c3].eq_ignore_ascii_case(b"TGA") => b'U',
+      aa => aa,
+    }
+  }
+
+  pub fn translate_codon(&self, c1: u8, c2: u8, c3: u8) -> u8 {
+    if !self.resolve_ambiguity || [c1, c2, c3].iter().all(|&c| nuc_to_index(c).is_some()) {
+      return self.exact_codon(c1, c2, c3);
+    }
+    let sets @ [s1, s2, s3] = [base_set(c1), base_set(c2), base_set(c3)];
+    if sets.contains(&0) {
+      return self.exact_codon(c1, c2, c3);
+    }
+    let bases = |set: u8| (0..4).filter(move |bit| set & (1 << bit) != 0).map(|bit| b"ACGT"[bit]);
+    let mut encoded: Vec<u8> = Vec::new();
+    for b1 in bases(s1) {
+      for b2 in bases(s2) {
+        for b3 in bases(s3) {
+          let aa = self.exact_codon(b1, b2, b3);
+          if !encoded.contains(&aa) {
+            encoded.push(aa);
+          }
+        }
+      }
+    }
+    encoded.sort_unstable();
+    match encoded.as_slice() {
+      [aa] => *aa,
+      b"DN" => b'B',
+      b"EQ" => b'Z',
+      b"IL" => b'J',
+      _ => b'X',
+    }
+  }
+
+  /// `translate_dna` with these codon rules
+  pub fn translate(&self, seq: &[u8]) -> Vec<u8> {
+    if self.is_standard() {
+      return translate_dna(seq);
+    }
+    seq.chunks_exact(CODON_SIZE).map(|codon| self.translate_codon(codon[0], codon[1], codon[2])).collect()
+  }
+}
+
+// ============================================================================
+// CDS Inference
+// ============================================================================
+// Nuclear genes start at ATG, but bacterial and mitochondrial genes also start
+// at near-cognate codons (GTG, TTG, ATA, ...), so which codons open a CDS is a
+// set rather than a constant. The set is a bitmask over the codon table index.
+
+const BASES: &[u8; 4] = b"TCAG";
+
+fn codon_index(codon: &[u8]) -> Option<usize> {
+  match codon {
+    [c1, c2, c3] => Some(nuc_to_index(*c1)? * 16 + nuc_to_index(*c2)? * 4 + nuc_to_index(*c3)?),
+    _ => None,
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct StartCodons(u64);
+
+impl Default for StartCodons {
+  /// ATG only (A=2, T=0, G=3 in the codon table index)
+  fn default() -> Self {
+    StartCodons(1 << (2 * 16 + 3))
+  }
+}
+
+impl StartCodons {
+  /// Codons separated by commas or spaces ("ATG,GTG,TTG"; U reads as T, case-insensitive),
+  /// or a named set: "standard" (ATG), "bacterial" (ATG, GTG, TTG), or "mitochondrial"
+  /// (ATG, ATA, ATT, ATC, GTG, the vertebrate mitochondrial code). `None` when a codon is
+  /// not three unambiguous bases or nothing is given
+  pub fn parse(codons: &str) -> Option<StartCodons> {
+    let codons = match codons.trim().to_ascii_lowercase().as_str() {
+      "standard" => "ATG",
+      "bacterial" => "ATG,GTG,TTG",
+      "mitochondrial" => "ATG,ATA,ATT,ATC,GTG",
+      _ => codons,
+    };
+    let mut set = 0u64;
+    for codon in codons.split(|c: char| c == ',' || c.is_whitespace()).filter(|codon| !codon.is_empty()) {
+      let codon = codon.replace(['U', 'u'], "T");
+      set |= 1 << codon_index(codon.as_bytes())?;
+    }
+    (set != 0).then_some(StartCodons(set))
+  }
+
+  /// Whether `codon` (three bases, case-insensitive) is in the set
+  pub fn contains(&self, codon: &[u8]) -> bool {
+    codon_index(codon).is_some_and(|index| self.0 & (1 << index) != 0)
+  }
+
+  /// Position of the first start codon in `seq`, in any frame
+  pub fn find(&self, seq: &[u8]) -> Option<usize> {
+    seq.windows(CODON_SIZE).position(|codon| self.contains(codon))
+  }
+
+  /// The codons in the set, comma-separated in codon table order
+  pub fn names(&self) -> String {
+    let codons: Vec<String> = (0..64)
+      .filter(|index| self.0 & (1 << index) != 0)
+      .map(|index| [BASES[index / 16], BASES[index / 4 % 4], BASES[index % 4]].iter().map(|&c| c as char).collect())
+      .collect();
+    codons.join(",")
+  }
+}
+
+/// Read an open reading frame from `start` up to and including the first in-frame stop codon.
+/// Returns half-open (start, end); an unterminated frame runs to the last complete codon.
+pub fn orf_from_start(seq: &[u8], start: usize) -> Option<(usize, usize)> {
+  if start >= seq.len() {
+    return None;
+  }
+  let mut pos = start;
+  while pos + CODON_SIZE <= seq.len() {
+    let aa = translate_codon(seq[pos], seq[pos + 1], seq[pos + 2]);
+    pos += CODON_SIZE;
+    if aa == b'*' {
+      break;
+    }
+  }
+  Some((start, pos))
+}
+
+/// Infer the coding region as the ORF starting at the first of `start_codons`.
+pub fn infer_cds(seq: &[u8], start_codons: &StartCodons) -> Option<(usize, usize)> {
+  orf_from_start(seq, start_codons.find(seq)?)
+}
+
+// ============================================================================
+// Reading Frames
+// ============================================================================
+// The protein comparison normally searches the frame of each sequence relative
+// to the nucleotide placement and keeps the combination with the highest
+// identity. That heuristic can pick a biologically wrong frame, so callers
+// that know the frames, or have annotated coding regions, can say so.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReadingFrames {
+  /// Try frames 0-2 of each sequence relative to its nucleotide offset and keep the best
+  /// combination; a pinned frame is the only one tried for its sequence. `start_codons` are
+  /// what the frame-detection notes look for; with `kozak` they take the start codon in the
+  /// strongest Kozak context instead of the first
+  Search { frame1: Option<usize>, frame2: Option<usize>, start_codons: StartCodons, kozak: bool },
+  /// Translate the given coding regions (0-based half-open, e.g. GenBank CDS features) as
+  /// they are, without the nucleotide placement or any search
+  Cds { cds1: (usize, usize), cds2: (usize, usize) },
+}
+
+impl Default for ReadingFrames {
+  fn default() -> Self {
+    ReadingFrames::Search { frame1: None, frame2: None, start_codons: StartCodons::default(), kozak: false }
+  }
+}
+
+impl ReadingFrames {
+  /// How the frames are chosen, as named in `frameDetection.method`
+  pub fn method(&self) -> &'static str {
+    match self {
+      ReadingFrames::Search { frame1: None, frame2: None, .. } => "search",
+      ReadingFrames::Search { .. } => "pinned",
+      ReadingFrames::Cds { .. } => "cds",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn only_a_whole_gap_codon_translates_to_a_gap() {
+    assert_eq!(translate_codon(b'A', b'T', b'G'), b'M');
+    assert_eq!(translate_codon(b'-', b'-', b'-'), GAP);
+    assert_eq!(translate_codon(b'A', b'-', b'G'), b'X');
+    assert_eq!(translate_codon(b'-', b'-', b'G'), b'X');
+    assert_eq!(translate_dna(b"ATG---A-GTAA"), b"M-X*");
+  }
+}
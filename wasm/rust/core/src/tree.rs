@@ -0,0 +1,232 @@
+// ============================================================================
+// Distance-Based Trees
+// ============================================================================
+// Nodes 0..n are the leaves; internal nodes are appended as clusters merge.
+// NJ yields an unrooted tree (written with a trifurcating root, as is usual
+// for Newick), UPGMA an ultrametric rooted one.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreeMethod {
+  NeighborJoining,
+  Upgma,
+}
+
+impl TreeMethod {
+  /// "upgma" (any case) or neighbor joining for anything else
+  pub fn parse(method: &str) -> TreeMethod {
+    match method.to_ascii_lowercase().as_str() {
+      "upgma" => TreeMethod::Upgma,
+      _ => TreeMethod::NeighborJoining,
+    }
+  }
+}
+
+struct Node {
+  label: Option<String>,
+  children: Vec<(usize, f64)>,
+}
+
+struct Tree {
+  nodes: Vec<Node>,
+  root: usize,
+}
+
+fn leaves(labels: &[String], n: usize) -> Vec<Node> {
+  (0..n)
+    .map(|i| Node {
+      label: Some(labels.get(i).cloned().unwrap_or_else(|| format!("seq{}", i + 1))),
+      children: Vec::new(),
+    })
+    .collect()
+}
+
+/// Grow a square distance table to make room for one more node
+fn add_row(dist: &mut Vec<Vec<f64>>) -> usize {
+  for row in dist.iter_mut() {
+    row.push(0.0);
+  }
+  dist.push(vec![0.0; dist.len() + 1]);
+  dist.len() - 1
+}
+
+fn neighbor_joining(dist: &[Vec<f64>], labels: &[String]) -> Tree {
+  let n = dist.len();
+  let mut nodes = leaves(labels, n);
+  let mut d: Vec<Vec<f64>> = dist.to_vec();
+  let mut active: Vec<usize> = (0..n).collect();
+
+  while active.len() > 3 {
+    let m = active.len() as f64;
+    let r: Vec<f64> = active.iter().map(|&i| active.iter().map(|&k| d[i][k]).sum()).collect();
+
+    // Pair minimizing the Q criterion; earliest pair wins ties
+    let mut best = (0, 1, f64::INFINITY);
+    for a in 0..active.len() {
+      for b in (a + 1)..active.len() {
+        let q = (m - 2.0) * d[active[a]][active[b]] - r[a] - r[b];
+        if q < best.2 {
+          best = (a, b, q);
+        }
+      }
+    }
+    let (a, b, _) = best;
+    let (i, j) = (active[a], active[b]);
+
+    let dij = d[i][j];
+    let li = (dij / 2.0 + (r[a] - r[b]) / (2.0 * (m - 2.0))).max(0.0);
+    let lj = (dij - li).max(0.0);
+
+    let u = add_row(&mut d);
+    for &k in &active {
+      if k != i && k != j {
+        let duk = (d[i][k] + d[j][k] - dij) / 2.0;
+        d[u][k] = duk;
+        d[k][u] = duk;
+      }
+    }
+    nodes.push(Node { label: None, children: vec![(i, li), (j, lj)] });
+
+    active.retain(|&k| k != i && k != j);
+    active.push(u);
+  }
+
+  let children = match active.as_slice() {
+    [i, j, k] => {
+      let (i, j, k) = (*i, *j, *k);
+      vec![
+        (i, ((d[i][j] + d[i][k] - d[j][k]) / 2.0).max(0.0)),
+        (j, ((d[i][j] + d[j][k] - d[i][k]) / 2.0).max(0.0)),
+        (k, ((d[i][k] + d[j][k] - d[i][j]) / 2.0).max(0.0)),
+      ]
+    }
+    [i, j] => vec![(*i, d[*i][*j] / 2.0), (*j, d[*i][*j] / 2.0)],
+    _ => return Tree { root: active.first().copied().unwrap_or(0), nodes },
+  };
+  nodes.push(Node { label: None, children });
+  Tree { root: nodes.len() - 1, nodes }
+}
+
+fn upgma(dist: &[Vec<f64>], labels: &[String]) -> Tree {
+  let n = dist.len();
+  let mut nodes = leaves(labels, n);
+  let mut d: Vec<Vec<f64>> = dist.to_vec();
+  let mut size: Vec<usize> = vec![1; n];
+  let mut height: Vec<f64> = vec![0.0; n];
+  let mut active: Vec<usize> = (0..n).collect();
+
+  while active.len() > 1 {
+    let mut best = (0, 1, f64::INFINITY);
+    for a in 0..active.len() {
+      for b in (a + 1)..active.len() {
+        let dab = d[active[a]][active[b]];
+        if dab < best.2 {
+          best = (a, b, dab);
+        }
+      }
+    }
+    let (a, b, dij) = best;
+    let (i, j) = (active[a], active[b]);
+
+    let u = add_row(&mut d);
+    let h = dij / 2.0;
+    for &k in &active {
+      if k != i && k != j {
+        let duk = (d[i][k] * size[i] as f64 + d[j][k] * size[j] as f64) / (size[i] + size[j]) as f64;
+        d[u][k] = duk;
+        d[k][u] = duk;
+      }
+    }
+    nodes.push(Node {
+      label: None,
+      children: vec![(i, (h - height[i]).max(0.0)), (j, (h - height[j]).max(0.0))],
+    });
+    size.push(size[i] + size[j]);
+    height.push(h);
+
+    active.retain(|&k| k != i && k != j);
+    active.push(u);
+  }
+
+  Tree { root: active.first().copied().unwrap_or(0), nodes }
+}
+
+// ============================================================================
+// Newick Output
+// ============================================================================
+fn newick_label(label: &str) -> String {
+  if label.chars().any(|c| "()[]':;, \t".contains(c)) {
+    format!("'{}'", label.replace('\'', "''"))
+  } else {
+    label.to_string()
+  }
+}
+
+fn write_newick(tree: &Tree, node: usize, out: &mut String) {
+  let Node { label, children } = &tree.nodes[node];
+  if !children.is_empty() {
+    out.push('(');
+    for (k, &(child, length)) in children.iter().enumerate() {
+      if k > 0 {
+        out.push(',');
+      }
+      write_newick(tree, child, out);
+      out.push_str(&format!(":{}", length));
+    }
+    out.push(')');
+  }
+  if let Some(label) = label {
+    out.push_str(&newick_label(label));
+  }
+}
+
+/// Tree over a square distance table in Newick format; missing labels default to seq1,
+/// seq2, ...
+pub fn newick(dist: &[Vec<f64>], labels: &[String], method: TreeMethod) -> String {
+  let tree = match method {
+    TreeMethod::Upgma => upgma(dist, labels),
+    TreeMethod::NeighborJoining => neighbor_joining(dist, labels),
+  };
+
+  let mut newick = String::new();
+  write_newick(&tree, tree.root, &mut newick);
+  newick.push(';');
+  newick
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn labels(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+  }
+
+  #[test]
+  fn upgma_recovers_an_ultrametric_tree() {
+    let dist = vec![
+      vec![0.0, 2.0, 6.0, 6.0],
+      vec![2.0, 0.0, 6.0, 6.0],
+      vec![6.0, 6.0, 0.0, 2.0],
+      vec![6.0, 6.0, 2.0, 0.0],
+    ];
+    assert_eq!(newick(&dist, &labels(&["A", "B", "C", "D"]), TreeMethod::parse("UPGMA")), "((A:1,B:1):2,(C:1,D:1):2);");
+  }
+
+  #[test]
+  fn neighbor_joining_recovers_additive_branch_lengths() {
+    // ((A:1,B:2):1,(C:3,D:1))
+    let dist = vec![
+      vec![0.0, 3.0, 5.0, 3.0],
+      vec![3.0, 0.0, 6.0, 4.0],
+      vec![5.0, 6.0, 0.0, 4.0],
+      vec![3.0, 4.0, 4.0, 0.0],
+    ];
+    assert_eq!(newick(&dist, &labels(&["A", "B"]), TreeMethod::parse("nj")), "(seq3:3,seq4:1,(A:1,B:2):1);");
+  }
+
+  #[test]
+  fn labels_with_newick_punctuation_are_quoted() {
+    let dist = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+    assert_eq!(newick(&dist, &labels(&["x y", "it's"]), TreeMethod::Upgma), "('x y':0.5,'it''s':0.5);");
+  }
+}
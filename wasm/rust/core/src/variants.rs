@@ -0,0 +1,199 @@
+use crate::alignment::Alignment;
+use crate::format::ResultFormat;
+use crate::iupac::{base_set, set_to_code};
+use crate::symbols::MISMATCH;
+
+// ============================================================================
+// Known Variants
+// ============================================================================
+// Mismatches between two alleles are often catalogued polymorphisms. Given the
+// known variants of seq1's region (e.g. a dbSNP VCF slice), each mismatch is
+// marked known or novel, so the novel ones stand out.
+
+#[derive(Clone)]
+pub struct KnownVariant {
+  /// VCF ID, e.g. an rsID; "." when the record has none
+  pub id: String,
+  /// 0-based position in seq1
+  pub position: usize,
+  /// Reference allele and alternate alleles, uppercase
+  pub reference: Vec<u8>,
+  pub alternates: Vec<Vec<u8>>,
+}
+
+impl KnownVariant {
+  /// Whether `base` is one of the variant's single-base alleles
+  fn has_allele(&self, base: u8) -> bool {
+    let base = [base.to_ascii_uppercase()];
+    self.reference == base || self.alternates.iter().any(|allele| *allele == base)
+  }
+}
+
+/// Variants from VCF text whose POS counts from `seq1_start`, the 1-based position of seq1's
+/// first base on the VCF's contig (1 when seq1 is the whole contig); header lines, records
+/// before seq1, and malformed records are skipped
+pub fn parse_vcf(text: &str, seq1_start: usize) -> Vec<KnownVariant> {
+  text
+    .lines()
+    .filter(|line| !line.starts_with('#'))
+    .filter_map(|line| {
+      let fields: Vec<&str> = line.split('\t').collect();
+      if fields.len() < 5 {
+        return None;
+      }
+      let position = fields[1].trim().parse::<usize>().ok()?.checked_sub(seq1_start.max(1))?;
+      let allele = |allele: &str| allele.trim().to_ascii_uppercase().into_bytes();
+      Some(KnownVariant {
+        id: fields[2].trim().to_string(),
+        position,
+        reference: allele(fields[3]),
+        alternates: fields[4].split(',').map(allele).collect(),
+      })
+    })
+    .collect()
+}
+
+/// A mismatch of the alignment with the known variant at its seq1 position, if any
+pub struct AnnotatedMismatch<'a> {
+  pub position1: usize,
+  pub position2: usize,
+  pub base1: u8,
+  pub base2: u8,
+  pub variant: Option<&'a KnownVariant>,
+}
+
+impl AnnotatedMismatch<'_> {
+  pub fn to_json(&self, format: &ResultFormat) -> String {
+    let (id, alleles, allele_known) = match self.variant {
+      Some(variant) => {
+        let alleles: Vec<String> = std::iter::once(&variant.reference)
+          .chain(&variant.alternates)
+          .map(|allele| String::from_utf8_lossy(allele).into_owned())
+          .collect();
+        (
+          serde_json::to_string(&variant.id).unwrap_or_else(|_| "null".to_string()),
+          serde_json::to_string(&alleles).unwrap_or_else(|_| "null".to_string()),
+          variant.has_allele(self.base2).to_string(),
+        )
+      }
+      None => ("null".to_string(), "null".to_string(), "null".to_string()),
+    };
+    format!(
+      r#"{{"position1":{},"position2":{},"base1":{},"base2":{},"known":{},"id":{},"alleles":{},"alleleKnown":{}}}"#,
+      format.coordinates.start(self.position1),
+      format.coordinates.start(self.position2),
+      base_json(self.base1),
+      base_json(self.base2),
+      self.variant.is_some(),
+      id,
+      alleles,
+      allele_known
+    )
+  }
+}
+
+fn base_json(base: u8) -> String {
+  serde_json::to_string(&(base as char).to_string()).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Every mismatch of `alignment` with the known variant (if any) that starts at its seq1
+/// position; `alleleKnown` then tells whether seq2's base is one of the variant's alleles
+pub fn annotate_mismatches<'a>(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  mask: &[u8],
+  variants: &'a [KnownVariant],
+) -> Vec<AnnotatedMismatch<'a>> {
+  mask
+    .iter()
+    .enumerate()
+    .filter(|&(_, &symbol)| symbol == MISMATCH)
+    .map(|(column, _)| {
+      let (position1, position2) = (alignment.offset1 + column, alignment.offset2 + column);
+      AnnotatedMismatch {
+        position1,
+        position2,
+        base1: bytes1[position1],
+        base2: bytes2[position2],
+        variant: variants.iter().find(|variant| variant.position == position1),
+      }
+    })
+    .collect()
+}
+
+// ============================================================================
+// Heterozygous Calls
+// ============================================================================
+// Sanger base callers write a two-base IUPAC code (R, Y, S, W, K, M) where a
+// heterozygous sample shows two overlapping peaks. Against a reference such a
+// column is not a plain mismatch: one of the two alleles may be the reference
+// base, or neither is and the sample carries non-reference alleles only.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum HeterozygousClass {
+  /// One allele is the reference base
+  ConsistentWithReference,
+  /// Neither allele is the reference base
+  NonReference,
+}
+
+impl HeterozygousClass {
+  pub fn name(self) -> &'static str {
+    match self {
+      HeterozygousClass::ConsistentWithReference => "hetConsistentWithReference",
+      HeterozygousClass::NonReference => "hetNonReference",
+    }
+  }
+}
+
+/// A two-base code in seq2 against an unambiguous base of the reference, seq1
+pub struct HeterozygousCall {
+  pub position1: usize,
+  pub position2: usize,
+  pub reference: u8,
+  pub call: u8,
+  pub class: HeterozygousClass,
+}
+
+impl HeterozygousCall {
+  /// The two bases the code stands for, in A, C, G, T order
+  pub fn alleles(&self) -> Vec<u8> {
+    let set = base_set(self.call);
+    (0..4).map(|bit| 1 << bit).filter(|&base| set & base != 0).map(set_to_code).collect()
+  }
+
+  pub fn to_json(&self, format: &ResultFormat) -> String {
+    let alleles: Vec<String> = self.alleles().iter().map(|&allele| format!("\"{}\"", allele as char)).collect();
+    format!(
+      r#"{{"position1":{},"position2":{},"reference":{},"call":{},"alleles":[{}],"class":"{}"}}"#,
+      format.coordinates.start(self.position1),
+      format.coordinates.start(self.position2),
+      base_json(self.reference),
+      base_json(self.call),
+      alleles.join(","),
+      self.class.name()
+    )
+  }
+}
+
+/// Columns of `alignment` where seq2 has a two-base IUPAC code and seq1 a single base
+pub fn heterozygous_calls(bytes1: &[u8], bytes2: &[u8], alignment: &Alignment) -> Vec<HeterozygousCall> {
+  (0..alignment.length)
+    .filter_map(|column| {
+      let (position1, position2) = (alignment.offset1 + column, alignment.offset2 + column);
+      let (reference, call) = (bytes1[position1], bytes2[position2]);
+      let (reference_set, call_set) = (base_set(reference), base_set(call));
+      if reference_set.count_ones() != 1 || call_set.count_ones() != 2 {
+        return None;
+      }
+      let class = if call_set & reference_set != 0 {
+        HeterozygousClass::ConsistentWithReference
+      } else {
+        HeterozygousClass::NonReference
+      };
+      Some(HeterozygousCall { position1, position2, reference, call, class })
+    })
+    .collect()
+}
+
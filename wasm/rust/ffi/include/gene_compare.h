@@ -0,0 +1,32 @@
+/* C ABI for gene-compare-core (build with `cargo build --release -p gene-compare-ffi`). */
+#ifndef GENE_COMPARE_H
+#define GENE_COMPARE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Each function returns a NUL-terminated JSON result (the same as the wasm
+ * compare_sequences_full / compare_proteins_full exports) that the caller
+ * releases with gene_compare_free. NULL is returned only for NULL inputs. */
+
+char *compare_sequences_c(const char *seq1, const char *seq2,
+                          size_t segment_window_length, double min_identity,
+                          double min_significant_length_group,
+                          double min_sequence_overlap_pct);
+
+char *compare_proteins_c(const char *seq1, const char *seq2,
+                         int32_t nuc_offset1, int32_t nuc_offset2, int32_t nuc_length,
+                         size_t aa_segment_window_length, double min_identity,
+                         double min_significant_length_group);
+
+void gene_compare_free(char *json);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GENE_COMPARE_H */
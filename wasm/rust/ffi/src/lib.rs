@@ -0,0 +1,164 @@
+//! C ABI over `gene-compare-core` for embedding the comparison in native
+//! services. Declarations are in `include/gene_compare.h`. With the `python`
+//! feature the library is also the `gene_compare` Python extension module.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{nucleotide_comparison, protein_comparison};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::scoring::Scoring;
+use gene_compare_core::translation::{ReadingFrames, Translation};
+
+#[cfg(feature = "python")]
+mod python;
+
+// ============================================================================
+// Result Buffers
+// ============================================================================
+// Results are the same JSON the wasm `_full` exports return, as NUL-terminated
+// strings allocated here. Callers own them and release them with
+// `gene_compare_free`; NULL comes back only for NULL inputs.
+
+fn into_buffer(json: String) -> *mut c_char {
+  // JSON built by the core never contains NUL bytes: inputs are C strings
+  CString::new(json).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// # Safety
+/// `seq` must be NULL or a valid NUL-terminated string that outlives the call.
+unsafe fn bytes<'a>(seq: *const c_char) -> Option<&'a [u8]> {
+  (!seq.is_null()).then(|| CStr::from_ptr(seq).to_bytes())
+}
+
+/// Nucleotide comparison; same result as `compare_sequences_full`.
+///
+/// # Safety
+/// `seq1` and `seq2` must be NULL or valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn compare_sequences_c(
+  seq1: *const c_char,
+  seq2: *const c_char,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> *mut c_char {
+  let (Some(bytes1), Some(bytes2)) = (bytes(seq1), bytes(seq2)) else {
+    return ptr::null_mut();
+  };
+  let (_, json) = nucleotide_comparison(
+    bytes1,
+    bytes2,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    min_sequence_overlap_pct,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    &mut (),
+  );
+  into_buffer(json)
+}
+
+/// Protein comparison over the region placed by `compare_sequences_c`; same result as
+/// `compare_proteins_full`.
+///
+/// # Safety
+/// `seq1` and `seq2` must be NULL or valid NUL-terminated strings.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn compare_proteins_c(
+  seq1: *const c_char,
+  seq2: *const c_char,
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> *mut c_char {
+  let (Some(bytes1), Some(bytes2)) = (bytes(seq1), bytes(seq2)) else {
+    return ptr::null_mut();
+  };
+  into_buffer(protein_comparison(
+    bytes1,
+    bytes2,
+    nuc_offset1,
+    nuc_offset2,
+    nuc_length,
+    aa_segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ReadingFrames::default(),
+    &Translation::default(),
+    &ResultFormat::default(),
+    &Scoring::default(),
+    BlockDetection::default(),
+    &mut (),
+  ))
+}
+
+/// Release a buffer returned by one of the comparison functions (NULL is ignored).
+///
+/// # Safety
+/// `json` must be NULL or a pointer returned by this library that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn gene_compare_free(json: *mut c_char) {
+  if !json.is_null() {
+    drop(CString::from_raw(json));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// The JSON behind a buffer, which is then released
+  unsafe fn take(json: *mut c_char) -> String {
+    let text = CStr::from_ptr(json).to_str().unwrap().to_string();
+    gene_compare_free(json);
+    text
+  }
+
+  #[test]
+  fn results_match_the_core_comparison() {
+    let (seq1, seq2) = (b"ATGGCTAGCTAGGATCCGATCGATCGGCTAGCTTACGA", b"GCTAGCTAGGATCCGATAGATCGGCTAGC");
+    let (c1, c2) = (CString::new(&seq1[..]).unwrap(), CString::new(&seq2[..]).unwrap());
+    let nucleotide = unsafe { take(compare_sequences_c(c1.as_ptr(), c2.as_ptr(), 9, 0.67, 0.15, 0.5)) };
+    let (_, expected) =
+      nucleotide_comparison(seq1, seq2, 9, 0.67, 0.15, 0.5, &ResultFormat::default(), &Scoring::default(), &mut ());
+    assert_eq!(nucleotide, expected);
+
+    let protein = unsafe { take(compare_proteins_c(c1.as_ptr(), c2.as_ptr(), 3, 0, 27, 3, 0.67, 0.15)) };
+    let expected = protein_comparison(
+      seq1,
+      seq2,
+      3,
+      0,
+      27,
+      3,
+      0.67,
+      0.15,
+      &ReadingFrames::default(),
+      &Translation::default(),
+      &ResultFormat::default(),
+      &Scoring::default(),
+      BlockDetection::default(),
+      &mut (),
+    );
+    assert_eq!(protein, expected);
+    assert!(protein.contains(r#""frame1""#));
+  }
+
+  #[test]
+  fn null_inputs_give_null() {
+    let seq = CString::new("ACGT").unwrap();
+    unsafe {
+      assert!(compare_sequences_c(ptr::null(), seq.as_ptr(), 9, 0.67, 0.15, 0.5).is_null());
+      assert!(compare_proteins_c(seq.as_ptr(), ptr::null(), 0, 0, 4, 3, 0.67, 0.15).is_null());
+      gene_compare_free(ptr::null_mut());
+    }
+  }
+}
@@ -0,0 +1,126 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{nucleotide_comparison, protein_comparison};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::scoring::Scoring;
+use gene_compare_core::translation::{ReadingFrames, Translation};
+use gene_compare_core::{
+  CODON_SIZE, DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+  DEFAULT_SEGMENT_WINDOW_LENGTH,
+};
+
+// ============================================================================
+// Python Module
+// ============================================================================
+// `import gene_compare` exposes the comparison the web UI runs, so notebooks
+// get the same numbers. Results are the wasm `_full` JSON decoded into dicts;
+// the GIL is released while comparing.
+
+const DEFAULT_AA_SEGMENT_WINDOW_LENGTH: usize = DEFAULT_SEGMENT_WINDOW_LENGTH / CODON_SIZE;
+
+fn to_dict<'py>(py: Python<'py>, json: &str) -> PyResult<Bound<'py, PyDict>> {
+  Ok(py.import("json")?.call_method1("loads", (json,))?.cast_into::<PyDict>()?)
+}
+
+/// Nucleotide comparison; same result as `compare_sequences_full`
+#[pyfunction]
+#[pyo3(signature = (
+  seq1,
+  seq2,
+  segment_window_length = DEFAULT_SEGMENT_WINDOW_LENGTH,
+  min_identity = DEFAULT_MIN_IDENTITY,
+  min_significant_length_group = DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+  min_sequence_overlap_pct = DEFAULT_MIN_SEQUENCE_OVERLAP_PCT,
+),
+text_signature = "(seq1, seq2, segment_window_length=66, min_identity=0.67, min_significant_length_group=0.15, min_sequence_overlap_pct=0.5)")]
+fn compare_sequences<'py>(
+  py: Python<'py>,
+  seq1: &str,
+  seq2: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+  let (_, json) = py.detach(|| {
+    nucleotide_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      min_sequence_overlap_pct,
+      &ResultFormat::default(),
+      &Scoring::default(),
+      &mut (),
+    )
+  });
+  to_dict(py, &json)
+}
+
+/// Protein comparison over the region placed by `compare_sequences`; same result as
+/// `compare_proteins_full`
+#[pyfunction]
+#[pyo3(signature = (
+  seq1,
+  seq2,
+  nuc_offset1,
+  nuc_offset2,
+  nuc_length,
+  aa_segment_window_length = DEFAULT_AA_SEGMENT_WINDOW_LENGTH,
+  min_identity = DEFAULT_MIN_IDENTITY,
+  min_significant_length_group = DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+),
+text_signature = "(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length=22, min_identity=0.67, min_significant_length_group=0.15)")]
+#[allow(clippy::too_many_arguments)]
+fn compare_proteins<'py>(
+  py: Python<'py>,
+  seq1: &str,
+  seq2: &str,
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+  let json = py.detach(|| {
+    protein_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      nuc_offset1,
+      nuc_offset2,
+      nuc_length,
+      aa_segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      &ReadingFrames::default(),
+      &Translation::default(),
+      &ResultFormat::default(),
+      &Scoring::default(),
+      BlockDetection::default(),
+      &mut (),
+    )
+  });
+  to_dict(py, &json)
+}
+
+/// Translate `seq` from `frame` (0-2) with the standard codon table; ambiguous codons become X
+/// unless `resolve_ambiguity`, and `selenocysteine` reads TGA as U
+#[pyfunction]
+#[pyo3(signature = (seq, frame = 0, selenocysteine = false, resolve_ambiguity = false))]
+fn translate(seq: &str, frame: usize, selenocysteine: bool, resolve_ambiguity: bool) -> String {
+  let bytes = seq.as_bytes();
+  let translation = Translation { selenocysteine, resolve_ambiguity };
+  String::from_utf8_lossy(&translation.translate(&bytes[frame.min(bytes.len())..])).into_owned()
+}
+
+#[pymodule]
+fn gene_compare(m: &Bound<'_, PyModule>) -> PyResult<()> {
+  m.add_function(wrap_pyfunction!(compare_sequences, m)?)?;
+  m.add_function(wrap_pyfunction!(compare_proteins, m)?)?;
+  m.add_function(wrap_pyfunction!(translate, m)?)?;
+  Ok(())
+}
@@ -1,6 +1,1399 @@
 /* tslint:disable */
 /* eslint-disable */
+/**
+ * A run of aligned columns of one kind, or a conserved block
+ */
+export interface HighlightRange {
+    /**
+     * Columns of the alignment, like block positions
+     */
+    start: number;
+    end: number;
+    /**
+     * \"match\", \"mismatch\", \"gap\", \"masked\" (lowercase or N in either sequence), or \"block\
+     */
+    kind: string;
+}
+
+/**
+ * Where the two proteins terminate
+ */
+export interface StopCodons {
+    /**
+     * Protein mask column of each sequence\'s first stop, if any
+     */
+    column1: number | undefined;
+    column2: number | undefined;
+    /**
+     * Where the stop codon starts in seq1 / seq2 (null for proteins compared directly)
+     */
+    codon1Start: number | undefined;
+    codon2Start: number | undefined;
+    /**
+     * Both stop in the same column
+     */
+    homologous: boolean;
+    /**
+     * `column2 - column1` when both stop
+     */
+    shift: number | undefined;
+}
+
+/**
+ * Present when the comparison ran with normalization enabled in its options
+ */
+export interface Normalization {
+    seq1: NormalizationReport;
+    seq2: NormalizationReport;
+}
+
+/**
+ * `compare_sequences_with_options` result when the best placement falls short of the
+ * options\' `min_alignment_identity`
+ */
+export interface NoSignificantAlignment {
+    /**
+     * Always true
+     */
+    noSignificantAlignment: boolean;
+    minAlignmentIdentity: number;
+    /**
+     * Identity and length of the best placement
+     */
+    identity: number;
+    length: number;
+    /**
+     * The result the placement would have given
+     */
+    bestAttempt: NucleotideResult;
+    normalization?: Normalization;
+    fingerprint: string;
+}
+
+/**
+ * In-frame insertion or deletion in the protein alignment of pre-aligned (gapped) input
+ */
+export interface Indel {
+    /**
+     * \"insertion\" (residues only in seq2) or \"deletion\" (residues only in seq1)
+     */
+    type: string;
+    /**
+     * First column, in the protein mask
+     */
+    position: number;
+    length: number;
+    residues: string;
+}
+
+/**
+ * Identity of the alignment at a fixed resolution, for zoomed-out views
+ */
+export interface Overview {
+    bins: number;
+    /**
+     * Mean columns per bin; bins differ by at most one column
+     */
+    binWidth: number;
+    identity: number[];
+}
+
+/**
+ * Unaligned ends of one sequence: 5\' before the aligned region, 3\' after it
+ */
+export interface Overhang {
+    fivePrimeLength: number;
+    fivePrime: string;
+    threePrimeLength: number;
+    threePrime: string;
+}
+
+/**
+ * A nucleotide mismatch with the known variant at its seq1 position
+ */
+export interface MismatchVariant {
+    position1: number;
+    position2: number;
+    base1: string;
+    base2: string;
+    known: boolean;
+    /**
+     * The variant\'s ID (e.g. an rsID), reference and alternate alleles, and whether seq2\'s
+     * base is one of them; null for novel mismatches
+     */
+    id: string | undefined;
+    alleles: string[] | undefined;
+    alleleKnown: boolean | undefined;
+}
+
+export interface NucleotideResult {
+    mask: string;
+    mismatches: number;
+    length: number;
+    /**
+     * Over the aligned columns
+     */
+    identity: number;
+    /**
+     * Matching columns over the full length of seq1
+     */
+    fullLengthIdentity: number;
+    /**
+     * Share of seq1 / seq2 in the aligned overlap
+     */
+    queryCoverage: number;
+    targetCoverage: number;
+    /**
+     * Sum of the column scores (see the core crate\'s scoring module)
+     */
+    score: number;
+    /**
+     * Score in bits; null when the scoring system has no positive or no negative scores
+     */
+    bitScore: number | null;
+    /**
+     * Expected number of equally good alignments between random sequences of these lengths
+     * and composition
+     */
+    evalue: number | null;
+    truncated: boolean;
+    offset1: number;
+    offset2: number;
+    /**
+     * \"0-based-half-open\" or \"1-based-inclusive\": how offsets and block positions count
+     */
+    coordinateSystem: string;
+    maskSymbols?: MaskStyle;
+    /**
+     * Null for empty inputs
+     */
+    overhangs: Overhangs | null;
+    conservedBlocks: Block[];
+    diagnostics: Diagnostic[];
+    normalization?: Normalization;
+    /**
+     * Present when the options ask for primers
+     */
+    primers?: Primer[];
+    /**
+     * Present when the options ask for highlight ranges
+     */
+    highlightRanges?: HighlightRange[];
+    /**
+     * Present for alignments of 100 kb or more, or when the options set overview bins
+     */
+    overview?: Overview;
+    /**
+     * Present when the options ask for heterozygous calls
+     */
+    heterozygousCalls?: HeterozygousCall[];
+    hetConsistentWithReference?: number;
+    hetNonReference?: number;
+    /**
+     * Mismatches that are not heterozygous calls
+     */
+    homozygousMismatches?: number;
+    /**
+     * Present when the options ask for mismatch hotspots
+     */
+    mismatchHotspots?: MismatchHotspot[];
+    /**
+     * Present when the options ask for composition-adjusted identity
+     */
+    adjustedIdentity?: AdjustedIdentity;
+    /**
+     * Present when the options carry known variants
+     */
+    mismatchVariants?: MismatchVariant[];
+    knownMismatches?: number;
+    novelMismatches?: number;
+    /**
+     * Hash of the result without this field (see the core crate\'s fingerprint module)
+     */
+    fingerprint: string;
+}
+
+/**
+ * Identity without the columns in homopolymer runs or low-complexity stretches
+ */
+export interface AdjustedIdentity {
+    identity: number;
+    /**
+     * Aligned columns counted, and those left out
+     */
+    columns: number;
+    excludedColumns: number;
+}
+
+/**
+ * An amino-acid mismatch and the codons that encode it
+ */
+export interface MismatchCodon {
+    /**
+     * Position in the protein mask
+     */
+    position: number;
+    aa1: string;
+    aa2: string;
+    /**
+     * Where the codon starts in seq1 / seq2
+     */
+    codon1Start: number;
+    codon1: string;
+    codon2Start: number;
+    codon2: string;
+}
+
+export interface Overhangs {
+    seq1: Overhang;
+    seq2: Overhang;
+}
+
+export interface Diagnostic {
+    /**
+     * \"info\" or \"warning\
+     */
+    level: string;
+    code: string;
+    message: string;
+    data: Record<string, number | null>;
+}
+
+export interface Block {
+    start: number;
+    end: number;
+    length: number;
+    /**
+     * Mask over the block: matching residues, \'?\' at mismatches (or the `maskSymbols`)
+     */
+    sequence: string;
+}
+
+export interface FrameDetection {
+    /**
+     * \"search\", \"pinned\" (frames given), or \"cds\" (coding regions given)
+     */
+    method: string;
+    startCodon1: number | null;
+    startCodon2: number | null;
+    /**
+     * Context of each start codon, when chosen by Kozak context
+     */
+    kozak1: KozakContext | null;
+    kozak2: KozakContext | null;
+    inferredFrame1: number | null;
+    inferredFrame2: number | null;
+    frameBroken: boolean;
+}
+
+export interface ProteinResult {
+    aa1: string;
+    aa2: string;
+    mask: string;
+    mismatches: number;
+    length: number;
+    identity: number;
+    /**
+     * Matching codons over the full length of seq1, in bases (residues for protein input)
+     */
+    fullLengthIdentity: number;
+    /**
+     * Share of seq1 / seq2 covered by the compared codons (residues for protein input)
+     */
+    queryCoverage: number;
+    targetCoverage: number;
+    /**
+     * Sum of the column scores (see the core crate\'s scoring module)
+     */
+    score: number;
+    /**
+     * Score in bits; null when the scoring system has no positive or no negative scores
+     */
+    bitScore: number | null;
+    /**
+     * Expected number of equally good alignments between random sequences of these lengths
+     * and composition
+     */
+    evalue: number | null;
+    truncated: boolean;
+    /**
+     * In codons
+     */
+    offset1: number;
+    offset2: number;
+    coordinateSystem: string;
+    maskSymbols?: MaskStyle;
+    /**
+     * Null for direct protein results; mRNA-vs-protein results set only `frame2`, the encoding
+     * frame of the mRNA (null when nothing aligns)
+     */
+    frame1: number | null;
+    frame2: number | null;
+    conservedBlocks: Block[];
+    /**
+     * Null for direct protein and mRNA-vs-protein results
+     */
+    mismatchCodons: MismatchCodon[] | null;
+    substitutions: Substitution[];
+    indels: Indel[];
+    frameshifts: Frameshift[];
+    stopCodons: StopCodons;
+    /**
+     * Null for direct protein and mRNA-vs-protein results
+     */
+    frameDetection: FrameDetection | null;
+    diagnostics: Diagnostic[];
+    normalization?: Normalization;
+    fingerprint: string;
+}
+
+/**
+ * Present when the options restyled the mask
+ */
+export interface MaskStyle {
+    mismatch: string;
+    /**
+     * Null when matching columns keep the residue
+     */
+    match: string | undefined;
+    gap: string;
+}
+
+/**
+ * How often one amino acid was replaced by another in the protein alignment
+ */
+export interface Substitution {
+    /**
+     * Residue in seq1
+     */
+    from: string;
+    /**
+     * Residue in seq2
+     */
+    to: string;
+    count: number;
+    blosum62: number;
+    /**
+     * BLOSUM62 scores the pair above zero
+     */
+    conservative: boolean;
+}
+
+/**
+ * A two-base IUPAC code in seq2 (a heterozygous Sanger call) against seq1\'s base
+ */
+export interface HeterozygousCall {
+    position1: number;
+    position2: number;
+    /**
+     * seq1\'s base
+     */
+    reference: string;
+    /**
+     * seq2\'s code and the two bases it stands for
+     */
+    call: string;
+    alleles: string[];
+    /**
+     * \"hetConsistentWithReference\" (one allele is the reference base) or \"hetNonReference\
+     */
+    class: string;
+}
+
+/**
+ * A primer that matches both sequences exactly, from a conserved block
+ */
+export interface Primer {
+    /**
+     * \"forward\" (read from seq1) or \"reverse\" (reverse complement)
+     */
+    direction: string;
+    /**
+     * 5\' to 3\'
+     */
+    sequence: string;
+    length: number;
+    /**
+     * First base of the site on the top strand of seq1 / seq2
+     */
+    start1: number;
+    start2: number;
+    /**
+     * Nearest-neighbor melting temperature, °C
+     */
+    tm: number;
+    gc: number;
+    /**
+     * Longest run the primer pairs with a copy of itself
+     */
+    selfComplementarity: number;
+    /**
+     * Index into `conservedBlocks`
+     */
+    block: number;
+}
+
+export interface KozakContext {
+    score: number;
+    maxScore: number;
+    /**
+     * \"strong\" (purine at -3 and G at +4), \"adequate\" (one of them), or \"weak\
+     */
+    strength: string;
+}
+
+/**
+ * Run of \'-\' gaps in pre-aligned input that is not a whole number of codons
+ */
+export interface Frameshift {
+    /**
+     * 1 or 2
+     */
+    sequence: number;
+    /**
+     * First gap, in the nucleotide sequence
+     */
+    position: number;
+    length: number;
+    /**
+     * Protein mask column of the codon the run starts in
+     */
+    column: number;
+}
+
+/**
+ * One of the most divergent windows of the alignment
+ */
+export interface MismatchHotspot {
+    start: number;
+    end: number;
+    /**
+     * Mismatch and gap columns
+     */
+    differences: number;
+    identity: number;
+}
+
+export interface NormalizationReport {
+    originalLength: number;
+    length: number;
+    uppercased: number;
+    whitespaceRemoved: number;
+    digitsRemoved: number;
+    uracilReplaced: number;
+    /**
+     * GenBank record text outside the ORIGIN section, in bytes
+     */
+    genbankRemoved: number;
+    polyATrimmed: number;
+}
+
+
+export class AbortHandle {
+  free(): void;
+  [Symbol.dispose](): void;
+  constructor();
+  abort(): void;
+  readonly aborted: boolean;
+}
+
+export class Comparator {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * Replace `old` at the 0-based `position` of sequence `seq_id` (1 or 2) with `new`, for
+   * trying candidate corrections, and return the result of the latest `compare` /
+   * `compare_seeded` placement on the edited sequences without searching offsets again:
+   * only the edited mask columns are rebuilt (every column after the edit when it changes
+   * the length), then the statistics and blocks. Edits before the aligned region move it.
+   * Errors when `old` is not at `position` or nothing was compared yet; `compare` afterwards
+   * searches offsets again
+   */
+  apply_edit(seq_id: number, position: number, old: string, _new: string, segment_window_length: number, min_identity: number, min_significant_length_group: number): string;
+  static from_bytes(seq1: Uint8Array, seq2: Uint8Array): Comparator;
+  /**
+   * Append `chunk` to sequence `seq_id` (1 or 2), so sequences too large to hold as one
+   * JS string (whole chromosomes) can be streamed in piece by piece. Chunks are appended
+   * verbatim; cached search structures are rebuilt on the next comparison.
+   */
+  push_chunk(seq_id: number, chunk: string): void;
+  /**
+   * Session stored by `serialize`; `recompute_blocks` and `blocks` work at once when it had
+   * been compared. Errors on bytes that are not a serialized session, including one whose
+   * match profile does not fit its sequences
+   */
+  static deserialize(bytes: Uint8Array): Comparator;
+  /**
+   * Take the options' result format (coordinate system, mask symbols) and block detection
+   * (`with_block_exit_identity`, `with_trim_block_edges`) for every result of the session;
+   * its thresholds and search settings stay with the arguments of each call. Neither is
+   * serialized
+   */
+  with_options(options: ComparisonOptions): Comparator;
+  /**
+   * Like `compare`, but places the sequences with the k-mer seeded offset search
+   */
+  compare_seeded(segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+  /**
+   * Current length of sequence `seq_id` (1 or 2; 0 for anything else)
+   */
+  sequence_length(seq_id: number): number;
+  /**
+   * Same result as `compare_proteins_full` on the session's sequences
+   */
+  compare_proteins(nuc_offset1: number, nuc_offset2: number, nuc_length: number, aa_segment_window_length: number, min_identity: number, min_significant_length_group: number): string;
+  /**
+   * `push_chunk` for `Uint8Array` chunks (e.g. straight from a `ReadableStream`)
+   */
+  push_chunk_bytes(seq_id: number, chunk: Uint8Array): void;
+  /**
+   * Re-run only conserved-block detection on the placement and mask from the latest
+   * `compare` / `compare_seeded`, for block-parameter sliders
+   */
+  recompute_blocks(segment_window_length: number, min_identity: number, min_significant_length_group: number): string;
+  constructor(seq1: string, seq2: string);
+  /**
+   * Session with two empty sequences, to be filled with `push_chunk`
+   */
+  static empty(): Comparator;
+  /**
+   * Conserved blocks of the latest `compare` / `compare_seeded` placement as
+   * `ConservedBlock` objects, detected with the given block parameters and the session's
+   * block detection
+   */
+  blocks(segment_window_length: number, min_identity: number, min_significant_length_group: number): ConservedBlock[];
+  /**
+   * Same result as `compare_sequences_full` on the session's sequences
+   */
+  compare(segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+  /**
+   * The session as bytes for `deserialize`: both sequences, the match profile if computed,
+   * and the latest placement
+   */
+  serialize(): Uint8Array;
+}
+
+export class ComparisonOptions {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * Take the start codon in the strongest Kozak context (gccRccATGG) rather than the
+   * first one, so upstream ATGs in a 5' UTR are passed over; results report the score
+   */
+  with_kozak(value: boolean): ComparisonOptions;
+  /**
+   * Translate seq1 only in this frame (0-2) relative to its nucleotide offset instead of
+   * searching all three; `undefined` (the default) searches. Other values are ignored
+   */
+  with_frame1(value?: number | null): ComparisonOptions;
+  /**
+   * Pin the frame of seq2, as for `with_frame1`
+   */
+  with_frame2(value?: number | null): ComparisonOptions;
+  /**
+   * Place the sequences with the k-mer seeded search instead of the exhaustive scan
+   */
+  with_seeded(value: boolean): ComparisonOptions;
+  /**
+   * Replace U with T before comparing, so RNA compares against DNA
+   */
+  with_u_to_t(value: boolean): ComparisonOptions;
+  /**
+   * Go back to searching frames over the nucleotide placement
+   */
+  without_cds(): ComparisonOptions;
+  /**
+   * Names accepted by `preset` (aliases not included)
+   */
+  static preset_names(): string[];
+  /**
+   * Accept pasted GenBank records: keep only the ORIGIN section and drop its line
+   * coordinates and spacing
+   */
+  with_genbank(value: boolean): ComparisonOptions;
+  /**
+   * Add `primers`: the best forward and reverse primer in each conserved block that
+   * matches both sequences exactly (18-25 bases, nearest-neighbor Tm 55-65 °C, 40-60%
+   * GC, a 3' GC clamp, little self-complementarity)
+   */
+  with_primers(value: boolean): ComparisonOptions;
+  /**
+   * Score of a nucleotide column with a '-' gap (default -2)
+   */
+  with_gap_score(value: number): ComparisonOptions;
+  /**
+   * Enable or disable every input-cleaning step below at once (poly-A trimming is
+   * left as it is)
+   */
+  with_normalize(value: boolean): ComparisonOptions;
+  /**
+   * Write offsets and block positions 1-based with inclusive ends, as NCBI shows them,
+   * instead of 0-based half-open; results name theirs in `coordinateSystem`
+   */
+  with_one_based(value: boolean): ComparisonOptions;
+  /**
+   * Compare case-insensitively by uppercasing both sequences first
+   */
+  with_uppercase(value: boolean): ComparisonOptions;
+  /**
+   * Mask character where either sequence has a '-' gap (default '-'). Letters, '*', and the
+   * mismatch or match symbol are refused and keep the current symbol
+   */
+  with_gap_symbol(value: string): ComparisonOptions;
+  /**
+   * How `compare_sequences_compressed` encodes its result: "gzip" (the default), "deflate"
+   * (zlib), "deflate-raw", or "none" for plain UTF-8, as read by `DecompressionStream`;
+   * unknown names keep the current format
+   */
+  with_compression(name: string): ComparisonOptions;
+  /**
+   * Score of a matching nucleotide column (default 1), and of matching amino acids with
+   * the "match-mismatch" protein matrix
+   */
+  with_match_score(value: number): ComparisonOptions;
+  /**
+   * Trim poly-A tails (12+ bases, at least 90% A) from the sequence ends before
+   * aligning; not included in `with_normalize`
+   */
+  with_trim_poly_a(value: boolean): ComparisonOptions;
+  /**
+   * Treat two-base IUPAC codes in seq2 (R, Y, S, W, K, M from heterozygous Sanger peaks)
+   * as heterozygous calls against the reference seq1: results list them in
+   * `heterozygousCalls`, each `hetConsistentWithReference` (one allele is seq1's base) or
+   * `hetNonReference`, with the counts and the `homozygousMismatches` left
+   */
+  with_heterozygous(value: boolean): ComparisonOptions;
+  /**
+   * Mask character at matches; `undefined` (the default) keeps the residue. Letters, '*',
+   * and the mismatch or gap symbol are refused and keep the current setting
+   */
+  with_match_symbol(value?: string | null): ComparisonOptions;
+  with_min_identity(value: number): ComparisonOptions;
+  /**
+   * Codons that start a CDS (default "ATG"): a list such as "ATG,GTG,TTG", or "standard",
+   * "bacterial", or "mitochondrial"; used for CDS inference and the frame-detection notes.
+   * Invalid lists keep the current codons
+   */
+  with_start_codons(codons: string): ComparisonOptions;
+  /**
+   * Remove digits (GenBank ORIGIN line coordinates) before comparing
+   */
+  with_strip_digits(value: boolean): ComparisonOptions;
+  /**
+   * Number of bins in `overview`, the identity of the alignment at a fixed resolution for
+   * zoomed-out views; added by default (2000 bins) once the alignment spans 100 kb, at any
+   * length when set here, and never when set to 0
+   */
+  with_overview_bins(value: number): ComparisonOptions;
+  /**
+   * Known variants of seq1's region as VCF text (e.g. a dbSNP slice), whose POS counts
+   * from `seq1_start`, the 1-based contig position of seq1's first base; results then list
+   * every mismatch in `mismatchVariants`, marked known (with its ID and alleles) or novel
+   */
+  with_known_variants(vcf: string, seq1_start: number): ComparisonOptions;
+  /**
+   * Score of a mismatching column (default -2), as for `with_match_score`
+   */
+  with_mismatch_score(value: number): ComparisonOptions;
+  /**
+   * Amino-acid scores: "blosum62" (the default) or "match-mismatch"; unknown names keep
+   * the current matrix
+   */
+  with_protein_matrix(name: string): ComparisonOptions;
+  /**
+   * Amino-acid scores from a matrix in NCBI's text format (as distributed with BLAST) or
+   * JSON (`{"A": {"A": 4, ...}, ...}` or `{"alphabet": "AR...", "scores": [[...], ...]}`),
+   * for alphabets beyond the built-in matrices; residues it lacks score as its X. A matrix
+   * that cannot be read keeps the current one
+   */
+  with_scoring_matrix(matrix: string): ComparisonOptions;
+  /**
+   * Translate TGA as selenocysteine (U) instead of a stop, for genes with a SECIS element
+   */
+  with_selenocysteine(value: boolean): ComparisonOptions;
+  /**
+   * Mask character at mismatches (default '?'); e.g. '.' together with
+   * `with_match_symbol('|')` gives BLAST-style match lines. Letters, '*', and the match or
+   * gap symbol are refused and keep the current symbol
+   */
+  with_mismatch_symbol(value: string): ComparisonOptions;
+  /**
+   * Add `highlightRanges`: the aligned columns merged into sorted `{start, end, kind}`
+   * runs of `match`, `mismatch`, `gap`, or `masked` (lowercase or N in either sequence),
+   * plus a `block` range per conserved block, so front ends need not rescan the mask
+   */
+  with_highlight_ranges(value: boolean): ComparisonOptions;
+  /**
+   * Remove whitespace and line breaks before comparing
+   */
+  with_strip_whitespace(value: boolean): ComparisonOptions;
+  /**
+   * Trim the mismatches (and gaps) at either end of each conserved block, so block
+   * positions, lengths, and sequences start and end on identical columns and can be used
+   * as primer or probe targets directly
+   */
+  with_trim_block_edges(value: boolean): ComparisonOptions;
+  /**
+   * Add `mismatchHotspots` to results: the `count` most divergent non-overlapping windows
+   * of `segment_window_length` columns (`start`, `end` in the mask as for conserved blocks,
+   * mismatch and gap `differences`, `identity`), most divergent first; 0 leaves them out
+   */
+  with_mismatch_hotspots(count: number): ComparisonOptions;
+  /**
+   * Nucleotide scores from a matrix in the `with_scoring_matrix` formats instead of the
+   * match and mismatch scores (base pairs it lacks score as its N); an empty string goes
+   * back to them, and a matrix that cannot be read keeps the current scores
+   */
+  with_nucleotide_matrix(matrix: string): ComparisonOptions;
+  /**
+   * Score of an amino-acid column with a '-' gap (default -4)
+   */
+  with_protein_gap_score(value: number): ComparisonOptions;
+  /**
+   * Detect conserved blocks with hysteresis on the identity of the window centered on
+   * each column: a block starts where it reaches `min_identity` and ends where it drops
+   * below this lower exit identity, for steadier boundaries than fixed windows (an exit
+   * above `min_identity` is taken as `min_identity`); `undefined` (the default) uses fixed windows
+   */
+  with_block_exit_identity(value?: number | null): ComparisonOptions;
+  /**
+   * Stop the offset search at the first placement reaching this identity (see
+   * `compare_sequences_good_enough`); `undefined` scans every offset
+   */
+  with_good_enough_identity(value?: number | null): ComparisonOptions;
+  /**
+   * Conserved-block window in nucleotides
+   */
+  with_segment_window_length(value: number): ComparisonOptions;
+  /**
+   * Identity the best placement must reach for `compare_sequences_with_options` to report
+   * it as the alignment; below it the result says `noSignificantAlignment` and carries the
+   * placement as `bestAttempt`. `undefined` (the default) reports any placement
+   */
+  with_min_alignment_identity(value?: number | null): ComparisonOptions;
+  /**
+   * Conserved-block window in amino acids, for protein comparisons
+   */
+  with_aa_segment_window_length(value: number): ComparisonOptions;
+  with_min_sequence_overlap_pct(value: number): ComparisonOptions;
+  /**
+   * Translate IUPAC-ambiguous codons to the amino acid (or stop) they all encode, B/Z/J
+   * for D/N, E/Q, and I/L, or X, instead of always X; gap codons become '-'
+   */
+  with_resolve_ambiguous_codons(value: boolean): ComparisonOptions;
+  with_min_significant_length_group(value: number): ComparisonOptions;
+  /**
+   * Add `adjustedIdentity` to results: the identity over the aligned columns outside
+   * homopolymer runs and low-complexity (DUST) stretches of either sequence, which poly-A
+   * tracts and repeats cannot inflate
+   */
+  with_composition_adjusted_identity(value: boolean): ComparisonOptions;
+  constructor();
+  /**
+   * Options for a named scenario: "strict" (alias "isoforms"), "default", "permissive"
+   * ("sanger"), or "cross-species" ("orthologs"); unknown names give the defaults
+   */
+  static preset(name: string): ComparisonOptions;
+  /**
+   * Compare the translations of these coding regions (e.g. GenBank CDS features, in the
+   * options' coordinate system) instead of searching frames over the nucleotide placement;
+   * takes precedence over pinned frames
+   */
+  with_cds(start1: number, end1: number, start2: number, end2: number): ComparisonOptions;
+  readonly gap_symbol: string;
+  readonly compression: string;
+  readonly match_score: number;
+  readonly trim_poly_a: boolean;
+  readonly heterozygous: boolean;
+  readonly match_symbol: string | undefined;
+  readonly min_identity: number;
+  readonly start_codons: string;
+  readonly strip_digits: boolean;
+  /**
+   * Bins set by `with_overview_bins`, or null for the default
+   */
+  readonly overview_bins: number | undefined;
+  readonly mismatch_score: number;
+  readonly protein_matrix: string;
+  readonly selenocysteine: boolean;
+  readonly mismatch_symbol: string;
+  readonly highlight_ranges: boolean;
+  readonly strip_whitespace: boolean;
+  readonly trim_block_edges: boolean;
+  readonly mismatch_hotspots: number;
+  readonly protein_gap_score: number;
+  readonly block_exit_identity: number | undefined;
+  /**
+   * Number of variants read by `with_known_variants`, or null without any list
+   */
+  readonly known_variant_count: number | undefined;
+  readonly good_enough_identity: number | undefined;
+  readonly segment_window_length: number;
+  readonly min_alignment_identity: number | undefined;
+  readonly aa_segment_window_length: number;
+  /**
+   * Whether nucleotide scores come from a `with_nucleotide_matrix` matrix
+   */
+  readonly custom_nucleotide_matrix: boolean;
+  readonly min_sequence_overlap_pct: number;
+  readonly resolve_ambiguous_codons: boolean;
+  readonly min_significant_length_group: number;
+  readonly composition_adjusted_identity: boolean;
+  /**
+   * `[start1, end1, start2, end2]` as given to `with_cds`
+   */
+  readonly cds: Uint32Array | undefined;
+  readonly kozak: boolean;
+  readonly frame1: number | undefined;
+  readonly frame2: number | undefined;
+  readonly seeded: boolean;
+  readonly u_to_t: boolean;
+  readonly genbank: boolean;
+  readonly primers: boolean;
+  readonly gap_score: number;
+  readonly one_based: boolean;
+  readonly uppercase: boolean;
+}
+
+export class ConservedBlock {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly end1: number;
+  readonly end2: number;
+  readonly length: number;
+  readonly start1: number;
+  readonly start2: number;
+  /**
+   * Fraction of the block's columns that match
+   */
+  readonly identity: number;
+  /**
+   * The block's mask: matching bases, '?' at mismatches
+   */
+  readonly sequence: string;
+}
+
+export class SequenceBuffer {
+  free(): void;
+  [Symbol.dispose](): void;
+  len(): number;
+  constructor(len: number);
+  /**
+   * A Uint8Array aliasing the buffer. The view is invalidated when WASM memory grows,
+   * so write into it immediately instead of holding on to it.
+   */
+  view(): Uint8Array;
+  is_empty(): boolean;
+}
+
+export class SequenceIndex {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * The suffix array itself: suffix start positions in sorted order
+   */
+  suffix_array(): Uint32Array;
+  /**
+   * Longest prefix of `query` that occurs in the sequence: `{"length", "position"}` with
+   * the first position it occurs at (null when the length is 0), e.g. for anchoring a read
+   */
+  longest_match(query: string): string;
+  /**
+   * Index `seq` (case-insensitive); an error when the construction would exceed the
+   * memory limit
+   */
+  constructor(seq: string);
+  /**
+   * Start of every occurrence of `fragment`, ascending
+   */
+  find(fragment: string): Uint32Array;
+  /**
+   * Occurrences of `fragment`, overlapping ones included
+   */
+  count(fragment: string): number;
+  contains(fragment: string): boolean;
+  readonly length: number;
+}
+
+/**
+ * The conserved blocks of a nucleotide or protein comparison result as FASTA records of
+ * sequence `which_seq` (1 or 2), `>block<n> seq<k>:<start>-<end> length=<n> identity=<x>`
+ * in the result's coordinate system, residues in lines of 60 without gaps. Protein results
+ * use their translations; for nucleotide results, `sequence` is that sequence as compared
+ * (after any normalization), or empty to fill mismatches with N (unless the result's
+ * `maskSymbols` replace matching bases with a symbol). Unreadable results, a `which_seq`
+ * other than 1 or 2, or a `sequence` that doesn't cover the aligned region or is missing
+ * give `{"error": ...}`
+ */
+export function blocks_to_fasta(result: string, which_seq: number, sequence: string): string;
+
+/**
+ * Build a tree from a row-major n×n distance matrix (e.g. `distance` from `pairwise_matrix`)
+ * and return it in Newick format. `method` is "nj" (default) or "upgma"; missing labels
+ * default to seq1, seq2, ...
+ */
+export function build_tree(distances: Float64Array, labels: string[], method: string): string;
+
+/**
+ * Outcome of a genome edit from the `reference` amplicon, the `edited` amplicon, and the
+ * `expected` amplicon after perfect HDR (empty when there is no donor template), all
+ * spanning the same primers: `outcome` is "unedited", "perfectHdr" (exactly the intended
+ * edits), "partialHdr" (some of them, or all with other changes), "nhejIndel", or
+ * "substitution". Differences are modelled as substitutions and at most one indel, given as
+ * `indel` (`type`, reference `position`, `size`, `inserted` bases, `frameshift`) and
+ * `substitutions` (each marked `intended` or not), with how many of the `intendedEdits` are
+ * present. With a `cut_site` (in seq1 coordinates of the options), unintended differences
+ * count only within `window` bases of it; the rest are tallied in `outsideWindow`
+ */
+export function classify_edited_allele(reference: string, edited: string, expected: string, cut_site: number | null | undefined, window: number, options: ComparisonOptions): string;
+
+/**
+ * Drop every cached comparison result (e.g. to release memory after a large comparison)
+ */
+export function clear_result_cache(): void;
+
+/**
+ * Comparison of a codon-optimized CDS with the natural one it recodes. Both are translated
+ * with the options' codon rules (over `with_cds` when set, else from the pinned frame or the
+ * first base) and the proteins placed against each other; `proteinIdentity` and
+ * `proteinIdentical` (same length, placed end to end, every residue equal) confirm the
+ * recoding kept the protein. `codonDifferences` lists each aligned codon pair that differs:
+ * its residue `position1`/`position2`, nucleotide `ntPosition1`/`ntPosition2`, both codons
+ * and amino acids, whether the change is `synonymous`, and the `changedBases` (1-3) within
+ * the codon. `codonUsage` gives the shift: changed, synonymous, and nonsynonymous codon
+ * counts, nucleotide identity over the aligned codons, GC and GC3 of each CDS, the codon
+ * counts of each, their `usageDistance` (0 to 1), and the `swaps` from one codon to another
+ * by how often they occur
+ */
+export function compare_codon_optimized(seq1: string, seq2: string, options: ComparisonOptions): string;
+
+/**
+ * Compare exon N of seq1 against exon N of seq2 for every annotated exon.
+ * Each pair is aligned independently with the same offset search as the full comparison;
+ * exons present in only one transcript are reported with null statistics. An exon pair
+ * whose offset search would exceed the memory limit gives the memory-limit error.
+ */
+export function compare_exons(seq1: string, seq2: string, exons1: Uint32Array, exons2: Uint32Array, min_sequence_overlap_pct: number): string;
+
+/**
+ * Compare the k-mer spectra of two sequences: Jaccard index and containment over distinct
+ * k-mers, and cosine similarity/distance over the count vectors.
+ */
+export function compare_kmer_spectra(seq1: string, seq2: string, k: number, canonical: boolean): string;
+
+/**
+ * Compare three or more sequences at once. Returns the gap-padded rows, per-column
+ * conservation (fraction of sequences carrying the majority base), Shannon entropy and
+ * information content in bits, residue frequencies for logo rendering, a majority
+ * consensus, and conserved blocks over the all-sequences-agree mask.
+ */
+export function compare_multiple(seqs: string[], segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * Compare one query against many targets in a single call.
+ * `targets_json` is a JSON array of sequences or `{ "id", "sequence" }` objects. Returns an
+ * array of `{ index, id, result }` sorted by identity (highest first, input order on ties),
+ * where `result` has the same shape as `compare_sequences_full`. `targets_json` that does not
+ * read as such an array gives `{"error": ...}`.
+ */
+export function compare_one_vs_many(query: string, targets_json: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * `compare_one_vs_many` configured by a `ComparisonOptions` object: each `result` has the
+ * shape of `compare_sequences_with_options`, and targets are ranked by the identity of
+ * their placement
+ */
+export function compare_one_vs_many_with_options(query: string, targets_json: string, options: ComparisonOptions): string;
+
+/**
+ * `query` and each CDS of the multi-FASTA `orthologs` translated with the options' codon
+ * rules (from the first base, a final stop dropped) and compared as proteins. `orthologs`
+ * ranks the panel by identity to the query (then coverage of the query, then input
+ * order): each with its `id`, `description`, `proteinLength`, `identity`, `coverage`,
+ * aligned `length`, `mismatches`, and `offset1`/`offset2` of the best placement.
+ * `identities` is the pairwise protein identity matrix in input order with the query
+ * first, and `sharedBlocks` the conserved blocks (the options' `aa_segment_window_length`
+ * and thresholds) where every protein of the panel agrees, in query residues
+ */
+export function compare_ortholog_panel(query: string, orthologs: string, options: ComparisonOptions): string;
+
+/**
+ * Comparison of a protein with an mRNA translated in its three forward frames (with the
+ * options' codon rules), reporting the frame and mRNA region that encode the protein as
+ * `encodingRegion`; for checking CDS annotations. The protein is normalized without the
+ * U-to-T and poly-A steps
+ */
+export function compare_protein_to_mrna(protein: string, mrna: string, options: ComparisonOptions): string;
+
+/**
+ * `compare_proteins_full` returning the result object. Throws an Error named
+ * EmptySequence, InvalidCharacters, FrameSearchFailed when no frame combination
+ * yields a protein alignment of at least one window, or MalformedResult.
+ */
+export function compare_proteins(seq1: string, seq2: string, nuc_offset1: number, nuc_offset2: number, nuc_length: number, aa_segment_window_length: number, min_identity: number, min_significant_length_group: number): ProteinResult;
+
+/**
+ * `compare_proteins_full` over byte inputs
+ */
+export function compare_proteins_bytes(seq1: Uint8Array, seq2: Uint8Array, nuc_offset1: number, nuc_offset2: number, nuc_length: number, aa_segment_window_length: number, min_identity: number, min_significant_length_group: number): string;
+
+/**
+ * Protein comparison of amino-acid sequences as given (protein FASTA such as NP_/XP_
+ * records): the best ungapped placement, mask, conserved blocks (`aa_segment_window_length`),
+ * and scores, in the `compare_proteins_with_options` result shape with `frame1`, `frame2`,
+ * `mismatchCodons`, and `frameDetection` null. Normalization skips the U-to-T and poly-A
+ * steps, which would change residues
+ */
+export function compare_proteins_direct(aa1: string, aa2: string, options: ComparisonOptions): string;
 
 export function compare_proteins_full(seq1: string, seq2: string, nuc_offset1: number, nuc_offset2: number, nuc_length: number, aa_segment_window_length: number, min_identity: number, min_significant_length_group: number): string;
 
+/**
+ * `compare_proteins_full` configured by a `ComparisonOptions` object (the amino-acid
+ * window comes from `aa_segment_window_length`); the nucleotide offsets and length refer
+ * to the normalized sequences and count in the options' coordinate system, as in the
+ * `compare_sequences_with_options` result. Pinned frames or a CDS replace the frame search
+ */
+export function compare_proteins_with_options(seq1: string, seq2: string, nuc_offset1: number, nuc_offset2: number, nuc_length: number, options: ComparisonOptions): string;
+
+/**
+ * `compare_sequences_full` over two sequences already resident in WASM memory
+ */
+export function compare_sequence_buffers(seq1: SequenceBuffer, seq2: SequenceBuffer, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * Nucleotide comparison with statistics reported separately for 5'UTR, CDS, and 3'UTR.
+ * `cds_start`/`cds_end` are 0-based half-open positions in seq1; when `cds_start` is omitted
+ * the CDS is inferred (first ATG to first in-frame stop), and when only `cds_end` is omitted
+ * it runs from `cds_start` to the first in-frame stop.
+ */
+export function compare_sequence_regions(seq1: string, seq2: string, cds_start: number | null | undefined, cds_end: number | null | undefined, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * `compare_sequence_regions` configured by a `ComparisonOptions` object: its thresholds,
+ * normalization, start codons, and Kozak setting (for the inferred CDS). Positions are 0-based half-open
+ * in the normalized seq1
+ */
+export function compare_sequence_regions_with_options(seq1: string, seq2: string, cds_start: number | null | undefined, cds_end: number | null | undefined, options: ComparisonOptions): string;
+
+/**
+ * `compare_sequences_full` returning the result object. Throws an Error named
+ * EmptySequence, InvalidCharacters (with `sequence`, `positions`, `count`),
+ * MemoryLimitExceeded (with `requiredBytes`, `limitBytes`), NoAlignmentFound, or
+ * MalformedResult when the result does not read as a `NucleotideResult`.
+ */
+export function compare_sequences(seq1: string, seq2: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): NucleotideResult;
+
+/**
+ * Same result as `compare_sequences_full`, returned as a Promise that resolves to the JSON
+ * string. The offset search yields to the event loop roughly every 16 ms, so long
+ * comparisons no longer freeze the page and no Worker is needed. `progress` is called
+ * as in `compare_sequences_with_progress`; the Promise rejects with "Comparison aborted"
+ * once `signal` (see `AbortHandle`) is aborted.
+ */
+export function compare_sequences_async(seq1: string, seq2: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number, progress: Function | null | undefined, signal: any): Promise<any>;
+
+/**
+ * `compare_sequences_full` over byte inputs (e.g. a Uint8Array of ASCII bases)
+ */
+export function compare_sequences_bytes(seq1: Uint8Array, seq2: Uint8Array, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * `compare_sequences_with_options` with the JSON compressed in Rust as the options'
+ * `with_compression` format says (gzip by default), for results too large to pass around
+ * as strings: decode with `new Response(new Blob([bytes]).stream().pipeThrough(new
+ * DecompressionStream("gzip"))).json()`
+ */
+export function compare_sequences_compressed(seq1: string, seq2: string, options: ComparisonOptions): Uint8Array;
+
 export function compare_sequences_full(seq1: string, seq2: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number): string;
+
+/**
+ * Same as `compare_sequences_full`, but the offset search stops at the first placement whose
+ * identity reaches `good_enough_identity` (e.g. 0.95) instead of scanning every offset, so
+ * obviously matching sequences finish much sooner; the placement may not be the optimum
+ */
+export function compare_sequences_good_enough(seq1: string, seq2: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number, good_enough_identity: number): string;
+
+/**
+ * `compare_sequences_full` configured by a `ComparisonOptions` object. With normalization
+ * enabled, offsets refer to the normalized sequences and the result has a `normalization`
+ * report per sequence. When the best placement falls short of `min_alignment_identity`,
+ * the result is `{"noSignificantAlignment": true, "minAlignmentIdentity", "identity",
+ * "length", "bestAttempt"}` with the usual result as `bestAttempt`.
+ */
+export function compare_sequences_with_options(seq1: string, seq2: string, options: ComparisonOptions): string;
+
+/**
+ * Same result as `compare_sequences_full`, calling `progress(percent, phase)` as the
+ * comparison advances. The call blocks, so in the browser run it in a Worker and
+ * forward the reports with `postMessage`; on the main thread use
+ * `compare_sequences_async`, which accepts the same callback. Throws "Comparison aborted"
+ * if `signal` (see `AbortHandle`) is aborted, e.g. from within the callback.
+ */
+export function compare_sequences_with_progress(seq1: string, seq2: string, segment_window_length: number, min_identity: number, min_significant_length_group: number, min_sequence_overlap_pct: number, progress: Function | null | undefined, signal: any): string;
+
+/**
+ * Consensus of two overlapping reads (e.g. two clones of the same insert).
+ * The pair is placed with the standard offset search; columns covered by only one
+ * read take that read's base, so the consensus spans the union of both reads.
+ * `policy` is "iupac" (default) or "majority". An error when the offset search would
+ * exceed the memory limit.
+ */
+export function consensus(seq1: string, seq2: string, policy: string): string;
+
+/**
+ * Conserved blocks of the best placement of seq2 against seq1, as `ConservedBlock` objects
+ * (positions in the sequences after the options' normalization), found with the options'
+ * block detection; an error when the placement would exceed the memory limit
+ */
+export function conserved_blocks(seq1: string, seq2: string, options: ComparisonOptions): ConservedBlock[];
+
+/**
+ * Sites of a CRISPR guide in two alleles and what their differences do to them. `guide` is
+ * the spacer (RNA or DNA) and `pam` the IUPAC PAM that must follow the protospacer (NGG
+ * when empty); `sites1` and `sites2` list each protospacer and PAM match with at most
+ * `max_mismatches` mismatches, all in the protospacer: `strand`, `start`, `end`,
+ * `pamStart`, the `cutSite` 3 bases upstream of the PAM, `mismatches`, and the matched
+ * bases. Over the best placement of the two, `pamChanges` lists PAMs on either strand that
+ * the differences create or destroy, and `siteChanges` the guide sites whose mismatches
+ * differ between the alleles (null where one has no site). An invalid guide or PAM gives
+ * `{"error": ...}`
+ */
+export function crispr_guide_sites(seq1: string, seq2: string, guide: string, pam: string, max_mismatches: number, options: ComparisonOptions): string;
+
+/**
+ * Detect inverted and transposed segments between two sequences from exact anchors of
+ * `anchor_length` bases (≤ 32) found on both strands. Segments shorter than
+ * `min_segment_length` are dropped; non-co-linear ones are listed in `structuralDifferences`.
+ * Reverse-strand segments give seq2 coordinates on the forward strand.
+ */
+export function detect_rearrangements(seq1: string, seq2: string, anchor_length: number, min_segment_length: number): string;
+
+/**
+ * Column-by-column diff of `a` and `b` from their first characters, with no offset search,
+ * blocks, or scoring: for short oligos where the full pipeline would cost more than the
+ * comparison. `symbols` styles the mask as up to three characters, the mismatch, match, and
+ * gap symbols ('?', the residue itself, and '-' when left out). Returns the compared
+ * `length` (the shorter input), `mismatches`, `identity`, the `mask`, and the 0-based
+ * mismatch `positions`
+ */
+export function diff_strings(a: string, b: string, symbols: string): string;
+
+/**
+ * Restriction sites present in one sequence but not at the same place in the other, over the
+ * best placement of seq2 against seq1, with the mismatching positions (in seq1) that make the
+ * difference: candidate digests for genotyping the two alleles
+ */
+export function differential_restriction_sites(seq1: string, seq2: string, enzymes: string, options: ComparisonOptions): string;
+
+/**
+ * Every position where `needle` occurs in `haystack` with at most `max_mismatches`
+ * substitutions (Hamming distance, case-insensitive, forward strand only), found by
+ * bit-parallel shift-and: 0-based `position` and `mismatches` of each hit, by position. For
+ * short signature motifs; `find_pattern` covers IUPAC codes and both strands
+ */
+export function find_approximate_matches(needle: string, haystack: string, max_mismatches: number): string;
+
+/**
+ * Hits of an IUPAC `pattern` in `seq` on either strand with up to `max_mismatches`
+ * mismatches: 0-based top-strand `position`, `strand`, `mismatches`, and the matched bases
+ */
+export function find_pattern(seq: string, pattern: string, max_mismatches: number): string;
+
+/**
+ * `find_pattern` in both sequences, with each one's fewest mismatches (null without a hit)
+ * and whether they differ, i.e. whether the probe binds one sequence better than the other
+ */
+export function find_pattern_in_both(seq1: string, seq2: string, pattern: string, max_mismatches: number): string;
+
+/**
+ * Window GC content of each sequence and window identity along the best placement of seq2
+ * against seq1, as parallel arrays with the windows' starts in seq1. `window` of 0 uses the
+ * options' segment window length and `step` of 0 gives non-overlapping windows; an overlap
+ * shorter than one window is a single window.
+ */
+export function gc_identity_profile(seq1: string, seq2: string, window: number, step: number, options: ComparisonOptions): string;
+
+/**
+ * Current scratch-memory cap in bytes (0 means unlimited)
+ */
+export function get_memory_limit(): number;
+
+/**
+ * k-mer count vector of a sequence as a JSON object (`{"ACG": 3, ...}`), keys sorted.
+ * With `canonical`, each k-mer is merged with its reverse complement.
+ */
+export function kmer_counts(seq: string, k: number, canonical: boolean): string;
+
+/**
+ * Position in the other sequence aligned with `pos` of sequence `seq` (1 or 2) of a
+ * nucleotide or protein comparison result; both positions count in the result's coordinate
+ * system. Undefined outside the aligned region, where the other sequence has a gap, or for
+ * an unreadable result
+ */
+export function map_position(result: string, seq: number, pos: number): number | undefined;
+
+/**
+ * miRNA seed sites (8mer, 7mer-m8, 7mer-A1, 6mer) of `seeds` in two 3'UTRs, and the sites the
+ * differences over their best placement create, destroy, or change in type. `seeds` lists
+ * mature miRNAs or seeds (nucleotides 2-8) as `name=SEQUENCE` or bare sequences; invalid
+ * entries come back in `invalid`. Pass the UTRs themselves, e.g. the 3'UTR region of
+ * `compare_sequence_regions`
+ */
+export function mirna_seed_sites(utr1: string, utr2: string, seeds: string, options: ComparisonOptions): string;
+
+/**
+ * Nearest-neighbor Tm (°C, null unless the oligo is all A/C/G/T), GC fraction, and self-dimer,
+ * 3' self-dimer, and hairpin stem lengths of `seq` at `salt` mM Na+ and `primer_conc` nM
+ * strand concentration (0 for the defaults, 50 mM and 50 nM). `warnings` names the checks
+ * the primer candidates would fail
+ */
+export function oligo_properties(seq: string, salt: number, primer_conc: number): string;
+
+/**
+ * Identity and p-distance (1 - identity) for every pair of sequences.
+ * `seeded` swaps the exhaustive offset scan for the k-mer seeded search, which is much
+ * faster on long inputs but may miss the optimal offset for highly divergent pairs.
+ */
+export function pairwise_matrix(seqs: string[], min_sequence_overlap_pct: number, seeded: boolean): string;
+
+/**
+ * Extract exon coordinates from GFF3 `exon` rows or GenBank `exon` feature lines.
+ * Returns flat 0-based half-open [start, end, ...] pairs in file order.
+ */
+export function parse_exon_annotations(text: string): Uint32Array;
+
+/**
+ * The part of a nucleotide or protein comparison result over `start..end` of sequence `seq`
+ * (1 or 2), in the result's coordinate system: `offset1`, `offset2`, and `length` of the
+ * aligned columns inside the range (offsets null when there are none), their `mask` (with
+ * the result's `maskSymbols` when it restyles the mask), `mismatches` with each one's `position1` and `position2` in `mismatchPositions`, the
+ * `conservedBlocks` cut to the range (in mask columns, as in the result), and the result's
+ * `mismatchVariants` or `mismatchCodons` inside it. Unreadable results or a `seq` other than
+ * 1 or 2 give `{"error": ...}`
+ */
+export function query_region(result: string, seq: number, start: number, end: number): string;
+
+/**
+ * Consensus of a forward read and a reverse read of the same template, in the forward
+ * read's orientation; `forward_quals` / `reverse_quals` are the reads' Phred scores as
+ * called (empty when there are none). Columns only one read covers take its base. Gives
+ * the `consensus`, a quality per base (`qualities`: reads agreeing add up, capped at 60;
+ * a resolved conflict keeps the difference; an ambiguous one is 0), where the reverse read
+ * starts in it (`reverseStart`, negative when it starts first), the `overlap` length, and
+ * the `conflicts` (0-based consensus `position`, both calls and qualities, and whether the
+ * `forward` or `reverse` call was taken or the column left `ambiguous`)
+ */
+export function read_pair_consensus(forward: string, forward_quals: Uint8Array, reverse: string, reverse_quals: Uint8Array): string;
+
+/**
+ * Interleaved text of a comparison result's aligned region: seq1, a match line ('|' where
+ * the residues agree), and seq2, `line_width` columns at a time (60 when 0) under a ruler,
+ * with positions in the result's coordinate system. Protein results carry their
+ * translations; nucleotide results carry only the mask, so `seq1` and `seq2` must be the
+ * sequences as compared (after any normalization). They are ignored for protein results
+ */
+export function render_alignment_text(result: string, line_width: number, seq1: string, seq2: string): string;
+
+/**
+ * A self-contained report of a comparison result (the JSON of a nucleotide, protein, or
+ * `compare_sequence_regions` comparison): summary statistics, the differences (with known
+ * variants when the result has them), conserved blocks, and per-region identity.
+ * `format` is "markdown" (default) or "html"; unreadable results give a one-line report
+ * saying so
+ */
+export function render_report(result: string, format: string): string;
+
+/**
+ * Restriction sites of `enzymes` (empty for all built-in enzymes) in `seq`, 0-based, with
+ * the strand matched and the top-strand cut position
+ */
+export function restriction_sites(seq: string, enzymes: string): string;
+
+/**
+ * Hits of the JASPAR or MEME `matrices` in both sequences (relative score at least
+ * `threshold`, 0 for 0.85, either strand), and the hits in the best placement's overlap that
+ * one sequence has and the other lacks: `lost` when only seq1 has it, `gained` when only seq2
+ * does, with both scores and the mismatching positions (in seq1) responsible
+ */
+export function scan_motifs(seq1: string, seq2: string, matrices: string, threshold: number, options: ComparisonOptions): string;
+
+/**
+ * Route diagnostic messages to `logger(message)` (e.g. `console.debug`); pass
+ * `undefined` to silence them again
+ */
+export function set_logger(logger?: Function | null): void;
+
+/**
+ * Set the scratch-memory cap in bytes for a single comparison (0 removes the cap)
+ */
+export function set_memory_limit(bytes: number): void;
+
+/**
+ * MinHash sketch of a nucleotide sequence: the `size` smallest distinct hashes of its
+ * canonical k-mers (k ≤ 32), sorted ascending.
+ */
+export function sketch(seq: string, k: number, size: number): BigUint64Array;
+
+/**
+ * Estimated Jaccard index and Mash distance between two sketches built with the same `k`.
+ * Sketches are compared over the `min(len1, len2)` smallest hashes of their union.
+ */
+export function sketch_distance(sketch1: BigUint64Array, sketch2: BigUint64Array, k: number): string;
+
+/**
+ * Mismatches over the best placement of seq2 that fall on a splice site of the exons
+ * annotated on seq1 (`exons1`, flat 0-based half-open pairs as from
+ * `parse_exon_annotations`): `canonical` on a donor or acceptor dinucleotide, or
+ * `nearJunction` within `window` bases of the exon boundary. `junctions` gives each site's
+ * dinucleotide in both sequences (null where seq2 does not cover it) and whether it is canonical
+ */
+export function splice_site_differences(seq1: string, seq2: string, exons1: Uint32Array, window: number, options: ComparisonOptions): string;
+
+/**
+ * Putative domains of a protein comparison result (from `compare_proteins_with_options`,
+ * `compare_proteins_direct`, and the like): stretches where the identity of the `window`
+ * residues around each column stays at or above `min_identity`, at least `min_length`
+ * residues long, as `domains` with their `start1`/`end1` and `start2`/`end2` in each
+ * protein (the result's coordinate system) and `identity`, and the `linkers` between
+ * consecutive domains. `window` of 0 uses the default amino-acid window and `min_length`
+ * of 0 the window; an unreadable result gives `{"error": ...}`
+ */
+export function suggest_domain_boundaries(result: string, window: number, min_identity: number, min_length: number): string;
+
+/**
+ * Sliding-window quality trim of a read: kept from the first `window`-base window whose
+ * mean Phred quality (`quals`, one per base) reaches `threshold` to the end of the last one,
+ * with low-quality bases left at either end cut too. Gives the kept `start`, `end`,
+ * `length`, and `sequence`, plus the `trimmed` ranges at the 5' and 3' ends
+ */
+export function trim_by_quality(seq: string, quals: Uint8Array, window: number, threshold: number): string;
+
+/**
+ * Cut bases from both ends of a read while they are uncalled (N, gaps, non-letters) or,
+ * when `quals` is not empty, their Phred quality is below `threshold`; same result shape as
+ * `trim_by_quality`
+ */
+export function trim_read_ends(seq: string, quals: Uint8Array, threshold: number): string;
+
+/**
+ * Check sequence text before comparison. Returns the detected `type` (dna, rna, protein,
+ * unknown), GenBank record / FASTA header / whitespace / lowercase contamination, invalid
+ * characters with their positions, `suggestions` for normalizing the text, and the
+ * `normalized` sequence (whose length is `length`).
+ */
+export function validate_sequence(seq: string): string;
+
+/**
+ * Occurrences of an expected `insert` in a circular `plasmid` map: every placement on
+ * either strand, including across the origin, with at most `max_mismatches` differences
+ * (IUPAC codes in the insert match any of their bases), overlapping placements on a strand
+ * reduced to the one with the fewest. Each occurrence gives its `strand`, plasmid `start`
+ * and `end` (where `wrapsOrigin`, `end` comes before `start`), `mismatches`, `identity`,
+ * and the `differences` by `insertPosition` and `plasmidPosition`, with the `expected` and
+ * `found` bases on the insert's strand. Positions follow the options' coordinate system;
+ * `verified` is whether exactly one occurrence has no differences
+ */
+export function verify_plasmid_insert(insert: string, plasmid: string, max_mismatches: number, options: ComparisonOptions): string;
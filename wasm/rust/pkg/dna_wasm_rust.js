@@ -2,11 +2,89 @@
 let imports = {};
 imports['__wbindgen_placeholder__'] = module.exports;
 
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_externrefs.set(idx, obj);
+    return idx;
+}
+
+function _assertChar(c) {
+    if (typeof(c) === 'number' && (c >= 0x110000 || (c >= 0xD800 && c < 0xE000))) throw new Error(`expected a valid Unicode scalar value, found ${c}`);
+}
+
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
+    }
+}
+
+const CLOSURE_DTORS = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(state => state.dtor(state.a, state.b));
+
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(wasm.__wbindgen_externrefs.get(mem.getUint32(i, true)));
+    }
+    wasm.__externref_drop_slice(ptr, len);
+    return result;
+}
+
+function getArrayU32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+function getArrayU64FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getBigUint64ArrayMemory0().subarray(ptr / 8, ptr / 8 + len);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+let cachedBigUint64ArrayMemory0 = null;
+function getBigUint64ArrayMemory0() {
+    if (cachedBigUint64ArrayMemory0 === null || cachedBigUint64ArrayMemory0.byteLength === 0) {
+        cachedBigUint64ArrayMemory0 = new BigUint64Array(wasm.memory.buffer);
+    }
+    return cachedBigUint64ArrayMemory0;
+}
+
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
+        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+    }
+    return cachedDataViewMemory0;
+}
+
+let cachedFloat64ArrayMemory0 = null;
+function getFloat64ArrayMemory0() {
+    if (cachedFloat64ArrayMemory0 === null || cachedFloat64ArrayMemory0.byteLength === 0) {
+        cachedFloat64ArrayMemory0 = new Float64Array(wasm.memory.buffer);
+    }
+    return cachedFloat64ArrayMemory0;
+}
+
 function getStringFromWasm0(ptr, len) {
     ptr = ptr >>> 0;
     return decodeText(ptr, len);
 }
 
+let cachedUint32ArrayMemory0 = null;
+function getUint32ArrayMemory0() {
+    if (cachedUint32ArrayMemory0 === null || cachedUint32ArrayMemory0.byteLength === 0) {
+        cachedUint32ArrayMemory0 = new Uint32Array(wasm.memory.buffer);
+    }
+    return cachedUint32ArrayMemory0;
+}
+
 let cachedUint8ArrayMemory0 = null;
 function getUint8ArrayMemory0() {
     if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
@@ -15,6 +93,85 @@ function getUint8ArrayMemory0() {
     return cachedUint8ArrayMemory0;
 }
 
+function handleError(f, args) {
+    try {
+        return f.apply(this, args);
+    } catch (e) {
+        const idx = addToExternrefTable0(e);
+        wasm.__wbindgen_exn_store(idx);
+    }
+}
+
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
+function makeMutClosure(arg0, arg1, dtor, f) {
+    const state = { a: arg0, b: arg1, cnt: 1, dtor };
+    const real = (...args) => {
+
+        // First up with a closure we increment the internal reference
+        // count. This ensures that the Rust closure environment won't
+        // be deallocated while we're invoking it.
+        state.cnt++;
+        const a = state.a;
+        state.a = 0;
+        try {
+            return f(a, state.b, ...args);
+        } finally {
+            state.a = a;
+            real._wbg_cb_unref();
+        }
+    };
+    real._wbg_cb_unref = () => {
+        if (--state.cnt === 0) {
+            state.dtor(state.a, state.b);
+            state.a = 0;
+            CLOSURE_DTORS.unregister(state);
+        }
+    };
+    CLOSURE_DTORS.register(real, state, state);
+    return real;
+}
+
+function passArray32ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 4, 4) >>> 0;
+    getUint32ArrayMemory0().set(arg, ptr / 4);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passArray64ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 8, 8) >>> 0;
+    getBigUint64ArrayMemory0().set(arg, ptr / 8);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8ArrayMemory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passArrayF64ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 8, 8) >>> 0;
+    getFloat64ArrayMemory0().set(arg, ptr / 8);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passArrayJsValueToWasm0(array, malloc) {
+    const ptr = malloc(array.length * 4, 4) >>> 0;
+    for (let i = 0; i < array.length; i++) {
+        const add = addToExternrefTable0(array[i]);
+        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);
+    }
+    WASM_VECTOR_LEN = array.length;
+    return ptr;
+}
+
 function passStringToWasm0(arg, malloc, realloc) {
     if (realloc === undefined) {
         const buf = cachedTextEncoder.encode(arg);
@@ -52,6 +209,12 @@ function passStringToWasm0(arg, malloc, realloc) {
     return ptr;
 }
 
+function takeFromExternrefTable0(idx) {
+    const value = wasm.__wbindgen_externrefs.get(idx);
+    wasm.__externref_table_dealloc(idx);
+    return value;
+}
+
 let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
 cachedTextDecoder.decode();
 function decodeText(ptr, len) {
@@ -73,68 +236,3375 @@ if (!('encodeInto' in cachedTextEncoder)) {
 
 let WASM_VECTOR_LEN = 0;
 
+function wasm_bindgen__convert__closures_____invoke__h34f7a0b5d2a13193(arg0, arg1, arg2) {
+    wasm.wasm_bindgen__convert__closures_____invoke__h34f7a0b5d2a13193(arg0, arg1, arg2);
+}
+
+function wasm_bindgen__convert__closures_____invoke__hb7ba44727ea16178(arg0, arg1, arg2, arg3) {
+    wasm.wasm_bindgen__convert__closures_____invoke__hb7ba44727ea16178(arg0, arg1, arg2, arg3);
+}
+
+const AbortHandleFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_aborthandle_free(ptr >>> 0, 1));
+
+const ComparatorFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_comparator_free(ptr >>> 0, 1));
+
+const ComparisonOptionsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_comparisonoptions_free(ptr >>> 0, 1));
+
+const ConservedBlockFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_conservedblock_free(ptr >>> 0, 1));
+
+const SequenceBufferFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_sequencebuffer_free(ptr >>> 0, 1));
+
+const SequenceIndexFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_sequenceindex_free(ptr >>> 0, 1));
+
 /**
- * @param {string} seq1
- * @param {string} seq2
- * @param {number} nuc_offset1
- * @param {number} nuc_offset2
- * @param {number} nuc_length
- * @param {number} aa_segment_window_length
- * @param {number} min_identity
- * @param {number} min_significant_length_group
- * @returns {string}
+ * Cancellation flag for in-flight comparisons: pass it as the `signal` argument and call
+ * `abort()`, e.g. from a click handler while `compare_sequences_async` is pending
  */
-function compare_proteins_full(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group) {
-    let deferred3_0;
-    let deferred3_1;
-    try {
+class AbortHandle {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        AbortHandleFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_aborthandle_free(ptr, 0);
+    }
+    constructor() {
+        const ret = wasm.aborthandle_new();
+        this.__wbg_ptr = ret >>> 0;
+        AbortHandleFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    abort() {
+        wasm.aborthandle_abort(this.__wbg_ptr);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get aborted() {
+        const ret = wasm.aborthandle_aborted(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) AbortHandle.prototype[Symbol.dispose] = AbortHandle.prototype.free;
+exports.AbortHandle = AbortHandle;
+
+class Comparator {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Comparator.prototype);
+        obj.__wbg_ptr = ptr;
+        ComparatorFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ComparatorFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_comparator_free(ptr, 0);
+    }
+    /**
+     * Replace `old` at the 0-based `position` of sequence `seq_id` (1 or 2) with `new`, for
+     * trying candidate corrections, and return the result of the latest `compare` /
+     * `compare_seeded` placement on the edited sequences without searching offsets again:
+     * only the edited mask columns are rebuilt (every column after the edit when it changes
+     * the length), then the statistics and blocks. Edits before the aligned region move it.
+     * Errors when `old` is not at `position` or nothing was compared yet; `compare` afterwards
+     * searches offsets again
+     * @param {number} seq_id
+     * @param {number} position
+     * @param {string} old
+     * @param {string} _new
+     * @param {number} segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @returns {string}
+     */
+    apply_edit(seq_id, position, old, _new, segment_window_length, min_identity, min_significant_length_group) {
+        let deferred4_0;
+        let deferred4_1;
+        try {
+            const ptr0 = passStringToWasm0(old, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            const ptr1 = passStringToWasm0(_new, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len1 = WASM_VECTOR_LEN;
+            const ret = wasm.comparator_apply_edit(this.__wbg_ptr, seq_id, position, ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group);
+            var ptr3 = ret[0];
+            var len3 = ret[1];
+            if (ret[3]) {
+                ptr3 = 0; len3 = 0;
+                throw takeFromExternrefTable0(ret[2]);
+            }
+            deferred4_0 = ptr3;
+            deferred4_1 = len3;
+            return getStringFromWasm0(ptr3, len3);
+        } finally {
+            wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+        }
+    }
+    /**
+     * @param {Uint8Array} seq1
+     * @param {Uint8Array} seq2
+     * @returns {Comparator}
+     */
+    static from_bytes(seq1, seq2) {
+        const ptr0 = passArray8ToWasm0(seq1, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(seq2, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.comparator_from_bytes(ptr0, len0, ptr1, len1);
+        return Comparator.__wrap(ret);
+    }
+    /**
+     * Append `chunk` to sequence `seq_id` (1 or 2), so sequences too large to hold as one
+     * JS string (whole chromosomes) can be streamed in piece by piece. Chunks are appended
+     * verbatim; cached search structures are rebuilt on the next comparison.
+     * @param {number} seq_id
+     * @param {string} chunk
+     */
+    push_chunk(seq_id, chunk) {
+        const ptr0 = passStringToWasm0(chunk, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparator_push_chunk(this.__wbg_ptr, seq_id, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * Session stored by `serialize`; `recompute_blocks` and `blocks` work at once when it had
+     * been compared. Errors on bytes that are not a serialized session, including one whose
+     * match profile does not fit its sequences
+     * @param {Uint8Array} bytes
+     * @returns {Comparator}
+     */
+    static deserialize(bytes) {
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparator_deserialize(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Comparator.__wrap(ret[0]);
+    }
+    /**
+     * Take the options' result format (coordinate system, mask symbols) and block detection
+     * (`with_block_exit_identity`, `with_trim_block_edges`) for every result of the session;
+     * its thresholds and search settings stay with the arguments of each call. Neither is
+     * serialized
+     * @param {ComparisonOptions} options
+     * @returns {Comparator}
+     */
+    with_options(options) {
+        const ptr = this.__destroy_into_raw();
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.comparator_with_options(ptr, options.__wbg_ptr);
+        return Comparator.__wrap(ret);
+    }
+    /**
+     * Like `compare`, but places the sequences with the k-mer seeded offset search
+     * @param {number} segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @param {number} min_sequence_overlap_pct
+     * @returns {string}
+     */
+    compare_seeded(segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparator_compare_seeded(this.__wbg_ptr, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * Current length of sequence `seq_id` (1 or 2; 0 for anything else)
+     * @param {number} seq_id
+     * @returns {number}
+     */
+    sequence_length(seq_id) {
+        const ret = wasm.comparator_sequence_length(this.__wbg_ptr, seq_id);
+        return ret >>> 0;
+    }
+    /**
+     * Same result as `compare_proteins_full` on the session's sequences
+     * @param {number} nuc_offset1
+     * @param {number} nuc_offset2
+     * @param {number} nuc_length
+     * @param {number} aa_segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @returns {string}
+     */
+    compare_proteins(nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group) {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparator_compare_proteins(this.__wbg_ptr, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * `push_chunk` for `Uint8Array` chunks (e.g. straight from a `ReadableStream`)
+     * @param {number} seq_id
+     * @param {Uint8Array} chunk
+     */
+    push_chunk_bytes(seq_id, chunk) {
+        const ptr0 = passArray8ToWasm0(chunk, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparator_push_chunk_bytes(this.__wbg_ptr, seq_id, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * Re-run only conserved-block detection on the placement and mask from the latest
+     * `compare` / `compare_seeded`, for block-parameter sliders
+     * @param {number} segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @returns {string}
+     */
+    recompute_blocks(segment_window_length, min_identity, min_significant_length_group) {
+        let deferred2_0;
+        let deferred2_1;
+        try {
+            const ret = wasm.comparator_recompute_blocks(this.__wbg_ptr, segment_window_length, min_identity, min_significant_length_group);
+            var ptr1 = ret[0];
+            var len1 = ret[1];
+            if (ret[3]) {
+                ptr1 = 0; len1 = 0;
+                throw takeFromExternrefTable0(ret[2]);
+            }
+            deferred2_0 = ptr1;
+            deferred2_1 = len1;
+            return getStringFromWasm0(ptr1, len1);
+        } finally {
+            wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+        }
+    }
+    /**
+     * @param {string} seq1
+     * @param {string} seq2
+     */
+    constructor(seq1, seq2) {
         const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
         const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
-        const ret = wasm.compare_proteins_full(ptr0, len0, ptr1, len1, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group);
-        deferred3_0 = ret[0];
-        deferred3_1 = ret[1];
-        return getStringFromWasm0(ret[0], ret[1]);
-    } finally {
-        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        const ret = wasm.comparator_from_bytes(ptr0, len0, ptr1, len1);
+        this.__wbg_ptr = ret >>> 0;
+        ComparatorFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Session with two empty sequences, to be filled with `push_chunk`
+     * @returns {Comparator}
+     */
+    static empty() {
+        const ret = wasm.comparator_empty();
+        return Comparator.__wrap(ret);
+    }
+    /**
+     * Conserved blocks of the latest `compare` / `compare_seeded` placement as
+     * `ConservedBlock` objects, detected with the given block parameters and the session's
+     * block detection
+     * @param {number} segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @returns {ConservedBlock[]}
+     */
+    blocks(segment_window_length, min_identity, min_significant_length_group) {
+        const ret = wasm.comparator_blocks(this.__wbg_ptr, segment_window_length, min_identity, min_significant_length_group);
+        if (ret[3]) {
+            throw takeFromExternrefTable0(ret[2]);
+        }
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Same result as `compare_sequences_full` on the session's sequences
+     * @param {number} segment_window_length
+     * @param {number} min_identity
+     * @param {number} min_significant_length_group
+     * @param {number} min_sequence_overlap_pct
+     * @returns {string}
+     */
+    compare(segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparator_compare(this.__wbg_ptr, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * The session as bytes for `deserialize`: both sequences, the match profile if computed,
+     * and the latest placement
+     * @returns {Uint8Array}
+     */
+    serialize() {
+        const ret = wasm.comparator_serialize(this.__wbg_ptr);
+        var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
     }
 }
-exports.compare_proteins_full = compare_proteins_full;
+if (Symbol.dispose) Comparator.prototype[Symbol.dispose] = Comparator.prototype.free;
+exports.Comparator = Comparator;
 
-/**
- * @param {string} seq1
- * @param {string} seq2
- * @param {number} segment_window_length
- * @param {number} min_identity
- * @param {number} min_significant_length_group
- * @param {number} min_sequence_overlap_pct
- * @returns {string}
- */
-function compare_sequences_full(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
-    let deferred3_0;
-    let deferred3_1;
-    try {
-        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+class ComparisonOptions {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(ComparisonOptions.prototype);
+        obj.__wbg_ptr = ptr;
+        ComparisonOptionsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ComparisonOptionsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_comparisonoptions_free(ptr, 0);
+    }
+    /**
+     * @returns {string}
+     */
+    get gap_symbol() {
+        const ret = wasm.comparisonoptions_gap_symbol(this.__wbg_ptr);
+        return String.fromCodePoint(ret);
+    }
+    /**
+     * Take the start codon in the strongest Kozak context (gccRccATGG) rather than the
+     * first one, so upstream ATGs in a 5' UTR are passed over; results report the score
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_kozak(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_kozak(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {string}
+     */
+    get compression() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparisonoptions_compression(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {number}
+     */
+    get match_score() {
+        const ret = wasm.comparisonoptions_match_score(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get trim_poly_a() {
+        const ret = wasm.comparisonoptions_trim_poly_a(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Translate seq1 only in this frame (0-2) relative to its nucleotide offset instead of
+     * searching all three; `undefined` (the default) searches. Other values are ignored
+     * @param {number | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_frame1(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_frame1(ptr, isLikeNone(value) ? 0x100000001 : (value) >>> 0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Pin the frame of seq2, as for `with_frame1`
+     * @param {number | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_frame2(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_frame2(ptr, isLikeNone(value) ? 0x100000001 : (value) >>> 0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Place the sequences with the k-mer seeded search instead of the exhaustive scan
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_seeded(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_seeded(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Replace U with T before comparing, so RNA compares against DNA
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_u_to_t(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_u_to_t(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Go back to searching frames over the nucleotide placement
+     * @returns {ComparisonOptions}
+     */
+    without_cds() {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_without_cds(ptr);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get heterozygous() {
+        const ret = wasm.comparisonoptions_heterozygous(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {string | undefined}
+     */
+    get match_symbol() {
+        const ret = wasm.comparisonoptions_match_symbol(this.__wbg_ptr);
+        return ret === 0xFFFFFF ? undefined : String.fromCodePoint(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get min_identity() {
+        const ret = wasm.comparisonoptions_min_identity(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * Names accepted by `preset` (aliases not included)
+     * @returns {string[]}
+     */
+    static preset_names() {
+        const ret = wasm.comparisonoptions_preset_names();
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {string}
+     */
+    get start_codons() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparisonoptions_start_codons(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {boolean}
+     */
+    get strip_digits() {
+        const ret = wasm.comparisonoptions_strip_digits(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Accept pasted GenBank records: keep only the ORIGIN section and drop its line
+     * coordinates and spacing
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_genbank(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_genbank(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Add `primers`: the best forward and reverse primer in each conserved block that
+     * matches both sequences exactly (18-25 bases, nearest-neighbor Tm 55-65 °C, 40-60%
+     * GC, a 3' GC clamp, little self-complementarity)
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_primers(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_primers(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Bins set by `with_overview_bins`, or null for the default
+     * @returns {number | undefined}
+     */
+    get overview_bins() {
+        const ret = wasm.comparisonoptions_overview_bins(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get mismatch_score() {
+        const ret = wasm.comparisonoptions_mismatch_score(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get protein_matrix() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.comparisonoptions_protein_matrix(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {boolean}
+     */
+    get selenocysteine() {
+        const ret = wasm.comparisonoptions_selenocysteine(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Score of a nucleotide column with a '-' gap (default -2)
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_gap_score(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_gap_score(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Enable or disable every input-cleaning step below at once (poly-A trimming is
+     * left as it is)
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_normalize(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_normalize(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Write offsets and block positions 1-based with inclusive ends, as NCBI shows them,
+     * instead of 0-based half-open; results name theirs in `coordinateSystem`
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_one_based(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_one_based(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Compare case-insensitively by uppercasing both sequences first
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_uppercase(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_uppercase(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {string}
+     */
+    get mismatch_symbol() {
+        const ret = wasm.comparisonoptions_mismatch_symbol(this.__wbg_ptr);
+        return String.fromCodePoint(ret);
+    }
+    /**
+     * Mask character where either sequence has a '-' gap (default '-'). Letters, '*', and the
+     * mismatch or match symbol are refused and keep the current symbol
+     * @param {string} value
+     * @returns {ComparisonOptions}
+     */
+    with_gap_symbol(value) {
+        const ptr = this.__destroy_into_raw();
+        const char0 = value.codePointAt(0);
+        _assertChar(char0);
+        const ret = wasm.comparisonoptions_with_gap_symbol(ptr, char0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get highlight_ranges() {
+        const ret = wasm.comparisonoptions_highlight_ranges(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get strip_whitespace() {
+        const ret = wasm.comparisonoptions_strip_whitespace(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get trim_block_edges() {
+        const ret = wasm.comparisonoptions_trim_block_edges(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * How `compare_sequences_compressed` encodes its result: "gzip" (the default), "deflate"
+     * (zlib), "deflate-raw", or "none" for plain UTF-8, as read by `DecompressionStream`;
+     * unknown names keep the current format
+     * @param {string} name
+     * @returns {ComparisonOptions}
+     */
+    with_compression(name) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
-        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-        const len1 = WASM_VECTOR_LEN;
-        const ret = wasm.compare_sequences_full(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
-        deferred3_0 = ret[0];
-        deferred3_1 = ret[1];
-        return getStringFromWasm0(ret[0], ret[1]);
-    } finally {
-        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        const ret = wasm.comparisonoptions_with_compression(ptr, ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Score of a matching nucleotide column (default 1), and of matching amino acids with
+     * the "match-mismatch" protein matrix
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_match_score(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_match_score(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Trim poly-A tails (12+ bases, at least 90% A) from the sequence ends before
+     * aligning; not included in `with_normalize`
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_trim_poly_a(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_trim_poly_a(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get mismatch_hotspots() {
+        const ret = wasm.comparisonoptions_mismatch_hotspots(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get protein_gap_score() {
+        const ret = wasm.comparisonoptions_protein_gap_score(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * Treat two-base IUPAC codes in seq2 (R, Y, S, W, K, M from heterozygous Sanger peaks)
+     * as heterozygous calls against the reference seq1: results list them in
+     * `heterozygousCalls`, each `hetConsistentWithReference` (one allele is seq1's base) or
+     * `hetNonReference`, with the counts and the `homozygousMismatches` left
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_heterozygous(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_heterozygous(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Mask character at matches; `undefined` (the default) keeps the residue. Letters, '*',
+     * and the mismatch or gap symbol are refused and keep the current setting
+     * @param {string | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_match_symbol(value) {
+        const ptr = this.__destroy_into_raw();
+        const char0 = isLikeNone(value) ? 0xFFFFFF : value.codePointAt(0);
+        if (char0 !== 0xFFFFFF) { _assertChar(char0); }
+        const ret = wasm.comparisonoptions_with_match_symbol(ptr, char0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_min_identity(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_min_identity(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Codons that start a CDS (default "ATG"): a list such as "ATG,GTG,TTG", or "standard",
+     * "bacterial", or "mitochondrial"; used for CDS inference and the frame-detection notes.
+     * Invalid lists keep the current codons
+     * @param {string} codons
+     * @returns {ComparisonOptions}
+     */
+    with_start_codons(codons) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(codons, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_with_start_codons(ptr, ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Remove digits (GenBank ORIGIN line coordinates) before comparing
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_strip_digits(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_strip_digits(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Number of bins in `overview`, the identity of the alignment at a fixed resolution for
+     * zoomed-out views; added by default (2000 bins) once the alignment spans 100 kb, at any
+     * length when set here, and never when set to 0
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_overview_bins(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_overview_bins(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get block_exit_identity() {
+        const ret = wasm.comparisonoptions_block_exit_identity(this.__wbg_ptr);
+        return ret[0] === 0 ? undefined : ret[1];
+    }
+    /**
+     * Number of variants read by `with_known_variants`, or null without any list
+     * @returns {number | undefined}
+     */
+    get known_variant_count() {
+        const ret = wasm.comparisonoptions_known_variant_count(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * Known variants of seq1's region as VCF text (e.g. a dbSNP slice), whose POS counts
+     * from `seq1_start`, the 1-based contig position of seq1's first base; results then list
+     * every mismatch in `mismatchVariants`, marked known (with its ID and alleles) or novel
+     * @param {string} vcf
+     * @param {number} seq1_start
+     * @returns {ComparisonOptions}
+     */
+    with_known_variants(vcf, seq1_start) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(vcf, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_with_known_variants(ptr, ptr0, len0, seq1_start);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Score of a mismatching column (default -2), as for `with_match_score`
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_mismatch_score(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_mismatch_score(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Amino-acid scores: "blosum62" (the default) or "match-mismatch"; unknown names keep
+     * the current matrix
+     * @param {string} name
+     * @returns {ComparisonOptions}
+     */
+    with_protein_matrix(name) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_with_protein_matrix(ptr, ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Amino-acid scores from a matrix in NCBI's text format (as distributed with BLAST) or
+     * JSON (`{"A": {"A": 4, ...}, ...}` or `{"alphabet": "AR...", "scores": [[...], ...]}`),
+     * for alphabets beyond the built-in matrices; residues it lacks score as its X. A matrix
+     * that cannot be read keeps the current one
+     * @param {string} matrix
+     * @returns {ComparisonOptions}
+     */
+    with_scoring_matrix(matrix) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(matrix, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_with_scoring_matrix(ptr, ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Translate TGA as selenocysteine (U) instead of a stop, for genes with a SECIS element
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_selenocysteine(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_selenocysteine(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get good_enough_identity() {
+        const ret = wasm.comparisonoptions_good_enough_identity(this.__wbg_ptr);
+        return ret[0] === 0 ? undefined : ret[1];
+    }
+    /**
+     * Mask character at mismatches (default '?'); e.g. '.' together with
+     * `with_match_symbol('|')` gives BLAST-style match lines. Letters, '*', and the match or
+     * gap symbol are refused and keep the current symbol
+     * @param {string} value
+     * @returns {ComparisonOptions}
+     */
+    with_mismatch_symbol(value) {
+        const ptr = this.__destroy_into_raw();
+        const char0 = value.codePointAt(0);
+        _assertChar(char0);
+        const ret = wasm.comparisonoptions_with_mismatch_symbol(ptr, char0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get segment_window_length() {
+        const ret = wasm.comparisonoptions_segment_window_length(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Add `highlightRanges`: the aligned columns merged into sorted `{start, end, kind}`
+     * runs of `match`, `mismatch`, `gap`, or `masked` (lowercase or N in either sequence),
+     * plus a `block` range per conserved block, so front ends need not rescan the mask
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_highlight_ranges(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_highlight_ranges(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Remove whitespace and line breaks before comparing
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_strip_whitespace(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_strip_whitespace(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Trim the mismatches (and gaps) at either end of each conserved block, so block
+     * positions, lengths, and sequences start and end on identical columns and can be used
+     * as primer or probe targets directly
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_trim_block_edges(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_trim_block_edges(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get min_alignment_identity() {
+        const ret = wasm.comparisonoptions_min_alignment_identity(this.__wbg_ptr);
+        return ret[0] === 0 ? undefined : ret[1];
+    }
+    /**
+     * Add `mismatchHotspots` to results: the `count` most divergent non-overlapping windows
+     * of `segment_window_length` columns (`start`, `end` in the mask as for conserved blocks,
+     * mismatch and gap `differences`, `identity`), most divergent first; 0 leaves them out
+     * @param {number} count
+     * @returns {ComparisonOptions}
+     */
+    with_mismatch_hotspots(count) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_mismatch_hotspots(ptr, count);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Nucleotide scores from a matrix in the `with_scoring_matrix` formats instead of the
+     * match and mismatch scores (base pairs it lacks score as its N); an empty string goes
+     * back to them, and a matrix that cannot be read keeps the current scores
+     * @param {string} matrix
+     * @returns {ComparisonOptions}
+     */
+    with_nucleotide_matrix(matrix) {
+        const ptr = this.__destroy_into_raw();
+        const ptr0 = passStringToWasm0(matrix, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_with_nucleotide_matrix(ptr, ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Score of an amino-acid column with a '-' gap (default -4)
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_protein_gap_score(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_protein_gap_score(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get aa_segment_window_length() {
+        const ret = wasm.comparisonoptions_aa_segment_window_length(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Whether nucleotide scores come from a `with_nucleotide_matrix` matrix
+     * @returns {boolean}
+     */
+    get custom_nucleotide_matrix() {
+        const ret = wasm.comparisonoptions_custom_nucleotide_matrix(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get min_sequence_overlap_pct() {
+        const ret = wasm.comparisonoptions_min_sequence_overlap_pct(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get resolve_ambiguous_codons() {
+        const ret = wasm.comparisonoptions_resolve_ambiguous_codons(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Detect conserved blocks with hysteresis on the identity of the window centered on
+     * each column: a block starts where it reaches `min_identity` and ends where it drops
+     * below this lower exit identity, for steadier boundaries than fixed windows (an exit
+     * above `min_identity` is taken as `min_identity`); `undefined` (the default) uses fixed windows
+     * @param {number | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_block_exit_identity(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_block_exit_identity(ptr, !isLikeNone(value), isLikeNone(value) ? 0 : value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Stop the offset search at the first placement reaching this identity (see
+     * `compare_sequences_good_enough`); `undefined` scans every offset
+     * @param {number | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_good_enough_identity(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_good_enough_identity(ptr, !isLikeNone(value), isLikeNone(value) ? 0 : value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Conserved-block window in nucleotides
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_segment_window_length(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_segment_window_length(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Identity the best placement must reach for `compare_sequences_with_options` to report
+     * it as the alignment; below it the result says `noSignificantAlignment` and carries the
+     * placement as `bestAttempt`. `undefined` (the default) reports any placement
+     * @param {number | null} [value]
+     * @returns {ComparisonOptions}
+     */
+    with_min_alignment_identity(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_min_alignment_identity(ptr, !isLikeNone(value), isLikeNone(value) ? 0 : value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get min_significant_length_group() {
+        const ret = wasm.comparisonoptions_min_significant_length_group(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get composition_adjusted_identity() {
+        const ret = wasm.comparisonoptions_composition_adjusted_identity(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Conserved-block window in amino acids, for protein comparisons
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_aa_segment_window_length(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_aa_segment_window_length(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_min_sequence_overlap_pct(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_min_sequence_overlap_pct(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Translate IUPAC-ambiguous codons to the amino acid (or stop) they all encode, B/Z/J
+     * for D/N, E/Q, and I/L, or X, instead of always X; gap codons become '-'
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_resolve_ambiguous_codons(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_resolve_ambiguous_codons(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @param {number} value
+     * @returns {ComparisonOptions}
+     */
+    with_min_significant_length_group(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_min_significant_length_group(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * Add `adjustedIdentity` to results: the identity over the aligned columns outside
+     * homopolymer runs and low-complexity (DUST) stretches of either sequence, which poly-A
+     * tracts and repeats cannot inflate
+     * @param {boolean} value
+     * @returns {ComparisonOptions}
+     */
+    with_composition_adjusted_identity(value) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_composition_adjusted_identity(ptr, value);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * `[start1, end1, start2, end2]` as given to `with_cds`
+     * @returns {Uint32Array | undefined}
+     */
+    get cds() {
+        const ret = wasm.comparisonoptions_cds(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+            wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        }
+        return v1;
+    }
+    constructor() {
+        const ret = wasm.comparisonoptions_new();
+        this.__wbg_ptr = ret >>> 0;
+        ComparisonOptionsFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get kozak() {
+        const ret = wasm.comparisonoptions_kozak(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get frame1() {
+        const ret = wasm.comparisonoptions_frame1(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get frame2() {
+        const ret = wasm.comparisonoptions_frame2(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * Options for a named scenario: "strict" (alias "isoforms"), "default", "permissive"
+     * ("sanger"), or "cross-species" ("orthologs"); unknown names give the defaults
+     * @param {string} name
+     * @returns {ComparisonOptions}
+     */
+    static preset(name) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.comparisonoptions_preset(ptr0, len0);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get seeded() {
+        const ret = wasm.comparisonoptions_seeded(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get u_to_t() {
+        const ret = wasm.comparisonoptions_u_to_t(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get genbank() {
+        const ret = wasm.comparisonoptions_genbank(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get primers() {
+        const ret = wasm.comparisonoptions_primers(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Compare the translations of these coding regions (e.g. GenBank CDS features, in the
+     * options' coordinate system) instead of searching frames over the nucleotide placement;
+     * takes precedence over pinned frames
+     * @param {number} start1
+     * @param {number} end1
+     * @param {number} start2
+     * @param {number} end2
+     * @returns {ComparisonOptions}
+     */
+    with_cds(start1, end1, start2, end2) {
+        const ptr = this.__destroy_into_raw();
+        const ret = wasm.comparisonoptions_with_cds(ptr, start1, end1, start2, end2);
+        return ComparisonOptions.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get gap_score() {
+        const ret = wasm.comparisonoptions_gap_score(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get one_based() {
+        const ret = wasm.comparisonoptions_one_based(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get uppercase() {
+        const ret = wasm.comparisonoptions_uppercase(this.__wbg_ptr);
+        return ret !== 0;
     }
 }
-exports.compare_sequences_full = compare_sequences_full;
+if (Symbol.dispose) ComparisonOptions.prototype[Symbol.dispose] = ComparisonOptions.prototype.free;
+exports.ComparisonOptions = ComparisonOptions;
+
+/**
+ * A conserved block placed on both sequences (0-based, end exclusive, unless the options
+ * ask for 1-based coordinates)
+ */
+class ConservedBlock {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(ConservedBlock.prototype);
+        obj.__wbg_ptr = ptr;
+        ConservedBlockFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ConservedBlockFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_conservedblock_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get end1() {
+        const ret = wasm.conservedblock_end1(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get end2() {
+        const ret = wasm.conservedblock_end2(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get length() {
+        const ret = wasm.conservedblock_length(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get start1() {
+        const ret = wasm.conservedblock_start1(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get start2() {
+        const ret = wasm.conservedblock_start2(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Fraction of the block's columns that match
+     * @returns {number}
+     */
+    get identity() {
+        const ret = wasm.conservedblock_identity(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * The block's mask: matching bases, '?' at mismatches
+     * @returns {string}
+     */
+    get sequence() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.conservedblock_sequence(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+if (Symbol.dispose) ConservedBlock.prototype[Symbol.dispose] = ConservedBlock.prototype.free;
+exports.ConservedBlock = ConservedBlock;
+
+/**
+ * Sequence storage owned by WASM memory. Fill it through `view()` and pass the
+ * buffer to `compare_sequence_buffers`, or reuse it across comparisons.
+ */
+class SequenceBuffer {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SequenceBufferFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_sequencebuffer_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    len() {
+        const ret = wasm.sequencebuffer_len(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {number} len
+     */
+    constructor(len) {
+        const ret = wasm.sequencebuffer_new(len);
+        this.__wbg_ptr = ret >>> 0;
+        SequenceBufferFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * A Uint8Array aliasing the buffer. The view is invalidated when WASM memory grows,
+     * so write into it immediately instead of holding on to it.
+     * @returns {Uint8Array}
+     */
+    view() {
+        const ret = wasm.sequencebuffer_view(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_empty() {
+        const ret = wasm.sequencebuffer_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) SequenceBuffer.prototype[Symbol.dispose] = SequenceBuffer.prototype.free;
+exports.SequenceBuffer = SequenceBuffer;
+
+class SequenceIndex {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SequenceIndexFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_sequenceindex_free(ptr, 0);
+    }
+    /**
+     * The suffix array itself: suffix start positions in sorted order
+     * @returns {Uint32Array}
+     */
+    suffix_array() {
+        const ret = wasm.sequenceindex_suffix_array(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Longest prefix of `query` that occurs in the sequence: `{"length", "position"}` with
+     * the first position it occurs at (null when the length is 0), e.g. for anchoring a read
+     * @param {string} query
+     * @returns {string}
+     */
+    longest_match(query) {
+        let deferred2_0;
+        let deferred2_1;
+        try {
+            const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            const ret = wasm.sequenceindex_longest_match(this.__wbg_ptr, ptr0, len0);
+            deferred2_0 = ret[0];
+            deferred2_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+        }
+    }
+    /**
+     * Index `seq` (case-insensitive); an error when the construction would exceed the
+     * memory limit
+     * @param {string} seq
+     */
+    constructor(seq) {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.sequenceindex_new(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        this.__wbg_ptr = ret[0] >>> 0;
+        SequenceIndexFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Start of every occurrence of `fragment`, ascending
+     * @param {string} fragment
+     * @returns {Uint32Array}
+     */
+    find(fragment) {
+        const ptr0 = passStringToWasm0(fragment, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.sequenceindex_find(this.__wbg_ptr, ptr0, len0);
+        var v2 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v2;
+    }
+    /**
+     * Occurrences of `fragment`, overlapping ones included
+     * @param {string} fragment
+     * @returns {number}
+     */
+    count(fragment) {
+        const ptr0 = passStringToWasm0(fragment, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.sequenceindex_count(this.__wbg_ptr, ptr0, len0);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get length() {
+        const ret = wasm.sequenceindex_length(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {string} fragment
+     * @returns {boolean}
+     */
+    contains(fragment) {
+        const ptr0 = passStringToWasm0(fragment, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.sequenceindex_contains(this.__wbg_ptr, ptr0, len0);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) SequenceIndex.prototype[Symbol.dispose] = SequenceIndex.prototype.free;
+exports.SequenceIndex = SequenceIndex;
+
+/**
+ * The conserved blocks of a nucleotide or protein comparison result as FASTA records of
+ * sequence `which_seq` (1 or 2), `>block<n> seq<k>:<start>-<end> length=<n> identity=<x>`
+ * in the result's coordinate system, residues in lines of 60 without gaps. Protein results
+ * use their translations; for nucleotide results, `sequence` is that sequence as compared
+ * (after any normalization), or empty to fill mismatches with N (unless the result's
+ * `maskSymbols` replace matching bases with a symbol). Unreadable results, a `which_seq`
+ * other than 1 or 2, or a `sequence` that doesn't cover the aligned region or is missing
+ * give `{"error": ...}`
+ * @param {string} result
+ * @param {number} which_seq
+ * @param {string} sequence
+ * @returns {string}
+ */
+function blocks_to_fasta(result, which_seq, sequence) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(sequence, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.blocks_to_fasta(ptr0, len0, which_seq, ptr1, len1);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.blocks_to_fasta = blocks_to_fasta;
+
+/**
+ * Build a tree from a row-major n×n distance matrix (e.g. `distance` from `pairwise_matrix`)
+ * and return it in Newick format. `method` is "nj" (default) or "upgma"; missing labels
+ * default to seq1, seq2, ...
+ * @param {Float64Array} distances
+ * @param {string[]} labels
+ * @param {string} method
+ * @returns {string}
+ */
+function build_tree(distances, labels, method) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passArrayF64ToWasm0(distances, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArrayJsValueToWasm0(labels, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(method, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ret = wasm.build_tree(ptr0, len0, ptr1, len1, ptr2, len2);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.build_tree = build_tree;
+
+/**
+ * Outcome of a genome edit from the `reference` amplicon, the `edited` amplicon, and the
+ * `expected` amplicon after perfect HDR (empty when there is no donor template), all
+ * spanning the same primers: `outcome` is "unedited", "perfectHdr" (exactly the intended
+ * edits), "partialHdr" (some of them, or all with other changes), "nhejIndel", or
+ * "substitution". Differences are modelled as substitutions and at most one indel, given as
+ * `indel` (`type`, reference `position`, `size`, `inserted` bases, `frameshift`) and
+ * `substitutions` (each marked `intended` or not), with how many of the `intendedEdits` are
+ * present. With a `cut_site` (in seq1 coordinates of the options), unintended differences
+ * count only within `window` bases of it; the rest are tallied in `outsideWindow`
+ * @param {string} reference
+ * @param {string} edited
+ * @param {string} expected
+ * @param {number | null | undefined} cut_site
+ * @param {number} window
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function classify_edited_allele(reference, edited, expected, cut_site, window, options) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(reference, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(edited, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(expected, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.classify_edited_allele(ptr0, len0, ptr1, len1, ptr2, len2, isLikeNone(cut_site) ? 0x100000001 : (cut_site) >>> 0, window, options.__wbg_ptr);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.classify_edited_allele = classify_edited_allele;
+
+/**
+ * Drop every cached comparison result (e.g. to release memory after a large comparison)
+ */
+function clear_result_cache() {
+    wasm.clear_result_cache();
+}
+exports.clear_result_cache = clear_result_cache;
+
+/**
+ * Comparison of a codon-optimized CDS with the natural one it recodes. Both are translated
+ * with the options' codon rules (over `with_cds` when set, else from the pinned frame or the
+ * first base) and the proteins placed against each other; `proteinIdentity` and
+ * `proteinIdentical` (same length, placed end to end, every residue equal) confirm the
+ * recoding kept the protein. `codonDifferences` lists each aligned codon pair that differs:
+ * its residue `position1`/`position2`, nucleotide `ntPosition1`/`ntPosition2`, both codons
+ * and amino acids, whether the change is `synonymous`, and the `changedBases` (1-3) within
+ * the codon. `codonUsage` gives the shift: changed, synonymous, and nonsynonymous codon
+ * counts, nucleotide identity over the aligned codons, GC and GC3 of each CDS, the codon
+ * counts of each, their `usageDistance` (0 to 1), and the `swaps` from one codon to another
+ * by how often they occur
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_codon_optimized(seq1, seq2, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_codon_optimized(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_codon_optimized = compare_codon_optimized;
+
+/**
+ * Compare exon N of seq1 against exon N of seq2 for every annotated exon.
+ * Each pair is aligned independently with the same offset search as the full comparison;
+ * exons present in only one transcript are reported with null statistics. An exon pair
+ * whose offset search would exceed the memory limit gives the memory-limit error.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {Uint32Array} exons1
+ * @param {Uint32Array} exons2
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_exons(seq1, seq2, exons1, exons2, min_sequence_overlap_pct) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passArray32ToWasm0(exons1, wasm.__wbindgen_malloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ptr3 = passArray32ToWasm0(exons2, wasm.__wbindgen_malloc);
+        const len3 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_exons(ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, min_sequence_overlap_pct);
+        deferred5_0 = ret[0];
+        deferred5_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred5_0, deferred5_1, 1);
+    }
+}
+exports.compare_exons = compare_exons;
+
+/**
+ * Compare the k-mer spectra of two sequences: Jaccard index and containment over distinct
+ * k-mers, and cosine similarity/distance over the count vectors.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} k
+ * @param {boolean} canonical
+ * @returns {string}
+ */
+function compare_kmer_spectra(seq1, seq2, k, canonical) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_kmer_spectra(ptr0, len0, ptr1, len1, k, canonical);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_kmer_spectra = compare_kmer_spectra;
+
+/**
+ * Compare three or more sequences at once. Returns the gap-padded rows, per-column
+ * conservation (fraction of sequences carrying the majority base), Shannon entropy and
+ * information content in bits, residue frequencies for logo rendering, a majority
+ * consensus, and conserved blocks over the all-sequences-agree mask.
+ * @param {string[]} seqs
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_multiple(seqs, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passArrayJsValueToWasm0(seqs, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_multiple(ptr0, len0, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.compare_multiple = compare_multiple;
+
+/**
+ * Compare one query against many targets in a single call.
+ * `targets_json` is a JSON array of sequences or `{ "id", "sequence" }` objects. Returns an
+ * array of `{ index, id, result }` sorted by identity (highest first, input order on ties),
+ * where `result` has the same shape as `compare_sequences_full`. `targets_json` that does not
+ * read as such an array gives `{"error": ...}`.
+ * @param {string} query
+ * @param {string} targets_json
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_one_vs_many(query, targets_json, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(targets_json, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_one_vs_many(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_one_vs_many = compare_one_vs_many;
+
+/**
+ * `compare_one_vs_many` configured by a `ComparisonOptions` object: each `result` has the
+ * shape of `compare_sequences_with_options`, and targets are ranked by the identity of
+ * their placement
+ * @param {string} query
+ * @param {string} targets_json
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_one_vs_many_with_options(query, targets_json, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(targets_json, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_one_vs_many_with_options(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_one_vs_many_with_options = compare_one_vs_many_with_options;
+
+/**
+ * `query` and each CDS of the multi-FASTA `orthologs` translated with the options' codon
+ * rules (from the first base, a final stop dropped) and compared as proteins. `orthologs`
+ * ranks the panel by identity to the query (then coverage of the query, then input
+ * order): each with its `id`, `description`, `proteinLength`, `identity`, `coverage`,
+ * aligned `length`, `mismatches`, and `offset1`/`offset2` of the best placement.
+ * `identities` is the pairwise protein identity matrix in input order with the query
+ * first, and `sharedBlocks` the conserved blocks (the options' `aa_segment_window_length`
+ * and thresholds) where every protein of the panel agrees, in query residues
+ * @param {string} query
+ * @param {string} orthologs
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_ortholog_panel(query, orthologs, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(orthologs, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_ortholog_panel(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_ortholog_panel = compare_ortholog_panel;
+
+/**
+ * Comparison of a protein with an mRNA translated in its three forward frames (with the
+ * options' codon rules), reporting the frame and mRNA region that encode the protein as
+ * `encodingRegion`; for checking CDS annotations. The protein is normalized without the
+ * U-to-T and poly-A steps
+ * @param {string} protein
+ * @param {string} mrna
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_protein_to_mrna(protein, mrna, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(protein, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(mrna, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_protein_to_mrna(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_protein_to_mrna = compare_protein_to_mrna;
+
+/**
+ * `compare_proteins_full` returning the result object. Throws an Error named
+ * EmptySequence, InvalidCharacters, FrameSearchFailed when no frame combination
+ * yields a protein alignment of at least one window, or MalformedResult.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} nuc_offset1
+ * @param {number} nuc_offset2
+ * @param {number} nuc_length
+ * @param {number} aa_segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @returns {ProteinResult}
+ */
+function compare_proteins(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group) {
+    const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    const ret = wasm.compare_proteins(ptr0, len0, ptr1, len1, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group);
+    if (ret[2]) {
+        throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+}
+exports.compare_proteins = compare_proteins;
+
+/**
+ * `compare_proteins_full` over byte inputs
+ * @param {Uint8Array} seq1
+ * @param {Uint8Array} seq2
+ * @param {number} nuc_offset1
+ * @param {number} nuc_offset2
+ * @param {number} nuc_length
+ * @param {number} aa_segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @returns {string}
+ */
+function compare_proteins_bytes(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passArray8ToWasm0(seq1, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(seq2, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_proteins_bytes(ptr0, len0, ptr1, len1, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_proteins_bytes = compare_proteins_bytes;
+
+/**
+ * Protein comparison of amino-acid sequences as given (protein FASTA such as NP_/XP_
+ * records): the best ungapped placement, mask, conserved blocks (`aa_segment_window_length`),
+ * and scores, in the `compare_proteins_with_options` result shape with `frame1`, `frame2`,
+ * `mismatchCodons`, and `frameDetection` null. Normalization skips the U-to-T and poly-A
+ * steps, which would change residues
+ * @param {string} aa1
+ * @param {string} aa2
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_proteins_direct(aa1, aa2, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(aa1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(aa2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_proteins_direct(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_proteins_direct = compare_proteins_direct;
+
+/**
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} nuc_offset1
+ * @param {number} nuc_offset2
+ * @param {number} nuc_length
+ * @param {number} aa_segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @returns {string}
+ */
+function compare_proteins_full(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_proteins_full(ptr0, len0, ptr1, len1, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_proteins_full = compare_proteins_full;
+
+/**
+ * `compare_proteins_full` configured by a `ComparisonOptions` object (the amino-acid
+ * window comes from `aa_segment_window_length`); the nucleotide offsets and length refer
+ * to the normalized sequences and count in the options' coordinate system, as in the
+ * `compare_sequences_with_options` result. Pinned frames or a CDS replace the frame search
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} nuc_offset1
+ * @param {number} nuc_offset2
+ * @param {number} nuc_length
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_proteins_with_options(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_proteins_with_options(ptr0, len0, ptr1, len1, nuc_offset1, nuc_offset2, nuc_length, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_proteins_with_options = compare_proteins_with_options;
+
+/**
+ * `compare_sequences_full` over two sequences already resident in WASM memory
+ * @param {SequenceBuffer} seq1
+ * @param {SequenceBuffer} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_sequence_buffers(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+        _assertClass(seq1, SequenceBuffer);
+        _assertClass(seq2, SequenceBuffer);
+        const ret = wasm.compare_sequence_buffers(seq1.__wbg_ptr, seq2.__wbg_ptr, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred1_0 = ret[0];
+        deferred1_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+}
+exports.compare_sequence_buffers = compare_sequence_buffers;
+
+/**
+ * Nucleotide comparison with statistics reported separately for 5'UTR, CDS, and 3'UTR.
+ * `cds_start`/`cds_end` are 0-based half-open positions in seq1; when `cds_start` is omitted
+ * the CDS is inferred (first ATG to first in-frame stop), and when only `cds_end` is omitted
+ * it runs from `cds_start` to the first in-frame stop.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number | null | undefined} cds_start
+ * @param {number | null | undefined} cds_end
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_sequence_regions(seq1, seq2, cds_start, cds_end, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_sequence_regions(ptr0, len0, ptr1, len1, isLikeNone(cds_start) ? 0x100000001 : (cds_start) >>> 0, isLikeNone(cds_end) ? 0x100000001 : (cds_end) >>> 0, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequence_regions = compare_sequence_regions;
+
+/**
+ * `compare_sequence_regions` configured by a `ComparisonOptions` object: its thresholds,
+ * normalization, start codons, and Kozak setting (for the inferred CDS). Positions are 0-based half-open
+ * in the normalized seq1
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number | null | undefined} cds_start
+ * @param {number | null | undefined} cds_end
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_sequence_regions_with_options(seq1, seq2, cds_start, cds_end, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_sequence_regions_with_options(ptr0, len0, ptr1, len1, isLikeNone(cds_start) ? 0x100000001 : (cds_start) >>> 0, isLikeNone(cds_end) ? 0x100000001 : (cds_end) >>> 0, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequence_regions_with_options = compare_sequence_regions_with_options;
+
+/**
+ * `compare_sequences_full` returning the result object. Throws an Error named
+ * EmptySequence, InvalidCharacters (with `sequence`, `positions`, `count`),
+ * MemoryLimitExceeded (with `requiredBytes`, `limitBytes`), NoAlignmentFound, or
+ * MalformedResult when the result does not read as a `NucleotideResult`.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {NucleotideResult}
+ */
+function compare_sequences(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    const ret = wasm.compare_sequences(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+    if (ret[2]) {
+        throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+}
+exports.compare_sequences = compare_sequences;
+
+/**
+ * Same result as `compare_sequences_full`, returned as a Promise that resolves to the JSON
+ * string. The offset search yields to the event loop roughly every 16 ms, so long
+ * comparisons no longer freeze the page and no Worker is needed. `progress` is called
+ * as in `compare_sequences_with_progress`; the Promise rejects with "Comparison aborted"
+ * once `signal` (see `AbortHandle`) is aborted.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @param {Function | null | undefined} progress
+ * @param {any} signal
+ * @returns {Promise<any>}
+ */
+function compare_sequences_async(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, progress, signal) {
+    const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    const ret = wasm.compare_sequences_async(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, isLikeNone(progress) ? 0 : addToExternrefTable0(progress), signal);
+    return ret;
+}
+exports.compare_sequences_async = compare_sequences_async;
+
+/**
+ * `compare_sequences_full` over byte inputs (e.g. a Uint8Array of ASCII bases)
+ * @param {Uint8Array} seq1
+ * @param {Uint8Array} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_sequences_bytes(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passArray8ToWasm0(seq1, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(seq2, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_sequences_bytes(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequences_bytes = compare_sequences_bytes;
+
+/**
+ * `compare_sequences_with_options` with the JSON compressed in Rust as the options'
+ * `with_compression` format says (gzip by default), for results too large to pass around
+ * as strings: decode with `new Response(new Blob([bytes]).stream().pipeThrough(new
+ * DecompressionStream("gzip"))).json()`
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {ComparisonOptions} options
+ * @returns {Uint8Array}
+ */
+function compare_sequences_compressed(seq1, seq2, options) {
+    const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    _assertClass(options, ComparisonOptions);
+    const ret = wasm.compare_sequences_compressed(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+    var v3 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    return v3;
+}
+exports.compare_sequences_compressed = compare_sequences_compressed;
+
+/**
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @returns {string}
+ */
+function compare_sequences_full(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_sequences_full(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequences_full = compare_sequences_full;
+
+/**
+ * Same as `compare_sequences_full`, but the offset search stops at the first placement whose
+ * identity reaches `good_enough_identity` (e.g. 0.95) instead of scanning every offset, so
+ * obviously matching sequences finish much sooner; the placement may not be the optimum
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @param {number} good_enough_identity
+ * @returns {string}
+ */
+function compare_sequences_good_enough(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, good_enough_identity) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_sequences_good_enough(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, good_enough_identity);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequences_good_enough = compare_sequences_good_enough;
+
+/**
+ * `compare_sequences_full` configured by a `ComparisonOptions` object. With normalization
+ * enabled, offsets refer to the normalized sequences and the result has a `normalization`
+ * report per sequence. When the best placement falls short of `min_alignment_identity`,
+ * the result is `{"noSignificantAlignment": true, "minAlignmentIdentity", "identity",
+ * "length", "bestAttempt"}` with the usual result as `bestAttempt`.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function compare_sequences_with_options(seq1, seq2, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.compare_sequences_with_options(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.compare_sequences_with_options = compare_sequences_with_options;
+
+/**
+ * Same result as `compare_sequences_full`, calling `progress(percent, phase)` as the
+ * comparison advances. The call blocks, so in the browser run it in a Worker and
+ * forward the reports with `postMessage`; on the main thread use
+ * `compare_sequences_async`, which accepts the same callback. Throws "Comparison aborted"
+ * if `signal` (see `AbortHandle`) is aborted, e.g. from within the callback.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} segment_window_length
+ * @param {number} min_identity
+ * @param {number} min_significant_length_group
+ * @param {number} min_sequence_overlap_pct
+ * @param {Function | null | undefined} progress
+ * @param {any} signal
+ * @returns {string}
+ */
+function compare_sequences_with_progress(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, progress, signal) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.compare_sequences_with_progress(ptr0, len0, ptr1, len1, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct, isLikeNone(progress) ? 0 : addToExternrefTable0(progress), signal);
+        var ptr3 = ret[0];
+        var len3 = ret[1];
+        if (ret[3]) {
+            ptr3 = 0; len3 = 0;
+            throw takeFromExternrefTable0(ret[2]);
+        }
+        deferred4_0 = ptr3;
+        deferred4_1 = len3;
+        return getStringFromWasm0(ptr3, len3);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.compare_sequences_with_progress = compare_sequences_with_progress;
+
+/**
+ * Consensus of two overlapping reads (e.g. two clones of the same insert).
+ * The pair is placed with the standard offset search; columns covered by only one
+ * read take that read's base, so the consensus spans the union of both reads.
+ * `policy` is "iupac" (default) or "majority". An error when the offset search would
+ * exceed the memory limit.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {string} policy
+ * @returns {string}
+ */
+function consensus(seq1, seq2, policy) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(policy, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ret = wasm.consensus(ptr0, len0, ptr1, len1, ptr2, len2);
+        var ptr4 = ret[0];
+        var len4 = ret[1];
+        if (ret[3]) {
+            ptr4 = 0; len4 = 0;
+            throw takeFromExternrefTable0(ret[2]);
+        }
+        deferred5_0 = ptr4;
+        deferred5_1 = len4;
+        return getStringFromWasm0(ptr4, len4);
+    } finally {
+        wasm.__wbindgen_free(deferred5_0, deferred5_1, 1);
+    }
+}
+exports.consensus = consensus;
+
+/**
+ * Conserved blocks of the best placement of seq2 against seq1, as `ConservedBlock` objects
+ * (positions in the sequences after the options' normalization), found with the options'
+ * block detection; an error when the placement would exceed the memory limit
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {ComparisonOptions} options
+ * @returns {ConservedBlock[]}
+ */
+function conserved_blocks(seq1, seq2, options) {
+    const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    _assertClass(options, ComparisonOptions);
+    const ret = wasm.conserved_blocks(ptr0, len0, ptr1, len1, options.__wbg_ptr);
+    if (ret[3]) {
+        throw takeFromExternrefTable0(ret[2]);
+    }
+    var v3 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v3;
+}
+exports.conserved_blocks = conserved_blocks;
+
+/**
+ * Sites of a CRISPR guide in two alleles and what their differences do to them. `guide` is
+ * the spacer (RNA or DNA) and `pam` the IUPAC PAM that must follow the protospacer (NGG
+ * when empty); `sites1` and `sites2` list each protospacer and PAM match with at most
+ * `max_mismatches` mismatches, all in the protospacer: `strand`, `start`, `end`,
+ * `pamStart`, the `cutSite` 3 bases upstream of the PAM, `mismatches`, and the matched
+ * bases. Over the best placement of the two, `pamChanges` lists PAMs on either strand that
+ * the differences create or destroy, and `siteChanges` the guide sites whose mismatches
+ * differ between the alleles (null where one has no site). An invalid guide or PAM gives
+ * `{"error": ...}`
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {string} guide
+ * @param {string} pam
+ * @param {number} max_mismatches
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function crispr_guide_sites(seq1, seq2, guide, pam, max_mismatches, options) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(guide, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ptr3 = passStringToWasm0(pam, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len3 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.crispr_guide_sites(ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, max_mismatches, options.__wbg_ptr);
+        deferred5_0 = ret[0];
+        deferred5_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred5_0, deferred5_1, 1);
+    }
+}
+exports.crispr_guide_sites = crispr_guide_sites;
+
+/**
+ * Detect inverted and transposed segments between two sequences from exact anchors of
+ * `anchor_length` bases (≤ 32) found on both strands. Segments shorter than
+ * `min_segment_length` are dropped; non-co-linear ones are listed in `structuralDifferences`.
+ * Reverse-strand segments give seq2 coordinates on the forward strand.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} anchor_length
+ * @param {number} min_segment_length
+ * @returns {string}
+ */
+function detect_rearrangements(seq1, seq2, anchor_length, min_segment_length) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.detect_rearrangements(ptr0, len0, ptr1, len1, anchor_length, min_segment_length);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.detect_rearrangements = detect_rearrangements;
+
+/**
+ * Column-by-column diff of `a` and `b` from their first characters, with no offset search,
+ * blocks, or scoring: for short oligos where the full pipeline would cost more than the
+ * comparison. `symbols` styles the mask as up to three characters, the mismatch, match, and
+ * gap symbols ('?', the residue itself, and '-' when left out). Returns the compared
+ * `length` (the shorter input), `mismatches`, `identity`, the `mask`, and the 0-based
+ * mismatch `positions`
+ * @param {string} a
+ * @param {string} b
+ * @param {string} symbols
+ * @returns {string}
+ */
+function diff_strings(a, b, symbols) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(a, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(b, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(symbols, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ret = wasm.diff_strings(ptr0, len0, ptr1, len1, ptr2, len2);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.diff_strings = diff_strings;
+
+/**
+ * Restriction sites present in one sequence but not at the same place in the other, over the
+ * best placement of seq2 against seq1, with the mismatching positions (in seq1) that make the
+ * difference: candidate digests for genotyping the two alleles
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {string} enzymes
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function differential_restriction_sites(seq1, seq2, enzymes, options) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(enzymes, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.differential_restriction_sites(ptr0, len0, ptr1, len1, ptr2, len2, options.__wbg_ptr);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.differential_restriction_sites = differential_restriction_sites;
+
+/**
+ * Every position where `needle` occurs in `haystack` with at most `max_mismatches`
+ * substitutions (Hamming distance, case-insensitive, forward strand only), found by
+ * bit-parallel shift-and: 0-based `position` and `mismatches` of each hit, by position. For
+ * short signature motifs; `find_pattern` covers IUPAC codes and both strands
+ * @param {string} needle
+ * @param {string} haystack
+ * @param {number} max_mismatches
+ * @returns {string}
+ */
+function find_approximate_matches(needle, haystack, max_mismatches) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(needle, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(haystack, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.find_approximate_matches(ptr0, len0, ptr1, len1, max_mismatches);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.find_approximate_matches = find_approximate_matches;
+
+/**
+ * Hits of an IUPAC `pattern` in `seq` on either strand with up to `max_mismatches`
+ * mismatches: 0-based top-strand `position`, `strand`, `mismatches`, and the matched bases
+ * @param {string} seq
+ * @param {string} pattern
+ * @param {number} max_mismatches
+ * @returns {string}
+ */
+function find_pattern(seq, pattern, max_mismatches) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(pattern, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.find_pattern(ptr0, len0, ptr1, len1, max_mismatches);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.find_pattern = find_pattern;
+
+/**
+ * `find_pattern` in both sequences, with each one's fewest mismatches (null without a hit)
+ * and whether they differ, i.e. whether the probe binds one sequence better than the other
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {string} pattern
+ * @param {number} max_mismatches
+ * @returns {string}
+ */
+function find_pattern_in_both(seq1, seq2, pattern, max_mismatches) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(pattern, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ret = wasm.find_pattern_in_both(ptr0, len0, ptr1, len1, ptr2, len2, max_mismatches);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.find_pattern_in_both = find_pattern_in_both;
+
+/**
+ * Window GC content of each sequence and window identity along the best placement of seq2
+ * against seq1, as parallel arrays with the windows' starts in seq1. `window` of 0 uses the
+ * options' segment window length and `step` of 0 gives non-overlapping windows; an overlap
+ * shorter than one window is a single window.
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {number} window
+ * @param {number} step
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function gc_identity_profile(seq1, seq2, window, step, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.gc_identity_profile(ptr0, len0, ptr1, len1, window, step, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.gc_identity_profile = gc_identity_profile;
+
+/**
+ * Current scratch-memory cap in bytes (0 means unlimited)
+ * @returns {number}
+ */
+function get_memory_limit() {
+    const ret = wasm.get_memory_limit();
+    return ret >>> 0;
+}
+exports.get_memory_limit = get_memory_limit;
+
+/**
+ * k-mer count vector of a sequence as a JSON object (`{"ACG": 3, ...}`), keys sorted.
+ * With `canonical`, each k-mer is merged with its reverse complement.
+ * @param {string} seq
+ * @param {number} k
+ * @param {boolean} canonical
+ * @returns {string}
+ */
+function kmer_counts(seq, k, canonical) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.kmer_counts(ptr0, len0, k, canonical);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.kmer_counts = kmer_counts;
+
+/**
+ * Position in the other sequence aligned with `pos` of sequence `seq` (1 or 2) of a
+ * nucleotide or protein comparison result; both positions count in the result's coordinate
+ * system. Undefined outside the aligned region, where the other sequence has a gap, or for
+ * an unreadable result
+ * @param {string} result
+ * @param {number} seq
+ * @param {number} pos
+ * @returns {number | undefined}
+ */
+function map_position(result, seq, pos) {
+    const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.map_position(ptr0, len0, seq, pos);
+    return ret === 0x100000001 ? undefined : ret;
+}
+exports.map_position = map_position;
+
+/**
+ * miRNA seed sites (8mer, 7mer-m8, 7mer-A1, 6mer) of `seeds` in two 3'UTRs, and the sites the
+ * differences over their best placement create, destroy, or change in type. `seeds` lists
+ * mature miRNAs or seeds (nucleotides 2-8) as `name=SEQUENCE` or bare sequences; invalid
+ * entries come back in `invalid`. Pass the UTRs themselves, e.g. the 3'UTR region of
+ * `compare_sequence_regions`
+ * @param {string} utr1
+ * @param {string} utr2
+ * @param {string} seeds
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function mirna_seed_sites(utr1, utr2, seeds, options) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(utr1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(utr2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(seeds, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.mirna_seed_sites(ptr0, len0, ptr1, len1, ptr2, len2, options.__wbg_ptr);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.mirna_seed_sites = mirna_seed_sites;
+
+/**
+ * Nearest-neighbor Tm (°C, null unless the oligo is all A/C/G/T), GC fraction, and self-dimer,
+ * 3' self-dimer, and hairpin stem lengths of `seq` at `salt` mM Na+ and `primer_conc` nM
+ * strand concentration (0 for the defaults, 50 mM and 50 nM). `warnings` names the checks
+ * the primer candidates would fail
+ * @param {string} seq
+ * @param {number} salt
+ * @param {number} primer_conc
+ * @returns {string}
+ */
+function oligo_properties(seq, salt, primer_conc) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.oligo_properties(ptr0, len0, salt, primer_conc);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.oligo_properties = oligo_properties;
+
+/**
+ * Identity and p-distance (1 - identity) for every pair of sequences.
+ * `seeded` swaps the exhaustive offset scan for the k-mer seeded search, which is much
+ * faster on long inputs but may miss the optimal offset for highly divergent pairs.
+ * @param {string[]} seqs
+ * @param {number} min_sequence_overlap_pct
+ * @param {boolean} seeded
+ * @returns {string}
+ */
+function pairwise_matrix(seqs, min_sequence_overlap_pct, seeded) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passArrayJsValueToWasm0(seqs, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.pairwise_matrix(ptr0, len0, min_sequence_overlap_pct, seeded);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.pairwise_matrix = pairwise_matrix;
+
+/**
+ * Extract exon coordinates from GFF3 `exon` rows or GenBank `exon` feature lines.
+ * Returns flat 0-based half-open [start, end, ...] pairs in file order.
+ * @param {string} text
+ * @returns {Uint32Array}
+ */
+function parse_exon_annotations(text) {
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.parse_exon_annotations(ptr0, len0);
+    var v2 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v2;
+}
+exports.parse_exon_annotations = parse_exon_annotations;
+
+/**
+ * The part of a nucleotide or protein comparison result over `start..end` of sequence `seq`
+ * (1 or 2), in the result's coordinate system: `offset1`, `offset2`, and `length` of the
+ * aligned columns inside the range (offsets null when there are none), their `mask` (with
+ * the result's `maskSymbols` when it restyles the mask), `mismatches` with each one's `position1` and `position2` in `mismatchPositions`, the
+ * `conservedBlocks` cut to the range (in mask columns, as in the result), and the result's
+ * `mismatchVariants` or `mismatchCodons` inside it. Unreadable results or a `seq` other than
+ * 1 or 2 give `{"error": ...}`
+ * @param {string} result
+ * @param {number} seq
+ * @param {number} start
+ * @param {number} end
+ * @returns {string}
+ */
+function query_region(result, seq, start, end) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.query_region(ptr0, len0, seq, start, end);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.query_region = query_region;
+
+/**
+ * Consensus of a forward read and a reverse read of the same template, in the forward
+ * read's orientation; `forward_quals` / `reverse_quals` are the reads' Phred scores as
+ * called (empty when there are none). Columns only one read covers take its base. Gives
+ * the `consensus`, a quality per base (`qualities`: reads agreeing add up, capped at 60;
+ * a resolved conflict keeps the difference; an ambiguous one is 0), where the reverse read
+ * starts in it (`reverseStart`, negative when it starts first), the `overlap` length, and
+ * the `conflicts` (0-based consensus `position`, both calls and qualities, and whether the
+ * `forward` or `reverse` call was taken or the column left `ambiguous`)
+ * @param {string} forward
+ * @param {Uint8Array} forward_quals
+ * @param {string} reverse
+ * @param {Uint8Array} reverse_quals
+ * @returns {string}
+ */
+function read_pair_consensus(forward, forward_quals, reverse, reverse_quals) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const ptr0 = passStringToWasm0(forward, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(forward_quals, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(reverse, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ptr3 = passArray8ToWasm0(reverse_quals, wasm.__wbindgen_malloc);
+        const len3 = WASM_VECTOR_LEN;
+        const ret = wasm.read_pair_consensus(ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3);
+        deferred5_0 = ret[0];
+        deferred5_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred5_0, deferred5_1, 1);
+    }
+}
+exports.read_pair_consensus = read_pair_consensus;
+
+/**
+ * Interleaved text of a comparison result's aligned region: seq1, a match line ('|' where
+ * the residues agree), and seq2, `line_width` columns at a time (60 when 0) under a ruler,
+ * with positions in the result's coordinate system. Protein results carry their
+ * translations; nucleotide results carry only the mask, so `seq1` and `seq2` must be the
+ * sequences as compared (after any normalization). They are ignored for protein results
+ * @param {string} result
+ * @param {number} line_width
+ * @param {string} seq1
+ * @param {string} seq2
+ * @returns {string}
+ */
+function render_alignment_text(result, line_width, seq1, seq2) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        const ret = wasm.render_alignment_text(ptr0, len0, line_width, ptr1, len1, ptr2, len2);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.render_alignment_text = render_alignment_text;
+
+/**
+ * A self-contained report of a comparison result (the JSON of a nucleotide, protein, or
+ * `compare_sequence_regions` comparison): summary statistics, the differences (with known
+ * variants when the result has them), conserved blocks, and per-region identity.
+ * `format` is "markdown" (default) or "html"; unreadable results give a one-line report
+ * saying so
+ * @param {string} result
+ * @param {string} format
+ * @returns {string}
+ */
+function render_report(result, format) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(format, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.render_report(ptr0, len0, ptr1, len1);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.render_report = render_report;
+
+/**
+ * Restriction sites of `enzymes` (empty for all built-in enzymes) in `seq`, 0-based, with
+ * the strand matched and the top-strand cut position
+ * @param {string} seq
+ * @param {string} enzymes
+ * @returns {string}
+ */
+function restriction_sites(seq, enzymes) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(enzymes, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.restriction_sites(ptr0, len0, ptr1, len1);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.restriction_sites = restriction_sites;
+
+/**
+ * Hits of the JASPAR or MEME `matrices` in both sequences (relative score at least
+ * `threshold`, 0 for 0.85, either strand), and the hits in the best placement's overlap that
+ * one sequence has and the other lacks: `lost` when only seq1 has it, `gained` when only seq2
+ * does, with both scores and the mismatching positions (in seq1) responsible
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {string} matrices
+ * @param {number} threshold
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function scan_motifs(seq1, seq2, matrices, threshold, options) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(matrices, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.scan_motifs(ptr0, len0, ptr1, len1, ptr2, len2, threshold, options.__wbg_ptr);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.scan_motifs = scan_motifs;
+
+/**
+ * Route diagnostic messages to `logger(message)` (e.g. `console.debug`); pass
+ * `undefined` to silence them again
+ * @param {Function | null} [logger]
+ */
+function set_logger(logger) {
+    wasm.set_logger(isLikeNone(logger) ? 0 : addToExternrefTable0(logger));
+}
+exports.set_logger = set_logger;
+
+/**
+ * Set the scratch-memory cap in bytes for a single comparison (0 removes the cap)
+ * @param {number} bytes
+ */
+function set_memory_limit(bytes) {
+    wasm.set_memory_limit(bytes);
+}
+exports.set_memory_limit = set_memory_limit;
+
+/**
+ * MinHash sketch of a nucleotide sequence: the `size` smallest distinct hashes of its
+ * canonical k-mers (k ≤ 32), sorted ascending.
+ * @param {string} seq
+ * @param {number} k
+ * @param {number} size
+ * @returns {BigUint64Array}
+ */
+function sketch(seq, k, size) {
+    const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.sketch(ptr0, len0, k, size);
+    var v2 = getArrayU64FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+    return v2;
+}
+exports.sketch = sketch;
+
+/**
+ * Estimated Jaccard index and Mash distance between two sketches built with the same `k`.
+ * Sketches are compared over the `min(len1, len2)` smallest hashes of their union.
+ * @param {BigUint64Array} sketch1
+ * @param {BigUint64Array} sketch2
+ * @param {number} k
+ * @returns {string}
+ */
+function sketch_distance(sketch1, sketch2, k) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passArray64ToWasm0(sketch1, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray64ToWasm0(sketch2, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.sketch_distance(ptr0, len0, ptr1, len1, k);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.sketch_distance = sketch_distance;
+
+/**
+ * Mismatches over the best placement of seq2 that fall on a splice site of the exons
+ * annotated on seq1 (`exons1`, flat 0-based half-open pairs as from
+ * `parse_exon_annotations`): `canonical` on a donor or acceptor dinucleotide, or
+ * `nearJunction` within `window` bases of the exon boundary. `junctions` gives each site's
+ * dinucleotide in both sequences (null where seq2 does not cover it) and whether it is canonical
+ * @param {string} seq1
+ * @param {string} seq2
+ * @param {Uint32Array} exons1
+ * @param {number} window
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function splice_site_differences(seq1, seq2, exons1, window, options) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const ptr0 = passStringToWasm0(seq1, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(seq2, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passArray32ToWasm0(exons1, wasm.__wbindgen_malloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.splice_site_differences(ptr0, len0, ptr1, len1, ptr2, len2, window, options.__wbg_ptr);
+        deferred4_0 = ret[0];
+        deferred4_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+    }
+}
+exports.splice_site_differences = splice_site_differences;
+
+/**
+ * Putative domains of a protein comparison result (from `compare_proteins_with_options`,
+ * `compare_proteins_direct`, and the like): stretches where the identity of the `window`
+ * residues around each column stays at or above `min_identity`, at least `min_length`
+ * residues long, as `domains` with their `start1`/`end1` and `start2`/`end2` in each
+ * protein (the result's coordinate system) and `identity`, and the `linkers` between
+ * consecutive domains. `window` of 0 uses the default amino-acid window and `min_length`
+ * of 0 the window; an unreadable result gives `{"error": ...}`
+ * @param {string} result
+ * @param {number} window
+ * @param {number} min_identity
+ * @param {number} min_length
+ * @returns {string}
+ */
+function suggest_domain_boundaries(result, window, min_identity, min_length) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(result, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.suggest_domain_boundaries(ptr0, len0, window, min_identity, min_length);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.suggest_domain_boundaries = suggest_domain_boundaries;
+
+/**
+ * Sliding-window quality trim of a read: kept from the first `window`-base window whose
+ * mean Phred quality (`quals`, one per base) reaches `threshold` to the end of the last one,
+ * with low-quality bases left at either end cut too. Gives the kept `start`, `end`,
+ * `length`, and `sequence`, plus the `trimmed` ranges at the 5' and 3' ends
+ * @param {string} seq
+ * @param {Uint8Array} quals
+ * @param {number} window
+ * @param {number} threshold
+ * @returns {string}
+ */
+function trim_by_quality(seq, quals, window, threshold) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(quals, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.trim_by_quality(ptr0, len0, ptr1, len1, window, threshold);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.trim_by_quality = trim_by_quality;
+
+/**
+ * Cut bases from both ends of a read while they are uncalled (N, gaps, non-letters) or,
+ * when `quals` is not empty, their Phred quality is below `threshold`; same result shape as
+ * `trim_by_quality`
+ * @param {string} seq
+ * @param {Uint8Array} quals
+ * @param {number} threshold
+ * @returns {string}
+ */
+function trim_read_ends(seq, quals, threshold) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray8ToWasm0(quals, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.trim_read_ends(ptr0, len0, ptr1, len1, threshold);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.trim_read_ends = trim_read_ends;
+
+/**
+ * Check sequence text before comparison. Returns the detected `type` (dna, rna, protein,
+ * unknown), GenBank record / FASTA header / whitespace / lowercase contamination, invalid
+ * characters with their positions, `suggestions` for normalizing the text, and the
+ * `normalized` sequence (whose length is `length`).
+ * @param {string} seq
+ * @returns {string}
+ */
+function validate_sequence(seq) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(seq, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.validate_sequence(ptr0, len0);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+exports.validate_sequence = validate_sequence;
+
+/**
+ * Occurrences of an expected `insert` in a circular `plasmid` map: every placement on
+ * either strand, including across the origin, with at most `max_mismatches` differences
+ * (IUPAC codes in the insert match any of their bases), overlapping placements on a strand
+ * reduced to the one with the fewest. Each occurrence gives its `strand`, plasmid `start`
+ * and `end` (where `wrapsOrigin`, `end` comes before `start`), `mismatches`, `identity`,
+ * and the `differences` by `insertPosition` and `plasmidPosition`, with the `expected` and
+ * `found` bases on the insert's strand. Positions follow the options' coordinate system;
+ * `verified` is whether exactly one occurrence has no differences
+ * @param {string} insert
+ * @param {string} plasmid
+ * @param {number} max_mismatches
+ * @param {ComparisonOptions} options
+ * @returns {string}
+ */
+function verify_plasmid_insert(insert, plasmid, max_mismatches, options) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const ptr0 = passStringToWasm0(insert, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(plasmid, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(options, ComparisonOptions);
+        const ret = wasm.verify_plasmid_insert(ptr0, len0, ptr1, len1, max_mismatches, options.__wbg_ptr);
+        deferred3_0 = ret[0];
+        deferred3_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+exports.verify_plasmid_insert = verify_plasmid_insert;
+
+exports.__wbg_Error_52673b7de5a0ca89 = function(arg0, arg1) {
+    const ret = Error(getStringFromWasm0(arg0, arg1));
+    return ret;
+};
+
+exports.__wbg_String_8f0eb39a4a4c2f66 = function(arg0, arg1) {
+    const ret = String(arg1);
+    const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+};
+
+exports.__wbg___wbindgen_is_falsy_7b9692021c137978 = function(arg0) {
+    const ret = !arg0;
+    return ret;
+};
+
+exports.__wbg___wbindgen_is_function_8d400b8b1af978cd = function(arg0) {
+    const ret = typeof(arg0) === 'function';
+    return ret;
+};
+
+exports.__wbg___wbindgen_is_object_ce774f3490692386 = function(arg0) {
+    const val = arg0;
+    const ret = typeof(val) === 'object' && val !== null;
+    return ret;
+};
+
+exports.__wbg___wbindgen_is_string_704ef9c8fc131030 = function(arg0) {
+    const ret = typeof(arg0) === 'string';
+    return ret;
+};
+
+exports.__wbg___wbindgen_is_undefined_f6b95eab589e0269 = function(arg0) {
+    const ret = arg0 === undefined;
+    return ret;
+};
+
+exports.__wbg___wbindgen_string_get_a2a31e16edf96e42 = function(arg0, arg1) {
+    const obj = arg1;
+    const ret = typeof(obj) === 'string' ? obj : undefined;
+    var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    var len1 = WASM_VECTOR_LEN;
+    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+};
 
 exports.__wbg___wbindgen_throw_dd24417ed36fc46e = function(arg0, arg1) {
     throw new Error(getStringFromWasm0(arg0, arg1));
 };
 
-exports.__wbg_log_1d990106d99dacb7 = function(arg0) {
-    console.log(arg0);
+exports.__wbg__wbg_cb_unref_87dfb5aaa0cbcea7 = function(arg0) {
+    arg0._wbg_cb_unref();
+};
+
+exports.__wbg_call_3020136f7a2d6e44 = function() { return handleError(function (arg0, arg1, arg2) {
+    const ret = arg0.call(arg1, arg2);
+    return ret;
+}, arguments) };
+
+exports.__wbg_call_abb4ff46ce38be40 = function() { return handleError(function (arg0, arg1) {
+    const ret = arg0.call(arg1);
+    return ret;
+}, arguments) };
+
+exports.__wbg_call_c8baa5c5e72d274e = function() { return handleError(function (arg0, arg1, arg2, arg3) {
+    const ret = arg0.call(arg1, arg2, arg3);
+    return ret;
+}, arguments) };
+
+exports.__wbg_conservedblock_new = function(arg0) {
+    const ret = ConservedBlock.__wrap(arg0);
+    return ret;
+};
+
+exports.__wbg_get_af9dab7e9603ea93 = function() { return handleError(function (arg0, arg1) {
+    const ret = Reflect.get(arg0, arg1);
+    return ret;
+}, arguments) };
+
+exports.__wbg_new_1ba21ce319a06297 = function() {
+    const ret = new Object();
+    return ret;
+};
+
+exports.__wbg_new_25f239778d6112b9 = function() {
+    const ret = new Array();
+    return ret;
+};
+
+exports.__wbg_new_b546ae120718850e = function() {
+    const ret = new Map();
+    return ret;
+};
+
+exports.__wbg_new_df1173567d5ff028 = function(arg0, arg1) {
+    const ret = new Error(getStringFromWasm0(arg0, arg1));
+    return ret;
+};
+
+exports.__wbg_new_ff12d2b041fb48f1 = function(arg0, arg1) {
+    try {
+        var state0 = {a: arg0, b: arg1};
+        var cb0 = (arg0, arg1) => {
+            const a = state0.a;
+            state0.a = 0;
+            try {
+                return wasm_bindgen__convert__closures_____invoke__hb7ba44727ea16178(a, state0.b, arg0, arg1);
+            } finally {
+                state0.a = a;
+            }
+        };
+        const ret = new Promise(cb0);
+        return ret;
+    } finally {
+        state0.a = state0.b = 0;
+    }
+};
+
+exports.__wbg_new_no_args_cb138f77cf6151ee = function(arg0, arg1) {
+    const ret = new Function(getStringFromWasm0(arg0, arg1));
+    return ret;
+};
+
+exports.__wbg_now_69d776cd24f5215b = function() {
+    const ret = Date.now();
+    return ret;
+};
+
+exports.__wbg_push_7d9be8f38fc13975 = function(arg0, arg1) {
+    const ret = arg0.push(arg1);
+    return ret;
+};
+
+exports.__wbg_queueMicrotask_9b549dfce8865860 = function(arg0) {
+    const ret = arg0.queueMicrotask;
+    return ret;
+};
+
+exports.__wbg_queueMicrotask_fca69f5bfad613a5 = function(arg0) {
+    queueMicrotask(arg0);
+};
+
+exports.__wbg_resolve_fd5bfbaa4ce36e1e = function(arg0) {
+    const ret = Promise.resolve(arg0);
+    return ret;
+};
+
+exports.__wbg_set_3f1d0b984ed272ed = function(arg0, arg1, arg2) {
+    arg0[arg1] = arg2;
+};
+
+exports.__wbg_set_781438a03c0c3c81 = function() { return handleError(function (arg0, arg1, arg2) {
+    const ret = Reflect.set(arg0, arg1, arg2);
+    return ret;
+}, arguments) };
+
+exports.__wbg_set_7df433eea03a5c14 = function(arg0, arg1, arg2) {
+    arg0[arg1 >>> 0] = arg2;
+};
+
+exports.__wbg_set_efaaf145b9377369 = function(arg0, arg1, arg2) {
+    const ret = arg0.set(arg1, arg2);
+    return ret;
+};
+
+exports.__wbg_set_name_df69b75cb0b4de8a = function(arg0, arg1, arg2) {
+    arg0.name = getStringFromWasm0(arg1, arg2);
+};
+
+exports.__wbg_static_accessor_GLOBAL_769e6b65d6557335 = function() {
+    const ret = typeof global === 'undefined' ? null : global;
+    return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+};
+
+exports.__wbg_static_accessor_GLOBAL_THIS_60cf02db4de8e1c1 = function() {
+    const ret = typeof globalThis === 'undefined' ? null : globalThis;
+    return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+};
+
+exports.__wbg_static_accessor_SELF_08f5a74c69739274 = function() {
+    const ret = typeof self === 'undefined' ? null : self;
+    return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+};
+
+exports.__wbg_static_accessor_WINDOW_a8924b26aa92d024 = function() {
+    const ret = typeof window === 'undefined' ? null : window;
+    return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+};
+
+exports.__wbg_then_429f7caf1026411d = function(arg0, arg1, arg2) {
+    const ret = arg0.then(arg1, arg2);
+    return ret;
+};
+
+exports.__wbg_then_4f95312d68691235 = function(arg0, arg1) {
+    const ret = arg0.then(arg1);
+    return ret;
 };
 
 exports.__wbindgen_cast_2241b6af4c4b2941 = function(arg0, arg1) {
@@ -143,6 +3613,36 @@ exports.__wbindgen_cast_2241b6af4c4b2941 = function(arg0, arg1) {
     return ret;
 };
 
+exports.__wbindgen_cast_4625c577ab2ec9ee = function(arg0) {
+    // Cast intrinsic for `U64 -> Externref`.
+    const ret = BigInt.asUintN(64, arg0);
+    return ret;
+};
+
+exports.__wbindgen_cast_9ae0607507abb057 = function(arg0) {
+    // Cast intrinsic for `I64 -> Externref`.
+    const ret = arg0;
+    return ret;
+};
+
+exports.__wbindgen_cast_af3ce77be917b34a = function(arg0, arg1) {
+    // Cast intrinsic for `Closure(Closure { dtor_idx: 102, function: Function { arguments: [Externref], shim_idx: 103, ret: Unit, inner_ret: Some(Unit) }, mutable: true }) -> Externref`.
+    const ret = makeMutClosure(arg0, arg1, wasm.wasm_bindgen__closure__destroy__h03bae81550a1e519, wasm_bindgen__convert__closures_____invoke__h34f7a0b5d2a13193);
+    return ret;
+};
+
+exports.__wbindgen_cast_cb9088102bce6b30 = function(arg0, arg1) {
+    // Cast intrinsic for `Ref(Slice(U8)) -> NamedExternref("Uint8Array")`.
+    const ret = getArrayU8FromWasm0(arg0, arg1);
+    return ret;
+};
+
+exports.__wbindgen_cast_d6cd19b81560fd6e = function(arg0) {
+    // Cast intrinsic for `F64 -> Externref`.
+    const ret = arg0;
+    return ret;
+};
+
 exports.__wbindgen_init_externref_table = function() {
     const table = wasm.__wbindgen_externrefs;
     const offset = table.grow(4);
@@ -1,10 +1,206 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_aborthandle_free: (a: number, b: number) => void;
+export const aborthandle_abort: (a: number) => void;
+export const aborthandle_aborted: (a: number) => number;
+export const aborthandle_new: () => number;
+export const classify_edited_allele: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => [number, number];
+export const compare_ortholog_panel: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const detect_rearrangements: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const differential_restriction_sites: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const restriction_sites: (a: number, b: number, c: number, d: number) => [number, number];
+export const validate_sequence: (a: number, b: number) => [number, number];
+export const mirna_seed_sites: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const render_report: (a: number, b: number, c: number, d: number) => [number, number];
+export const compare_kmer_spectra: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const crispr_guide_sites: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number];
+export const kmer_counts: (a: number, b: number, c: number, d: number) => [number, number];
+export const verify_plasmid_insert: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const compare_multiple: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const compare_proteins: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number, number];
+export const compare_sequences: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number, number];
+export const render_alignment_text: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const suggest_domain_boundaries: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const compare_codon_optimized: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const consensus: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const query_region: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const read_pair_consensus: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const splice_site_differences: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const trim_by_quality: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const trim_read_ends: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const build_tree: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const find_approximate_matches: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const find_pattern: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const find_pattern_in_both: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const gc_identity_profile: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const __wbg_comparator_free: (a: number, b: number) => void;
+export const __wbg_sequencebuffer_free: (a: number, b: number) => void;
+export const comparator_apply_edit: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number, number, number];
+export const comparator_blocks: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const comparator_compare: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const comparator_compare_proteins: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const comparator_compare_seeded: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const comparator_deserialize: (a: number, b: number) => [number, number, number];
+export const comparator_empty: () => number;
+export const comparator_from_bytes: (a: number, b: number, c: number, d: number) => number;
+export const comparator_push_chunk: (a: number, b: number, c: number, d: number) => [number, number];
+export const comparator_recompute_blocks: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const comparator_sequence_length: (a: number, b: number) => number;
+export const comparator_serialize: (a: number) => [number, number];
+export const comparator_with_options: (a: number, b: number) => number;
+export const compare_proteins_bytes: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number];
+export const compare_sequence_buffers: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const compare_sequences_bytes: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const compare_sequences_with_progress: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: any) => [number, number, number, number];
+export const sequencebuffer_is_empty: (a: number) => number;
+export const sequencebuffer_len: (a: number) => number;
+export const sequencebuffer_new: (a: number) => number;
+export const sequencebuffer_view: (a: number) => any;
+export const comparator_push_chunk_bytes: (a: number, b: number, c: number, d: number) => [number, number];
+export const comparator_new: (a: number, b: number, c: number, d: number) => number;
+export const __wbg_comparisonoptions_free: (a: number, b: number) => void;
+export const compare_protein_to_mrna: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const compare_proteins_direct: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const compare_proteins_with_options: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const compare_sequence_regions_with_options: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const compare_sequences_compressed: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const compare_sequences_with_options: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const comparisonoptions_aa_segment_window_length: (a: number) => number;
+export const comparisonoptions_block_exit_identity: (a: number) => [number, number];
+export const comparisonoptions_cds: (a: number) => [number, number];
+export const comparisonoptions_composition_adjusted_identity: (a: number) => number;
+export const comparisonoptions_compression: (a: number) => [number, number];
+export const comparisonoptions_custom_nucleotide_matrix: (a: number) => number;
+export const comparisonoptions_frame1: (a: number) => number;
+export const comparisonoptions_frame2: (a: number) => number;
+export const comparisonoptions_gap_score: (a: number) => number;
+export const comparisonoptions_gap_symbol: (a: number) => number;
+export const comparisonoptions_genbank: (a: number) => number;
+export const comparisonoptions_good_enough_identity: (a: number) => [number, number];
+export const comparisonoptions_heterozygous: (a: number) => number;
+export const comparisonoptions_highlight_ranges: (a: number) => number;
+export const comparisonoptions_known_variant_count: (a: number) => number;
+export const comparisonoptions_kozak: (a: number) => number;
+export const comparisonoptions_match_score: (a: number) => number;
+export const comparisonoptions_match_symbol: (a: number) => number;
+export const comparisonoptions_min_alignment_identity: (a: number) => [number, number];
+export const comparisonoptions_min_identity: (a: number) => number;
+export const comparisonoptions_min_sequence_overlap_pct: (a: number) => number;
+export const comparisonoptions_min_significant_length_group: (a: number) => number;
+export const comparisonoptions_mismatch_hotspots: (a: number) => number;
+export const comparisonoptions_mismatch_score: (a: number) => number;
+export const comparisonoptions_mismatch_symbol: (a: number) => number;
+export const comparisonoptions_new: () => number;
+export const comparisonoptions_one_based: (a: number) => number;
+export const comparisonoptions_overview_bins: (a: number) => number;
+export const comparisonoptions_preset: (a: number, b: number) => number;
+export const comparisonoptions_preset_names: () => [number, number];
+export const comparisonoptions_primers: (a: number) => number;
+export const comparisonoptions_protein_gap_score: (a: number) => number;
+export const comparisonoptions_protein_matrix: (a: number) => [number, number];
+export const comparisonoptions_resolve_ambiguous_codons: (a: number) => number;
+export const comparisonoptions_seeded: (a: number) => number;
+export const comparisonoptions_segment_window_length: (a: number) => number;
+export const comparisonoptions_selenocysteine: (a: number) => number;
+export const comparisonoptions_start_codons: (a: number) => [number, number];
+export const comparisonoptions_strip_digits: (a: number) => number;
+export const comparisonoptions_strip_whitespace: (a: number) => number;
+export const comparisonoptions_trim_block_edges: (a: number) => number;
+export const comparisonoptions_trim_poly_a: (a: number) => number;
+export const comparisonoptions_u_to_t: (a: number) => number;
+export const comparisonoptions_uppercase: (a: number) => number;
+export const comparisonoptions_with_aa_segment_window_length: (a: number, b: number) => number;
+export const comparisonoptions_with_block_exit_identity: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_cds: (a: number, b: number, c: number, d: number, e: number) => number;
+export const comparisonoptions_with_composition_adjusted_identity: (a: number, b: number) => number;
+export const comparisonoptions_with_compression: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_frame1: (a: number, b: number) => number;
+export const comparisonoptions_with_frame2: (a: number, b: number) => number;
+export const comparisonoptions_with_gap_score: (a: number, b: number) => number;
+export const comparisonoptions_with_gap_symbol: (a: number, b: number) => number;
+export const comparisonoptions_with_genbank: (a: number, b: number) => number;
+export const comparisonoptions_with_good_enough_identity: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_heterozygous: (a: number, b: number) => number;
+export const comparisonoptions_with_highlight_ranges: (a: number, b: number) => number;
+export const comparisonoptions_with_known_variants: (a: number, b: number, c: number, d: number) => number;
+export const comparisonoptions_with_kozak: (a: number, b: number) => number;
+export const comparisonoptions_with_match_score: (a: number, b: number) => number;
+export const comparisonoptions_with_match_symbol: (a: number, b: number) => number;
+export const comparisonoptions_with_min_alignment_identity: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_min_identity: (a: number, b: number) => number;
+export const comparisonoptions_with_min_sequence_overlap_pct: (a: number, b: number) => number;
+export const comparisonoptions_with_min_significant_length_group: (a: number, b: number) => number;
+export const comparisonoptions_with_mismatch_hotspots: (a: number, b: number) => number;
+export const comparisonoptions_with_mismatch_score: (a: number, b: number) => number;
+export const comparisonoptions_with_mismatch_symbol: (a: number, b: number) => number;
+export const comparisonoptions_with_normalize: (a: number, b: number) => number;
+export const comparisonoptions_with_nucleotide_matrix: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_one_based: (a: number, b: number) => number;
+export const comparisonoptions_with_overview_bins: (a: number, b: number) => number;
+export const comparisonoptions_with_primers: (a: number, b: number) => number;
+export const comparisonoptions_with_protein_gap_score: (a: number, b: number) => number;
+export const comparisonoptions_with_protein_matrix: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_resolve_ambiguous_codons: (a: number, b: number) => number;
+export const comparisonoptions_with_scoring_matrix: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_seeded: (a: number, b: number) => number;
+export const comparisonoptions_with_segment_window_length: (a: number, b: number) => number;
+export const comparisonoptions_with_selenocysteine: (a: number, b: number) => number;
+export const comparisonoptions_with_start_codons: (a: number, b: number, c: number) => number;
+export const comparisonoptions_with_strip_digits: (a: number, b: number) => number;
+export const comparisonoptions_with_strip_whitespace: (a: number, b: number) => number;
+export const comparisonoptions_with_trim_block_edges: (a: number, b: number) => number;
+export const comparisonoptions_with_trim_poly_a: (a: number, b: number) => number;
+export const comparisonoptions_with_u_to_t: (a: number, b: number) => number;
+export const comparisonoptions_with_uppercase: (a: number, b: number) => number;
+export const comparisonoptions_without_cds: (a: number) => number;
+export const sketch: (a: number, b: number, c: number, d: number) => [number, number];
+export const sketch_distance: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const __wbg_conservedblock_free: (a: number, b: number) => void;
+export const __wbg_sequenceindex_free: (a: number, b: number) => void;
+export const blocks_to_fasta: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const compare_one_vs_many: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const compare_one_vs_many_with_options: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const conserved_blocks: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const conservedblock_end1: (a: number) => number;
+export const conservedblock_end2: (a: number) => number;
+export const conservedblock_identity: (a: number) => number;
+export const conservedblock_length: (a: number) => number;
+export const conservedblock_sequence: (a: number) => [number, number];
+export const conservedblock_start1: (a: number) => number;
+export const conservedblock_start2: (a: number) => number;
+export const map_position: (a: number, b: number, c: number, d: number) => number;
+export const oligo_properties: (a: number, b: number, c: number, d: number) => [number, number];
+export const scan_motifs: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
+export const sequenceindex_contains: (a: number, b: number, c: number) => number;
+export const sequenceindex_count: (a: number, b: number, c: number) => number;
+export const sequenceindex_find: (a: number, b: number, c: number) => [number, number];
+export const sequenceindex_length: (a: number) => number;
+export const sequenceindex_longest_match: (a: number, b: number, c: number) => [number, number];
+export const sequenceindex_new: (a: number, b: number) => [number, number, number];
+export const sequenceindex_suffix_array: (a: number) => [number, number];
+export const clear_result_cache: () => void;
+export const compare_sequence_regions: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number];
+export const compare_sequences_async: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: any) => any;
+export const set_logger: (a: number) => void;
+export const set_memory_limit: (a: number) => void;
+export const get_memory_limit: () => number;
+export const compare_exons: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => [number, number];
 export const compare_proteins_full: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => [number, number];
 export const compare_sequences_full: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
-export const __wbindgen_externrefs: WebAssembly.Table;
+export const compare_sequences_good_enough: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => [number, number];
+export const diff_strings: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const pairwise_matrix: (a: number, b: number, c: number, d: number) => [number, number];
+export const parse_exon_annotations: (a: number, b: number) => [number, number];
+export const wasm_bindgen__convert__closures_____invoke__h34f7a0b5d2a13193: (a: number, b: number, c: any) => void;
+export const wasm_bindgen__closure__destroy__h03bae81550a1e519: (a: number, b: number) => void;
+export const wasm_bindgen__convert__closures_____invoke__hb7ba44727ea16178: (a: number, b: number, c: any, d: any) => void;
 export const __wbindgen_malloc: (a: number, b: number) => number;
 export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_exn_store: (a: number) => void;
+export const __externref_table_alloc: () => number;
+export const __wbindgen_externrefs: WebAssembly.Table;
 export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __externref_table_dealloc: (a: number) => void;
+export const __externref_drop_slice: (a: number, b: number) => void;
 export const __wbindgen_start: () => void;
@@ -0,0 +1,128 @@
+use gene_compare_core::format::CoordinateSystem;
+use gene_compare_core::symbols::GAP;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Alignment Text
+// ============================================================================
+// The aligned region of a result as classic interleaved text (seq1, a match
+// line, seq2) for pasting into emails and lab notebooks. Each seq1/seq2 line
+// is flanked by the positions of its first and last residue, and a ruler
+// above it labels every tenth seq1 position.
+
+const DEFAULT_LINE_WIDTH: usize = 60;
+const RULER_STEP: usize = 10;
+
+/// One sequence's aligned residues (gaps included) and the 0-based index of the first
+struct Row<'a> {
+  name: &'static str,
+  residues: &'a [u8],
+  first: usize,
+}
+
+impl Row<'_> {
+  /// Index of the first residue at or after `column`, counting gaps as nothing
+  fn index_at(&self, column: usize) -> usize {
+    self.first + self.residues[..column].iter().filter(|&&c| c != GAP).count()
+  }
+}
+
+/// Aligned residues of `sequence` from the given result offset and length, `None` when the
+/// sequence doesn't cover them
+fn region(sequence: &[u8], offset: usize, length: usize) -> Option<&[u8]> {
+  sequence.get(offset..offset.checked_add(length)?)
+}
+
+fn render(rows: [Row; 2], coordinates: CoordinateSystem, line_width: usize) -> String {
+  let columns = rows[0].residues.len().min(rows[1].residues.len());
+  let width = rows
+    .iter()
+    .map(|row| coordinates.end(row.index_at(columns)).max(coordinates.start(row.first)).to_string().len())
+    .max()
+    .unwrap_or(1);
+  let margin = 5 + width + 2;
+  let mut out = String::new();
+  for line_start in (0..columns).step_by(line_width) {
+    let line_end = (line_start + line_width).min(columns);
+
+    // Ruler over seq1: each label ends above the residue it numbers
+    let mut ruler = " ".repeat(margin);
+    let mut index = rows[0].index_at(line_start);
+    for column in line_start..line_end {
+      if rows[0].residues[column] == GAP {
+        continue;
+      }
+      let position = coordinates.start(index);
+      let label = position.to_string();
+      let label_end = margin + column - line_start + 1;
+      if position > 0 && position.is_multiple_of(RULER_STEP) && label_end >= ruler.len() + label.len() {
+        ruler += &" ".repeat(label_end - label.len() - ruler.len());
+        ruler += &label;
+      }
+      index += 1;
+    }
+    out += ruler.trim_end();
+    out += "\n";
+
+    let matches: String = (line_start..line_end)
+      .map(|column| {
+        let (a, b) = (rows[0].residues[column], rows[1].residues[column]);
+        if a != GAP && b != GAP && a.eq_ignore_ascii_case(&b) { '|' } else { ' ' }
+      })
+      .collect();
+    for (i, row) in rows.iter().enumerate() {
+      let (start, end) = (row.index_at(line_start), row.index_at(line_end));
+      out += &format!(
+        "{:<5} {:>width$} {} {}\n",
+        row.name,
+        coordinates.start(start),
+        String::from_utf8_lossy(&row.residues[line_start..line_end]),
+        coordinates.end(end),
+        width = width
+      );
+      if i == 0 {
+        out += &format!("{}{}\n", " ".repeat(margin), matches.trim_end());
+      }
+    }
+    out += "\n";
+  }
+  out.trim_end().to_string() + "\n"
+}
+
+/// Interleaved text of a comparison result's aligned region: seq1, a match line ('|' where
+/// the residues agree), and seq2, `line_width` columns at a time (60 when 0) under a ruler,
+/// with positions in the result's coordinate system. Protein results carry their
+/// translations; nucleotide results carry only the mask, so `seq1` and `seq2` must be the
+/// sequences as compared (after any normalization). They are ignored for protein results
+#[wasm_bindgen]
+pub fn render_alignment_text(result: &str, line_width: usize, seq1: &str, seq2: &str) -> String {
+  let Some(result) = serde_json::from_str::<Value>(result).ok().filter(Value::is_object) else {
+    return "The comparison result could not be read.\n".to_string();
+  };
+  let coordinates = match result.get("coordinateSystem").and_then(Value::as_str) {
+    Some("1-based-inclusive") => CoordinateSystem::OneBased,
+    _ => CoordinateSystem::ZeroBased,
+  };
+  let number = |key: &str| result.get(key).and_then(Value::as_u64).unwrap_or(0) as usize;
+  let index = |key: &str| coordinates.start_index(number(key) as i32).max(0) as usize;
+  let line_width = if line_width == 0 { DEFAULT_LINE_WIDTH } else { line_width };
+
+  let rows = match (result.get("aa1").and_then(Value::as_str), result.get("aa2").and_then(Value::as_str)) {
+    // Protein results: the translations start at the aligned region, in residues
+    (Some(aa1), Some(aa2)) => Some([
+      Row { name: "seq1", residues: aa1.as_bytes(), first: index("offset1") },
+      Row { name: "seq2", residues: aa2.as_bytes(), first: index("offset2") },
+    ]),
+    _ => {
+      let (offset1, offset2, length) = (index("offset1"), index("offset2"), number("length"));
+      region(seq1.as_bytes(), offset1, length).zip(region(seq2.as_bytes(), offset2, length)).map(|(region1, region2)| {
+        [Row { name: "seq1", residues: region1, first: offset1 }, Row { name: "seq2", residues: region2, first: offset2 }]
+      })
+    }
+  };
+  match rows {
+    Some(rows) => render(rows, coordinates, line_width),
+    None => "The sequences do not cover the aligned region of the comparison result.\n".to_string(),
+  }
+}
@@ -0,0 +1,134 @@
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::nucleotide_comparison;
+use crate::options::{nucleotide_comparison_with_options, ComparisonOptions};
+
+// ============================================================================
+// Query-vs-Many Comparison
+// ============================================================================
+// One query screened against a panel of targets (candidate clones, alleles,
+// orthologs) in a single call, so JS crosses the wasm boundary once. Each
+// target is compared afresh rather than through the result cache: a screen
+// rarely repeats a pair, and the ranking needs each placement's identity,
+// which a cached JSON result does not carry.
+/// A target is either a bare sequence or an object carrying an identifier
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Target {
+  Sequence(String),
+  Named { id: Option<String>, sequence: String },
+}
+
+impl Target {
+  fn parts(&self) -> (Option<&str>, &str) {
+    match self {
+      Target::Sequence(sequence) => (None, sequence),
+      Target::Named { id, sequence } => (id.as_deref(), sequence),
+    }
+  }
+}
+
+/// Compare one query against many targets in a single call.
+/// `targets_json` is a JSON array of sequences or `{ "id", "sequence" }` objects. Returns an
+/// array of `{ index, id, result }` sorted by identity (highest first, input order on ties),
+/// where `result` has the same shape as `compare_sequences_full`. `targets_json` that does not
+/// read as such an array gives `{"error": ...}`.
+#[wasm_bindgen]
+pub fn compare_one_vs_many(
+  query: &str,
+  targets_json: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  rank_targets(targets_json, |sequence| {
+    nucleotide_comparison(
+      query.as_bytes(),
+      sequence.as_bytes(),
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      min_sequence_overlap_pct,
+    )
+  })
+}
+
+/// `compare_one_vs_many` configured by a `ComparisonOptions` object: each `result` has the
+/// shape of `compare_sequences_with_options`, and targets are ranked by the identity of
+/// their placement
+#[wasm_bindgen]
+pub fn compare_one_vs_many_with_options(query: &str, targets_json: &str, options: &ComparisonOptions) -> String {
+  rank_targets(targets_json, |sequence| nucleotide_comparison_with_options(query, sequence, options))
+}
+
+/// Shared body of the query-vs-many comparisons; `compare` gives a target's identity and
+/// result
+fn rank_targets(targets_json: &str, compare: impl Fn(&str) -> (f64, String)) -> String {
+  let targets: Vec<Target> = match serde_json::from_str(targets_json) {
+    Ok(targets) => targets,
+    Err(_) => return r#"{"error":"targets_json must be a JSON array of sequences or {id, sequence} objects"}"#.to_string(),
+  };
+
+  let mut results: Vec<(usize, f64, String)> = targets
+    .iter()
+    .enumerate()
+    .map(|(index, target)| {
+      let (id, sequence) = target.parts();
+      let (identity, result) = compare(sequence);
+      let id_json = id.map_or_else(|| "null".to_string(), |id| serde_json::to_string(id).unwrap_or_default());
+      (index, identity, format!(r#"{{"index":{},"id":{},"result":{}}}"#, index, id_json, result))
+    })
+    .collect();
+
+  results.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+  let parts: Vec<String> = results.into_iter().map(|(_, _, json)| json).collect();
+  format!("[{}]", parts.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gene_compare_core::{
+    DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+    DEFAULT_SEGMENT_WINDOW_LENGTH,
+  };
+  use serde_json::Value;
+
+  fn one_vs_many(targets_json: &str) -> Value {
+    let json = compare_one_vs_many(
+      "ACGTACGTTGCA",
+      targets_json,
+      DEFAULT_SEGMENT_WINDOW_LENGTH,
+      DEFAULT_MIN_IDENTITY,
+      DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+      DEFAULT_MIN_SEQUENCE_OVERLAP_PCT,
+    );
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn malformed_targets_give_an_error() {
+    assert!(one_vs_many(r#"{"sequence":"ACGT"}"#)["error"].is_string());
+    assert!(one_vs_many("[\"ACGT\"").get("error").is_some());
+  }
+
+  #[test]
+  fn targets_are_sorted_by_identity() {
+    let results = one_vs_many(r#"["TTTTTTTTTTTT",{"id":"same","sequence":"ACGTACGTTGCA"}]"#);
+    assert_eq!(results[0]["id"], "same");
+    assert_eq!(results[1]["index"], 0);
+  }
+
+  #[test]
+  fn options_configure_every_target() {
+    let options = ComparisonOptions::new().with_match_symbol(Some('|')).with_uppercase(true);
+    let json = compare_one_vs_many_with_options("acgtacgttgca", r#"["TTTTTTTTTTTT","ACGTACGTTGCA"]"#, &options);
+    let results: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(results[0]["index"], 1);
+    assert_eq!(results[0]["result"]["mask"], "||||||||||||");
+    assert!(results[0]["result"]["normalization"].is_object());
+  }
+}
@@ -0,0 +1,234 @@
+use gene_compare_core::alignment::Alignment;
+use gene_compare_core::blocks::{find_blocks, ConservedBlock};
+use gene_compare_core::format::CoordinateSystem;
+use gene_compare_core::symbols::{GAP, MISMATCH};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::mapping::{mask_symbols, Placement};
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Conserved Blocks as Objects
+// ============================================================================
+// The JSON results carry blocks in mask coordinates; this class carries them
+// in each sequence's own coordinates, with getters, so TypeScript callers get
+// typed objects without parsing a string.
+
+/// A conserved block placed on both sequences (0-based, end exclusive, unless the options
+/// ask for 1-based coordinates)
+#[wasm_bindgen(js_name = ConservedBlock)]
+pub struct AlignedBlock {
+  start1: usize,
+  end1: usize,
+  start2: usize,
+  end2: usize,
+  length: usize,
+  identity: f64,
+  sequence: String,
+}
+
+#[wasm_bindgen(js_class = ConservedBlock)]
+impl AlignedBlock {
+  #[wasm_bindgen(getter)]
+  pub fn start1(&self) -> usize {
+    self.start1
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn end1(&self) -> usize {
+    self.end1
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn start2(&self) -> usize {
+    self.start2
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn end2(&self) -> usize {
+    self.end2
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn length(&self) -> usize {
+    self.length
+  }
+
+  /// Fraction of the block's columns that match
+  #[wasm_bindgen(getter)]
+  pub fn identity(&self) -> f64 {
+    self.identity
+  }
+
+  /// The block's mask: matching bases, '?' at mismatches
+  #[wasm_bindgen(getter)]
+  pub fn sequence(&self) -> String {
+    self.sequence.clone()
+  }
+}
+
+pub(crate) fn aligned_blocks(
+  alignment: &Alignment,
+  blocks: &[ConservedBlock],
+  coordinates: CoordinateSystem,
+) -> Vec<AlignedBlock> {
+  blocks
+    .iter()
+    .map(|block| {
+      let matches = block.sequence.iter().filter(|&&c| c != b'?').count();
+      AlignedBlock {
+        start1: coordinates.start(alignment.offset1 + block.start),
+        end1: coordinates.end(alignment.offset1 + block.end),
+        start2: coordinates.start(alignment.offset2 + block.start),
+        end2: coordinates.end(alignment.offset2 + block.end),
+        length: block.length,
+        identity: if block.length == 0 { 0.0 } else { matches as f64 / block.length as f64 },
+        sequence: String::from_utf8_lossy(&block.sequence).into_owned(),
+      }
+    })
+    .collect()
+}
+
+/// Conserved blocks of the best placement of seq2 against seq1, as `ConservedBlock` objects
+/// (positions in the sequences after the options' normalization), found with the options'
+/// block detection; an error when the placement would exceed the memory limit
+#[wasm_bindgen]
+pub fn conserved_blocks(seq1: &str, seq2: &str, options: &ComparisonOptions) -> Result<Vec<AlignedBlock>, JsError> {
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return Ok(Vec::new());
+  }
+  let alignment = options.place(bytes1, bytes2).map_err(|exceeded| JsError::new(&exceeded.to_json()))?;
+  let mask = alignment.mask(bytes1, bytes2);
+  let blocks = find_blocks(
+    &mask,
+    options.segment_window_length(),
+    options.min_identity(),
+    options.min_significant_length_group(),
+    options.block_detection(),
+  );
+  Ok(aligned_blocks(&alignment, &blocks, options.coordinates()))
+}
+
+// ============================================================================
+// Conserved Blocks as FASTA (exported)
+// ============================================================================
+// One record per block of one sequence, ready to paste into BLAST or hand to
+// other tools. Protein results carry their translations over the aligned
+// columns; nucleotide results only the mask, whose mismatches hide which base
+// the sequence had, so those come out as N unless the sequence is supplied. A
+// mask restyled with a match symbol hides every base, and needs the sequence.
+
+const FASTA_LINE_WIDTH: usize = 60;
+
+/// The conserved blocks of a nucleotide or protein comparison result as FASTA records of
+/// sequence `which_seq` (1 or 2), `>block<n> seq<k>:<start>-<end> length=<n> identity=<x>`
+/// in the result's coordinate system, residues in lines of 60 without gaps. Protein results
+/// use their translations; for nucleotide results, `sequence` is that sequence as compared
+/// (after any normalization), or empty to fill mismatches with N (unless the result's
+/// `maskSymbols` replace matching bases with a symbol). Unreadable results, a `which_seq`
+/// other than 1 or 2, or a `sequence` that doesn't cover the aligned region or is missing
+/// give `{"error": ...}`
+#[wasm_bindgen]
+pub fn blocks_to_fasta(result: &str, which_seq: u8, sequence: &str) -> String {
+  let error = |message: &str| format!(r#"{{"error":"{}"}}"#, message);
+  let Some(result) = serde_json::from_str::<Value>(result).ok().filter(Value::is_object) else {
+    return error("The comparison result could not be read");
+  };
+  let Some(placement) = Placement::from_result(&result) else {
+    return error("The comparison result has no offsets and length");
+  };
+  let seq = match which_seq {
+    1 => 0,
+    2 => 1,
+    _ => return error("which_seq must be 1 or 2"),
+  };
+  let offset = placement.offsets[seq];
+  let translation = result.get(["aa1", "aa2"][seq]).and_then(Value::as_str).map(str::as_bytes);
+  let symbols = mask_symbols(&result);
+  let region = if translation.is_some() {
+    None
+  } else if sequence.is_empty() {
+    if symbols.matched.is_some() {
+      return error("The result's mask shows a match symbol instead of bases; pass the compared sequence");
+    }
+    None
+  } else {
+    match offset.checked_add(placement.length).and_then(|end| sequence.as_bytes().get(offset..end)) {
+      Some(region) => Some(region),
+      None => return error("The sequence does not cover the aligned region of the comparison result"),
+    }
+  };
+
+  let coordinates = placement.coordinates;
+  let blocks = result.get("conservedBlocks").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+  let mut out = String::new();
+  for (number, block) in blocks.iter().enumerate() {
+    let field = |key: &str| block.get(key).and_then(Value::as_u64).map(|n| n as usize);
+    let (Some(start), Some(end)) = (field("start"), field("end")) else {
+      continue;
+    };
+    let start = coordinates.start_index(start as i32).max(0) as usize;
+    let mask = symbols.canonical(block.get("sequence").and_then(Value::as_str).unwrap_or_default().as_bytes());
+    let residues: Vec<u8> = match (translation, region) {
+      (Some(residues), _) | (None, Some(residues)) => residues.get(start..end).unwrap_or_default().to_vec(),
+      (None, None) => mask.iter().map(|&c| if c == MISMATCH { b'N' } else { c }).collect(),
+    };
+    let residues: Vec<u8> = residues.into_iter().filter(|&c| c != GAP).collect();
+    let matches = mask.iter().filter(|&&c| c != MISMATCH && c != GAP).count();
+    let identity = if mask.is_empty() { 0.0 } else { matches as f64 / mask.len() as f64 };
+    out += &format!(
+      ">block{} seq{}:{}-{} length={} identity={:.3}\n",
+      number + 1,
+      which_seq,
+      coordinates.start(offset + start),
+      coordinates.end(offset + end),
+      end - start,
+      identity
+    );
+    for line in residues.chunks(FASTA_LINE_WIDTH) {
+      out += &String::from_utf8_lossy(line);
+      out.push('\n');
+    }
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::options::compare_sequences_with_options;
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn fasta_of_a_restyled_result_matches_the_default() {
+    let (seq1, seq2) = pair();
+    let plain = compare_sequences_with_options(&seq1, &seq2, &ComparisonOptions::new());
+    let styled = compare_sequences_with_options(&seq1, &seq2, &restyled());
+    assert!(blocks_to_fasta(&styled, 2, "").contains("error"));
+    assert_eq!(blocks_to_fasta(&styled, 2, &seq2), blocks_to_fasta(&plain, 2, &seq2));
+    let header = blocks_to_fasta(&plain, 2, "").lines().next().unwrap().to_string();
+    assert!(header.ends_with("identity=0.972"), "{}", header);
+  }
+
+  #[test]
+  fn block_objects_follow_the_options_block_detection() {
+    let mut state = 7u64;
+    let seq1: String = (0..300)
+      .map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ['A', 'C', 'G', 'T'][(state >> 33) as usize % 4]
+      })
+      .collect();
+    let seq2 = format!("{}{}", if seq1.starts_with('A') { 'C' } else { 'A' }, &seq1[1..]);
+    let blocks = |options: &ComparisonOptions| {
+      conserved_blocks(&seq1, &seq2, options).ok().unwrap().iter().map(|block| (block.start1(), block.sequence())).collect::<Vec<_>>()
+    };
+    let untrimmed = blocks(&ComparisonOptions::new());
+    let trimmed = blocks(&ComparisonOptions::new().with_trim_block_edges(true));
+    assert_eq!((untrimmed[0].0, &untrimmed[0].1[..1]), (0, "?"));
+    assert_eq!((trimmed[0].0, trimmed[0].1.len()), (1, seq1.len() - 1));
+  }
+}
@@ -0,0 +1,95 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{nucleotide_comparison, protein_comparison};
+
+// ============================================================================
+// Byte Inputs
+// ============================================================================
+// `&str` arguments are UTF-8 encoded and copied on every call. The `_bytes`
+// variants take a Uint8Array (one memcpy, no encoding), and SequenceBuffer
+// lets JS write a sequence straight into WASM memory so it is never copied.
+
+/// Sequence storage owned by WASM memory. Fill it through `view()` and pass the
+/// buffer to `compare_sequence_buffers`, or reuse it across comparisons.
+#[wasm_bindgen]
+pub struct SequenceBuffer {
+  bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl SequenceBuffer {
+  #[wasm_bindgen(constructor)]
+  pub fn new(len: usize) -> SequenceBuffer {
+    SequenceBuffer { bytes: vec![0; len] }
+  }
+
+  /// A Uint8Array aliasing the buffer. The view is invalidated when WASM memory grows,
+  /// so write into it immediately instead of holding on to it.
+  pub fn view(&mut self) -> js_sys::Uint8Array {
+    // SAFETY: the view is handed straight to JS, which is told not to keep it across allocations
+    unsafe { js_sys::Uint8Array::view_mut_raw(self.bytes.as_mut_ptr(), self.bytes.len()) }
+  }
+
+  pub fn len(&self) -> usize {
+    self.bytes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.bytes.is_empty()
+  }
+}
+
+impl SequenceBuffer {
+  pub(crate) fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+}
+
+/// `compare_sequences_full` over byte inputs (e.g. a Uint8Array of ASCII bases)
+#[wasm_bindgen]
+pub fn compare_sequences_bytes(
+  seq1: &[u8],
+  seq2: &[u8],
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  nucleotide_comparison(seq1, seq2, segment_window_length, min_identity, min_significant_length_group, min_sequence_overlap_pct).1
+}
+
+/// `compare_sequences_full` over two sequences already resident in WASM memory
+#[wasm_bindgen]
+pub fn compare_sequence_buffers(
+  seq1: &SequenceBuffer,
+  seq2: &SequenceBuffer,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  nucleotide_comparison(
+    seq1.as_bytes(),
+    seq2.as_bytes(),
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    min_sequence_overlap_pct,
+  ).1
+}
+
+/// `compare_proteins_full` over byte inputs
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compare_proteins_bytes(
+  seq1: &[u8],
+  seq2: &[u8],
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> String {
+  protein_comparison(seq1, seq2, nuc_offset1, nuc_offset2, nuc_length, aa_segment_window_length, min_identity, min_significant_length_group)
+}
@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Result Cache
+// ============================================================================
+// Recent results of the full comparisons, keyed by hashes of both sequences
+// plus every parameter, so flipping between the nucleotide and protein views
+// of the same pair is instant (a protein hit skips the frame-detection log
+// lines). Least recently used entries are evicted once either the entry or
+// the byte budget is exceeded. Each thread (Worker) has its own cache.
+
+const CACHE_MAX_ENTRIES: usize = 32;
+const CACHE_MAX_BYTES: usize = 32 << 20;
+
+#[derive(PartialEq, Eq)]
+pub(crate) struct CacheKey {
+  kind: &'static str,
+  seq1: (u64, usize),
+  seq2: (u64, usize),
+  options: Vec<u64>,
+}
+
+impl CacheKey {
+  /// `options` holds every parameter that affects the result, as raw bits
+  pub(crate) fn new(kind: &'static str, seq1: &[u8], seq2: &[u8], options: &[u64]) -> Self {
+    CacheKey { kind, seq1: (hash_bytes(seq1), seq1.len()), seq2: (hash_bytes(seq2), seq2.len()), options: options.to_vec() }
+  }
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  bytes.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[derive(Default)]
+struct ResultCache {
+  /// Most recently used first
+  entries: VecDeque<(CacheKey, String)>,
+  bytes: usize,
+}
+
+impl ResultCache {
+  fn get(&mut self, key: &CacheKey) -> Option<String> {
+    let index = self.entries.iter().position(|(k, _)| k == key)?;
+    let entry = self.entries.remove(index)?;
+    let value = entry.1.clone();
+    self.entries.push_front(entry);
+    Some(value)
+  }
+
+  fn insert(&mut self, key: CacheKey, value: String) {
+    if value.len() > CACHE_MAX_BYTES {
+      return;
+    }
+    self.bytes += value.len();
+    self.entries.push_front((key, value));
+    while self.entries.len() > CACHE_MAX_ENTRIES || self.bytes > CACHE_MAX_BYTES {
+      match self.entries.pop_back() {
+        Some((_, evicted)) => self.bytes -= evicted.len(),
+        None => break,
+      }
+    }
+  }
+}
+
+thread_local! {
+  static CACHE: RefCell<ResultCache> = RefCell::new(ResultCache::default());
+}
+
+/// Cached result for `key`, computing and storing it on a miss
+pub(crate) fn cached(key: CacheKey, compute: impl FnOnce() -> String) -> String {
+  if let Some(hit) = CACHE.with(|cache| cache.borrow_mut().get(&key)) {
+    return hit;
+  }
+  let value = compute();
+  CACHE.with(|cache| cache.borrow_mut().insert(key, value.clone()));
+  value
+}
+
+/// Drop every cached comparison result (e.g. to release memory after a large comparison)
+#[wasm_bindgen]
+pub fn clear_result_cache() {
+  CACHE.with(|cache| *cache.borrow_mut() = ResultCache::default());
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn a_hit_skips_the_computation_until_a_parameter_changes() {
+    let key = |options: &[u64]| CacheKey::new("test", b"ACGT", b"ACGA", options);
+    assert_eq!(cached(key(&[1]), || "first".to_string()), "first");
+    assert_eq!(cached(key(&[1]), || unreachable!()), "first");
+    assert_eq!(cached(key(&[2]), || "second".to_string()), "second");
+    clear_result_cache();
+    assert_eq!(cached(key(&[1]), || "recomputed".to_string()), "recomputed");
+  }
+
+  #[test]
+  fn least_recently_used_entries_are_evicted_first() {
+    let key = |n: u64| CacheKey::new("test", b"A", b"A", &[n]);
+    let mut cache = ResultCache::default();
+    for n in 0..CACHE_MAX_ENTRIES as u64 {
+      cache.insert(key(n), n.to_string());
+    }
+    assert!(cache.get(&key(0)).is_some());
+    cache.insert(key(CACHE_MAX_ENTRIES as u64), "new".to_string());
+    assert!(cache.get(&key(0)).is_some());
+    assert!(cache.get(&key(1)).is_none());
+    assert_eq!(cache.entries.len(), CACHE_MAX_ENTRIES);
+
+    cache.insert(key(u64::MAX), "x".repeat(CACHE_MAX_BYTES + 1));
+    assert!(cache.get(&key(u64::MAX)).is_none());
+  }
+}
@@ -0,0 +1,57 @@
+use std::cell::Cell;
+
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Cancellation
+// ============================================================================
+// Long-running exports take an optional signal: an `AbortHandle`, a DOM
+// `AbortSignal`, or any object with a truthy `aborted` property once the work
+// should stop. It is checked between offset-scan chunks and between phases,
+// so an abort takes effect within one chunk (FFT match counts for very large
+// inputs are computed in one step and cannot be interrupted).
+
+pub(crate) const ABORTED_MESSAGE: &str = "Comparison aborted";
+
+/// Cancellation flag for in-flight comparisons: pass it as the `signal` argument and call
+/// `abort()`, e.g. from a click handler while `compare_sequences_async` is pending
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct AbortHandle {
+  aborted: Cell<bool>,
+}
+
+#[wasm_bindgen]
+impl AbortHandle {
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> AbortHandle {
+    AbortHandle::default()
+  }
+
+  pub fn abort(&self) {
+    self.aborted.set(true);
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn aborted(&self) -> bool {
+    self.aborted.get()
+  }
+}
+
+pub(crate) struct Cancellation {
+  signal: JsValue,
+}
+
+impl Cancellation {
+  pub(crate) fn new(signal: JsValue) -> Self {
+    Cancellation { signal }
+  }
+
+  pub(crate) fn is_aborted(&self) -> bool {
+    if !self.signal.is_object() {
+      return false;
+    }
+    Reflect::get(&self.signal, &JsValue::from_str("aborted")).is_ok_and(|aborted| aborted.is_truthy())
+  }
+}
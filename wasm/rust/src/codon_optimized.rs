@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use gene_compare_core::codon_optimized::{compare_recoding, Codon};
+use gene_compare_core::translation::ReadingFrames;
+use gene_compare_core::CODON_SIZE;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Codon-Optimized Comparison (exported)
+// ============================================================================
+fn usage_json(usage: &BTreeMap<Codon, usize>) -> String {
+  let counts: Vec<String> =
+    usage.iter().map(|(codon, count)| format!(r#""{}":{}"#, String::from_utf8_lossy(codon), count)).collect();
+  format!("{{{}}}", counts.join(","))
+}
+
+/// Comparison of a codon-optimized CDS with the natural one it recodes. Both are translated
+/// with the options' codon rules (over `with_cds` when set, else from the pinned frame or the
+/// first base) and the proteins placed against each other; `proteinIdentity` and
+/// `proteinIdentical` (same length, placed end to end, every residue equal) confirm the
+/// recoding kept the protein. `codonDifferences` lists each aligned codon pair that differs:
+/// its residue `position1`/`position2`, nucleotide `ntPosition1`/`ntPosition2`, both codons
+/// and amino acids, whether the change is `synonymous`, and the `changedBases` (1-3) within
+/// the codon. `codonUsage` gives the shift: changed, synonymous, and nonsynonymous codon
+/// counts, nucleotide identity over the aligned codons, GC and GC3 of each CDS, the codon
+/// counts of each, their `usageDistance` (0 to 1), and the `swaps` from one codon to another
+/// by how often they occur
+#[wasm_bindgen]
+pub fn compare_codon_optimized(seq1: &str, seq2: &str, options: &ComparisonOptions) -> String {
+  let error = |message: &str| format!(r#"{{"error":"{}"}}"#, message);
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (bytes1.to_ascii_uppercase(), bytes2.to_ascii_uppercase());
+  let ((start1, end1), (start2, end2)) = match options.reading_frames() {
+    ReadingFrames::Cds { cds1, cds2 } => (cds1, cds2),
+    ReadingFrames::Search { frame1, frame2, .. } => {
+      ((frame1.unwrap_or(0), bytes1.len()), (frame2.unwrap_or(0), bytes2.len()))
+    }
+  };
+  let region = |bytes: &[u8], start: usize, end: usize| -> Vec<u8> {
+    let end = end.min(bytes.len());
+    let start = start.min(end);
+    bytes[start..start + (end - start) / CODON_SIZE * CODON_SIZE].to_vec()
+  };
+  let (cds1, cds2) = (region(&bytes1, start1, end1), region(&bytes2, start2, end2));
+  if cds1.is_empty() || cds2.is_empty() {
+    return error("Both sequences need at least one codon");
+  }
+  let translation = options.translation();
+  let (aa1, aa2) = (translation.translate(&cds1), translation.translate(&cds2));
+  let alignment = match options.place(&aa1, &aa2) {
+    Ok(alignment) => alignment,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+  let coordinates = options.coordinates();
+
+  let recoding = compare_recoding(&cds1, &cds2, &aa1, &aa2, &alignment);
+
+  let differences: Vec<String> = recoding
+    .differences
+    .iter()
+    .map(|difference| {
+      let changed: Vec<String> = difference.changed_bases.iter().map(usize::to_string).collect();
+      format!(
+        r#"{{"position1":{},"position2":{},"ntPosition1":{},"ntPosition2":{},"codon1":"{}","codon2":"{}","aa1":"{}","aa2":"{}","synonymous":{},"changedBases":[{}]}}"#,
+        coordinates.start(difference.residue1),
+        coordinates.start(difference.residue2),
+        coordinates.start(start1 + difference.nt1),
+        coordinates.start(start2 + difference.nt2),
+        String::from_utf8_lossy(&difference.codon1),
+        String::from_utf8_lossy(&difference.codon2),
+        difference.aa1 as char,
+        difference.aa2 as char,
+        difference.synonymous,
+        changed.join(",")
+      )
+    })
+    .collect();
+  let swaps: Vec<String> = recoding
+    .swaps
+    .iter()
+    .map(|swap| {
+      format!(
+        r#"{{"from":"{}","to":"{}","aa":"{}","count":{}}}"#,
+        String::from_utf8_lossy(&swap.from),
+        String::from_utf8_lossy(&swap.to),
+        swap.aa as char,
+        swap.count
+      )
+    })
+    .collect();
+
+  let changed = differences.len();
+  format!(
+    r#"{{"coordinateSystem":"{}","cds1":{{"start":{},"end":{}}},"cds2":{{"start":{},"end":{}}},"protein1Length":{},"protein2Length":{},"offset1":{},"offset2":{},"length":{},"proteinIdentity":{},"proteinIdentical":{},"codonDifferences":[{}],"codonUsage":{{"changedCodons":{},"synonymousChanges":{},"nonsynonymousChanges":{},"changedCodonFraction":{},"nucleotideIdentity":{},"gc1":{},"gc2":{},"gc3_1":{},"gc3_2":{},"usage1":{},"usage2":{},"usageDistance":{},"swaps":[{}]}}}}"#,
+    coordinates.name(),
+    coordinates.start(start1),
+    coordinates.end(start1 + cds1.len()),
+    coordinates.start(start2),
+    coordinates.end(start2 + cds2.len()),
+    aa1.len(),
+    aa2.len(),
+    coordinates.start(alignment.offset1),
+    coordinates.start(alignment.offset2),
+    alignment.length,
+    recoding.protein_identity,
+    recoding.protein_identical,
+    differences.join(","),
+    changed,
+    recoding.synonymous,
+    changed - recoding.synonymous,
+    recoding.changed_codon_fraction,
+    recoding.nucleotide_identity,
+    recoding.gc1,
+    recoding.gc2,
+    recoding.gc3_1,
+    recoding.gc3_2,
+    usage_json(&recoding.usage1),
+    usage_json(&recoding.usage2),
+    recoding.usage_distance,
+    swaps.join(",")
+  )
+}
@@ -0,0 +1,511 @@
+use gene_compare_core::alignment::{find_best_alignment_in_profile, match_profile, profile_scratch_bytes, seeded_scratch_bytes, Alignment, SeedIndex};
+use gene_compare_core::blocks::{count_mismatches_in_mask, find_blocks, BlockDetection};
+use gene_compare_core::comparison::{compare_regions, empty_nucleotide_result, mask_result_json};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::limits::{check_memory, EXHAUSTIVE_TOO_LARGE, SEEDED_TOO_LARGE};
+use gene_compare_core::scoring::Scoring;
+use wasm_bindgen::prelude::*;
+
+use crate::blocks::{aligned_blocks, AlignedBlock};
+use crate::options::ComparisonOptions;
+use crate::progress::Progress;
+use crate::protein_comparison;
+
+// ============================================================================
+// Comparison Sessions
+// ============================================================================
+// A Comparator owns both sequences plus everything derived from them that
+// does not depend on thresholds: the per-offset match profile (computed on the
+// first exhaustive comparison) and the seed index (on the first seeded one).
+// Moving a UI slider then only re-runs selection, masking, and block detection;
+// block-parameter sliders can skip straight to block detection. The result
+// format and block detection come from `with_options` and hold for every
+// result of the session.
+//
+// `serialize` stores the sequences, the match profile, and the latest
+// placement as bytes (e.g. for IndexedDB); `deserialize` restores them
+// without recomputing. The seed index and mask are cheap to rebuild and are
+// left out. All integers are little-endian u64s.
+
+/// Leads every serialized session; the digit is the format version
+const SESSION_MAGIC: &[u8; 4] = b"GCS1";
+
+#[wasm_bindgen]
+pub struct Comparator {
+  seq1: Vec<u8>,
+  seq2: Vec<u8>,
+  profile: Option<Vec<usize>>,
+  seeds: Option<SeedIndex>,
+  /// Placement and mask from the latest `compare` / `compare_seeded`
+  last: Option<(Alignment, Vec<u8>)>,
+  format: ResultFormat,
+  detection: BlockDetection,
+}
+
+#[wasm_bindgen]
+impl Comparator {
+  #[wasm_bindgen(constructor)]
+  pub fn new(seq1: &str, seq2: &str) -> Comparator {
+    Comparator::from_bytes(seq1.as_bytes(), seq2.as_bytes())
+  }
+
+  pub fn from_bytes(seq1: &[u8], seq2: &[u8]) -> Comparator {
+    Comparator {
+      seq1: seq1.to_vec(),
+      seq2: seq2.to_vec(),
+      profile: None,
+      seeds: None,
+      last: None,
+      format: ResultFormat::default(),
+      detection: BlockDetection::default(),
+    }
+  }
+
+  /// Take the options' result format (coordinate system, mask symbols) and block detection
+  /// (`with_block_exit_identity`, `with_trim_block_edges`) for every result of the session;
+  /// its thresholds and search settings stay with the arguments of each call. Neither is
+  /// serialized
+  pub fn with_options(mut self, options: &ComparisonOptions) -> Comparator {
+    self.format = *options.format();
+    self.detection = options.block_detection();
+    self
+  }
+
+  /// Session with two empty sequences, to be filled with `push_chunk`
+  pub fn empty() -> Comparator {
+    Comparator::from_bytes(&[], &[])
+  }
+
+  /// Append `chunk` to sequence `seq_id` (1 or 2), so sequences too large to hold as one
+  /// JS string (whole chromosomes) can be streamed in piece by piece. Chunks are appended
+  /// verbatim; cached search structures are rebuilt on the next comparison.
+  pub fn push_chunk(&mut self, seq_id: u32, chunk: &str) -> Result<(), JsError> {
+    self.push_chunk_bytes(seq_id, chunk.as_bytes())
+  }
+
+  /// `push_chunk` for `Uint8Array` chunks (e.g. straight from a `ReadableStream`)
+  pub fn push_chunk_bytes(&mut self, seq_id: u32, chunk: &[u8]) -> Result<(), JsError> {
+    let seq = match seq_id {
+      1 => &mut self.seq1,
+      2 => &mut self.seq2,
+      _ => return Err(JsError::new("seq_id must be 1 or 2")),
+    };
+    seq.extend_from_slice(chunk);
+    self.profile = None;
+    self.seeds = None;
+    self.last = None;
+    Ok(())
+  }
+
+  /// The session as bytes for `deserialize`: both sequences, the match profile if computed,
+  /// and the latest placement
+  pub fn serialize(&self) -> Vec<u8> {
+    let mut bytes = SESSION_MAGIC.to_vec();
+    let mut push = |value: usize| bytes.extend_from_slice(&(value as u64).to_le_bytes());
+    push(self.seq1.len());
+    push(self.seq2.len());
+    bytes.extend_from_slice(&self.seq1);
+    bytes.extend_from_slice(&self.seq2);
+    match &self.profile {
+      Some(profile) => {
+        bytes.push(1);
+        bytes.extend_from_slice(&(profile.len() as u64).to_le_bytes());
+        for &count in profile {
+          bytes.extend_from_slice(&(count as u64).to_le_bytes());
+        }
+      }
+      None => bytes.push(0),
+    }
+    match &self.last {
+      Some((alignment, _)) => {
+        bytes.push(1);
+        for value in [alignment.offset1, alignment.offset2, alignment.length, alignment.mismatches] {
+          bytes.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+        bytes.extend_from_slice(&alignment.identity.to_bits().to_le_bytes());
+      }
+      None => bytes.push(0),
+    }
+    bytes
+  }
+
+  /// Session stored by `serialize`; `recompute_blocks` and `blocks` work at once when it had
+  /// been compared. Errors on bytes that are not a serialized session, including one whose
+  /// match profile does not fit its sequences
+  pub fn deserialize(bytes: &[u8]) -> Result<Comparator, JsError> {
+    decode_session(bytes).ok_or_else(|| JsError::new("not a serialized Comparator session"))
+  }
+
+  /// Current length of sequence `seq_id` (1 or 2; 0 for anything else)
+  pub fn sequence_length(&self, seq_id: u32) -> usize {
+    match seq_id {
+      1 => self.seq1.len(),
+      2 => self.seq2.len(),
+      _ => 0,
+    }
+  }
+
+  /// Same result as `compare_sequences_full` on the session's sequences
+  pub fn compare(
+    &mut self,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+    min_sequence_overlap_pct: f64,
+  ) -> String {
+    if self.seq1.is_empty() || self.seq2.is_empty() {
+      return empty_nucleotide_result(&self.format);
+    }
+    if self.profile.is_none() {
+      if let Err(exceeded) = check_memory(profile_scratch_bytes(self.seq1.len(), self.seq2.len()), EXHAUSTIVE_TOO_LARGE) {
+        return exceeded.to_json();
+      }
+    }
+    let (seq1, seq2) = (&self.seq1, &self.seq2);
+    let profile = self.profile.get_or_insert_with(|| match_profile(seq1, seq2));
+    let alignment = find_best_alignment_in_profile(seq1.len(), seq2.len(), profile, min_sequence_overlap_pct);
+    self.finish(alignment, segment_window_length, min_identity, min_significant_length_group)
+  }
+
+  /// Like `compare`, but places the sequences with the k-mer seeded offset search
+  pub fn compare_seeded(
+    &mut self,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+    min_sequence_overlap_pct: f64,
+  ) -> String {
+    if self.seq1.is_empty() || self.seq2.is_empty() {
+      return empty_nucleotide_result(&self.format);
+    }
+    if self.seeds.is_none() {
+      if let Err(exceeded) = check_memory(seeded_scratch_bytes(self.seq1.len(), self.seq2.len()), SEEDED_TOO_LARGE) {
+        return exceeded.to_json();
+      }
+    }
+    let (seq1, seq2) = (&self.seq1, &self.seq2);
+    let seeds = self.seeds.get_or_insert_with(|| SeedIndex::new(seq1));
+    let alignment = seeds.align(seq1, seq2, min_sequence_overlap_pct);
+    self.finish(alignment, segment_window_length, min_identity, min_significant_length_group)
+  }
+
+  /// Re-run only conserved-block detection on the placement and mask from the latest
+  /// `compare` / `compare_seeded`, for block-parameter sliders
+  pub fn recompute_blocks(
+    &self,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> Result<String, JsError> {
+    if self.seq1.is_empty() || self.seq2.is_empty() {
+      return Ok(empty_nucleotide_result(&self.format));
+    }
+    let (alignment, mask) = self.last.as_ref().ok_or_else(|| JsError::new("call compare or compare_seeded first"))?;
+    Ok(mask_result_json(
+      &self.seq1,
+      &self.seq2,
+      alignment,
+      mask,
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      &self.format,
+      &Scoring::default(),
+      self.detection,
+      &mut Progress::none(),
+    ))
+  }
+
+  /// Conserved blocks of the latest `compare` / `compare_seeded` placement as
+  /// `ConservedBlock` objects, detected with the given block parameters and the session's
+  /// block detection
+  pub fn blocks(
+    &self,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> Result<Vec<AlignedBlock>, JsError> {
+    let (alignment, mask) = self.last.as_ref().ok_or_else(|| JsError::new("call compare or compare_seeded first"))?;
+    let blocks = find_blocks(mask, segment_window_length, min_identity, min_significant_length_group, self.detection);
+    Ok(aligned_blocks(alignment, &blocks, self.format.coordinates))
+  }
+
+  /// Replace `old` at the 0-based `position` of sequence `seq_id` (1 or 2) with `new`, for
+  /// trying candidate corrections, and return the result of the latest `compare` /
+  /// `compare_seeded` placement on the edited sequences without searching offsets again:
+  /// only the edited mask columns are rebuilt (every column after the edit when it changes
+  /// the length), then the statistics and blocks. Edits before the aligned region move it.
+  /// Errors when `old` is not at `position` or nothing was compared yet; `compare` afterwards
+  /// searches offsets again
+  #[allow(clippy::too_many_arguments)]
+  pub fn apply_edit(
+    &mut self,
+    seq_id: u32,
+    position: usize,
+    old: &str,
+    new: &str,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> Result<String, JsError> {
+    let (old, new) = (old.as_bytes(), new.as_bytes());
+    if self.last.is_none() {
+      return Err(JsError::new("call compare or compare_seeded first"));
+    }
+    let (seq, i) = match seq_id {
+      1 => (&mut self.seq1, 0),
+      2 => (&mut self.seq2, 1),
+      _ => return Err(JsError::new("seq_id must be 1 or 2")),
+    };
+    if seq.get(position..position + old.len()) != Some(old) {
+      return Err(JsError::new("old does not match the sequence at position"));
+    }
+    seq.splice(position..position + old.len(), new.iter().copied());
+    self.profile = None;
+    if i == 0 {
+      self.seeds = None;
+    }
+
+    let (alignment, mut mask) = self.last.take().ok_or_else(|| JsError::new("call compare or compare_seeded first"))?;
+    let mut offsets = [alignment.offset1, alignment.offset2];
+    // First column to rebuild, and whether every column from there on is
+    let (first_column, rebuild_tail) = if position + old.len() <= offsets[i] {
+      offsets[i] = offsets[i] + new.len() - old.len();
+      (None, false)
+    } else if position < offsets[i] {
+      // The edit straddles the start of the region, which now starts with it
+      offsets[i] = position;
+      (Some(0), true)
+    } else {
+      (Some(position - offsets[i]), old.len() != new.len())
+    };
+    let length = if rebuild_tail {
+      (self.seq1.len() - offsets[0]).min(self.seq2.len() - offsets[1])
+    } else {
+      alignment.length
+    };
+    let start = first_column.unwrap_or(length).min(length).min(mask.len());
+    let (end, old_end) = if rebuild_tail {
+      (length, mask.len())
+    } else {
+      let end = (start + new.len()).min(length);
+      (end, end)
+    };
+    let rebuilt = compare_regions(&self.seq1[offsets[0] + start..offsets[0] + end], &self.seq2[offsets[1] + start..offsets[1] + end]).0;
+    mask.splice(start..old_end, rebuilt);
+
+    let mismatches = count_mismatches_in_mask(&mask);
+    let identity = if length == 0 { 0.0 } else { 1.0 - mismatches as f64 / length as f64 };
+    let alignment = Alignment { offset1: offsets[0], offset2: offsets[1], length, mismatches, identity };
+    Ok(self.keep(alignment, mask, segment_window_length, min_identity, min_significant_length_group))
+  }
+
+  /// Same result as `compare_proteins_full` on the session's sequences
+  pub fn compare_proteins(
+    &self,
+    nuc_offset1: i32,
+    nuc_offset2: i32,
+    nuc_length: i32,
+    aa_segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> String {
+    protein_comparison(
+      &self.seq1,
+      &self.seq2,
+      nuc_offset1,
+      nuc_offset2,
+      nuc_length,
+      aa_segment_window_length,
+      min_identity,
+      min_significant_length_group,
+    )
+  }
+}
+
+impl Comparator {
+  /// Mask and JSON for a new placement
+  fn finish(
+    &mut self,
+    alignment: Alignment,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> String {
+    let mask = alignment.mask(&self.seq1, &self.seq2);
+    self.keep(alignment, mask, segment_window_length, min_identity, min_significant_length_group)
+  }
+
+  /// JSON for a placement and its mask, keeping both for `recompute_blocks` and `apply_edit`
+  fn keep(
+    &mut self,
+    alignment: Alignment,
+    mask: Vec<u8>,
+    segment_window_length: usize,
+    min_identity: f64,
+    min_significant_length_group: f64,
+  ) -> String {
+    let json = mask_result_json(
+      &self.seq1,
+      &self.seq2,
+      &alignment,
+      &mask,
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      &self.format,
+      &Scoring::default(),
+      self.detection,
+      &mut Progress::none(),
+    );
+    self.last = Some((alignment, mask));
+    json
+  }
+}
+
+/// Reads a serialized session front to back
+struct SessionReader<'a> {
+  bytes: &'a [u8],
+}
+
+impl<'a> SessionReader<'a> {
+  fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+    let (taken, rest) = self.bytes.split_at_checked(len)?;
+    self.bytes = rest;
+    Some(taken)
+  }
+
+  fn number(&mut self) -> Option<u64> {
+    Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+  }
+
+  fn size(&mut self) -> Option<usize> {
+    usize::try_from(self.number()?).ok()
+  }
+
+  fn flag(&mut self) -> Option<bool> {
+    match self.take(1)?[0] {
+      0 => Some(false),
+      1 => Some(true),
+      _ => None,
+    }
+  }
+}
+
+fn decode_session(bytes: &[u8]) -> Option<Comparator> {
+  let mut reader = SessionReader { bytes };
+  if reader.take(SESSION_MAGIC.len())? != SESSION_MAGIC {
+    return None;
+  }
+  let (len1, len2) = (reader.size()?, reader.size()?);
+  let mut comparator = Comparator::from_bytes(reader.take(len1)?, reader.take(len2)?);
+  if reader.flag()? {
+    let count = reader.size()?;
+    // A profile has one count per offset, so a well-formed one fits in the remaining bytes
+    if count > reader.bytes.len() / 8 || len1 == 0 || len2 == 0 || count != len1 + len2 - 1 {
+      return None;
+    }
+    let profile: Vec<usize> = (0..count).map(|_| reader.size()).collect::<Option<_>>()?;
+    // Entry k counts the matches where seq2 starts at offset k - (len2 - 1) of seq1
+    let overlap = |k: usize| (len1 - k.saturating_sub(len2 - 1)).min(len2 - (len2 - 1).saturating_sub(k));
+    if profile.iter().enumerate().any(|(k, &matches)| matches > overlap(k)) {
+      return None;
+    }
+    comparator.profile = Some(profile);
+  }
+  if reader.flag()? {
+    let (offset1, offset2, length, mismatches) = (reader.size()?, reader.size()?, reader.size()?, reader.size()?);
+    let identity = f64::from_bits(reader.number()?);
+    if offset1.checked_add(length)? > comparator.seq1.len() || offset2.checked_add(length)? > comparator.seq2.len() {
+      return None;
+    }
+    let alignment = Alignment { offset1, offset2, length, mismatches, identity };
+    let mask = alignment.mask(&comparator.seq1, &comparator.seq2);
+    comparator.last = Some((alignment, mask));
+  }
+  reader.bytes.is_empty().then_some(comparator)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn session_options_hold_for_every_result() {
+    let (seq1, seq2) = pair();
+    let options = restyled().with_one_based(true).with_block_exit_identity(Some(0.5));
+    let mut plain = Comparator::new(&seq1, &seq2);
+    let mut styled = Comparator::new(&seq1, &seq2).with_options(&options);
+    let result = styled.compare(30, 0.67, 0.15, 0.5);
+    assert_ne!(result, plain.compare(30, 0.67, 0.15, 0.5));
+    assert!(result.contains(r#""mask":"|||||.|"#));
+    assert_eq!(styled.recompute_blocks(30, 0.67, 0.15).ok().unwrap(), result);
+
+    let detected = find_blocks(&plain.last.as_ref().unwrap().1, 30, 0.67, 0.15, options.block_detection());
+    let blocks = styled.blocks(30, 0.67, 0.15).ok().unwrap();
+    assert_eq!(blocks.len(), detected.len());
+    assert_eq!(blocks[0].start1(), detected[0].start + 1);
+
+    let edited = styled.apply_edit(1, 5, &seq1[5..6], &seq2[5..6], 30, 0.67, 0.15).ok().unwrap();
+    assert!(edited.contains(r#""mask":"||||||"#));
+  }
+
+  #[test]
+  fn corrupted_profiles_are_not_sessions() {
+    let (seq1, seq2) = (b"ACGTACGTTA", b"CGTACG");
+    let mut comparator = Comparator::from_bytes(seq1, seq2);
+    comparator.compare(4, 0.67, 0.15, 0.5);
+    let bytes = comparator.serialize();
+    assert!(decode_session(&bytes).is_some());
+
+    let counts = SESSION_MAGIC.len() + 16 + seq1.len() + seq2.len() + 1 + 8;
+    let set = |bytes: &mut Vec<u8>, at: usize, value: u64| bytes[at..at + 8].copy_from_slice(&value.to_le_bytes());
+
+    // Two matches where only seq2's last base overlaps seq1
+    let mut overcounted = bytes.clone();
+    set(&mut overcounted, counts, 2);
+    assert!(decode_session(&overcounted).is_none());
+
+    // One offset short of len1 + len2 - 1
+    let mut short = bytes.clone();
+    set(&mut short, counts - 8, (seq1.len() + seq2.len() - 2) as u64);
+    short.drain(counts..counts + 8);
+    assert!(decode_session(&short).is_none());
+  }
+
+  #[test]
+  fn a_session_survives_serialization() {
+    let (seq1, seq2) = pair();
+    let mut comparator = Comparator::new(&seq1, &seq2);
+    assert_eq!(decode_session(&comparator.serialize()).unwrap().sequence_length(2), seq2.len());
+
+    let result = comparator.compare(30, 0.67, 0.15, 0.5);
+    let bytes = comparator.serialize();
+    let mut restored = decode_session(&bytes).unwrap();
+    assert_eq!(restored.recompute_blocks(30, 0.67, 0.15).ok().unwrap(), result);
+    assert_eq!(restored.serialize(), bytes);
+    assert_eq!(restored.compare(30, 0.67, 0.15, 0.5), result);
+
+    assert!(decode_session(&bytes[..bytes.len() - 1]).is_none());
+    assert!(decode_session(&[bytes.as_slice(), &[0]].concat()).is_none());
+    assert!(decode_session(b"not a session").is_none());
+  }
+
+  #[test]
+  fn edits_keep_the_placement() {
+    let (seq1, seq2) = pair();
+    let mut comparator = Comparator::new(&seq1, &seq2);
+    comparator.compare_seeded(30, 0.67, 0.15, 0.5);
+    let corrected: String = seq2[..5].to_string() + &seq1[5..6] + &seq2[6..];
+    let edited = comparator.apply_edit(2, 5, &seq2[5..6], &seq1[5..6], 30, 0.67, 0.15).ok().unwrap();
+    assert_eq!(edited, Comparator::new(&seq1, &corrected).compare_seeded(30, 0.67, 0.15, 0.5));
+
+    // Deleting a prefix before the aligned region moves the region
+    let prefixed = format!("TT{}", seq1);
+    let mut comparator = Comparator::new(&seq1, &prefixed);
+    comparator.compare(30, 0.67, 0.15, 0.5);
+    let edited = comparator.apply_edit(2, 0, "TT", "", 30, 0.67, 0.15).ok().unwrap();
+    assert_eq!(edited, Comparator::new(&seq1, &seq1).compare(30, 0.67, 0.15, 0.5));
+  }
+}
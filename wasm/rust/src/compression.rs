@@ -0,0 +1,117 @@
+use miniz_oxide::deflate::{compress_to_vec, compress_to_vec_zlib};
+
+// ============================================================================
+// Compressed Results
+// ============================================================================
+// Multi-megabyte JSON strings are slow to hand to Workers and to persist, so
+// results can leave Rust as compressed bytes instead. The formats are those of
+// the browser's DecompressionStream: "gzip", "deflate" (zlib-wrapped), and
+// "deflate-raw". miniz_oxide does the deflating; gzip adds its own header
+// and CRC-32 trailer here.
+
+/// Deflate level: fast, and close to the best ratio on repetitive JSON
+const LEVEL: u8 = 6;
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Compression {
+  #[default]
+  None,
+  Gzip,
+  Deflate,
+  DeflateRaw,
+}
+
+impl Compression {
+  /// "gzip", "deflate", "deflate-raw", or "none"/"" (case-insensitive); `None` for anything else
+  pub fn from_name(name: &str) -> Option<Compression> {
+    match name.to_ascii_lowercase().as_str() {
+      "" | "none" => Some(Compression::None),
+      "gzip" => Some(Compression::Gzip),
+      "deflate" => Some(Compression::Deflate),
+      "deflate-raw" => Some(Compression::DeflateRaw),
+      _ => None,
+    }
+  }
+
+  pub fn name(self) -> &'static str {
+    match self {
+      Compression::None => "none",
+      Compression::Gzip => "gzip",
+      Compression::Deflate => "deflate",
+      Compression::DeflateRaw => "deflate-raw",
+    }
+  }
+
+  pub fn compress(self, data: &[u8]) -> Vec<u8> {
+    match self {
+      Compression::None => data.to_vec(),
+      Compression::Gzip => gzip(data),
+      Compression::Deflate => compress_to_vec_zlib(data, LEVEL),
+      Compression::DeflateRaw => compress_to_vec(data, LEVEL),
+    }
+  }
+}
+
+const fn crc32_table() -> [u32; 256] {
+  let mut table = [0u32; 256];
+  let mut i = 0;
+  while i < 256 {
+    let mut crc = i as u32;
+    let mut bit = 0;
+    while bit < 8 {
+      crc = if crc & 1 == 1 { 0xEDB8_8320 ^ (crc >> 1) } else { crc >> 1 };
+      bit += 1;
+    }
+    table[i] = crc;
+    i += 1;
+  }
+  table
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+fn crc32(data: &[u8]) -> u32 {
+  !data.iter().fold(!0u32, |crc, &byte| CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// RFC 1952 member: a minimal header (no name or time, unknown OS), the deflate stream, then
+/// the CRC-32 and length of the input
+fn gzip(data: &[u8]) -> Vec<u8> {
+  let mut out = vec![0x1F, 0x8B, 8, 0, 0, 0, 0, 0, 0, 0xFF];
+  out.extend(compress_to_vec(data, LEVEL));
+  out.extend_from_slice(&crc32(data).to_le_bytes());
+  out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use miniz_oxide::inflate::{decompress_to_vec, decompress_to_vec_zlib};
+
+  #[test]
+  fn crc32_matches_the_check_value() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414F_A339);
+  }
+
+  #[test]
+  fn gzip_members_inflate_back_to_the_input() {
+    let data = br#"{"mask":"ACGT??ACGT","conservedBlocks":[]}"#.repeat(50);
+    let member = Compression::Gzip.compress(&data);
+    assert_eq!(member[..4], [0x1F, 0x8B, 8, 0]);
+    let (body, trailer) = member[10..].split_at(member.len() - 18);
+    assert_eq!(decompress_to_vec(body).unwrap(), data);
+    assert_eq!(trailer[..4], crc32(&data).to_le_bytes());
+    assert_eq!(trailer[4..], (data.len() as u32).to_le_bytes());
+  }
+
+  #[test]
+  fn deflate_formats_inflate_back_to_the_input() {
+    let data = b"ACGTACGTTTGA".repeat(20);
+    assert_eq!(decompress_to_vec_zlib(&Compression::Deflate.compress(&data)).unwrap(), data);
+    assert_eq!(decompress_to_vec(&Compression::DeflateRaw.compress(&data)).unwrap(), data);
+    assert_eq!(Compression::None.compress(&data), data);
+  }
+}
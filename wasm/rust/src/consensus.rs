@@ -0,0 +1,81 @@
+use gene_compare_core::consensus::{pair_consensus, read_pair_consensus as core_read_pair_consensus, ConsensusPolicy};
+use gene_compare_core::DEFAULT_MIN_SEQUENCE_OVERLAP_PCT;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Consensus of an Aligned Pair (exported)
+// ============================================================================
+/// Consensus of two overlapping reads (e.g. two clones of the same insert).
+/// The pair is placed with the standard offset search; columns covered by only one
+/// read take that read's base, so the consensus spans the union of both reads.
+/// `policy` is "iupac" (default) or "majority". An error when the offset search would
+/// exceed the memory limit.
+#[wasm_bindgen]
+pub fn consensus(seq1: &str, seq2: &str, policy: &str) -> Result<String, JsError> {
+  let bytes1 = seq1.as_bytes();
+  let bytes2 = seq2.as_bytes();
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return Ok(String::from_utf8_lossy(if bytes1.is_empty() { bytes2 } else { bytes1 }).to_ascii_uppercase());
+  }
+
+  let policy = ConsensusPolicy::parse(policy);
+  let alignment = ComparisonOptions::placement(DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, false)
+    .place(bytes1, bytes2)
+    .map_err(|exceeded| JsError::new(&exceeded.to_json()))?;
+
+  Ok(String::from_utf8_lossy(&pair_consensus(bytes1, bytes2, &alignment, policy)).into_owned())
+}
+
+// ============================================================================
+// Forward / Reverse Read Consensus (exported)
+// ============================================================================
+/// Consensus of a forward read and a reverse read of the same template, in the forward
+/// read's orientation; `forward_quals` / `reverse_quals` are the reads' Phred scores as
+/// called (empty when there are none). Columns only one read covers take its base. Gives
+/// the `consensus`, a quality per base (`qualities`: reads agreeing add up, capped at 60;
+/// a resolved conflict keeps the difference; an ambiguous one is 0), where the reverse read
+/// starts in it (`reverseStart`, negative when it starts first), the `overlap` length, and
+/// the `conflicts` (0-based consensus `position`, both calls and qualities, and whether the
+/// `forward` or `reverse` call was taken or the column left `ambiguous`)
+#[wasm_bindgen]
+pub fn read_pair_consensus(forward: &str, forward_quals: &[u8], reverse: &str, reverse_quals: &[u8]) -> String {
+  if (!forward_quals.is_empty() && forward_quals.len() != forward.len())
+    || (!reverse_quals.is_empty() && reverse_quals.len() != reverse.len())
+  {
+    return r#"{"error":"quals must be empty or have one Phred score per base of their read"}"#.to_string();
+  }
+  let options = ComparisonOptions::placement(DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, false);
+  let pair = match core_read_pair_consensus(forward.as_bytes(), forward_quals, reverse.as_bytes(), reverse_quals, |a, b| options.place(a, b)) {
+    Ok(pair) => pair,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+
+  let text = |bytes: &[u8]| serde_json::to_string(&String::from_utf8_lossy(bytes)).unwrap_or_else(|_| "\"\"".to_string());
+  let quality_json = |quality: Option<u8>| quality.map_or("null".to_string(), |quality| quality.to_string());
+  let conflicts: Vec<String> = pair
+    .conflicts
+    .iter()
+    .map(|conflict| {
+      format!(
+        r#"{{"position":{},"forward":{},"forwardQuality":{},"reverse":{},"reverseQuality":{},"resolution":"{}"}}"#,
+        conflict.position,
+        text(&[conflict.forward]),
+        quality_json(conflict.forward_quality),
+        text(&[conflict.reverse]),
+        quality_json(conflict.reverse_quality),
+        conflict.resolution.name()
+      )
+    })
+    .collect();
+  let qualities: Vec<String> = pair.qualities.iter().map(u8::to_string).collect();
+  format!(
+    r#"{{"consensus":{},"qualities":[{}],"reverseStart":{},"overlap":{},"conflicts":[{}]}}"#,
+    text(&pair.bases),
+    qualities.join(","),
+    pair.reverse_start,
+    pair.overlap,
+    conflicts.join(",")
+  )
+}
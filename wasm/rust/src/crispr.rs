@@ -0,0 +1,130 @@
+use gene_compare_core::crispr::{pam_changes, Guide, GuideSite};
+use gene_compare_core::format::CoordinateSystem;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// CRISPR Guide Sites (exported)
+// ============================================================================
+
+fn text(bytes: &[u8]) -> String {
+  serde_json::to_string(&String::from_utf8_lossy(bytes)).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn sites_json(seq: &[u8], sites: &[GuideSite], coordinates: CoordinateSystem, pam_length: usize) -> Vec<String> {
+  sites
+    .iter()
+    .map(|site| {
+      let start = site.hit.position;
+      format!(
+        r#"{{"strand":"{}","start":{},"end":{},"pamStart":{},"cutSite":{},"mismatches":{},"match":{},"pam":{}}}"#,
+        if site.hit.reverse { '-' } else { '+' },
+        coordinates.start(start),
+        coordinates.end(start + site.length),
+        coordinates.start(site.pam_start),
+        coordinates.start(site.cut),
+        site.hit.mismatches,
+        text(&seq[start..start + site.length]),
+        text(&seq[site.pam_start..site.pam_start + pam_length])
+      )
+    })
+    .collect()
+}
+
+/// Sites of a CRISPR guide in two alleles and what their differences do to them. `guide` is
+/// the spacer (RNA or DNA) and `pam` the IUPAC PAM that must follow the protospacer (NGG
+/// when empty); `sites1` and `sites2` list each protospacer and PAM match with at most
+/// `max_mismatches` mismatches, all in the protospacer: `strand`, `start`, `end`,
+/// `pamStart`, the `cutSite` 3 bases upstream of the PAM, `mismatches`, and the matched
+/// bases. Over the best placement of the two, `pamChanges` lists PAMs on either strand that
+/// the differences create or destroy, and `siteChanges` the guide sites whose mismatches
+/// differ between the alleles (null where one has no site). An invalid guide or PAM gives
+/// `{"error": ...}`
+#[wasm_bindgen]
+pub fn crispr_guide_sites(
+  seq1: &str,
+  seq2: &str,
+  guide: &str,
+  pam: &str,
+  max_mismatches: usize,
+  options: &ComparisonOptions,
+) -> String {
+  let Some(guide) = Guide::new(guide, pam) else {
+    return r#"{"error":"The guide must be A/C/G/T/U bases and the PAM IUPAC codes"}"#.to_string();
+  };
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let coordinates = options.coordinates();
+  let (sites1, sites2) = (guide.find_sites(bytes1, max_mismatches), guide.find_sites(bytes2, max_mismatches));
+
+  let (mut pams, mut site_changes) = (Vec::new(), Vec::new());
+  if !bytes1.is_empty() && !bytes2.is_empty() {
+    let alignment = match options.place(bytes1, bytes2) {
+      Ok(alignment) => alignment,
+      Err(exceeded) => return exceeded.to_json(),
+    };
+    let strand = |reverse: bool| if reverse { '-' } else { '+' };
+    for change in pam_changes(bytes1, bytes2, &alignment, &guide) {
+      let (position1, position2) = (alignment.offset1 + change.column, alignment.offset2 + change.column);
+      let pam_length = guide.pam.len();
+      let mismatches: Vec<String> =
+        change.mismatches.iter().map(|&column| coordinates.start(alignment.offset1 + column).to_string()).collect();
+      pams.push(format!(
+        r#"{{"strand":"{}","change":"{}","position1":{},"position2":{},"pam1":{},"pam2":{},"mismatches":[{}]}}"#,
+        strand(change.reverse),
+        change.change(),
+        coordinates.start(position1),
+        coordinates.start(position2),
+        text(&bytes1[position1..position1 + pam_length]),
+        text(&bytes2[position2..position2 + pam_length]),
+        mismatches.join(",")
+      ));
+    }
+
+    // Sites in the aligned region, by column and strand, paired across the alleles
+    let columns = |sites: &[GuideSite], offset: usize| -> Vec<(usize, bool, usize)> {
+      sites
+        .iter()
+        .filter_map(|site| {
+          let column = site.hit.position.checked_sub(offset)?;
+          (column + site.length <= alignment.length).then_some((column, site.hit.reverse, site.hit.mismatches))
+        })
+        .collect()
+    };
+    let (columns1, columns2) = (columns(&sites1, alignment.offset1), columns(&sites2, alignment.offset2));
+    let mut keys: Vec<(usize, bool)> =
+      columns1.iter().chain(&columns2).map(|&(column, reverse, _)| (column, reverse)).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    for (column, reverse) in keys {
+      let find = |columns: &[(usize, bool, usize)]| {
+        columns.iter().find(|site| (site.0, site.1) == (column, reverse)).map(|site| site.2)
+      };
+      let (mismatches1, mismatches2) = (find(&columns1), find(&columns2));
+      if mismatches1 == mismatches2 {
+        continue;
+      }
+      let optional = |value: Option<usize>| value.map_or("null".to_string(), |value| value.to_string());
+      site_changes.push(format!(
+        r#"{{"strand":"{}","position1":{},"position2":{},"mismatches1":{},"mismatches2":{}}}"#,
+        strand(reverse),
+        coordinates.start(alignment.offset1 + column),
+        coordinates.start(alignment.offset2 + column),
+        optional(mismatches1),
+        optional(mismatches2)
+      ));
+    }
+  }
+
+  format!(
+    r#"{{"coordinateSystem":"{}","guide":{},"pam":{},"sites1":[{}],"sites2":[{}],"pamChanges":[{}],"siteChanges":[{}]}}"#,
+    coordinates.name(),
+    text(&guide.protospacer),
+    text(&guide.pam),
+    sites_json(bytes1, &sites1, coordinates, guide.pam.len()).join(","),
+    sites_json(bytes2, &sites2, coordinates, guide.pam.len()).join(","),
+    pams.join(","),
+    site_changes.join(",")
+  )
+}
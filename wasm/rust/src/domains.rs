@@ -0,0 +1,81 @@
+use gene_compare_core::blocks::conserved_segments;
+use gene_compare_core::symbols::{GAP, MISMATCH};
+use gene_compare_core::{CODON_SIZE, DEFAULT_SEGMENT_WINDOW_LENGTH};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::mapping::{result_mask, Placement};
+
+// ============================================================================
+// Domain Boundary Suggestions (exported)
+// ============================================================================
+// Folded domains are under tighter constraint than the linkers and tails
+// between them, so two homologous proteins stay identical over a domain and
+// diverge across a linker. Sustained conservation in the comparison mask is a
+// cheap first guess at domain boundaries when no domain database is at hand.
+
+fn identity(mask: &[u8]) -> f64 {
+  let identical = mask.iter().filter(|&&c| c != MISMATCH && c != GAP).count();
+  if mask.is_empty() { 0.0 } else { identical as f64 / mask.len() as f64 }
+}
+
+/// Putative domains of a protein comparison result (from `compare_proteins_with_options`,
+/// `compare_proteins_direct`, and the like): stretches where the identity of the `window`
+/// residues around each column stays at or above `min_identity`, at least `min_length`
+/// residues long, as `domains` with their `start1`/`end1` and `start2`/`end2` in each
+/// protein (the result's coordinate system) and `identity`, and the `linkers` between
+/// consecutive domains. `window` of 0 uses the default amino-acid window and `min_length`
+/// of 0 the window; an unreadable result gives `{"error": ...}`
+#[wasm_bindgen]
+pub fn suggest_domain_boundaries(result: &str, window: usize, min_identity: f64, min_length: usize) -> String {
+  let Some(result) = serde_json::from_str::<Value>(result).ok().filter(Value::is_object) else {
+    return r#"{"error":"The comparison result could not be read"}"#.to_string();
+  };
+  let Some(placement) = Placement::from_result(&result) else {
+    return r#"{"error":"The comparison result has no offsets and length"}"#.to_string();
+  };
+  let mask = result_mask(&result);
+  let mask = &mask[..placement.length.min(mask.len())];
+  let window = if window == 0 { DEFAULT_SEGMENT_WINDOW_LENGTH / CODON_SIZE } else { window };
+  let min_length = if min_length == 0 { window } else { min_length };
+  let segments = conserved_segments(mask, window, min_identity, min_length);
+
+  let coordinates = placement.coordinates;
+  let span = |start: usize, end: usize| {
+    format!(
+      r#""start1":{},"end1":{},"start2":{},"end2":{},"length":{},"identity":{}"#,
+      coordinates.start(placement.offsets[0] + start),
+      coordinates.end(placement.offsets[0] + end),
+      coordinates.start(placement.offsets[1] + start),
+      coordinates.end(placement.offsets[1] + end),
+      end - start,
+      identity(&mask[start..end])
+    )
+  };
+  let domains: Vec<String> = segments.iter().map(|&(start, end)| format!("{{{}}}", span(start, end))).collect();
+  let linkers: Vec<String> = segments.windows(2).map(|pair| format!("{{{}}}", span(pair[0].1, pair[1].0))).collect();
+  format!(
+    r#"{{"coordinateSystem":"{}","window":{},"minIdentity":{},"domains":[{}],"linkers":[{}]}}"#,
+    coordinates.name(),
+    window,
+    min_identity,
+    domains.join(","),
+    linkers.join(",")
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::options::{compare_sequences_with_options, ComparisonOptions};
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn restyled_results_give_the_same_domains() {
+    let (seq1, seq2) = pair();
+    let domains = |options: &ComparisonOptions| {
+      suggest_domain_boundaries(&compare_sequences_with_options(&seq1, &seq2, options), 20, 0.99, 10)
+    };
+    assert_eq!(domains(&restyled()), domains(&ComparisonOptions::new()));
+  }
+}
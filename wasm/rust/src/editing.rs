@@ -0,0 +1,73 @@
+use gene_compare_core::editing::{classify_edit, EditClassification};
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Edited-Allele Outcomes (exported)
+// ============================================================================
+
+fn classification_json(classification: &EditClassification, options: &ComparisonOptions) -> String {
+  let coordinates = options.coordinates();
+  let indel = classification.edited.indel.as_ref().map_or("null".to_string(), |indel| {
+    format!(
+      r#"{{"type":"{}","position":{},"size":{},"inserted":"{}","frameshift":{}}}"#,
+      if indel.length > 0 { "insertion" } else { "deletion" },
+      coordinates.start(indel.position),
+      indel.length.unsigned_abs(),
+      String::from_utf8_lossy(&indel.inserted),
+      indel.length % 3 != 0
+    )
+  });
+  let substitutions: Vec<String> = classification
+    .edited
+    .substitutions
+    .iter()
+    .map(|substitution| {
+      format!(
+        r#"{{"position":{},"reference":"{}","edited":"{}","intended":{}}}"#,
+        coordinates.start(substitution.position),
+        substitution.reference as char,
+        substitution.edited as char,
+        classification.is_intended(substitution)
+      )
+    })
+    .collect();
+  format!(
+    r#"{{"coordinateSystem":"{}","outcome":"{}","indel":{},"substitutions":[{}],"intendedEdits":{},"intendedPresent":{},"outsideWindow":{}}}"#,
+    coordinates.name(),
+    classification.outcome.name(),
+    indel,
+    substitutions.join(","),
+    classification.intended_total,
+    classification.intended_present,
+    classification.outside_window
+  )
+}
+
+/// Outcome of a genome edit from the `reference` amplicon, the `edited` amplicon, and the
+/// `expected` amplicon after perfect HDR (empty when there is no donor template), all
+/// spanning the same primers: `outcome` is "unedited", "perfectHdr" (exactly the intended
+/// edits), "partialHdr" (some of them, or all with other changes), "nhejIndel", or
+/// "substitution". Differences are modelled as substitutions and at most one indel, given as
+/// `indel` (`type`, reference `position`, `size`, `inserted` bases, `frameshift`) and
+/// `substitutions` (each marked `intended` or not), with how many of the `intendedEdits` are
+/// present. With a `cut_site` (in seq1 coordinates of the options), unintended differences
+/// count only within `window` bases of it; the rest are tallied in `outsideWindow`
+#[wasm_bindgen]
+pub fn classify_edited_allele(
+  reference: &str,
+  edited: &str,
+  expected: &str,
+  cut_site: Option<usize>,
+  window: usize,
+  options: &ComparisonOptions,
+) -> String {
+  let (reference, edited, _) = options.normalize(reference, edited);
+  if reference.is_empty() || edited.is_empty() {
+    return r#"{"error":"The reference and edited amplicons must not be empty"}"#.to_string();
+  }
+  let expected = options.normalize_one(expected);
+  let cut_site = cut_site.map(|cut| options.coordinates().start_index(cut as i32).max(0) as usize);
+  classification_json(&classify_edit(&reference, &edited, &expected, cut_site, window), options)
+}
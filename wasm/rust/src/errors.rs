@@ -0,0 +1,222 @@
+use std::fmt;
+
+use js_sys::{Array, Reflect};
+use gene_compare_core::iupac::base_set;
+use gene_compare_core::limits::LimitExceeded;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+use crate::types::{NucleotideResult, ProteinResult};
+use crate::{nucleotide_result_json, protein_comparison};
+
+// ============================================================================
+// Typed Errors
+// ============================================================================
+// The `_full` exports keep returning JSON strings for lib/comparison.js. The
+// checked exports below return the result as a typed object (see types.rs) and
+// throw an Error whose `name` is the error kind, with any details as extra
+// properties.
+
+/// Invalid positions reported per sequence; the count is always exact
+const MAX_REPORTED_POSITIONS: usize = 100;
+
+/// Why a checked comparison could not produce a result
+#[derive(Debug)]
+pub enum ComparisonError {
+  EmptySequence { sequence: u8 },
+  InvalidCharacters { sequence: u8, positions: Vec<usize>, count: usize },
+  MemoryLimitExceeded { required: usize, limit: usize },
+  NoAlignmentFound,
+  FrameSearchFailed,
+  /// The result JSON did not match its typed shape
+  MalformedResult { message: String },
+}
+
+impl ComparisonError {
+  fn kind(&self) -> &'static str {
+    match self {
+      ComparisonError::EmptySequence { .. } => "EmptySequence",
+      ComparisonError::InvalidCharacters { .. } => "InvalidCharacters",
+      ComparisonError::MemoryLimitExceeded { .. } => "MemoryLimitExceeded",
+      ComparisonError::NoAlignmentFound => "NoAlignmentFound",
+      ComparisonError::FrameSearchFailed => "FrameSearchFailed",
+      ComparisonError::MalformedResult { .. } => "MalformedResult",
+    }
+  }
+}
+
+impl fmt::Display for ComparisonError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ComparisonError::EmptySequence { sequence } => write!(f, "sequence {} is empty", sequence),
+      ComparisonError::InvalidCharacters { sequence, count, .. } => {
+        write!(f, "sequence {} contains {} invalid character(s)", sequence, count)
+      }
+      ComparisonError::MemoryLimitExceeded { required, limit } => write!(
+        f,
+        "comparison needs about {} bytes of scratch memory, over the {} byte limit; try seeded mode (Comparator.compare_seeded) or raise set_memory_limit",
+        required, limit
+      ),
+      ComparisonError::NoAlignmentFound => write!(f, "no placement satisfies the minimum overlap"),
+      ComparisonError::FrameSearchFailed => write!(f, "no reading frame combination produced a protein alignment"),
+      ComparisonError::MalformedResult { message } => write!(f, "the comparison result could not be read: {}", message),
+    }
+  }
+}
+
+impl std::error::Error for ComparisonError {}
+
+impl From<LimitExceeded> for ComparisonError {
+  fn from(exceeded: LimitExceeded) -> Self {
+    ComparisonError::MemoryLimitExceeded { required: exceeded.required, limit: exceeded.limit }
+  }
+}
+
+impl From<ComparisonError> for JsValue {
+  fn from(error: ComparisonError) -> JsValue {
+    let js_error = js_sys::Error::new(&error.to_string());
+    js_error.set_name(error.kind());
+    let set = |key: &str, value: JsValue| {
+      let _ = Reflect::set(&js_error, &JsValue::from_str(key), &value);
+    };
+    set("kind", JsValue::from_str(error.kind()));
+    match &error {
+      ComparisonError::EmptySequence { sequence } => set("sequence", JsValue::from(*sequence)),
+      ComparisonError::InvalidCharacters { sequence, positions, count } => {
+        set("sequence", JsValue::from(*sequence));
+        set("positions", positions.iter().map(|&p| JsValue::from(p as u32)).collect::<Array>().into());
+        set("count", JsValue::from(*count as u32));
+      }
+      ComparisonError::MemoryLimitExceeded { required, limit } => {
+        set("requiredBytes", JsValue::from(*required as f64));
+        set("limitBytes", JsValue::from(*limit as f64));
+      }
+      ComparisonError::NoAlignmentFound | ComparisonError::FrameSearchFailed | ComparisonError::MalformedResult { .. } => {}
+    }
+    js_error.into()
+  }
+}
+
+/// Non-empty sequence of IUPAC nucleotide codes (either case) and gaps
+fn check_nucleotides(seq: &[u8], sequence: u8) -> Result<(), ComparisonError> {
+  if seq.is_empty() {
+    return Err(ComparisonError::EmptySequence { sequence });
+  }
+  let invalid = seq.iter().enumerate().filter(|&(_, &c)| base_set(c) == 0 && c != b'-').map(|(i, _)| i);
+  let count = invalid.clone().count();
+  if count > 0 {
+    let positions = invalid.take(MAX_REPORTED_POSITIONS).collect();
+    return Err(ComparisonError::InvalidCharacters { sequence, positions, count });
+  }
+  Ok(())
+}
+
+/// Typed view of a result built by the JSON exports
+fn parse_result<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, ComparisonError> {
+  serde_json::from_str(json).map_err(|error| ComparisonError::MalformedResult { message: error.to_string() })
+}
+
+// ============================================================================
+// Checked Comparisons (exported)
+// ============================================================================
+/// `compare_sequences_full` returning the result object. Throws an Error named
+/// EmptySequence, InvalidCharacters (with `sequence`, `positions`, `count`),
+/// MemoryLimitExceeded (with `requiredBytes`, `limitBytes`), NoAlignmentFound, or
+/// MalformedResult when the result does not read as a `NucleotideResult`.
+#[wasm_bindgen]
+pub fn compare_sequences(
+  seq1: &str,
+  seq2: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> Result<NucleotideResult, ComparisonError> {
+  let (bytes1, bytes2) = (seq1.as_bytes(), seq2.as_bytes());
+  check_nucleotides(bytes1, 1)?;
+  check_nucleotides(bytes2, 2)?;
+
+  let alignment = ComparisonOptions::placement(min_sequence_overlap_pct, false).place(bytes1, bytes2)?;
+  if alignment.length == 0 {
+    return Err(ComparisonError::NoAlignmentFound);
+  }
+  parse_result(&nucleotide_result_json(
+    bytes1,
+    bytes2,
+    &alignment,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+  ))
+}
+
+/// `compare_proteins_full` returning the result object. Throws an Error named
+/// EmptySequence, InvalidCharacters, FrameSearchFailed when no frame combination
+/// yields a protein alignment of at least one window, or MalformedResult.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compare_proteins(
+  seq1: &str,
+  seq2: &str,
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> Result<ProteinResult, ComparisonError> {
+  let (bytes1, bytes2) = (seq1.as_bytes(), seq2.as_bytes());
+  check_nucleotides(bytes1, 1)?;
+  check_nucleotides(bytes2, 2)?;
+  if nuc_offset1 < 0 || nuc_offset2 < 0 || nuc_length <= 0 {
+    return Err(ComparisonError::FrameSearchFailed);
+  }
+
+  let json = protein_comparison(
+    bytes1,
+    bytes2,
+    nuc_offset1,
+    nuc_offset2,
+    nuc_length,
+    aa_segment_window_length,
+    min_identity,
+    min_significant_length_group,
+  );
+  let result: ProteinResult = parse_result(&json)?;
+  if result.length == 0 {
+    return Err(ComparisonError::FrameSearchFailed);
+  }
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unreadable_result_is_a_malformed_result_error() {
+    let error = parse_result::<NucleotideResult>(r#"{"mask":"","evalue":inf}"#).err().unwrap();
+    assert_eq!(error.kind(), "MalformedResult");
+  }
+
+  #[test]
+  fn sequence_checks_report_their_kind() {
+    assert_eq!(check_nucleotides(b"", 2).unwrap_err().kind(), "EmptySequence");
+    match check_nucleotides(b"ACGTXNZ-", 1) {
+      Err(ComparisonError::InvalidCharacters { sequence: 1, positions, count: 2 }) => assert_eq!(positions, [4, 6]),
+      _ => panic!("expected InvalidCharacters"),
+    }
+    assert!(check_nucleotides(b"acgtRYN-", 1).is_ok());
+  }
+
+  #[test]
+  fn untranslated_protein_results_read_as_protein_results() {
+    use crate::options::{compare_protein_to_mrna, compare_proteins_direct};
+    let options = ComparisonOptions::new();
+    let direct: ProteinResult = parse_result(&compare_proteins_direct("MKTAYIAKQR", "MKTAYIAKQR", &options)).ok().unwrap();
+    assert!(direct.frame1.is_none() && direct.frame2.is_none() && direct.mismatch_codons.is_none());
+    let mrna: ProteinResult = parse_result(&compare_protein_to_mrna("MKT*", "ATGAAAACGTAA", &options)).ok().unwrap();
+    assert_eq!(mrna.frame2, Some(0));
+    assert!(mrna.frame_detection.is_none());
+  }
+}
@@ -0,0 +1,64 @@
+use gene_compare_core::exons::{compare_exons as core_compare_exons, parse_exon_annotations as core_parse_exon_annotations};
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Exon Annotations (exported)
+// ============================================================================
+/// Extract exon coordinates from GFF3 `exon` rows or GenBank `exon` feature lines.
+/// Returns flat 0-based half-open [start, end, ...] pairs in file order.
+#[wasm_bindgen]
+pub fn parse_exon_annotations(text: &str) -> Vec<u32> {
+  core_parse_exon_annotations(text)
+}
+
+// ============================================================================
+// Exon-by-Exon Comparison (exported)
+// ============================================================================
+fn range_json(range: Option<(usize, usize)>) -> (String, String, String) {
+  match range {
+    Some((start, end)) => (start.to_string(), end.to_string(), (end - start).to_string()),
+    None => ("null".to_string(), "null".to_string(), "null".to_string()),
+  }
+}
+
+/// Compare exon N of seq1 against exon N of seq2 for every annotated exon.
+/// Each pair is aligned independently with the same offset search as the full comparison;
+/// exons present in only one transcript are reported with null statistics. An exon pair
+/// whose offset search would exceed the memory limit gives the memory-limit error.
+#[wasm_bindgen]
+pub fn compare_exons(seq1: &str, seq2: &str, exons1: &[u32], exons2: &[u32], min_sequence_overlap_pct: f64) -> String {
+  let options = ComparisonOptions::placement(min_sequence_overlap_pct, false);
+  let exons = match core_compare_exons(seq1.as_bytes(), seq2.as_bytes(), exons1, exons2, |a, b| options.place(a, b)) {
+    Ok(exons) => exons,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+
+  let rows: Vec<String> = exons
+    .iter()
+    .enumerate()
+    .map(|(i, exon)| {
+      let (start1, end1, length1) = range_json(exon.range1);
+      let (start2, end2, length2) = range_json(exon.range2);
+      let stats = match (exon.range1, exon.range2, &exon.alignment) {
+        (Some((s1, e1)), Some((s2, e2)), Some(alignment)) => format!(
+          r#""lengthDifference":{},"alignedLength":{},"mismatches":{},"identity":{},"offset1":{},"offset2":{}"#,
+          (e2 - s2) as i64 - (e1 - s1) as i64,
+          alignment.length,
+          alignment.mismatches,
+          alignment.identity,
+          alignment.offset1,
+          alignment.offset2
+        ),
+        _ => r#""lengthDifference":null,"alignedLength":0,"mismatches":null,"identity":null,"offset1":null,"offset2":null"#.to_string(),
+      };
+      format!(
+        r#"{{"exon":{},"start1":{},"end1":{},"length1":{},"start2":{},"end2":{},"length2":{},{}}}"#,
+        i + 1, start1, end1, length1, start2, end2, length2, stats
+      )
+    })
+    .collect();
+
+  format!(r#"{{"exons":[{}]}}"#, rows.join(","))
+}
@@ -0,0 +1,65 @@
+use gene_compare_core::limits::{check_memory, SUFFIX_ARRAY_TOO_LARGE};
+use gene_compare_core::suffix::{suffix_array_scratch_bytes, SuffixArray};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Sequence Index (exported)
+// ============================================================================
+// A suffix array over one sequence, built once and queried many times: where
+// a fragment occurs, how often, and how much of a query occurs at all.
+// Positions are 0-based.
+
+#[wasm_bindgen]
+pub struct SequenceIndex {
+  index: SuffixArray,
+}
+
+#[wasm_bindgen]
+impl SequenceIndex {
+  /// Index `seq` (case-insensitive); an error when the construction would exceed the
+  /// memory limit
+  #[wasm_bindgen(constructor)]
+  pub fn new(seq: &str) -> Result<SequenceIndex, JsError> {
+    check_memory(suffix_array_scratch_bytes(seq.len()), SUFFIX_ARRAY_TOO_LARGE)
+      .map_err(|exceeded| JsError::new(&exceeded.to_json()))?;
+    Ok(SequenceIndex { index: SuffixArray::new(seq.as_bytes()) })
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn length(&self) -> usize {
+    self.index.len()
+  }
+
+  /// Occurrences of `fragment`, overlapping ones included
+  pub fn count(&self, fragment: &str) -> usize {
+    self.index.count(fragment.as_bytes())
+  }
+
+  pub fn contains(&self, fragment: &str) -> bool {
+    !fragment.is_empty() && self.index.count(fragment.as_bytes()) > 0
+  }
+
+  /// Start of every occurrence of `fragment`, ascending
+  pub fn find(&self, fragment: &str) -> Vec<u32> {
+    if fragment.is_empty() {
+      return Vec::new();
+    }
+    self.index.positions(fragment.as_bytes()).into_iter().map(|position| position as u32).collect()
+  }
+
+  /// Longest prefix of `query` that occurs in the sequence: `{"length", "position"}` with
+  /// the first position it occurs at (null when the length is 0), e.g. for anchoring a read
+  pub fn longest_match(&self, query: &str) -> String {
+    let (length, position) = self.index.longest_match(query.as_bytes());
+    format!(
+      r#"{{"length":{},"position":{}}}"#,
+      length,
+      position.map_or("null".to_string(), |position| position.to_string())
+    )
+  }
+
+  /// The suffix array itself: suffix start positions in sorted order
+  pub fn suffix_array(&self) -> Vec<u32> {
+    self.index.suffixes().to_vec()
+  }
+}
@@ -1,146 +1,129 @@
 use wasm_bindgen::prelude::*;
-use web_sys::console;
 
-// ============================================================================
-// Constants
-// ============================================================================
-const CODON_SIZE: usize = 3; // Fundamental: 3 nucleotides = 1 codon (does not change)
+use gene_compare_core::alignment::{exhaustive_scratch_bytes, find_good_enough_alignment, Alignment};
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{self, empty_nucleotide_result};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::limits::{check_memory, EXHAUSTIVE_TOO_LARGE};
+use gene_compare_core::scoring::Scoring;
+use gene_compare_core::symbols::MaskSymbols;
+use gene_compare_core::translation::{ReadingFrames, Translation};
 
-// ============================================================================
-// Codon Table
-// ============================================================================
-// Amino acid lookup table
-// Encoding: index = i1*16 + i2*4 + i3 where T=0, C=1, A=2, G=3
-// Built from JS codon table to ensure exact match
-const AMINO_ACIDS: &[u8] = b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+use cache::{cached, CacheKey};
+use progress::Progress;
 
-fn nuc_to_index(c: u8) -> Option<usize> {
-  match c {
-    b'T' | b't' => Some(0),
-    b'C' | b'c' => Some(1),
-    b'A' | b'a' => Some(2),
-    b'G' | b'g' => Some(3),
-    _ => None,
-  }
-}
+mod alignment_text;
+mod batch;
+mod blocks;
+mod buffers;
+mod cache;
+mod cancel;
+mod codon_optimized;
+mod comparator;
+mod compression;
+mod consensus;
+mod crispr;
+mod domains;
+mod editing;
+mod errors;
+mod exons;
+mod index;
+mod limits;
+mod logging;
+mod mapping;
+mod matrix;
+mod mirna;
+mod motifs;
+mod msa;
+mod options;
+mod ortholog;
+mod nonblocking;
+mod oligo;
+mod parallel;
+mod pattern;
+mod plasmid;
+mod profile;
+mod progress;
+mod quality;
+mod query;
+mod rearrangement;
+mod regions;
+mod report;
+mod restriction;
+mod sketch;
+mod splice;
+#[cfg(feature = "svg")]
+mod svg;
+mod spectrum;
+#[cfg(feature = "telemetry")]
+mod telemetry;
+#[cfg(test)]
+mod test_support;
+mod tree;
+mod types;
+mod validate;
 
-fn translate_codon(c1: u8, c2: u8, c3: u8) -> u8 {
-  match (nuc_to_index(c1), nuc_to_index(c2), nuc_to_index(c3)) {
-    (Some(i1), Some(i2), Some(i3)) => AMINO_ACIDS[i1 * 16 + i2 * 4 + i3],
-    _ => b'X',
-  }
-}
-
-fn translate_dna_internal(seq: &[u8]) -> Vec<u8> {
-  let codon_count = seq.len() / 3;
-  let mut result = Vec::with_capacity(codon_count);
-  for i in 0..codon_count {
-    let pos = i * 3;
-    result.push(translate_codon(seq[pos], seq[pos + 1], seq[pos + 2]));
-  }
-  result
-}
+pub use alignment_text::render_alignment_text;
+pub use batch::{compare_one_vs_many, compare_one_vs_many_with_options};
+pub use blocks::{blocks_to_fasta, conserved_blocks, AlignedBlock};
+pub use buffers::{compare_proteins_bytes, compare_sequence_buffers, compare_sequences_bytes, SequenceBuffer};
+pub use cache::clear_result_cache;
+pub use cancel::AbortHandle;
+pub use codon_optimized::compare_codon_optimized;
+pub use comparator::Comparator;
+pub use consensus::{consensus, read_pair_consensus};
+pub use crispr::crispr_guide_sites;
+pub use domains::suggest_domain_boundaries;
+pub use editing::classify_edited_allele;
+pub use errors::{compare_proteins, compare_sequences, ComparisonError};
+pub use exons::{compare_exons, parse_exon_annotations};
+pub use index::SequenceIndex;
+pub use mapping::map_position;
+pub use matrix::pairwise_matrix;
+pub use mirna::mirna_seed_sites;
+pub use motifs::scan_motifs;
+pub use limits::{get_memory_limit, set_memory_limit};
+pub use logging::set_logger;
+pub use msa::compare_multiple;
+pub use nonblocking::compare_sequences_async;
+pub use oligo::oligo_properties;
+pub use ortholog::compare_ortholog_panel;
+pub use options::{
+  compare_protein_to_mrna, compare_proteins_direct, compare_proteins_with_options, compare_sequence_regions_with_options,
+  compare_sequences_compressed, compare_sequences_with_options, ComparisonOptions,
+};
+#[cfg(all(feature = "threads", target_arch = "wasm32"))]
+pub use parallel::init_thread_pool;
+pub use pattern::{find_approximate_matches, find_pattern, find_pattern_in_both};
+pub use plasmid::verify_plasmid_insert;
+pub use profile::gc_identity_profile;
+pub use progress::compare_sequences_with_progress;
+pub use quality::{trim_by_quality, trim_read_ends};
+pub use query::query_region;
+pub use rearrangement::detect_rearrangements;
+pub use regions::compare_sequence_regions;
+pub use report::render_report;
+pub use restriction::{differential_restriction_sites, restriction_sites};
+pub use sketch::{sketch, sketch_distance};
+pub use spectrum::{compare_kmer_spectra, kmer_counts};
+pub use splice::splice_site_differences;
+#[cfg(feature = "svg")]
+pub use svg::render_svg;
+#[cfg(feature = "telemetry")]
+pub use telemetry::{compare_proteins_profiled, compare_sequences_profiled};
+pub use tree::build_tree;
+pub use types::{
+  AdjustedIdentity, Block, Diagnostic, FrameDetection, Frameshift, HeterozygousCall, HighlightRange, Indel, KozakContext,
+  MaskStyle, MismatchCodon, MismatchHotspot, MismatchVariant, NoSignificantAlignment, Normalization,
+  NormalizationReport, NucleotideResult, Overhang, Overhangs, Overview, Primer, ProteinResult, StopCodons, Substitution,
+};
+pub use validate::validate_sequence;
 
 // ============================================================================
-// Comparison Core
+// JSON Helpers
 // ============================================================================
-fn compare_regions(seq1: &[u8], seq2: &[u8]) -> (Vec<u8>, usize) {
-  let len = seq1.len().min(seq2.len());
-  let mut mask = Vec::with_capacity(len);
-  let mut mismatches = 0;
-  
-  for i in 0..len {
-    if seq1[i] == seq2[i] {
-      mask.push(seq1[i]);
-    } else {
-      mask.push(b'?');
-      mismatches += 1;
-    }
-  }
-  
-  (mask, mismatches)
-}
-
-fn count_mismatches_in_mask(mask: &[u8]) -> usize {
-  mask.iter().filter(|&&b| b == b'?').count()
-}
-
-// ============================================================================
-// Conserved Blocks
-// ============================================================================
-#[derive(Clone)]
-struct ConservedBlock {
-  start: usize,
-  end: usize,
-  length: usize,
-  sequence: Vec<u8>,
-}
-
-fn find_conserved_blocks(mask: &[u8], window_size: usize, min_identity: f64, min_significant_length_group: f64) -> Vec<ConservedBlock> {
-  let mut blocks = Vec::new();
-  let mut current_block = Vec::new();
-  let mut block_start = 0;
-  let mut in_block = false;
-  
-  let mut i = 0;
-  while i < mask.len() {
-    let end = (i + window_size).min(mask.len());
-    let window = &mask[i..end];
-    let mismatches = count_mismatches_in_mask(window);
-    let identity = 1.0 - (mismatches as f64) / (window.len() as f64);
-    
-    if identity >= min_identity {
-      if !in_block {
-        block_start = i;
-        in_block = true;
-      }
-      current_block.extend_from_slice(window);
-    } else {
-      if !current_block.is_empty() {
-        blocks.push(ConservedBlock {
-          start: block_start,
-          end: block_start + current_block.len(),
-          length: current_block.len(),
-          sequence: current_block.clone(),
-        });
-        current_block.clear();
-        in_block = false;
-      }
-    }
-    i += window_size;
-  }
-  
-  // Save final block
-  if !current_block.is_empty() {
-    blocks.push(ConservedBlock {
-      start: block_start,
-      end: block_start + current_block.len(),
-      length: current_block.len(),
-      sequence: current_block,
-    });
-  }
-  
-  // Filter small blocks
-  if blocks.len() > 1 {
-    let max_length = blocks.iter().map(|b| b.length).max().unwrap_or(0);
-    let min_significant = (max_length as f64 * min_significant_length_group) as usize;
-    let filtered: Vec<_> = blocks.iter().filter(|b| b.length >= min_significant).cloned().collect();
-    if !filtered.is_empty() {
-      return filtered;
-    }
-  }
-  
-  blocks
-}
-
-fn blocks_to_json(blocks: &[ConservedBlock]) -> String {
-  let parts: Vec<String> = blocks.iter().map(|b| {
-    format!(
-      r#"{{"start":{},"end":{},"length":{},"sequence":"{}"}}"#,
-      b.start, b.end, b.length,
-      String::from_utf8_lossy(&b.sequence)
-    )
-  }).collect();
+fn f64_array_json(values: &[f64]) -> String {
+  let parts: Vec<String> = values.iter().map(|v| v.to_string()).collect();
   format!("[{}]", parts.join(","))
 }
 
@@ -156,78 +139,124 @@ pub fn compare_sequences_full(
   min_significant_length_group: f64,
   min_sequence_overlap_pct: f64,
 ) -> String {
-  let bytes1 = seq1.as_bytes();
-  let bytes2 = seq2.as_bytes();
-  
+  let options = [
+    segment_window_length as u64,
+    min_identity.to_bits(),
+    min_significant_length_group.to_bits(),
+    min_sequence_overlap_pct.to_bits(),
+    // Over-limit inputs produce an error result, so the cap is part of the key
+    get_memory_limit() as u64,
+  ];
+  cached(CacheKey::new("nucleotide", seq1.as_bytes(), seq2.as_bytes(), &options), || {
+    nucleotide_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      min_sequence_overlap_pct,
+    ).1
+  })
+}
+
+/// Same as `compare_sequences_full`, but the offset search stops at the first placement whose
+/// identity reaches `good_enough_identity` (e.g. 0.95) instead of scanning every offset, so
+/// obviously matching sequences finish much sooner; the placement may not be the optimum
+#[wasm_bindgen]
+pub fn compare_sequences_good_enough(
+  seq1: &str,
+  seq2: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  good_enough_identity: f64,
+) -> String {
+  let (bytes1, bytes2) = (seq1.as_bytes(), seq2.as_bytes());
   if bytes1.is_empty() || bytes2.is_empty() {
-    return r#"{"mask":"","mismatches":0,"length":0,"identity":0,"truncated":true,"offset1":0,"offset2":0,"conservedBlocks":[]}"#.to_string();
+    return empty_nucleotide_result(&ResultFormat::default());
   }
-  
-  let len1 = bytes1.len() as i32;
-  let len2 = bytes2.len() as i32;
-  let min_overlap = ((len1.min(len2) as f64) * min_sequence_overlap_pct).ceil() as i32;
-  
-  let mut best_offset1: i32 = 0;
-  let mut best_offset2: i32 = 0;
-  let mut best_identity: f64 = 0.0;
-  let mut best_overlap_len: i32 = 0;
-  let mut best_mismatches: i32 = i32::MAX;
-  
-  // Find best alignment
-  for offset in (-len2 + min_overlap)..=(len1 - min_overlap) {
-    let start1 = if offset > 0 { offset } else { 0 };
-    let start2 = if offset < 0 { -offset } else { 0 };
-    let overlap_len = (len1 - start1).min(len2 - start2);
-    
-    if overlap_len < min_overlap {
-      continue;
-    }
-    
-    // Count mismatches
-    let mut mismatches: i32 = 0;
-    for i in 0..overlap_len {
-      if bytes1[(start1 + i) as usize] != bytes2[(start2 + i) as usize] {
-        mismatches += 1;
-      }
-    }
-    
-    let identity = 1.0 - (mismatches as f64) / (overlap_len as f64);
-    let is_better = identity > best_identity + 0.01
-      || ((identity - best_identity).abs() < 0.01 && overlap_len > best_overlap_len);
-    
-    if is_better {
-      best_identity = identity;
-      best_offset1 = start1;
-      best_offset2 = start2;
-      best_overlap_len = overlap_len;
-      best_mismatches = mismatches;
-    }
-    
-    if mismatches == 0 {
-      break;
-    }
+  if let Err(exceeded) = check_memory(exhaustive_scratch_bytes(bytes1.len(), bytes2.len()), EXHAUSTIVE_TOO_LARGE) {
+    return exceeded.to_json();
   }
   
-  // Build mask
-  let region1 = &bytes1[best_offset1 as usize..(best_offset1 + best_overlap_len) as usize];
-  let region2 = &bytes2[best_offset2 as usize..(best_offset2 + best_overlap_len) as usize];
-  let (mask, _) = compare_regions(region1, region2);
-  
-  // Find conserved blocks
-  let blocks = find_conserved_blocks(&mask, segment_window_length, min_identity, min_significant_length_group);
-  
-  let truncated = len1 != len2 || best_offset1 != 0 || best_offset2 != 0;
-  
+  let alignment = find_good_enough_alignment(bytes1, bytes2, min_sequence_overlap_pct, good_enough_identity);
+  nucleotide_result_json(bytes1, bytes2, &alignment, segment_window_length, min_identity, min_significant_length_group)
+}
+
+/// Shared body of the nucleotide comparison; returns the identity alongside the JSON result
+fn nucleotide_comparison(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> (f64, String) {
+  comparison::nucleotide_comparison(
+    bytes1,
+    bytes2,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    min_sequence_overlap_pct,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    &mut Progress::none(),
+  )
+}
+
+/// Mask, conserved blocks, and JSON for an alignment that has already been found
+fn nucleotide_result_json(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> String {
+  comparison::nucleotide_result_json(
+    bytes1,
+    bytes2,
+    alignment,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    BlockDetection::default(),
+    &mut Progress::none(),
+  )
+}
+
+// ============================================================================
+// Positional Diff (exported)
+// ============================================================================
+/// Column-by-column diff of `a` and `b` from their first characters, with no offset search,
+/// blocks, or scoring: for short oligos where the full pipeline would cost more than the
+/// comparison. `symbols` styles the mask as up to three characters, the mismatch, match, and
+/// gap symbols ('?', the residue itself, and '-' when left out). Returns the compared
+/// `length` (the shorter input), `mismatches`, `identity`, the `mask`, and the 0-based
+/// mismatch `positions`
+#[wasm_bindgen]
+pub fn diff_strings(a: &str, b: &str, symbols: &str) -> String {
+  let (a, b) = (a.as_bytes(), b.as_bytes());
+  let (mask, mismatches) = comparison::compare_regions(a, b);
+  let symbol = |index: usize| symbols.chars().nth(index).filter(|c| c.is_ascii_graphic() || *c == ' ').map(|c| c as u8);
+  let defaults = MaskSymbols::default();
+  let style = MaskSymbols {
+    mismatch: symbol(0).unwrap_or(defaults.mismatch),
+    matched: symbol(1),
+    gap: symbol(2).unwrap_or(defaults.gap),
+  };
+  let positions: Vec<String> = (0..mask.len()).filter(|&i| a[i] != b[i]).map(|i| i.to_string()).collect();
   format!(
-    r#"{{"mask":"{}","mismatches":{},"length":{},"identity":{},"truncated":{},"offset1":{},"offset2":{},"conservedBlocks":{}}}"#,
-    String::from_utf8_lossy(&mask),
-    best_mismatches,
-    best_overlap_len,
-    best_identity,
-    truncated,
-    best_offset1,
-    best_offset2,
-    blocks_to_json(&blocks)
+    r#"{{"length":{},"mismatches":{},"identity":{},"mask":{},"positions":[{}]}}"#,
+    mask.len(),
+    mismatches,
+    if mask.is_empty() { 0.0 } else { 1.0 - mismatches as f64 / mask.len() as f64 },
+    serde_json::to_string(&String::from_utf8_lossy(&style.render(&mask, a, b))).unwrap_or_else(|_| "\"\"".to_string()),
+    positions.join(",")
   )
 }
 
@@ -235,6 +264,7 @@ pub fn compare_sequences_full(
 // Full Protein Comparison (exported)
 // ============================================================================
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn compare_proteins_full(
   seq1: &str,
   seq2: &str,
@@ -245,123 +275,56 @@ pub fn compare_proteins_full(
   min_identity: f64,
   min_significant_length_group: f64,
 ) -> String {
-  // Logging for reading frame detection
-  console::log_1(&"\n📍 Reading Frame Detection:".into());
-  console::log_1(&"   Note: mRNA sequences include 5' UTR, so they don't start at codon boundaries".into());
-  
-  // Find start codons
-  let find_start_codon = |seq: &str| -> Option<usize> {
-    seq.find("ATG")
-  };
-  
-  let start1 = find_start_codon(seq1);
-  let start2 = find_start_codon(seq2);
-  
-  if let (Some(s1), Some(s2)) = (start1, start2) {
-    let frame1 = ((nuc_offset1 - s1 as i32) % CODON_SIZE as i32 + CODON_SIZE as i32) % CODON_SIZE as i32;
-    let frame2 = ((nuc_offset2 - s2 as i32) % CODON_SIZE as i32 + CODON_SIZE as i32) % CODON_SIZE as i32;
-    
-    console::log_1(&format!("   Found start codons: seq1 at position {}, seq2 at position {}", s1, s2).into());
-    console::log_1(&format!("   Alignment offset: seq1[{}], seq2[{}]", nuc_offset1, nuc_offset2).into());
-    console::log_1(&format!("   Inferred frames relative to CDS: seq1 +{}, seq2 +{}", frame1, frame2).into());
-    
-    if frame1 != frame2 {
-      console::log_1(&"   ⚠️  Nucleotide alignment broke the reading frame!".into());
-      console::log_1(&"   Searching all 9 frame combinations for best protein alignment...".into());
-    }
-  } else {
-    console::log_1(&"   Start codon not found in one or both sequences".into());
-    console::log_1(&"   Trying all 9 reading frame combinations...".into());
-  }
-  
-  let bytes1 = seq1.as_bytes();
-  let bytes2 = seq2.as_bytes();
-  
-  // Find best reading frame
-  let mut best_frame1: usize = 0;
-  let mut best_frame2: usize = 0;
-  let mut best_identity: f64 = 0.0;
-  let mut best_aa1: Vec<u8> = Vec::new();
-  let mut best_aa2: Vec<u8> = Vec::new();
-  
-  for frame1 in 0..CODON_SIZE {
-    for frame2 in 0..CODON_SIZE {
-      let start1 = (nuc_offset1 as usize) + frame1;
-      let start2 = (nuc_offset2 as usize) + frame2;
-      let adjusted_len = ((nuc_length as usize).saturating_sub(frame1))
-        .min((nuc_length as usize).saturating_sub(frame2));
-      
-      if adjusted_len < aa_segment_window_length * CODON_SIZE {
-        continue;
-      }
-      
-      let end1 = (start1 + adjusted_len).min(bytes1.len());
-      let end2 = (start2 + adjusted_len).min(bytes2.len());
-      
-      if start1 >= bytes1.len() || start2 >= bytes2.len() {
-        continue;
-      }
-      
-      let region1 = &bytes1[start1..end1];
-      let region2 = &bytes2[start2..end2];
-      
-      let aa1 = translate_dna_internal(region1);
-      let aa2 = translate_dna_internal(region2);
-      
-      let min_len = aa1.len().min(aa2.len());
-      if min_len == 0 {
-        continue;
-      }
-      
-      let (_, mismatches) = compare_regions(&aa1[..min_len], &aa2[..min_len]);
-      let identity = 1.0 - (mismatches as f64) / (min_len as f64);
-      
-      // Match JS behavior: use > (strictly greater) so first frame with best identity wins
-      // When frames are checked in order (0,0), (0,1), (0,2), (1,0), etc., the first one
-      // that achieves the best identity will be selected and subsequent equal identities won't replace it
-      if identity > best_identity {
-        best_identity = identity;
-        best_frame1 = frame1;
-        best_frame2 = frame2;
-        best_aa1 = aa1;
-        best_aa2 = aa2;
-      }
-    }
-  }
-  
-  // Compare best amino acid sequences
-  let length = best_aa1.len().min(best_aa2.len());
-  let (mask, mismatches) = if length > 0 {
-    compare_regions(&best_aa1[..length], &best_aa2[..length])
-  } else {
-    (Vec::new(), 0)
-  };
-  
-  // Use best_identity from the loop (matches JS behavior)
-  let identity = best_identity;
-  
-  // Find conserved blocks on amino acids
-  let blocks = find_conserved_blocks(&mask, aa_segment_window_length, min_identity, min_significant_length_group);
-  
-  let adjusted_offset1 = nuc_offset1 as usize + best_frame1;
-  let adjusted_offset2 = nuc_offset2 as usize + best_frame2;
-  
-  // Log best alignment
-  console::log_1(&format!("   ✓ Best protein alignment: seq1 +{}, seq2 +{}", best_frame1, best_frame2).into());
-  
-  format!(
-    r#"{{"aa1":"{}","aa2":"{}","mask":"{}","mismatches":{},"length":{},"identity":{},"truncated":{},"offset1":{},"offset2":{},"frame1":{},"frame2":{},"conservedBlocks":{}}}"#,
-    String::from_utf8_lossy(&best_aa1),
-    String::from_utf8_lossy(&best_aa2),
-    String::from_utf8_lossy(&mask),
-    mismatches,
-    length,
-    identity,
-    best_aa1.len() != best_aa2.len(),
-    adjusted_offset1 / CODON_SIZE,
-    adjusted_offset2 / CODON_SIZE,
-    best_frame1,
-    best_frame2,
-    blocks_to_json(&blocks)
+  let options = [
+    nuc_offset1 as u64,
+    nuc_offset2 as u64,
+    nuc_length as u64,
+    aa_segment_window_length as u64,
+    min_identity.to_bits(),
+    min_significant_length_group.to_bits(),
+    // Over-limit inputs produce an error result, so the cap is part of the key
+    get_memory_limit() as u64,
+  ];
+  cached(CacheKey::new("protein", seq1.as_bytes(), seq2.as_bytes(), &options), || {
+    protein_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      nuc_offset1,
+      nuc_offset2,
+      nuc_length,
+      aa_segment_window_length,
+      min_identity,
+      min_significant_length_group,
+    )
+  })
+}
+
+/// Shared body of the protein comparison for string and byte inputs
+#[allow(clippy::too_many_arguments)]
+fn protein_comparison(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> String {
+  comparison::protein_comparison(
+    bytes1,
+    bytes2,
+    nuc_offset1,
+    nuc_offset2,
+    nuc_length,
+    aa_segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ReadingFrames::default(),
+    &Translation::default(),
+    &ResultFormat::default(),
+    &Scoring::default(),
+    BlockDetection::default(),
+    &mut Progress::none(),
   )
 }
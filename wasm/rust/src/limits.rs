@@ -0,0 +1,18 @@
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Memory Limits
+// ============================================================================
+// The cap itself lives in the core crate; these exports set it from JS.
+
+/// Set the scratch-memory cap in bytes for a single comparison (0 removes the cap)
+#[wasm_bindgen]
+pub fn set_memory_limit(bytes: usize) {
+  gene_compare_core::limits::set_memory_limit(bytes);
+}
+
+/// Current scratch-memory cap in bytes (0 means unlimited)
+#[wasm_bindgen]
+pub fn get_memory_limit() -> usize {
+  gene_compare_core::limits::memory_limit()
+}
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Logging
+// ============================================================================
+// Diagnostic messages go to a logger installed from JS and are dropped when
+// none is set. Builds with the `web` feature fall back to
+// `console.log`, as the module did before the logger existed.
+
+thread_local! {
+  static LOGGER: RefCell<Option<Function>> = const { RefCell::new(None) };
+}
+
+/// Route diagnostic messages to `logger(message)` (e.g. `console.debug`); pass
+/// `undefined` to silence them again
+#[wasm_bindgen]
+pub fn set_logger(logger: Option<Function>) {
+  LOGGER.with(|current| *current.borrow_mut() = logger);
+}
+
+pub(crate) fn log(message: &str) {
+  let logged = LOGGER.with(|current| match current.borrow().as_ref() {
+    Some(logger) => {
+      // A throwing logger must not abort the comparison
+      let _ = logger.call1(&JsValue::UNDEFINED, &JsValue::from_str(message));
+      true
+    }
+    None => false,
+  });
+  if !logged {
+    default_log(message);
+  }
+}
+
+#[cfg(feature = "web")]
+fn default_log(message: &str) {
+  web_sys::console::log_1(&message.into());
+}
+
+#[cfg(not(feature = "web"))]
+fn default_log(_message: &str) {}
@@ -0,0 +1,106 @@
+use gene_compare_core::format::CoordinateSystem;
+use gene_compare_core::symbols::{MaskSymbols, GAP};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Coordinate Mapping
+// ============================================================================
+// Results place seq2 against seq1 without gaps of their own, so a position in
+// the aligned region of one sequence maps to the same column of the other.
+// Pre-aligned input can still have gaps, and a column where either sequence
+// has one maps nowhere. Positions count in the result's coordinate system
+// (residues for protein results).
+
+/// Where a result places the two sequences, read back from its JSON
+pub(crate) struct Placement {
+  pub coordinates: CoordinateSystem,
+  /// 0-based starts of the aligned region in seq1 and seq2
+  pub offsets: [usize; 2],
+  pub length: usize,
+}
+
+impl Placement {
+  pub fn from_result(result: &Value) -> Option<Placement> {
+    let coordinates = match result.get("coordinateSystem")?.as_str()? {
+      "1-based-inclusive" => CoordinateSystem::OneBased,
+      _ => CoordinateSystem::ZeroBased,
+    };
+    let number = |key: &str| result.get(key).and_then(Value::as_u64).map(|n| n as usize);
+    let index = |key: &str| number(key).map(|n| coordinates.start_index(n as i32).max(0) as usize);
+    Some(Placement { coordinates, offsets: [index("offset1")?, index("offset2")?], length: number("length")? })
+  }
+
+  /// Column of the 0-based `index` of sequence `seq` (0 or 1), if it is aligned
+  pub fn column(&self, seq: usize, index: usize) -> Option<usize> {
+    index.checked_sub(self.offsets[seq]).filter(|&column| column < self.length)
+  }
+}
+
+/// The mask symbols a result names in `maskSymbols`, the default ones when it names none
+pub(crate) fn mask_symbols(result: &Value) -> MaskSymbols {
+  let default = MaskSymbols::default();
+  let symbol = |key: &str| {
+    let symbol = result.get("maskSymbols")?.get(key)?.as_str()?;
+    symbol.bytes().next().filter(|_| symbol.len() == 1)
+  };
+  MaskSymbols {
+    mismatch: symbol("mismatch").unwrap_or(default.mismatch),
+    matched: symbol("match"),
+    gap: symbol("gap").unwrap_or(default.gap),
+  }
+}
+
+/// A result's `mask` in the default symbols: '?' at mismatches and '-' at gaps
+pub(crate) fn result_mask(result: &Value) -> Vec<u8> {
+  let mask = result.get("mask").and_then(Value::as_str).unwrap_or_default();
+  mask_symbols(result).canonical(mask.as_bytes())
+}
+
+/// Whether `column` holds a gap in `seq` (0 or 1): protein results carry their translations,
+/// nucleotide results only the mask, which has '-' where both sequences do
+fn is_gap(result: &Value, seq: usize, column: usize) -> bool {
+  match result.get(["aa1", "aa2"][seq]).and_then(Value::as_str) {
+    Some(residues) => residues.as_bytes().get(column) == Some(&GAP),
+    None => result_mask(result).get(column) == Some(&GAP),
+  }
+}
+
+/// Position in the other sequence aligned with `pos` of sequence `seq` (1 or 2) of a
+/// nucleotide or protein comparison result; both positions count in the result's coordinate
+/// system. Undefined outside the aligned region, where the other sequence has a gap, or for
+/// an unreadable result
+#[wasm_bindgen]
+pub fn map_position(result: &str, seq: u8, pos: usize) -> Option<usize> {
+  let result = serde_json::from_str::<Value>(result).ok()?;
+  let placement = Placement::from_result(&result)?;
+  let (from, to) = match seq {
+    1 => (0, 1),
+    2 => (1, 0),
+    _ => return None,
+  };
+  let index = placement.coordinates.start_index(pos as i32);
+  let column = placement.column(from, usize::try_from(index).ok()?)?;
+  if is_gap(&result, from, column) || is_gap(&result, to, column) {
+    return None;
+  }
+  Some(placement.coordinates.start(placement.offsets[to] + column))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::options::{compare_sequences_with_options, ComparisonOptions};
+  use crate::test_support::restyled;
+
+  #[test]
+  fn gaps_are_found_in_restyled_masks() {
+    let seq1 = "ACGTAC-GTACGTACGTACG";
+    let gapped = |options: ComparisonOptions| {
+      let result = compare_sequences_with_options(seq1, seq1, &options.with_gap_symbol('~'));
+      (0..seq1.len()).filter(|&pos| map_position(&result, 1, pos).is_none()).collect::<Vec<_>>()
+    };
+    assert_eq!(gapped(ComparisonOptions::new()), [6]);
+    assert_eq!(gapped(restyled()), [6]);
+  }
+}
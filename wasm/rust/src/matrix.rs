@@ -0,0 +1,34 @@
+use gene_compare_core::msa::identity_matrix;
+use wasm_bindgen::prelude::*;
+
+use crate::f64_array_json;
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// All-vs-All Identity Matrix
+// ============================================================================
+fn matrix_json(matrix: &[Vec<f64>]) -> String {
+  let rows: Vec<String> = matrix.iter().map(|row| f64_array_json(row)).collect();
+  format!("[{}]", rows.join(","))
+}
+
+/// Identity and p-distance (1 - identity) for every pair of sequences.
+/// `seeded` swaps the exhaustive offset scan for the k-mer seeded search, which is much
+/// faster on long inputs but may miss the optimal offset for highly divergent pairs.
+#[wasm_bindgen]
+pub fn pairwise_matrix(seqs: Vec<String>, min_sequence_overlap_pct: f64, seeded: bool) -> String {
+  let bytes: Vec<&[u8]> = seqs.iter().map(|s| s.as_bytes()).collect();
+  let options = ComparisonOptions::placement(min_sequence_overlap_pct, seeded);
+  let identity = match identity_matrix(&bytes, |a, b| options.place(a, b)) {
+    Ok(identity) => identity,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+  let distance: Vec<Vec<f64>> = identity.iter().map(|row| row.iter().map(|v| 1.0 - v).collect()).collect();
+
+  format!(
+    r#"{{"size":{},"identity":{},"distance":{}}}"#,
+    bytes.len(),
+    matrix_json(&identity),
+    matrix_json(&distance)
+  )
+}
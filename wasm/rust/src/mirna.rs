@@ -0,0 +1,83 @@
+use gene_compare_core::mirna::{parse_seeds, seed_site_changes, Seed, SeedSite, SiteType};
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// microRNA Seed Sites (exported)
+// ============================================================================
+
+fn seed_fields(seed: &Seed) -> String {
+  format!(
+    r#""mirna":{},"seed":"{}""#,
+    serde_json::to_string(&seed.name).unwrap_or_else(|_| "\"\"".to_string()),
+    String::from_utf8_lossy(&seed.seed)
+  )
+}
+
+fn sites_json(seed: &Seed, sites: &[SeedSite], options: &ComparisonOptions) -> Vec<String> {
+  sites
+    .iter()
+    .map(|site| {
+      let (start, end) = site.span();
+      format!(
+        r#"{{{},"type":"{}","start":{},"end":{}}}"#,
+        seed_fields(seed),
+        site.site_type.name(),
+        options.coordinates().start(start),
+        options.coordinates().end(end)
+      )
+    })
+    .collect()
+}
+
+/// miRNA seed sites (8mer, 7mer-m8, 7mer-A1, 6mer) of `seeds` in two 3'UTRs, and the sites the
+/// differences over their best placement create, destroy, or change in type. `seeds` lists
+/// mature miRNAs or seeds (nucleotides 2-8) as `name=SEQUENCE` or bare sequences; invalid
+/// entries come back in `invalid`. Pass the UTRs themselves, e.g. the 3'UTR region of
+/// `compare_sequence_regions`
+#[wasm_bindgen]
+pub fn mirna_seed_sites(utr1: &str, utr2: &str, seeds: &str, options: &ComparisonOptions) -> String {
+  let (seeds, invalid) = parse_seeds(seeds);
+  let (bytes1, bytes2, _) = options.normalize(utr1, utr2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let coordinates = options.coordinates();
+
+  let (mut sites1, mut sites2) = (Vec::new(), Vec::new());
+  for seed in &seeds {
+    sites1.extend(sites_json(seed, &seed.find_sites(bytes1), options));
+    sites2.extend(sites_json(seed, &seed.find_sites(bytes2), options));
+  }
+
+  let mut changes = Vec::new();
+  if !bytes1.is_empty() && !bytes2.is_empty() {
+    let alignment = match options.place(bytes1, bytes2) {
+      Ok(alignment) => alignment,
+      Err(exceeded) => return exceeded.to_json(),
+    };
+    let name = |site_type: Option<SiteType>| site_type.map_or("null".to_string(), |site_type| format!(r#""{}""#, site_type.name()));
+    for change in seed_site_changes(bytes1, bytes2, &alignment, &seeds) {
+      let mismatches: Vec<String> =
+        change.mismatches.iter().map(|&column| coordinates.start(alignment.offset1 + column).to_string()).collect();
+      changes.push(format!(
+        r#"{{{},"change":"{}","type1":{},"type2":{},"core1":{},"core2":{},"mismatches":[{}]}}"#,
+        seed_fields(change.seed),
+        change.change(),
+        name(change.type1),
+        name(change.type2),
+        coordinates.start(alignment.offset1 + change.column),
+        coordinates.start(alignment.offset2 + change.column),
+        mismatches.join(",")
+      ));
+    }
+  }
+
+  format!(
+    r#"{{"coordinateSystem":"{}","sites1":[{}],"sites2":[{}],"changes":[{}],"invalid":{}}}"#,
+    coordinates.name(),
+    sites1.join(","),
+    sites2.join(","),
+    changes.join(","),
+    serde_json::to_string(&invalid).unwrap_or_else(|_| "[]".to_string())
+  )
+}
@@ -0,0 +1,84 @@
+use gene_compare_core::motif::{motif_changes, parse_motifs, Motif, MotifHit, DEFAULT_MOTIF_THRESHOLD};
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Motif Scanning (exported)
+// ============================================================================
+// Transcription-factor and other binding motifs from JASPAR or MEME files,
+// scanned in both sequences, with the sites a mismatch creates or destroys.
+
+fn motif_fields(motif: &Motif) -> String {
+  let json_string = |value: &str| serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string());
+  format!(r#""motif":{},"name":{}"#, json_string(&motif.id), json_string(&motif.name))
+}
+
+fn hits_json(motif: &Motif, hits: &[MotifHit], options: &ComparisonOptions) -> Vec<String> {
+  hits
+    .iter()
+    .map(|hit| {
+      format!(
+        r#"{{{},"position":{},"strand":"{}","score":{}}}"#,
+        motif_fields(motif),
+        options.coordinates().start(hit.position),
+        if hit.reverse { '-' } else { '+' },
+        hit.score
+      )
+    })
+    .collect()
+}
+
+/// Hits of the JASPAR or MEME `matrices` in both sequences (relative score at least
+/// `threshold`, 0 for 0.85, either strand), and the hits in the best placement's overlap that
+/// one sequence has and the other lacks: `lost` when only seq1 has it, `gained` when only seq2
+/// does, with both scores and the mismatching positions (in seq1) responsible
+#[wasm_bindgen]
+pub fn scan_motifs(seq1: &str, seq2: &str, matrices: &str, threshold: f64, options: &ComparisonOptions) -> String {
+  let motifs = parse_motifs(matrices);
+  let threshold = if threshold > 0.0 { threshold } else { DEFAULT_MOTIF_THRESHOLD };
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let coordinates = options.coordinates();
+
+  let (mut hits1, mut hits2) = (Vec::new(), Vec::new());
+  for motif in &motifs {
+    hits1.extend(hits_json(motif, &motif.scan(bytes1, threshold), options));
+    hits2.extend(hits_json(motif, &motif.scan(bytes2, threshold), options));
+  }
+
+  let mut changes = Vec::new();
+  if !bytes1.is_empty() && !bytes2.is_empty() {
+    let alignment = match options.place(bytes1, bytes2) {
+      Ok(alignment) => alignment,
+      Err(exceeded) => return exceeded.to_json(),
+    };
+    let optional = |score: Option<f64>| score.map_or("null".to_string(), |score| score.to_string());
+    for change in motif_changes(bytes1, bytes2, &alignment, &motifs, threshold) {
+      let mismatches: Vec<String> =
+        change.mismatches.iter().map(|&column| coordinates.start(alignment.offset1 + column).to_string()).collect();
+      changes.push(format!(
+        r#"{{{},"change":"{}","position1":{},"position2":{},"strand":"{}","score1":{},"score2":{},"mismatches":[{}]}}"#,
+        motif_fields(change.motif),
+        if change.in_seq1 { "lost" } else { "gained" },
+        coordinates.start(alignment.offset1 + change.column),
+        coordinates.start(alignment.offset2 + change.column),
+        if change.reverse { '-' } else { '+' },
+        optional(change.score1),
+        optional(change.score2),
+        mismatches.join(",")
+      ));
+    }
+  }
+
+  let summary: Vec<String> = motifs.iter().map(|motif| format!(r#"{{{},"width":{}}}"#, motif_fields(motif), motif.width())).collect();
+  format!(
+    r#"{{"threshold":{},"coordinateSystem":"{}","motifs":[{}],"hits1":[{}],"hits2":[{}],"changes":[{}]}}"#,
+    threshold,
+    coordinates.name(),
+    summary.join(","),
+    hits1.join(","),
+    hits2.join(","),
+    changes.join(",")
+  )
+}
@@ -0,0 +1,71 @@
+use gene_compare_core::blocks::{blocks_to_json, find_conserved_blocks};
+use gene_compare_core::msa::center_star_alignment;
+use wasm_bindgen::prelude::*;
+
+use crate::f64_array_json;
+use crate::options::ComparisonOptions;
+
+fn frequencies_json(frequencies: &[(u8, f64)]) -> String {
+  let parts: Vec<String> = frequencies.iter().map(|&(c, share)| format!(r#""{}":{}"#, c as char, share)).collect();
+  format!("{{{}}}", parts.join(","))
+}
+
+// ============================================================================
+// Multiple Sequence Comparison (exported)
+// ============================================================================
+/// Compare three or more sequences at once. Returns the gap-padded rows, per-column
+/// conservation (fraction of sequences carrying the majority base), Shannon entropy and
+/// information content in bits, residue frequencies for logo rendering, a majority
+/// consensus, and conserved blocks over the all-sequences-agree mask.
+#[wasm_bindgen]
+pub fn compare_multiple(
+  seqs: Vec<String>,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  let bytes: Vec<&[u8]> = seqs.iter().map(|s| s.as_bytes()).collect();
+  if bytes.is_empty() {
+    return r#"{"center":0,"columns":0,"rows":[],"conservation":[],"entropy":[],"informationContent":[],"frequencies":[],"consensus":"","mask":"","conservedBlocks":[]}"#.to_string();
+  }
+
+  let options = ComparisonOptions::placement(min_sequence_overlap_pct, false);
+  let msa = match center_star_alignment(&bytes, |a, b| options.place(a, b)) {
+    Ok(msa) => msa,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+  let profile = msa.column_profile();
+  let frequencies: Vec<String> = profile.frequencies.iter().map(|column| frequencies_json(column)).collect();
+
+  let blocks = find_conserved_blocks(&profile.mask, segment_window_length, min_identity, min_significant_length_group);
+
+  let rows: Vec<String> = msa
+    .rows
+    .iter()
+    .enumerate()
+    .map(|(i, row)| {
+      format!(
+        r#"{{"index":{},"start":{},"identityToCenter":{},"aligned":"{}"}}"#,
+        i,
+        msa.starts[i],
+        msa.identities[i],
+        String::from_utf8_lossy(row)
+      )
+    })
+    .collect();
+
+  format!(
+    r#"{{"center":{},"columns":{},"rows":[{}],"conservation":{},"entropy":{},"informationContent":{},"frequencies":[{}],"consensus":"{}","mask":"{}","conservedBlocks":{}}}"#,
+    msa.center,
+    msa.columns,
+    rows.join(","),
+    f64_array_json(&profile.conservation),
+    f64_array_json(&profile.entropy),
+    f64_array_json(&profile.information_content),
+    frequencies.join(","),
+    String::from_utf8_lossy(&profile.consensus),
+    String::from_utf8_lossy(&profile.mask),
+    blocks_to_json(&blocks)
+  )
+}
@@ -0,0 +1,101 @@
+use js_sys::{Date, Function, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use gene_compare_core::alignment::{exhaustive_scratch_bytes, OffsetScan, OffsetScorer};
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{empty_nucleotide_result, nucleotide_result_json};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::limits::{check_memory, EXHAUSTIVE_TOO_LARGE};
+use gene_compare_core::observer::Observer;
+use gene_compare_core::scoring::Scoring;
+
+use crate::cancel::{Cancellation, ABORTED_MESSAGE};
+use crate::progress::Progress;
+
+// ============================================================================
+// Non-Blocking Comparison
+// ============================================================================
+// The offset scan runs chunk by chunk and hands control back to the event loop
+// (via setTimeout, so rendering and input get a turn) whenever a frame's worth
+// of time has passed. Inputs large enough for FFT match counts compute those
+// in one step first. Results are identical to the blocking export.
+
+const YIELD_INTERVAL_MS: f64 = 16.0;
+
+/// Resolve on the next macrotask; resolves immediately where setTimeout is unavailable
+async fn yield_to_event_loop() {
+  let promise = Promise::new(&mut |resolve, _reject| {
+    let set_timeout = Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+      .ok()
+      .and_then(|f| f.dyn_into::<Function>().ok());
+    let scheduled = set_timeout.is_some_and(|f| f.call2(&JsValue::UNDEFINED, &resolve, &JsValue::from(0)).is_ok());
+    if !scheduled {
+      let _ = resolve.call0(&JsValue::UNDEFINED);
+    }
+  });
+  let _ = JsFuture::from(promise).await;
+}
+
+/// Same result as `compare_sequences_full`, returned as a Promise that resolves to the JSON
+/// string. The offset search yields to the event loop roughly every 16 ms, so long
+/// comparisons no longer freeze the page and no Worker is needed. `progress` is called
+/// as in `compare_sequences_with_progress`; the Promise rejects with "Comparison aborted"
+/// once `signal` (see `AbortHandle`) is aborted.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub async fn compare_sequences_async(
+  seq1: String,
+  seq2: String,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  progress: Option<Function>,
+  signal: JsValue,
+) -> Result<JsValue, JsError> {
+  let bytes1 = seq1.as_bytes();
+  let bytes2 = seq2.as_bytes();
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return Ok(JsValue::from_str(&empty_nucleotide_result(&ResultFormat::default())));
+  }
+  if let Err(exceeded) = check_memory(exhaustive_scratch_bytes(bytes1.len(), bytes2.len()), EXHAUSTIVE_TOO_LARGE) {
+    return Ok(JsValue::from_str(&exceeded.to_json()));
+  }
+  
+  let cancellation = Cancellation::new(signal);
+  let mut progress = Progress::new(progress);
+  progress.report("offsetScan", 0.0);
+  let scorer = OffsetScorer::new(bytes1, bytes2);
+  let score = |&offset: &i32| scorer.score(offset);
+  let mut scan = OffsetScan::new(bytes1.len(), bytes2.len(), min_sequence_overlap_pct);
+  let mut last_yield = Date::now();
+  while !scan.scan_chunk(&score) {
+    progress.report("offsetScan", scan.fraction_done());
+    if Date::now() - last_yield >= YIELD_INTERVAL_MS {
+      yield_to_event_loop().await;
+      last_yield = Date::now();
+    }
+    if cancellation.is_aborted() {
+      return Err(JsError::new(ABORTED_MESSAGE));
+    }
+  }
+  progress.report("offsetScan", 1.0);
+  if cancellation.is_aborted() {
+    return Err(JsError::new(ABORTED_MESSAGE));
+  }
+  
+  Ok(JsValue::from_str(&nucleotide_result_json(
+    bytes1,
+    bytes2,
+    &scan.best,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    BlockDetection::default(),
+    &mut progress,
+  )))
+}
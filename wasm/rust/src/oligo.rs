@@ -0,0 +1,39 @@
+use gene_compare_core::primers::{
+  gc_fraction, hairpin_stem, melting_temperature, primer_warnings, self_complementarity, three_prime_complementarity,
+  OligoConditions,
+};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Oligo Properties (exported)
+// ============================================================================
+// The same Tm and complementarity checks the primer candidates pass, for an
+// oligo typed into the primer panel.
+
+/// Nearest-neighbor Tm (°C, null unless the oligo is all A/C/G/T), GC fraction, and self-dimer,
+/// 3' self-dimer, and hairpin stem lengths of `seq` at `salt` mM Na+ and `primer_conc` nM
+/// strand concentration (0 for the defaults, 50 mM and 50 nM). `warnings` names the checks
+/// the primer candidates would fail
+#[wasm_bindgen]
+pub fn oligo_properties(seq: &str, salt: f64, primer_conc: f64) -> String {
+  let defaults = OligoConditions::default();
+  let conditions = OligoConditions {
+    sodium: if salt > 0.0 { salt / 1e3 } else { defaults.sodium },
+    primer: if primer_conc > 0.0 { primer_conc / 1e9 } else { defaults.primer },
+  };
+  let bytes: Vec<u8> = seq.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+  let tm = melting_temperature(&bytes, &conditions);
+  let gc = gc_fraction(&bytes);
+  let (dimer, three_prime_dimer, hairpin) =
+    (self_complementarity(&bytes), three_prime_complementarity(&bytes), hairpin_stem(&bytes));
+  format!(
+    r#"{{"length":{},"tm":{},"gc":{},"selfDimer":{},"threePrimeDimer":{},"hairpin":{},"warnings":{}}}"#,
+    bytes.len(),
+    tm.map_or("null".to_string(), |tm| ((tm * 10.0).round() / 10.0).to_string()),
+    gc,
+    dimer,
+    three_prime_dimer,
+    hairpin,
+    serde_json::to_string(&primer_warnings(&bytes, &conditions)).unwrap_or_else(|_| "[]".to_string())
+  )
+}
@@ -0,0 +1,1102 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use gene_compare_core::alignment::{
+  exhaustive_scratch_bytes, find_best_alignment, find_good_enough_alignment, find_seeded_alignment, seeded_scratch_bytes,
+  Alignment,
+};
+use gene_compare_core::blocks::{
+  count_mismatches_in_mask, divergent_windows, find_blocks, overview_identity, BlockDetection, BlockMethod,
+};
+use gene_compare_core::comparison::{self, empty_nucleotide_result};
+use gene_compare_core::complexity::adjusted_identity;
+use gene_compare_core::fingerprint::{extend_result, with_fingerprint};
+use gene_compare_core::format::{CoordinateSystem, ResultFormat};
+use gene_compare_core::highlights::highlight_ranges;
+use gene_compare_core::limits::{check_memory, LimitExceeded, EXHAUSTIVE_TOO_LARGE, SEEDED_TOO_LARGE};
+use gene_compare_core::normalize::{Normalization, NormalizationReport};
+use gene_compare_core::primers::{primer_candidates, OligoConditions};
+use gene_compare_core::scoring::{ProteinMatrix, ScoreMatrix, Scoring};
+use gene_compare_core::translation::{ReadingFrames, StartCodons, Translation};
+use gene_compare_core::variants::{annotate_mismatches, heterozygous_calls, parse_vcf, HeterozygousClass, KnownVariant};
+use gene_compare_core::{
+  CODON_SIZE, DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+  DEFAULT_SEGMENT_WINDOW_LENGTH,
+};
+use wasm_bindgen::prelude::*;
+
+use crate::compression::Compression;
+use crate::f64_array_json;
+use crate::progress::Progress;
+use crate::regions::sequence_regions;
+
+/// Alignments at least this long get an `overview` unless the options set its bins
+const OVERVIEW_MIN_LENGTH: usize = 100_000;
+const DEFAULT_OVERVIEW_BINS: usize = 2000;
+
+// ============================================================================
+// Comparison Options
+// ============================================================================
+// One object instead of a growing list of positional numbers. Defaults match
+// lib/constants.js; each `with_*` method returns the updated options so calls
+// chain: `new ComparisonOptions().with_min_identity(0.8).with_seeded(true)`.
+// The positional exports remain for existing callers.
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ComparisonOptions {
+  segment_window_length: usize,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  block_exit_identity: Option<f64>,
+  trim_block_edges: bool,
+  good_enough_identity: Option<f64>,
+  min_alignment_identity: Option<f64>,
+  seeded: bool,
+  format: ResultFormat,
+  normalization: Normalization,
+  scoring: Scoring,
+  frame1: Option<usize>,
+  frame2: Option<usize>,
+  cds: Option<[usize; 4]>,
+  start_codons: StartCodons,
+  kozak: bool,
+  translation: Translation,
+  primers: bool,
+  highlight_ranges: bool,
+  overview_bins: Option<usize>,
+  compression: Option<Compression>,
+  heterozygous: bool,
+  composition_adjusted: bool,
+  mismatch_hotspots: usize,
+  known_variants: Option<Vec<KnownVariant>>,
+}
+
+impl Default for ComparisonOptions {
+  fn default() -> Self {
+    ComparisonOptions {
+      segment_window_length: DEFAULT_SEGMENT_WINDOW_LENGTH,
+      aa_segment_window_length: DEFAULT_SEGMENT_WINDOW_LENGTH / CODON_SIZE,
+      min_identity: DEFAULT_MIN_IDENTITY,
+      min_significant_length_group: DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP,
+      min_sequence_overlap_pct: DEFAULT_MIN_SEQUENCE_OVERLAP_PCT,
+      block_exit_identity: None,
+      trim_block_edges: false,
+      good_enough_identity: None,
+      min_alignment_identity: None,
+      seeded: false,
+      format: ResultFormat::default(),
+      normalization: Normalization::default(),
+      scoring: Scoring::default(),
+      frame1: None,
+      frame2: None,
+      cds: None,
+      start_codons: StartCodons::default(),
+      kozak: false,
+      translation: Translation::default(),
+      primers: false,
+      highlight_ranges: false,
+      overview_bins: None,
+      compression: None,
+      heterozygous: false,
+      composition_adjusted: false,
+      mismatch_hotspots: 0,
+      known_variants: None,
+    }
+  }
+}
+
+// ============================================================================
+// Presets
+// ============================================================================
+// (name, alias, window, min identity, min significant length group, min overlap)
+const PRESETS: &[(&str, &str, usize, f64, f64, f64)] = &[
+  // Same-species isoforms: long shared exons, near-identical sequence
+  ("strict", "isoforms", 66, 0.9, 0.15, 0.8),
+  ("default", "default", DEFAULT_SEGMENT_WINDOW_LENGTH, DEFAULT_MIN_IDENTITY, DEFAULT_MIN_SIGNIFICANT_LENGTH_GROUP, DEFAULT_MIN_SEQUENCE_OVERLAP_PCT),
+  // Noisy Sanger reads: short windows, error-tolerant, reads may only overlap at their ends
+  ("permissive", "sanger", 30, 0.6, 0.1, 0.3),
+  // Cross-species orthologs: lower identity spread over longer conserved stretches
+  ("cross-species", "orthologs", 90, 0.55, 0.2, 0.5),
+];
+
+#[wasm_bindgen]
+impl ComparisonOptions {
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> ComparisonOptions {
+    ComparisonOptions::default()
+  }
+
+  /// Options for a named scenario: "strict" (alias "isoforms"), "default", "permissive"
+  /// ("sanger"), or "cross-species" ("orthologs"); unknown names give the defaults
+  pub fn preset(name: &str) -> ComparisonOptions {
+    let name = name.to_ascii_lowercase();
+    match PRESETS.iter().find(|preset| preset.0 == name || preset.1 == name) {
+      Some(&(_, _, window, min_identity, min_significant_length_group, min_sequence_overlap_pct)) => ComparisonOptions {
+        segment_window_length: window,
+        aa_segment_window_length: window / CODON_SIZE,
+        min_identity,
+        min_significant_length_group,
+        min_sequence_overlap_pct,
+        ..ComparisonOptions::default()
+      },
+      None => ComparisonOptions::default(),
+    }
+  }
+
+  /// Names accepted by `preset` (aliases not included)
+  pub fn preset_names() -> Vec<String> {
+    PRESETS.iter().map(|preset| preset.0.to_string()).collect()
+  }
+
+  /// Conserved-block window in nucleotides
+  pub fn with_segment_window_length(mut self, value: usize) -> ComparisonOptions {
+    self.segment_window_length = value;
+    self
+  }
+
+  /// Conserved-block window in amino acids, for protein comparisons
+  pub fn with_aa_segment_window_length(mut self, value: usize) -> ComparisonOptions {
+    self.aa_segment_window_length = value;
+    self
+  }
+
+  pub fn with_min_identity(mut self, value: f64) -> ComparisonOptions {
+    self.min_identity = value;
+    self
+  }
+
+  pub fn with_min_significant_length_group(mut self, value: f64) -> ComparisonOptions {
+    self.min_significant_length_group = value;
+    self
+  }
+
+  pub fn with_min_sequence_overlap_pct(mut self, value: f64) -> ComparisonOptions {
+    self.min_sequence_overlap_pct = value;
+    self
+  }
+
+  /// Detect conserved blocks with hysteresis on the identity of the window centered on
+  /// each column: a block starts where it reaches `min_identity` and ends where it drops
+  /// below this lower exit identity, for steadier boundaries than fixed windows (an exit
+  /// above `min_identity` is taken as `min_identity`); `undefined` (the default) uses fixed windows
+  pub fn with_block_exit_identity(mut self, value: Option<f64>) -> ComparisonOptions {
+    self.block_exit_identity = value;
+    self
+  }
+
+  /// Trim the mismatches (and gaps) at either end of each conserved block, so block
+  /// positions, lengths, and sequences start and end on identical columns and can be used
+  /// as primer or probe targets directly
+  pub fn with_trim_block_edges(mut self, value: bool) -> ComparisonOptions {
+    self.trim_block_edges = value;
+    self
+  }
+
+  /// Stop the offset search at the first placement reaching this identity (see
+  /// `compare_sequences_good_enough`); `undefined` scans every offset
+  pub fn with_good_enough_identity(mut self, value: Option<f64>) -> ComparisonOptions {
+    self.good_enough_identity = value;
+    self
+  }
+
+  /// Identity the best placement must reach for `compare_sequences_with_options` to report
+  /// it as the alignment; below it the result says `noSignificantAlignment` and carries the
+  /// placement as `bestAttempt`. `undefined` (the default) reports any placement
+  pub fn with_min_alignment_identity(mut self, value: Option<f64>) -> ComparisonOptions {
+    self.min_alignment_identity = value;
+    self
+  }
+
+  /// Place the sequences with the k-mer seeded search instead of the exhaustive scan
+  pub fn with_seeded(mut self, value: bool) -> ComparisonOptions {
+    self.seeded = value;
+    self
+  }
+
+  /// Mask character at mismatches (default '?'); e.g. '.' together with
+  /// `with_match_symbol('|')` gives BLAST-style match lines. Letters, '*', and the match or
+  /// gap symbol are refused and keep the current symbol
+  pub fn with_mismatch_symbol(mut self, value: char) -> ComparisonOptions {
+    let symbols = self.format.symbols;
+    if let Some(symbol) = mask_symbol(value).filter(|&c| Some(c) != symbols.matched && c != symbols.gap) {
+      self.format.symbols.mismatch = symbol;
+    }
+    self
+  }
+
+  /// Mask character at matches; `undefined` (the default) keeps the residue. Letters, '*',
+  /// and the mismatch or gap symbol are refused and keep the current setting
+  pub fn with_match_symbol(mut self, value: Option<char>) -> ComparisonOptions {
+    let symbols = self.format.symbols;
+    match value {
+      None => self.format.symbols.matched = None,
+      Some(value) => {
+        if let Some(symbol) = mask_symbol(value).filter(|&c| c != symbols.mismatch && c != symbols.gap) {
+          self.format.symbols.matched = Some(symbol);
+        }
+      }
+    }
+    self
+  }
+
+  /// Mask character where either sequence has a '-' gap (default '-'). Letters, '*', and the
+  /// mismatch or match symbol are refused and keep the current symbol
+  pub fn with_gap_symbol(mut self, value: char) -> ComparisonOptions {
+    let symbols = self.format.symbols;
+    if let Some(symbol) = mask_symbol(value).filter(|&c| c != symbols.mismatch && Some(c) != symbols.matched) {
+      self.format.symbols.gap = symbol;
+    }
+    self
+  }
+
+  /// Write offsets and block positions 1-based with inclusive ends, as NCBI shows them,
+  /// instead of 0-based half-open; results name theirs in `coordinateSystem`
+  pub fn with_one_based(mut self, value: bool) -> ComparisonOptions {
+    self.format.coordinates = if value { CoordinateSystem::OneBased } else { CoordinateSystem::ZeroBased };
+    self
+  }
+
+  /// Enable or disable every input-cleaning step below at once (poly-A trimming is
+  /// left as it is)
+  pub fn with_normalize(mut self, value: bool) -> ComparisonOptions {
+    let trim_poly_a = self.normalization.trim_poly_a;
+    self.normalization = if value { Normalization::all() } else { Normalization::default() };
+    self.normalization.trim_poly_a = trim_poly_a;
+    self
+  }
+
+  /// Compare case-insensitively by uppercasing both sequences first
+  pub fn with_uppercase(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.uppercase = value;
+    self
+  }
+
+  /// Remove whitespace and line breaks before comparing
+  pub fn with_strip_whitespace(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.strip_whitespace = value;
+    self
+  }
+
+  /// Remove digits (GenBank ORIGIN line coordinates) before comparing
+  pub fn with_strip_digits(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.strip_digits = value;
+    self
+  }
+
+  /// Replace U with T before comparing, so RNA compares against DNA
+  pub fn with_u_to_t(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.u_to_t = value;
+    self
+  }
+
+  /// Accept pasted GenBank records: keep only the ORIGIN section and drop its line
+  /// coordinates and spacing
+  pub fn with_genbank(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.genbank = value;
+    self
+  }
+
+  /// Trim poly-A tails (12+ bases, at least 90% A) from the sequence ends before
+  /// aligning; not included in `with_normalize`
+  pub fn with_trim_poly_a(mut self, value: bool) -> ComparisonOptions {
+    self.normalization.trim_poly_a = value;
+    self
+  }
+
+  /// Score of a matching nucleotide column (default 1), and of matching amino acids with
+  /// the "match-mismatch" protein matrix
+  pub fn with_match_score(mut self, value: i32) -> ComparisonOptions {
+    self.scoring.match_score = value;
+    self
+  }
+
+  /// Score of a mismatching column (default -2), as for `with_match_score`
+  pub fn with_mismatch_score(mut self, value: i32) -> ComparisonOptions {
+    self.scoring.mismatch_score = value;
+    self
+  }
+
+  /// Score of a nucleotide column with a '-' gap (default -2)
+  pub fn with_gap_score(mut self, value: i32) -> ComparisonOptions {
+    self.scoring.gap_score = value;
+    self
+  }
+
+  /// Amino-acid scores: "blosum62" (the default) or "match-mismatch"; unknown names keep
+  /// the current matrix
+  pub fn with_protein_matrix(mut self, name: &str) -> ComparisonOptions {
+    match name.to_ascii_lowercase().as_str() {
+      "blosum62" => self.scoring.protein_matrix = ProteinMatrix::Blosum62,
+      "match-mismatch" => self.scoring.protein_matrix = ProteinMatrix::MatchMismatch,
+      _ => {}
+    }
+    self
+  }
+
+  /// Amino-acid scores from a matrix in NCBI's text format (as distributed with BLAST) or
+  /// JSON (`{"A": {"A": 4, ...}, ...}` or `{"alphabet": "AR...", "scores": [[...], ...]}`),
+  /// for alphabets beyond the built-in matrices; residues it lacks score as its X. A matrix
+  /// that cannot be read keeps the current one
+  pub fn with_scoring_matrix(mut self, matrix: &str) -> ComparisonOptions {
+    if let Some(matrix) = ScoreMatrix::parse(matrix) {
+      self.scoring.protein_matrix = ProteinMatrix::Custom(Arc::new(matrix));
+    }
+    self
+  }
+
+  /// Nucleotide scores from a matrix in the `with_scoring_matrix` formats instead of the
+  /// match and mismatch scores (base pairs it lacks score as its N); an empty string goes
+  /// back to them, and a matrix that cannot be read keeps the current scores
+  pub fn with_nucleotide_matrix(mut self, matrix: &str) -> ComparisonOptions {
+    if matrix.trim().is_empty() {
+      self.scoring.nucleotide_matrix = None;
+    } else if let Some(matrix) = ScoreMatrix::parse(matrix) {
+      self.scoring.nucleotide_matrix = Some(Arc::new(matrix));
+    }
+    self
+  }
+
+  /// Score of an amino-acid column with a '-' gap (default -4)
+  pub fn with_protein_gap_score(mut self, value: i32) -> ComparisonOptions {
+    self.scoring.protein_gap_score = value;
+    self
+  }
+
+  /// Translate seq1 only in this frame (0-2) relative to its nucleotide offset instead of
+  /// searching all three; `undefined` (the default) searches. Other values are ignored
+  pub fn with_frame1(mut self, value: Option<usize>) -> ComparisonOptions {
+    if value.is_none_or(|frame| frame < CODON_SIZE) {
+      self.frame1 = value;
+    }
+    self
+  }
+
+  /// Pin the frame of seq2, as for `with_frame1`
+  pub fn with_frame2(mut self, value: Option<usize>) -> ComparisonOptions {
+    if value.is_none_or(|frame| frame < CODON_SIZE) {
+      self.frame2 = value;
+    }
+    self
+  }
+
+  /// Compare the translations of these coding regions (e.g. GenBank CDS features, in the
+  /// options' coordinate system) instead of searching frames over the nucleotide placement;
+  /// takes precedence over pinned frames
+  pub fn with_cds(mut self, start1: usize, end1: usize, start2: usize, end2: usize) -> ComparisonOptions {
+    self.cds = Some([start1, end1, start2, end2]);
+    self
+  }
+
+  /// Go back to searching frames over the nucleotide placement
+  pub fn without_cds(mut self) -> ComparisonOptions {
+    self.cds = None;
+    self
+  }
+
+  /// Codons that start a CDS (default "ATG"): a list such as "ATG,GTG,TTG", or "standard",
+  /// "bacterial", or "mitochondrial"; used for CDS inference and the frame-detection notes.
+  /// Invalid lists keep the current codons
+  pub fn with_start_codons(mut self, codons: &str) -> ComparisonOptions {
+    if let Some(start_codons) = StartCodons::parse(codons) {
+      self.start_codons = start_codons;
+    }
+    self
+  }
+
+  /// Take the start codon in the strongest Kozak context (gccRccATGG) rather than the
+  /// first one, so upstream ATGs in a 5' UTR are passed over; results report the score
+  pub fn with_kozak(mut self, value: bool) -> ComparisonOptions {
+    self.kozak = value;
+    self
+  }
+
+  /// Translate TGA as selenocysteine (U) instead of a stop, for genes with a SECIS element
+  pub fn with_selenocysteine(mut self, value: bool) -> ComparisonOptions {
+    self.translation.selenocysteine = value;
+    self
+  }
+
+  /// Translate IUPAC-ambiguous codons to the amino acid (or stop) they all encode, B/Z/J
+  /// for D/N, E/Q, and I/L, or X, instead of always X; gap codons become '-'
+  pub fn with_resolve_ambiguous_codons(mut self, value: bool) -> ComparisonOptions {
+    self.translation.resolve_ambiguity = value;
+    self
+  }
+
+  /// Add `primers`: the best forward and reverse primer in each conserved block that
+  /// matches both sequences exactly (18-25 bases, nearest-neighbor Tm 55-65 °C, 40-60%
+  /// GC, a 3' GC clamp, little self-complementarity)
+  pub fn with_primers(mut self, value: bool) -> ComparisonOptions {
+    self.primers = value;
+    self
+  }
+
+  /// Add `highlightRanges`: the aligned columns merged into sorted `{start, end, kind}`
+  /// runs of `match`, `mismatch`, `gap`, or `masked` (lowercase or N in either sequence),
+  /// plus a `block` range per conserved block, so front ends need not rescan the mask
+  pub fn with_highlight_ranges(mut self, value: bool) -> ComparisonOptions {
+    self.highlight_ranges = value;
+    self
+  }
+
+  /// Number of bins in `overview`, the identity of the alignment at a fixed resolution for
+  /// zoomed-out views; added by default (2000 bins) once the alignment spans 100 kb, at any
+  /// length when set here, and never when set to 0
+  pub fn with_overview_bins(mut self, value: usize) -> ComparisonOptions {
+    self.overview_bins = Some(value);
+    self
+  }
+
+  /// How `compare_sequences_compressed` encodes its result: "gzip" (the default), "deflate"
+  /// (zlib), "deflate-raw", or "none" for plain UTF-8, as read by `DecompressionStream`;
+  /// unknown names keep the current format
+  pub fn with_compression(mut self, name: &str) -> ComparisonOptions {
+    if let Some(compression) = Compression::from_name(name) {
+      self.compression = Some(compression);
+    }
+    self
+  }
+
+  /// Treat two-base IUPAC codes in seq2 (R, Y, S, W, K, M from heterozygous Sanger peaks)
+  /// as heterozygous calls against the reference seq1: results list them in
+  /// `heterozygousCalls`, each `hetConsistentWithReference` (one allele is seq1's base) or
+  /// `hetNonReference`, with the counts and the `homozygousMismatches` left
+  pub fn with_heterozygous(mut self, value: bool) -> ComparisonOptions {
+    self.heterozygous = value;
+    self
+  }
+
+  /// Add `adjustedIdentity` to results: the identity over the aligned columns outside
+  /// homopolymer runs and low-complexity (DUST) stretches of either sequence, which poly-A
+  /// tracts and repeats cannot inflate
+  pub fn with_composition_adjusted_identity(mut self, value: bool) -> ComparisonOptions {
+    self.composition_adjusted = value;
+    self
+  }
+
+  /// Add `mismatchHotspots` to results: the `count` most divergent non-overlapping windows
+  /// of `segment_window_length` columns (`start`, `end` in the mask as for conserved blocks,
+  /// mismatch and gap `differences`, `identity`), most divergent first; 0 leaves them out
+  pub fn with_mismatch_hotspots(mut self, count: usize) -> ComparisonOptions {
+    self.mismatch_hotspots = count;
+    self
+  }
+
+  /// Known variants of seq1's region as VCF text (e.g. a dbSNP slice), whose POS counts
+  /// from `seq1_start`, the 1-based contig position of seq1's first base; results then list
+  /// every mismatch in `mismatchVariants`, marked known (with its ID and alleles) or novel
+  pub fn with_known_variants(mut self, vcf: &str, seq1_start: usize) -> ComparisonOptions {
+    self.known_variants = Some(parse_vcf(vcf, seq1_start));
+    self
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn segment_window_length(&self) -> usize {
+    self.segment_window_length
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn aa_segment_window_length(&self) -> usize {
+    self.aa_segment_window_length
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn min_identity(&self) -> f64 {
+    self.min_identity
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn min_significant_length_group(&self) -> f64 {
+    self.min_significant_length_group
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn min_sequence_overlap_pct(&self) -> f64 {
+    self.min_sequence_overlap_pct
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn block_exit_identity(&self) -> Option<f64> {
+    self.block_exit_identity
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn trim_block_edges(&self) -> bool {
+    self.trim_block_edges
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn good_enough_identity(&self) -> Option<f64> {
+    self.good_enough_identity
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn min_alignment_identity(&self) -> Option<f64> {
+    self.min_alignment_identity
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn seeded(&self) -> bool {
+    self.seeded
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn mismatch_symbol(&self) -> char {
+    self.format.symbols.mismatch as char
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn match_symbol(&self) -> Option<char> {
+    self.format.symbols.matched.map(char::from)
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn gap_symbol(&self) -> char {
+    self.format.symbols.gap as char
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn one_based(&self) -> bool {
+    self.format.coordinates == CoordinateSystem::OneBased
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn uppercase(&self) -> bool {
+    self.normalization.uppercase
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn strip_whitespace(&self) -> bool {
+    self.normalization.strip_whitespace
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn strip_digits(&self) -> bool {
+    self.normalization.strip_digits
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn u_to_t(&self) -> bool {
+    self.normalization.u_to_t
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn genbank(&self) -> bool {
+    self.normalization.genbank
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn trim_poly_a(&self) -> bool {
+    self.normalization.trim_poly_a
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn match_score(&self) -> i32 {
+    self.scoring.match_score
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn mismatch_score(&self) -> i32 {
+    self.scoring.mismatch_score
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn gap_score(&self) -> i32 {
+    self.scoring.gap_score
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn protein_matrix(&self) -> String {
+    match self.scoring.protein_matrix {
+      ProteinMatrix::Blosum62 => "blosum62",
+      ProteinMatrix::MatchMismatch => "match-mismatch",
+      ProteinMatrix::Custom(_) => "custom",
+    }
+    .to_string()
+  }
+
+  /// Whether nucleotide scores come from a `with_nucleotide_matrix` matrix
+  #[wasm_bindgen(getter)]
+  pub fn custom_nucleotide_matrix(&self) -> bool {
+    self.scoring.nucleotide_matrix.is_some()
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn protein_gap_score(&self) -> i32 {
+    self.scoring.protein_gap_score
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn frame1(&self) -> Option<usize> {
+    self.frame1
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn frame2(&self) -> Option<usize> {
+    self.frame2
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn start_codons(&self) -> String {
+    self.start_codons.names()
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn kozak(&self) -> bool {
+    self.kozak
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn selenocysteine(&self) -> bool {
+    self.translation.selenocysteine
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn resolve_ambiguous_codons(&self) -> bool {
+    self.translation.resolve_ambiguity
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn primers(&self) -> bool {
+    self.primers
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn highlight_ranges(&self) -> bool {
+    self.highlight_ranges
+  }
+
+  /// Bins set by `with_overview_bins`, or null for the default
+  #[wasm_bindgen(getter)]
+  pub fn overview_bins(&self) -> Option<usize> {
+    self.overview_bins
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn compression(&self) -> String {
+    self.compression.unwrap_or(Compression::Gzip).name().to_string()
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn heterozygous(&self) -> bool {
+    self.heterozygous
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn composition_adjusted_identity(&self) -> bool {
+    self.composition_adjusted
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn mismatch_hotspots(&self) -> usize {
+    self.mismatch_hotspots
+  }
+
+  /// Number of variants read by `with_known_variants`, or null without any list
+  #[wasm_bindgen(getter)]
+  pub fn known_variant_count(&self) -> Option<usize> {
+    self.known_variants.as_ref().map(Vec::len)
+  }
+
+  /// `[start1, end1, start2, end2]` as given to `with_cds`
+  #[wasm_bindgen(getter)]
+  pub fn cds(&self) -> Option<Vec<usize>> {
+    self.cds.map(|cds| cds.to_vec())
+  }
+}
+
+impl ComparisonOptions {
+  /// Options with only the search settings, for exports that take them as arguments
+  pub(crate) fn placement(min_sequence_overlap_pct: f64, seeded: bool) -> ComparisonOptions {
+    ComparisonOptions::new().with_min_sequence_overlap_pct(min_sequence_overlap_pct).with_seeded(seeded)
+  }
+
+  /// Best placement of seq2 against seq1 by the options' search (seeded, stopping at the
+  /// good-enough identity, or the exhaustive scan); an error when the search would need more
+  /// scratch memory than the limit allows
+  pub(crate) fn place(&self, bytes1: &[u8], bytes2: &[u8]) -> Result<Alignment, LimitExceeded> {
+    let pct = self.min_sequence_overlap_pct;
+    if self.seeded {
+      check_memory(seeded_scratch_bytes(bytes1.len(), bytes2.len()), SEEDED_TOO_LARGE)?;
+      return Ok(find_seeded_alignment(bytes1, bytes2, pct));
+    }
+    check_memory(exhaustive_scratch_bytes(bytes1.len(), bytes2.len()), EXHAUSTIVE_TOO_LARGE)?;
+    Ok(match self.good_enough_identity {
+      Some(identity) => find_good_enough_alignment(bytes1, bytes2, pct, identity),
+      None => find_best_alignment(bytes1, bytes2, pct),
+    })
+  }
+
+  pub(crate) fn format(&self) -> &ResultFormat {
+    &self.format
+  }
+
+  pub(crate) fn coordinates(&self) -> CoordinateSystem {
+    self.format.coordinates
+  }
+
+  /// How conserved blocks are detected
+  pub(crate) fn block_detection(&self) -> BlockDetection {
+    let method = match self.block_exit_identity {
+      Some(exit_identity) => BlockMethod::Hysteresis { exit_identity },
+      None => BlockMethod::Windows,
+    };
+    BlockDetection { method, trim_edges: self.trim_block_edges }
+  }
+
+  /// How the protein comparison picks frames, with CDS starts converted to 0-based
+  pub(crate) fn reading_frames(&self) -> ReadingFrames {
+    let Some([start1, end1, start2, end2]) = self.cds else {
+      return ReadingFrames::Search {
+        frame1: self.frame1,
+        frame2: self.frame2,
+        start_codons: self.start_codons,
+        kozak: self.kozak,
+      };
+    };
+    let start = |start: usize| self.coordinates().start_index(start as i32).max(0) as usize;
+    ReadingFrames::Cds { cds1: (start(start1), end1), cds2: (start(start2), end2) }
+  }
+
+  pub(crate) fn translation(&self) -> &Translation {
+    &self.translation
+  }
+
+  /// Both sequences after the configured normalization, with what changed in each
+  pub(crate) fn normalize<'a>(
+    &self,
+    seq1: &'a str,
+    seq2: &'a str,
+  ) -> (Cow<'a, [u8]>, Cow<'a, [u8]>, [NormalizationReport; 2]) {
+    let (bytes1, report1) = self.normalization.apply(seq1.as_bytes());
+    let (bytes2, report2) = self.normalization.apply(seq2.as_bytes());
+    (bytes1, bytes2, [report1, report2])
+  }
+
+  /// One sequence after the configured normalization
+  pub(crate) fn normalize_one<'a>(&self, seq: &'a str) -> Cow<'a, [u8]> {
+    self.normalization.apply(seq.as_bytes()).0
+  }
+
+  /// `result` with a `normalization` field when any step is enabled
+  fn with_normalization_report(&self, result: String, reports: &[NormalizationReport; 2]) -> String {
+    if !self.normalization.is_enabled() {
+      return result;
+    }
+    let fields = format!(r#""normalization":{{"seq1":{},"seq2":{}}}"#, reports[0].to_json(), reports[1].to_json());
+    extend_result(result, &fields)
+  }
+}
+
+/// Printable ASCII except '"' and '\', which would need escaping in the result JSON, and
+/// the letters and '*' that residues are written with, which a mask could not tell apart
+fn mask_symbol(value: char) -> Option<u8> {
+  (value.is_ascii_graphic() || value == ' ')
+    .then_some(value as u8)
+    .filter(|c| !c.is_ascii_alphabetic() && !matches!(c, b'"' | b'\\' | b'*'))
+}
+
+// ============================================================================
+// Comparisons with Options (exported)
+// ============================================================================
+/// `compare_sequences_full` configured by a `ComparisonOptions` object. With normalization
+/// enabled, offsets refer to the normalized sequences and the result has a `normalization`
+/// report per sequence. When the best placement falls short of `min_alignment_identity`,
+/// the result is `{"noSignificantAlignment": true, "minAlignmentIdentity", "identity",
+/// "length", "bestAttempt"}` with the usual result as `bestAttempt`.
+#[wasm_bindgen]
+pub fn compare_sequences_with_options(seq1: &str, seq2: &str, options: &ComparisonOptions) -> String {
+  nucleotide_comparison_with_options(seq1, seq2, options).1
+}
+
+/// `compare_sequences_with_options` with the JSON compressed in Rust as the options'
+/// `with_compression` format says (gzip by default), for results too large to pass around
+/// as strings: decode with `new Response(new Blob([bytes]).stream().pipeThrough(new
+/// DecompressionStream("gzip"))).json()`
+#[wasm_bindgen]
+pub fn compare_sequences_compressed(seq1: &str, seq2: &str, options: &ComparisonOptions) -> Vec<u8> {
+  let result = compare_sequences_with_options(seq1, seq2, options);
+  options.compression.unwrap_or(Compression::Gzip).compress(result.as_bytes())
+}
+
+/// Shared body of the nucleotide comparisons with options; returns the placement's identity
+/// (0 when there is none) alongside the JSON result
+pub(crate) fn nucleotide_comparison_with_options(seq1: &str, seq2: &str, options: &ComparisonOptions) -> (f64, String) {
+  let (bytes1, bytes2, reports) = options.normalize(seq1, seq2);
+  let (identity, result) = nucleotide_with_options(&bytes1, &bytes2, options);
+  (identity, options.with_normalization_report(result, &reports))
+}
+
+fn nucleotide_with_options(bytes1: &[u8], bytes2: &[u8], options: &ComparisonOptions) -> (f64, String) {
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return (0.0, empty_nucleotide_result(&options.format));
+  }
+
+  let alignment = match options.place(bytes1, bytes2) {
+    Ok(alignment) => alignment,
+    Err(exceeded) => return (0.0, exceeded.to_json()),
+  };
+
+  let result = aligned_result_with_options(bytes1, bytes2, &alignment, options);
+  let result = match options.min_alignment_identity {
+    Some(min_identity) if alignment.identity < min_identity => with_fingerprint(format!(
+      r#"{{"noSignificantAlignment":true,"minAlignmentIdentity":{},"identity":{},"length":{},"bestAttempt":{}}}"#,
+      min_identity, alignment.identity, alignment.length, result
+    )),
+    _ => result,
+  };
+  (alignment.identity, result)
+}
+
+/// The nucleotide result for a placement, with the fields the options add
+fn aligned_result_with_options(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  alignment: &Alignment,
+  options: &ComparisonOptions,
+) -> String {
+  let result = comparison::nucleotide_result_json(
+    bytes1,
+    bytes2,
+    alignment,
+    options.segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    &options.format,
+    &options.scoring,
+    options.block_detection(),
+    &mut Progress::none(),
+  );
+  let overview_bins = match options.overview_bins {
+    Some(bins) => bins,
+    None if alignment.length >= OVERVIEW_MIN_LENGTH => DEFAULT_OVERVIEW_BINS,
+    None => 0,
+  };
+  if !options.primers
+    && !options.highlight_ranges
+    && overview_bins == 0
+    && !options.heterozygous
+    && !options.composition_adjusted
+    && options.mismatch_hotspots == 0
+    && options.known_variants.is_none()
+  {
+    return result;
+  }
+  let mask = alignment.mask(bytes1, bytes2);
+  let blocks = find_blocks(
+    &mask,
+    options.segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    options.block_detection(),
+  );
+  let region1 = &bytes1[alignment.offset1..alignment.offset1 + alignment.length];
+  let mut fields = Vec::new();
+  if options.primers {
+    let primers: Vec<String> =
+      primer_candidates(region1, &mask, &blocks, (alignment.offset1, alignment.offset2), &OligoConditions::default())
+        .iter()
+        .map(|primer| primer.to_json(&options.format))
+        .collect();
+    fields.push(format!(r#""primers":[{}]"#, primers.join(",")));
+  }
+  if options.highlight_ranges {
+    let region2 = &bytes2[alignment.offset2..alignment.offset2 + alignment.length];
+    let ranges: Vec<String> =
+      highlight_ranges(region1, region2, &mask, &blocks).iter().map(|range| range.to_json(&options.format)).collect();
+    fields.push(format!(r#""highlightRanges":[{}]"#, ranges.join(",")));
+  }
+  if overview_bins > 0 {
+    let identity = overview_identity(&mask, overview_bins);
+    fields.push(format!(
+      r#""overview":{{"bins":{},"binWidth":{},"identity":{}}}"#,
+      identity.len(),
+      if identity.is_empty() { 0.0 } else { mask.len() as f64 / identity.len() as f64 },
+      f64_array_json(&identity)
+    ));
+  }
+  if options.heterozygous {
+    let calls = heterozygous_calls(bytes1, bytes2, alignment);
+    let consistent =
+      calls.iter().filter(|call| call.class == HeterozygousClass::ConsistentWithReference).count();
+    let rows: Vec<String> = calls.iter().map(|call| call.to_json(&options.format)).collect();
+    fields.push(format!(
+      r#""heterozygousCalls":[{}],"hetConsistentWithReference":{},"hetNonReference":{},"homozygousMismatches":{}"#,
+      rows.join(","),
+      consistent,
+      calls.len() - consistent,
+      count_mismatches_in_mask(&mask) - calls.len()
+    ));
+  }
+  if options.mismatch_hotspots > 0 {
+    let hotspots: Vec<String> = divergent_windows(&mask, options.segment_window_length, options.mismatch_hotspots)
+      .iter()
+      .map(|hotspot| {
+        format!(
+          r#"{{"start":{},"end":{},"differences":{},"identity":{}}}"#,
+          options.format.coordinates.start(hotspot.start),
+          options.format.coordinates.end(hotspot.end),
+          hotspot.differences,
+          hotspot.identity
+        )
+      })
+      .collect();
+    fields.push(format!(r#""mismatchHotspots":[{}]"#, hotspots.join(",")));
+  }
+  if options.composition_adjusted {
+    let adjusted = adjusted_identity(bytes1, bytes2, (alignment.offset1, alignment.offset2), &mask);
+    fields.push(format!(
+      r#""adjustedIdentity":{{"identity":{},"columns":{},"excludedColumns":{}}}"#,
+      adjusted.identity, adjusted.columns, adjusted.excluded
+    ));
+  }
+  if let Some(variants) = &options.known_variants {
+    let mismatches = annotate_mismatches(bytes1, bytes2, alignment, &mask, variants);
+    let known = mismatches.iter().filter(|mismatch| mismatch.variant.is_some()).count();
+    let rows: Vec<String> = mismatches.iter().map(|mismatch| mismatch.to_json(&options.format)).collect();
+    fields.push(format!(
+      r#""mismatchVariants":[{}],"knownMismatches":{},"novelMismatches":{}"#,
+      rows.join(","),
+      known,
+      mismatches.len() - known
+    ));
+  }
+  extend_result(result, &fields.join(","))
+}
+
+/// `compare_proteins_full` configured by a `ComparisonOptions` object (the amino-acid
+/// window comes from `aa_segment_window_length`); the nucleotide offsets and length refer
+/// to the normalized sequences and count in the options' coordinate system, as in the
+/// `compare_sequences_with_options` result. Pinned frames or a CDS replace the frame search
+#[wasm_bindgen]
+pub fn compare_proteins_with_options(
+  seq1: &str,
+  seq2: &str,
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  options: &ComparisonOptions,
+) -> String {
+  let (bytes1, bytes2, reports) = options.normalize(seq1, seq2);
+  let coordinates = options.format.coordinates;
+  let result = comparison::protein_comparison(
+    &bytes1,
+    &bytes2,
+    coordinates.start_index(nuc_offset1),
+    coordinates.start_index(nuc_offset2),
+    nuc_length,
+    options.aa_segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    &options.reading_frames(),
+    &options.translation,
+    &options.format,
+    &options.scoring,
+    options.block_detection(),
+    &mut Progress::none(),
+  );
+  options.with_normalization_report(result, &reports)
+}
+
+/// Protein comparison of amino-acid sequences as given (protein FASTA such as NP_/XP_
+/// records): the best ungapped placement, mask, conserved blocks (`aa_segment_window_length`),
+/// and scores, in the `compare_proteins_with_options` result shape with `frame1`, `frame2`,
+/// `mismatchCodons`, and `frameDetection` null. Normalization skips the U-to-T and poly-A
+/// steps, which would change residues
+#[wasm_bindgen]
+pub fn compare_proteins_direct(aa1: &str, aa2: &str, options: &ComparisonOptions) -> String {
+  let options = ComparisonOptions { normalization: options.normalization.for_protein(), ..options.clone() };
+  let (bytes1, bytes2, reports) = options.normalize(aa1, aa2);
+  let result = comparison::protein_direct_comparison(
+    &bytes1,
+    &bytes2,
+    options.aa_segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    options.min_sequence_overlap_pct,
+    &options.format,
+    &options.scoring,
+    options.block_detection(),
+    &mut Progress::none(),
+  );
+  options.with_normalization_report(result, &reports)
+}
+
+/// Comparison of a protein with an mRNA translated in its three forward frames (with the
+/// options' codon rules), reporting the frame and mRNA region that encode the protein as
+/// `encodingRegion`; for checking CDS annotations. The protein is normalized without the
+/// U-to-T and poly-A steps
+#[wasm_bindgen]
+pub fn compare_protein_to_mrna(protein: &str, mrna: &str, options: &ComparisonOptions) -> String {
+  let (protein, report1) = options.normalization.for_protein().apply(protein.as_bytes());
+  let (mrna, report2) = options.normalization.apply(mrna.as_bytes());
+  let result = comparison::protein_mrna_comparison(
+    &protein,
+    &mrna,
+    options.aa_segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    options.min_sequence_overlap_pct,
+    &options.translation,
+    &options.format,
+    &options.scoring,
+    options.block_detection(),
+    &mut Progress::none(),
+  );
+  options.with_normalization_report(result, &[report1, report2])
+}
+
+/// `compare_sequence_regions` configured by a `ComparisonOptions` object: its thresholds,
+/// normalization, start codons, and Kozak setting (for the inferred CDS). Positions are 0-based half-open
+/// in the normalized seq1
+#[wasm_bindgen]
+pub fn compare_sequence_regions_with_options(
+  seq1: &str,
+  seq2: &str,
+  cds_start: Option<usize>,
+  cds_end: Option<usize>,
+  options: &ComparisonOptions,
+) -> String {
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  sequence_regions(
+    &bytes1,
+    &bytes2,
+    cds_start,
+    cds_end,
+    options.segment_window_length,
+    options.min_identity,
+    options.min_significant_length_group,
+    options,
+    &options.start_codons,
+    options.kozak,
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn symbols(options: &ComparisonOptions) -> (u8, Option<u8>, u8) {
+    let symbols = options.format.symbols;
+    (symbols.mismatch, symbols.matched, symbols.gap)
+  }
+
+  #[test]
+  fn mask_symbols_refuse_residue_letters() {
+    let options = ComparisonOptions::new().with_mismatch_symbol('A').with_match_symbol(Some('n')).with_gap_symbol('*');
+    assert_eq!(symbols(&options), (b'?', None, b'-'));
+    let options = ComparisonOptions::new().with_mismatch_symbol('.').with_match_symbol(Some('|')).with_gap_symbol('~');
+    assert_eq!(symbols(&options), (b'.', Some(b'|'), b'~'));
+  }
+
+  #[test]
+  fn mask_symbols_refuse_a_symbol_already_in_use() {
+    let options = ComparisonOptions::new().with_gap_symbol('?').with_match_symbol(Some('-')).with_mismatch_symbol('-');
+    assert_eq!(symbols(&options), (b'?', None, b'-'));
+    let options = ComparisonOptions::new().with_match_symbol(Some('|')).with_gap_symbol('|').with_match_symbol(None).with_gap_symbol('|');
+    assert_eq!(symbols(&options), (b'?', None, b'|'));
+  }
+}
@@ -0,0 +1,103 @@
+use gene_compare_core::alignment::Alignment;
+use gene_compare_core::blocks::find_blocks;
+use gene_compare_core::ortholog::{compare_panel, parse_fasta};
+use wasm_bindgen::prelude::*;
+
+use crate::f64_array_json;
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Ortholog Panel (exported)
+// ============================================================================
+fn text(value: &str) -> String {
+  serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// `query` and each CDS of the multi-FASTA `orthologs` translated with the options' codon
+/// rules (from the first base, a final stop dropped) and compared as proteins. `orthologs`
+/// ranks the panel by identity to the query (then coverage of the query, then input
+/// order): each with its `id`, `description`, `proteinLength`, `identity`, `coverage`,
+/// aligned `length`, `mismatches`, and `offset1`/`offset2` of the best placement.
+/// `identities` is the pairwise protein identity matrix in input order with the query
+/// first, and `sharedBlocks` the conserved blocks (the options' `aa_segment_window_length`
+/// and thresholds) where every protein of the panel agrees, in query residues
+#[wasm_bindgen]
+pub fn compare_ortholog_panel(query: &str, orthologs: &str, options: &ComparisonOptions) -> String {
+  let records = parse_fasta(orthologs);
+  let translation = options.translation();
+  let protein = |cds: &str| {
+    let mut protein = translation.translate(&options.normalize_one(cds));
+    if protein.last() == Some(&b'*') {
+      protein.pop();
+    }
+    protein
+  };
+  let query = protein(query);
+  if query.is_empty() {
+    return r#"{"error":"The query needs at least one codon"}"#.to_string();
+  }
+  let mut proteins = vec![query];
+  proteins.extend(records.iter().map(|record| protein(&record.sequence)));
+  let coordinates = options.coordinates();
+
+  let panel = match compare_panel(&proteins, |a, b| options.place(a, b)) {
+    Ok(panel) => panel,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+  let query_length = proteins[0].len();
+  let ranked: Vec<String> = panel
+    .order
+    .iter()
+    .enumerate()
+    .map(|(rank, &index)| {
+      let record = &records[index];
+      let alignment = panel.placements[index].as_ref();
+      let offset = |offset: fn(&Alignment) -> usize| {
+        alignment.map_or("null".to_string(), |alignment| coordinates.start(offset(alignment)).to_string())
+      };
+      format!(
+        r#"{{"rank":{},"index":{},"id":{},"description":{},"proteinLength":{},"identity":{},"coverage":{},"length":{},"mismatches":{},"offset1":{},"offset2":{}}}"#,
+        rank + 1,
+        index,
+        text(&record.id),
+        text(&record.description),
+        proteins[index + 1].len(),
+        panel.identity(index),
+        panel.coverage(index, query_length),
+        alignment.map_or(0, |alignment| alignment.length),
+        alignment.map_or(0, |alignment| alignment.mismatches),
+        offset(|alignment| alignment.offset1),
+        offset(|alignment| alignment.offset2)
+      )
+    })
+    .collect();
+
+  let identities: Vec<String> = panel.identities.iter().map(|row| f64_array_json(row)).collect();
+  let blocks: Vec<String> = find_blocks(
+    &panel.shared_mask,
+    options.aa_segment_window_length(),
+    options.min_identity(),
+    options.min_significant_length_group(),
+    options.block_detection(),
+  )
+  .iter()
+  .map(|block| {
+    format!(
+      r#"{{"start":{},"end":{},"length":{},"sequence":"{}"}}"#,
+      coordinates.start(block.start),
+      coordinates.end(block.end),
+      block.length,
+      String::from_utf8_lossy(&block.sequence)
+    )
+  })
+  .collect();
+
+  format!(
+    r#"{{"coordinateSystem":"{}","queryLength":{},"orthologs":[{}],"identities":[{}],"sharedBlocks":[{}]}}"#,
+    coordinates.name(),
+    query_length,
+    ranked.join(","),
+    identities.join(","),
+    blocks.join(",")
+  )
+}
@@ -0,0 +1,9 @@
+// ============================================================================
+// Thread Pool
+// ============================================================================
+// With the `threads` feature the core crate spreads independent work items
+// across the global rayon pool; in the browser that pool is a set of Web
+// Workers that has to be started from JS first.
+
+#[cfg(all(feature = "threads", target_arch = "wasm32"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
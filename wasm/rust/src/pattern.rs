@@ -0,0 +1,66 @@
+use gene_compare_core::pattern::{approximate_matches, find_pattern as find_hits, PatternHit};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Probe and Guide Search (exported)
+// ============================================================================
+// Where a probe, primer, or guide lands, allowing mismatches, so callers can
+// check that it binds one allele and not the other.
+
+fn hits_json(seq: &[u8], pattern_length: usize, hits: &[PatternHit]) -> String {
+  let parts: Vec<String> = hits
+    .iter()
+    .map(|hit| {
+      format!(
+        r#"{{"position":{},"strand":"{}","mismatches":{},"match":{}}}"#,
+        hit.position,
+        if hit.reverse { '-' } else { '+' },
+        hit.mismatches,
+        serde_json::to_string(&String::from_utf8_lossy(&seq[hit.position..hit.position + pattern_length]))
+          .unwrap_or_else(|_| "\"\"".to_string())
+      )
+    })
+    .collect();
+  format!("[{}]", parts.join(","))
+}
+
+/// Hits of an IUPAC `pattern` in `seq` on either strand with up to `max_mismatches`
+/// mismatches: 0-based top-strand `position`, `strand`, `mismatches`, and the matched bases
+#[wasm_bindgen]
+pub fn find_pattern(seq: &str, pattern: &str, max_mismatches: usize) -> String {
+  let pattern = pattern.trim().as_bytes();
+  let hits = find_hits(seq.as_bytes(), pattern, max_mismatches);
+  format!(r#"{{"hits":{}}}"#, hits_json(seq.as_bytes(), pattern.len(), &hits))
+}
+
+/// `find_pattern` in both sequences, with each one's fewest mismatches (null without a hit)
+/// and whether they differ, i.e. whether the probe binds one sequence better than the other
+#[wasm_bindgen]
+pub fn find_pattern_in_both(seq1: &str, seq2: &str, pattern: &str, max_mismatches: usize) -> String {
+  let pattern = pattern.trim().as_bytes();
+  let (hits1, hits2) = (find_hits(seq1.as_bytes(), pattern, max_mismatches), find_hits(seq2.as_bytes(), pattern, max_mismatches));
+  let best = |hits: &[PatternHit]| hits.iter().map(|hit| hit.mismatches).min();
+  let (best1, best2) = (best(&hits1), best(&hits2));
+  let optional = |value: Option<usize>| value.map_or("null".to_string(), |v| v.to_string());
+  format!(
+    r#"{{"hits1":{},"hits2":{},"bestMismatches1":{},"bestMismatches2":{},"distinguishes":{}}}"#,
+    hits_json(seq1.as_bytes(), pattern.len(), &hits1),
+    hits_json(seq2.as_bytes(), pattern.len(), &hits2),
+    optional(best1),
+    optional(best2),
+    best1 != best2
+  )
+}
+
+/// Every position where `needle` occurs in `haystack` with at most `max_mismatches`
+/// substitutions (Hamming distance, case-insensitive, forward strand only), found by
+/// bit-parallel shift-and: 0-based `position` and `mismatches` of each hit, by position. For
+/// short signature motifs; `find_pattern` covers IUPAC codes and both strands
+#[wasm_bindgen]
+pub fn find_approximate_matches(needle: &str, haystack: &str, max_mismatches: usize) -> String {
+  let hits: Vec<String> = approximate_matches(needle.trim().as_bytes(), haystack.as_bytes(), max_mismatches)
+    .iter()
+    .map(|&(position, mismatches)| format!(r#"{{"position":{},"mismatches":{}}}"#, position, mismatches))
+    .collect();
+  format!(r#"{{"hits":[{}]}}"#, hits.join(","))
+}
@@ -0,0 +1,70 @@
+use gene_compare_core::plasmid::find_insert;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Plasmid Insert Verification (exported)
+// ============================================================================
+/// Occurrences of an expected `insert` in a circular `plasmid` map: every placement on
+/// either strand, including across the origin, with at most `max_mismatches` differences
+/// (IUPAC codes in the insert match any of their bases), overlapping placements on a strand
+/// reduced to the one with the fewest. Each occurrence gives its `strand`, plasmid `start`
+/// and `end` (where `wrapsOrigin`, `end` comes before `start`), `mismatches`, `identity`,
+/// and the `differences` by `insertPosition` and `plasmidPosition`, with the `expected` and
+/// `found` bases on the insert's strand. Positions follow the options' coordinate system;
+/// `verified` is whether exactly one occurrence has no differences
+#[wasm_bindgen]
+pub fn verify_plasmid_insert(
+  insert: &str,
+  plasmid: &str,
+  max_mismatches: usize,
+  options: &ComparisonOptions,
+) -> String {
+  let (insert, plasmid, _) = options.normalize(insert, plasmid);
+  if insert.is_empty() || insert.len() > plasmid.len() {
+    return r#"{"error":"The insert must be non-empty and no longer than the plasmid"}"#.to_string();
+  }
+  let occurrences = find_insert(&insert, &plasmid, max_mismatches);
+
+  let coordinates = options.coordinates();
+  let rows: Vec<String> = occurrences
+    .iter()
+    .map(|occurrence| {
+      let hit = &occurrence.hit;
+      let differences: Vec<String> = occurrence
+        .differences
+        .iter()
+        .map(|difference| {
+          format!(
+            r#"{{"insertPosition":{},"plasmidPosition":{},"expected":"{}","found":"{}"}}"#,
+            coordinates.start(difference.insert_position),
+            coordinates.start(difference.plasmid_position),
+            difference.expected as char,
+            difference.found as char
+          )
+        })
+        .collect();
+      format!(
+        r#"{{"strand":"{}","start":{},"end":{},"wrapsOrigin":{},"mismatches":{},"identity":{},"differences":[{}]}}"#,
+        if hit.reverse { '-' } else { '+' },
+        coordinates.start(hit.position),
+        coordinates.end(occurrence.end),
+        occurrence.wraps_origin,
+        hit.mismatches,
+        (insert.len() - hit.mismatches) as f64 / insert.len() as f64,
+        differences.join(",")
+      )
+    })
+    .collect();
+  let verified = occurrences.len() == 1 && occurrences[0].hit.mismatches == 0;
+  format!(
+    r#"{{"coordinateSystem":"{}","insertLength":{},"plasmidLength":{},"count":{},"verified":{},"occurrences":[{}]}}"#,
+    coordinates.name(),
+    insert.len(),
+    plasmid.len(),
+    occurrences.len(),
+    verified,
+    rows.join(",")
+  )
+}
@@ -0,0 +1,61 @@
+use gene_compare_core::profile::{gc_identity_profile as core_gc_identity_profile, GcIdentityProfile};
+use wasm_bindgen::prelude::*;
+
+use crate::f64_array_json;
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// GC and Identity Profile (exported)
+// ============================================================================
+/// Window GC content of each sequence and window identity along the best placement of seq2
+/// against seq1, as parallel arrays with the windows' starts in seq1. `window` of 0 uses the
+/// options' segment window length and `step` of 0 gives non-overlapping windows; an overlap
+/// shorter than one window is a single window.
+#[wasm_bindgen]
+pub fn gc_identity_profile(seq1: &str, seq2: &str, window: usize, step: usize, options: &ComparisonOptions) -> String {
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let window = if window == 0 { options.segment_window_length().max(1) } else { window };
+  let step = if step == 0 { window } else { step };
+
+  let alignment = if bytes1.is_empty() || bytes2.is_empty() {
+    None
+  } else {
+    match options.place(bytes1, bytes2) {
+      Ok(alignment) => Some(alignment),
+      Err(exceeded) => return exceeded.to_json(),
+    }
+  };
+  let profile = alignment
+    .as_ref()
+    .map_or_else(GcIdentityProfile::default, |alignment| core_gc_identity_profile(bytes1, bytes2, alignment, window, step));
+
+  let coordinates = options.coordinates();
+  let (offset1, offset2) = alignment.map_or((0, 0), |alignment| (alignment.offset1, alignment.offset2));
+  let positions: Vec<String> = profile.starts.iter().map(|&start| coordinates.start(offset1 + start).to_string()).collect();
+  format!(
+    r#"{{"window":{},"step":{},"offset1":{},"offset2":{},"coordinateSystem":"{}","starts":[{}],"gc1":{},"gc2":{},"identity":{}}}"#,
+    window,
+    step,
+    coordinates.start(offset1),
+    coordinates.start(offset2),
+    coordinates.name(),
+    positions.join(","),
+    f64_array_json(&profile.gc1),
+    f64_array_json(&profile.gc2),
+    f64_array_json(&profile.identity)
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn mask_symbols_do_not_change_the_profile() {
+    let (seq1, seq2) = pair();
+    let profile = |options: &ComparisonOptions| gc_identity_profile(&seq1, &seq2, 50, 25, options);
+    assert_eq!(profile(&restyled()), profile(&ComparisonOptions::new()));
+  }
+}
@@ -0,0 +1,135 @@
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+use gene_compare_core::alignment::{exhaustive_scratch_bytes, OffsetScan, OffsetScorer};
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{empty_nucleotide_result, nucleotide_result_json};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::limits::{check_memory, EXHAUSTIVE_TOO_LARGE};
+use gene_compare_core::observer::Observer;
+use gene_compare_core::scoring::Scoring;
+
+use crate::cancel::{Cancellation, ABORTED_MESSAGE};
+use crate::logging::log;
+#[cfg(feature = "telemetry")]
+use crate::telemetry::Timings;
+
+// ============================================================================
+// Progress Reporting
+// ============================================================================
+// Callbacks receive (percent, phase) with percent 0-100 within the phase:
+// "offsetScan", then "mask", then "blocks" ("translation" replaces the offset
+// scan for proteins). Reports are throttled to whole percent steps so the
+// callback costs nothing measurable in the hot loop. Diagnostic messages go
+// to the logger installed with `set_logger`.
+
+pub(crate) struct Progress {
+  callback: Option<Function>,
+  last: Option<(&'static str, u32)>,
+  #[cfg(feature = "telemetry")]
+  timings: Option<Timings>,
+}
+
+impl Progress {
+  pub(crate) fn new(callback: Option<Function>) -> Self {
+    Progress {
+      callback,
+      last: None,
+      #[cfg(feature = "telemetry")]
+      timings: None,
+    }
+  }
+  
+  pub(crate) fn none() -> Self {
+    Progress::new(None)
+  }
+  
+  /// No callback, but every report is timestamped
+  #[cfg(feature = "telemetry")]
+  pub(crate) fn timed() -> Self {
+    Progress { timings: Some(Timings::default()), ..Progress::none() }
+  }
+  
+  #[cfg(feature = "telemetry")]
+  pub(crate) fn timings(&self) -> Option<&Timings> {
+    self.timings.as_ref()
+  }
+}
+
+impl Observer for Progress {
+  fn report(&mut self, phase: &'static str, fraction: f64) {
+    #[cfg(feature = "telemetry")]
+    if let Some(timings) = &mut self.timings {
+      timings.record(phase);
+    }
+    let Some(callback) = &self.callback else { return };
+    let percent = (fraction.clamp(0.0, 1.0) * 100.0).floor() as u32;
+    if self.last == Some((phase, percent)) {
+      return;
+    }
+    self.last = Some((phase, percent));
+    // Exceptions thrown by the callback are ignored; they must not abort the comparison
+    let _ = callback.call2(&JsValue::UNDEFINED, &JsValue::from(percent), &JsValue::from_str(phase));
+  }
+  
+  fn log(&mut self, message: &str) {
+    log(message);
+  }
+}
+
+/// Same result as `compare_sequences_full`, calling `progress(percent, phase)` as the
+/// comparison advances. The call blocks, so in the browser run it in a Worker and
+/// forward the reports with `postMessage`; on the main thread use
+/// `compare_sequences_async`, which accepts the same callback. Throws "Comparison aborted"
+/// if `signal` (see `AbortHandle`) is aborted, e.g. from within the callback.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compare_sequences_with_progress(
+  seq1: &str,
+  seq2: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+  progress: Option<Function>,
+  signal: JsValue,
+) -> Result<String, JsError> {
+  let bytes1 = seq1.as_bytes();
+  let bytes2 = seq2.as_bytes();
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return Ok(empty_nucleotide_result(&ResultFormat::default()));
+  }
+  if let Err(exceeded) = check_memory(exhaustive_scratch_bytes(bytes1.len(), bytes2.len()), EXHAUSTIVE_TOO_LARGE) {
+    return Ok(exceeded.to_json());
+  }
+  
+  let cancellation = Cancellation::new(signal);
+  let mut progress = Progress::new(progress);
+  progress.report("offsetScan", 0.0);
+  let scorer = OffsetScorer::new(bytes1, bytes2);
+  let score = |&offset: &i32| scorer.score(offset);
+  let mut scan = OffsetScan::new(bytes1.len(), bytes2.len(), min_sequence_overlap_pct);
+  while !scan.scan_chunk(&score) {
+    if cancellation.is_aborted() {
+      return Err(JsError::new(ABORTED_MESSAGE));
+    }
+    progress.report("offsetScan", scan.fraction_done());
+  }
+  progress.report("offsetScan", 1.0);
+  if cancellation.is_aborted() {
+    return Err(JsError::new(ABORTED_MESSAGE));
+  }
+  
+  Ok(nucleotide_result_json(
+    bytes1,
+    bytes2,
+    &scan.best,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ResultFormat::default(),
+    &Scoring::default(),
+    BlockDetection::default(),
+    &mut progress,
+  ))
+}
@@ -0,0 +1,57 @@
+use gene_compare_core::quality::{is_uncalled, trim_by_quality as trim_window, trim_ends, trim_unknown_ends, TrimRange};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Read Trimming (exported)
+// ============================================================================
+// Chromatogram-derived reads cleaned up before comparison, with the ranges
+// that were cut reported so the trimmed read can still be placed in the
+// original trace. Positions are 0-based half-open in `seq`.
+
+const QUALS_LENGTH_ERROR: &str = r#"{"error":"quals must have one Phred score per base of seq"}"#;
+
+fn trimmed_json(seq: &[u8], kept: TrimRange) -> String {
+  let mut cut = Vec::new();
+  if kept.start > 0 {
+    cut.push(format!(r#"{{"side":"5'","start":0,"end":{}}}"#, kept.start));
+  }
+  if kept.end < seq.len() {
+    cut.push(format!(r#"{{"side":"3'","start":{},"end":{}}}"#, kept.end, seq.len()));
+  }
+  format!(
+    r#"{{"start":{},"end":{},"length":{},"sequence":{},"trimmed":[{}]}}"#,
+    kept.start,
+    kept.end,
+    kept.len(),
+    serde_json::to_string(&String::from_utf8_lossy(&seq[kept.start..kept.end])).unwrap_or_else(|_| "\"\"".to_string()),
+    cut.join(",")
+  )
+}
+
+/// Sliding-window quality trim of a read: kept from the first `window`-base window whose
+/// mean Phred quality (`quals`, one per base) reaches `threshold` to the end of the last one,
+/// with low-quality bases left at either end cut too. Gives the kept `start`, `end`,
+/// `length`, and `sequence`, plus the `trimmed` ranges at the 5' and 3' ends
+#[wasm_bindgen]
+pub fn trim_by_quality(seq: &str, quals: &[u8], window: usize, threshold: f64) -> String {
+  let seq = seq.as_bytes();
+  if quals.len() != seq.len() {
+    return QUALS_LENGTH_ERROR.to_string();
+  }
+  trimmed_json(seq, trim_window(quals, window, threshold))
+}
+
+/// Cut bases from both ends of a read while they are uncalled (N, gaps, non-letters) or,
+/// when `quals` is not empty, their Phred quality is below `threshold`; same result shape as
+/// `trim_by_quality`
+#[wasm_bindgen]
+pub fn trim_read_ends(seq: &str, quals: &[u8], threshold: u8) -> String {
+  let seq = seq.as_bytes();
+  if quals.is_empty() {
+    return trimmed_json(seq, trim_unknown_ends(seq));
+  }
+  if quals.len() != seq.len() {
+    return QUALS_LENGTH_ERROR.to_string();
+  }
+  trimmed_json(seq, trim_ends(quals, threshold, |i| is_uncalled(seq[i])))
+}
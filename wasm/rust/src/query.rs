@@ -0,0 +1,135 @@
+use gene_compare_core::symbols::MISMATCH;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::mapping::{mask_symbols, Placement};
+
+// ============================================================================
+// Region Queries
+// ============================================================================
+// A viewport's share of a result: the columns of the alignment that fall in a
+// range of one sequence, their mask and mismatches, and the conserved blocks
+// cut to them. The UI can keep the summary and fetch these as it scrolls.
+
+/// The part of a nucleotide or protein comparison result over `start..end` of sequence `seq`
+/// (1 or 2), in the result's coordinate system: `offset1`, `offset2`, and `length` of the
+/// aligned columns inside the range (offsets null when there are none), their `mask` (with
+/// the result's `maskSymbols` when it restyles the mask), `mismatches` with each one's `position1` and `position2` in `mismatchPositions`, the
+/// `conservedBlocks` cut to the range (in mask columns, as in the result), and the result's
+/// `mismatchVariants` or `mismatchCodons` inside it. Unreadable results or a `seq` other than
+/// 1 or 2 give `{"error": ...}`
+#[wasm_bindgen]
+pub fn query_region(result: &str, seq: u8, start: usize, end: usize) -> String {
+  let error = |message: &str| format!(r#"{{"error":"{}"}}"#, message);
+  let Some(result) = serde_json::from_str::<Value>(result).ok().filter(Value::is_object) else {
+    return error("The comparison result could not be read");
+  };
+  let Some(placement) = Placement::from_result(&result) else {
+    return error("The comparison result has no offsets and length");
+  };
+  let from = match seq {
+    1 => 0,
+    2 => 1,
+    _ => return error("seq must be 1 or 2"),
+  };
+  let coordinates = placement.coordinates;
+  let styled = result.get("mask").and_then(Value::as_str).unwrap_or_default().as_bytes();
+  let symbols = mask_symbols(&result);
+  let mask = symbols.canonical(styled);
+
+  // Columns of the alignment inside the range
+  let first = coordinates.start_index(start as i32).max(0) as usize;
+  let limit = placement.length.min(mask.len());
+  let column_start = first.saturating_sub(placement.offsets[from]).min(limit);
+  let column_end = end.saturating_sub(placement.offsets[from]).clamp(column_start, limit);
+  let columns = column_start..column_end;
+
+  let position = |seq: usize, column: usize| coordinates.start(placement.offsets[seq] + column);
+  let mismatches: Vec<String> = columns
+    .clone()
+    .filter(|&column| mask[column] == MISMATCH)
+    .map(|column| format!(r#"{{"position1":{},"position2":{}}}"#, position(0, column), position(1, column)))
+    .collect();
+
+  let blocks: Vec<String> = result
+    .get("conservedBlocks")
+    .and_then(Value::as_array)
+    .map(Vec::as_slice)
+    .unwrap_or_default()
+    .iter()
+    .filter_map(|block| {
+      let field = |key: &str| block.get(key).and_then(Value::as_u64).map(|n| n as usize);
+      let block_start = coordinates.start_index(field("start")? as i32).max(0) as usize;
+      let (clipped_start, clipped_end) = (block_start.max(columns.start), field("end")?.min(columns.end));
+      (clipped_start < clipped_end).then(|| {
+        let sequence = block.get("sequence").and_then(Value::as_str).unwrap_or_default();
+        let cut = sequence.get(clipped_start - block_start..clipped_end - block_start).unwrap_or_default();
+        format!(
+          r#"{{"start":{},"end":{},"length":{},"sequence":{}}}"#,
+          coordinates.start(clipped_start),
+          coordinates.end(clipped_end),
+          clipped_end - clipped_start,
+          serde_json::to_string(cut).unwrap_or_else(|_| "\"\"".to_string())
+        )
+      })
+    })
+    .collect();
+
+  // Annotated mismatches the result already carries, by their position in `seq` (variants)
+  // or mask column (codons)
+  let mut extra = String::new();
+  let within = |value: Option<u64>, low: usize, high: usize| value.is_some_and(|value| (low..high).contains(&(value as usize)));
+  if let Some(variants) = result.get("mismatchVariants").and_then(Value::as_array) {
+    let key = ["position1", "position2"][from];
+    let (low, high) = (position(from, columns.start), position(from, columns.end));
+    let rows: Vec<String> =
+      variants.iter().filter(|row| within(row.get(key).and_then(Value::as_u64), low, high)).map(Value::to_string).collect();
+    extra += &format!(r#","mismatchVariants":[{}]"#, rows.join(","));
+  }
+  if let Some(codons) = result.get("mismatchCodons").and_then(Value::as_array) {
+    let rows: Vec<String> = codons
+      .iter()
+      .filter(|row| {
+        within(row.get("position").and_then(Value::as_u64), coordinates.start(columns.start), coordinates.start(columns.end))
+      })
+      .map(Value::to_string)
+      .collect();
+    extra += &format!(r#","mismatchCodons":[{}]"#, rows.join(","));
+  }
+
+  let offset = |seq: usize| if columns.is_empty() { "null".to_string() } else { position(seq, columns.start).to_string() };
+  format!(
+    r#"{{"seq":{},"start":{},"end":{},"coordinateSystem":"{}"{},"offset1":{},"offset2":{},"length":{},"mask":{},"mismatches":{},"mismatchPositions":[{}],"conservedBlocks":[{}]{}}}"#,
+    seq,
+    start,
+    end,
+    coordinates.name(),
+    symbols.json_field(),
+    offset(0),
+    offset(1),
+    columns.len(),
+    serde_json::to_string(&String::from_utf8_lossy(&styled[columns.clone()])).unwrap_or_else(|_| "\"\"".to_string()),
+    mismatches.len(),
+    mismatches.join(","),
+    blocks.join(","),
+    extra
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::options::{compare_sequences_with_options, ComparisonOptions};
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn restyled_results_give_the_same_mismatches() {
+    let (seq1, seq2) = pair();
+    let query = |options: &ComparisonOptions| {
+      let region: Value = serde_json::from_str(&query_region(&compare_sequences_with_options(&seq1, &seq2, options), 1, 0, 100)).unwrap();
+      region["mismatchPositions"].clone()
+    };
+    assert_eq!(query(&restyled()), query(&ComparisonOptions::new()));
+    assert_eq!(query(&ComparisonOptions::new()).as_array().unwrap().len(), 3);
+  }
+}
@@ -0,0 +1,35 @@
+use gene_compare_core::rearrangement::{find_rearrangements, Segment, SegmentKind, Strand};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Inversion and Rearrangement Detection (exported)
+// ============================================================================
+fn segment_json(s: &Segment) -> String {
+  format!(
+    r#"{{"start1":{},"end1":{},"start2":{},"end2":{},"length":{},"strand":"{}","type":"{}"}}"#,
+    s.start1,
+    s.end1,
+    s.start2,
+    s.end2,
+    s.end1 - s.start1,
+    if s.strand == Strand::Forward { "+" } else { "-" },
+    s.kind.name()
+  )
+}
+
+/// Detect inverted and transposed segments between two sequences from exact anchors of
+/// `anchor_length` bases (≤ 32) found on both strands. Segments shorter than
+/// `min_segment_length` are dropped; non-co-linear ones are listed in `structuralDifferences`.
+/// Reverse-strand segments give seq2 coordinates on the forward strand.
+#[wasm_bindgen]
+pub fn detect_rearrangements(seq1: &str, seq2: &str, anchor_length: usize, min_segment_length: usize) -> String {
+  let segments = find_rearrangements(seq1.as_bytes(), seq2.as_bytes(), anchor_length, min_segment_length);
+
+  let all: Vec<String> = segments.iter().map(segment_json).collect();
+  let structural: Vec<String> = segments.iter().filter(|s| s.kind != SegmentKind::Colinear).map(segment_json).collect();
+  format!(
+    r#"{{"segments":[{}],"structuralDifferences":[{}]}}"#,
+    all.join(","),
+    structural.join(",")
+  )
+}
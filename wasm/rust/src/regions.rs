@@ -0,0 +1,106 @@
+use gene_compare_core::blocks::blocks_to_json;
+use gene_compare_core::kozak::kozak_context;
+use gene_compare_core::regions::{partition_regions, select_cds, RegionStats};
+use gene_compare_core::translation::StartCodons;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// UTR / CDS Partitioning (exported)
+// ============================================================================
+fn region_to_json(r: &RegionStats) -> String {
+  format!(
+    r#"{{"name":"{}","start":{},"end":{},"length":{},"mismatches":{},"identity":{},"conservedBlocks":{}}}"#,
+    r.name,
+    r.start,
+    r.end,
+    r.end - r.start,
+    r.mismatches,
+    r.identity,
+    blocks_to_json(&r.blocks)
+  )
+}
+
+/// Nucleotide comparison with statistics reported separately for 5'UTR, CDS, and 3'UTR.
+/// `cds_start`/`cds_end` are 0-based half-open positions in seq1; when `cds_start` is omitted
+/// the CDS is inferred (first ATG to first in-frame stop), and when only `cds_end` is omitted
+/// it runs from `cds_start` to the first in-frame stop.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compare_sequence_regions(
+  seq1: &str,
+  seq2: &str,
+  cds_start: Option<usize>,
+  cds_end: Option<usize>,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  sequence_regions(
+    seq1.as_bytes(),
+    seq2.as_bytes(),
+    cds_start,
+    cds_end,
+    segment_window_length,
+    min_identity,
+    min_significant_length_group,
+    &ComparisonOptions::placement(min_sequence_overlap_pct, false),
+    &StartCodons::default(),
+    false,
+  )
+}
+
+/// Shared body of the region comparisons; an inferred CDS opens at the first of `start_codons`,
+/// or with `kozak` at the one in the strongest Kozak context (reported as `cds.kozak`); the
+/// sequences are placed by `search`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sequence_regions(
+  bytes1: &[u8],
+  bytes2: &[u8],
+  cds_start: Option<usize>,
+  cds_end: Option<usize>,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  search: &ComparisonOptions,
+  start_codons: &StartCodons,
+  kozak: bool,
+) -> String {
+  if bytes1.is_empty() || bytes2.is_empty() {
+    return r#"{"offset1":0,"offset2":0,"length":0,"cds":null,"regions":[]}"#.to_string();
+  }
+
+  let cds = select_cds(bytes1, cds_start, cds_end, start_codons, kozak);
+
+  let alignment = match search.place(bytes1, bytes2) {
+    Ok(alignment) => alignment,
+    Err(exceeded) => return exceeded.to_json(),
+  };
+  let mask = alignment.mask(bytes1, bytes2);
+
+  let (cds_json, regions_json) = match cds {
+    Some(cds) if cds.start < cds.end => {
+      let stats = partition_regions(&mask, alignment.offset1, cds, segment_window_length, min_identity, min_significant_length_group);
+      let parts: Vec<String> = stats.iter().map(region_to_json).collect();
+
+      (
+        format!(
+          r#"{{"start":{},"end":{},"inferred":{},"kozak":{}}}"#,
+          cds.start,
+          cds.end,
+          cds.inferred,
+          if kozak { kozak_context(bytes1, cds.start).to_json() } else { "null".to_string() }
+        ),
+        format!("[{}]", parts.join(",")),
+      )
+    }
+    _ => ("null".to_string(), "[]".to_string()),
+  };
+
+  format!(
+    r#"{{"offset1":{},"offset2":{},"length":{},"cds":{},"regions":{}}}"#,
+    alignment.offset1, alignment.offset2, alignment.length, cds_json, regions_json
+  )
+}
@@ -0,0 +1,251 @@
+use gene_compare_core::symbols::MISMATCH;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::mapping::result_mask;
+
+// ============================================================================
+// Comparison Reports
+// ============================================================================
+// A nucleotide, protein, or regions result rendered as a document users can
+// save: summary statistics, the differences, the conserved blocks, and the
+// per-region identity when the result has regions. Reports are built from the
+// result JSON alone, so they render results saved earlier too.
+
+#[derive(Clone, Copy, PartialEq)]
+enum ReportFormat {
+  Markdown,
+  Html,
+}
+
+struct Table {
+  title: &'static str,
+  headers: Vec<&'static str>,
+  rows: Vec<Vec<String>>,
+}
+
+fn escape_html(text: &str) -> String {
+  text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn escape_markdown(text: &str) -> String {
+  text.replace('|', "\\|").replace('\n', " ")
+}
+
+impl Table {
+  fn render(&self, format: ReportFormat) -> String {
+    match format {
+      ReportFormat::Markdown => {
+        let row = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+        let mut out = format!("## {}\n\n", self.title);
+        if self.rows.is_empty() {
+          return out + "None.\n\n";
+        }
+        out += &row(self.headers.iter().map(|header| header.to_string()).collect());
+        out += &row(self.headers.iter().map(|_| "---".to_string()).collect());
+        for cells in &self.rows {
+          out += &row(cells.iter().map(|cell| escape_markdown(cell)).collect());
+        }
+        out + "\n"
+      }
+      ReportFormat::Html => {
+        let mut out = format!("<h2>{}</h2>\n", self.title);
+        if self.rows.is_empty() {
+          return out + "<p>None.</p>\n";
+        }
+        out += "<table>\n<tr>";
+        for header in &self.headers {
+          out += &format!("<th>{}</th>", header);
+        }
+        out += "</tr>\n";
+        for cells in &self.rows {
+          out += "<tr>";
+          for cell in cells {
+            out += &format!("<td>{}</td>", escape_html(cell));
+          }
+          out += "</tr>\n";
+        }
+        out + "</table>\n"
+      }
+    }
+  }
+}
+
+/// A JSON value as table text: strings unquoted, null as "-"
+fn cell(value: &Value) -> String {
+  match value {
+    Value::Null => "-".to_string(),
+    Value::String(text) => text.clone(),
+    Value::Number(number) => match number.as_f64().filter(|n| n.fract() != 0.0) {
+      // Small E-values would round to zero
+      Some(n) if n.abs() < 1e-3 => format!("{:.2e}", n),
+      Some(n) => format!("{:.4}", n),
+      None => number.to_string(),
+    },
+    other => other.to_string(),
+  }
+}
+
+fn percent(value: &Value) -> String {
+  value.as_f64().map_or("-".to_string(), |fraction| format!("{:.2}%", fraction * 100.0))
+}
+
+fn summary(result: &Value) -> Table {
+  let fields: &[(&str, &str, bool)] = &[
+    ("length", "Aligned length", false),
+    ("mismatches", "Mismatches", false),
+    ("identity", "Identity", true),
+    ("fullLengthIdentity", "Full-length identity", true),
+    ("queryCoverage", "Query coverage", true),
+    ("targetCoverage", "Target coverage", true),
+    ("score", "Score", false),
+    ("bitScore", "Bit score", false),
+    ("evalue", "E-value", false),
+    ("offset1", "Offset in seq1", false),
+    ("offset2", "Offset in seq2", false),
+    ("frame1", "Frame in seq1", false),
+    ("frame2", "Frame in seq2", false),
+    ("coordinateSystem", "Coordinates", false),
+    ("knownMismatches", "Known variants", false),
+    ("novelMismatches", "Novel differences", false),
+  ];
+  let rows = fields
+    .iter()
+    .filter_map(|&(key, label, is_fraction)| {
+      let value = result.get(key)?;
+      Some(vec![label.to_string(), if is_fraction { percent(value) } else { cell(value) }])
+    })
+    .collect();
+  Table { title: "Summary", headers: vec!["Statistic", "Value"], rows }
+}
+
+fn differences(result: &Value) -> Table {
+  let list = |key: &str| result.get(key).and_then(Value::as_array).cloned().unwrap_or_default();
+  let field = |entry: &Value, key: &str| cell(entry.get(key).unwrap_or(&Value::Null));
+  if result.get("mismatchVariants").is_some() {
+    let rows = list("mismatchVariants")
+      .iter()
+      .map(|entry| {
+        let known = entry.get("known").and_then(Value::as_bool).unwrap_or(false);
+        vec![
+          field(entry, "position1"),
+          field(entry, "position2"),
+          format!("{} > {}", field(entry, "base1"), field(entry, "base2")),
+          if known { field(entry, "id") } else { "novel".to_string() },
+        ]
+      })
+      .collect();
+    return Table { title: "Differences", headers: vec!["Position in seq1", "Position in seq2", "Change", "Known variant"], rows };
+  }
+  if result.get("aa1").is_some() {
+    let rows = list("mismatchCodons")
+      .iter()
+      .map(|entry| {
+        vec![
+          field(entry, "position"),
+          format!("{} > {}", field(entry, "aa1"), field(entry, "aa2")),
+          format!("{} > {}", field(entry, "codon1"), field(entry, "codon2")),
+        ]
+      })
+      .collect();
+    return Table { title: "Differences", headers: vec!["Residue", "Change", "Codons"], rows };
+  }
+  // Nucleotide results carry the differences as mismatches in the mask
+  let offset = |key: &str| result.get(key).and_then(Value::as_u64).unwrap_or(0);
+  let rows = result_mask(result)
+    .into_iter()
+    .enumerate()
+    .filter(|&(_, symbol)| symbol == MISMATCH)
+    .map(|(column, _)| vec![(offset("offset1") + column as u64).to_string(), (offset("offset2") + column as u64).to_string()])
+    .collect();
+  Table { title: "Differences", headers: vec!["Position in seq1", "Position in seq2"], rows }
+}
+
+fn blocks_table(title: &'static str, blocks: &[Value]) -> Table {
+  let rows = blocks
+    .iter()
+    .map(|block| {
+      let sequence = block.get("sequence").and_then(Value::as_str).unwrap_or_default();
+      let shown: String = sequence.chars().take(60).collect();
+      let shown = if shown.len() < sequence.len() { shown + "…" } else { shown };
+      let field = |key: &str| cell(block.get(key).unwrap_or(&Value::Null));
+      vec![field("start"), field("end"), field("length"), shown]
+    })
+    .collect();
+  Table { title, headers: vec!["Start", "End", "Length", "Sequence"], rows }
+}
+
+fn regions_table(regions: &[Value]) -> Table {
+  let rows = regions
+    .iter()
+    .map(|region| {
+      let field = |key: &str| cell(region.get(key).unwrap_or(&Value::Null));
+      vec![field("name"), field("start"), field("end"), field("mismatches"), percent(region.get("identity").unwrap_or(&Value::Null))]
+    })
+    .collect();
+  Table { title: "Regions", headers: vec!["Region", "Start", "End", "Mismatches", "Identity"], rows }
+}
+
+/// A self-contained report of a comparison result (the JSON of a nucleotide, protein, or
+/// `compare_sequence_regions` comparison): summary statistics, the differences (with known
+/// variants when the result has them), conserved blocks, and per-region identity.
+/// `format` is "markdown" (default) or "html"; unreadable results give a one-line report
+/// saying so
+#[wasm_bindgen]
+pub fn render_report(result: &str, format: &str) -> String {
+  let format = if format.eq_ignore_ascii_case("html") { ReportFormat::Html } else { ReportFormat::Markdown };
+  let title = "Gene comparison report";
+  let body = match serde_json::from_str::<Value>(result) {
+    Ok(result) if result.is_object() => {
+      let mut tables = Vec::new();
+      tables.push(summary(&result));
+      if let Some(regions) = result.get("regions").and_then(Value::as_array) {
+        tables.push(regions_table(regions));
+        for region in regions {
+          let name = region.get("name").and_then(Value::as_str).unwrap_or_default();
+          let blocks = region.get("conservedBlocks").and_then(Value::as_array).cloned().unwrap_or_default();
+          let title = match name {
+            "5'UTR" => "Conserved blocks: 5'UTR",
+            "CDS" => "Conserved blocks: CDS",
+            "3'UTR" => "Conserved blocks: 3'UTR",
+            _ => "Conserved blocks",
+          };
+          tables.push(blocks_table(title, &blocks));
+        }
+      } else {
+        tables.push(differences(&result));
+        let blocks = result.get("conservedBlocks").and_then(Value::as_array).cloned().unwrap_or_default();
+        tables.push(blocks_table("Conserved blocks", &blocks));
+      }
+      tables.iter().map(|table| table.render(format)).collect::<String>()
+    }
+    _ => match format {
+      ReportFormat::Markdown => "The comparison result could not be read.\n".to_string(),
+      ReportFormat::Html => "<p>The comparison result could not be read.</p>\n".to_string(),
+    },
+  };
+  match format {
+    ReportFormat::Markdown => format!("# {}\n\n{}", title, body),
+    ReportFormat::Html => format!(
+      "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\nbody {{ font-family: sans-serif; margin: 2em; }}\ntable {{ border-collapse: collapse; margin-bottom: 1.5em; }}\nth, td {{ border: 1px solid #ccc; padding: 0.25em 0.6em; text-align: left; }}\ntd:last-child {{ font-family: monospace; }}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n"
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::options::{compare_sequences_with_options, ComparisonOptions};
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn restyled_results_list_their_differences() {
+    let (seq1, seq2) = pair();
+    let rows = |options: &ComparisonOptions| {
+      let result: Value = serde_json::from_str(&compare_sequences_with_options(&seq1, &seq2, options)).unwrap();
+      differences(&result).rows
+    };
+    assert_eq!(rows(&restyled()), rows(&ComparisonOptions::new()));
+    assert_eq!(rows(&restyled()).len(), 7);
+  }
+}
@@ -0,0 +1,81 @@
+use gene_compare_core::restriction::{differential_sites, parse_enzymes, Enzyme};
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Restriction Site Mapping (exported)
+// ============================================================================
+// Enzyme lists are comma- or space-separated built-in names and `Name=SITE`
+// entries (see the core crate's restriction module); unrecognized entries are
+// listed in the result's `unknown` array rather than failing the call.
+
+fn enzyme_json(enzyme: &Enzyme) -> String {
+  format!(
+    r#""enzyme":{},"site":"{}""#,
+    serde_json::to_string(&enzyme.name).unwrap_or_else(|_| "\"\"".to_string()),
+    String::from_utf8_lossy(&enzyme.site)
+  )
+}
+
+fn unknown_json(unknown: &[String]) -> String {
+  serde_json::to_string(unknown).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Restriction sites of `enzymes` (empty for all built-in enzymes) in `seq`, 0-based, with
+/// the strand matched and the top-strand cut position
+#[wasm_bindgen]
+pub fn restriction_sites(seq: &str, enzymes: &str) -> String {
+  let (enzymes, unknown) = parse_enzymes(enzymes);
+  let bytes = seq.as_bytes();
+  let mut sites = Vec::new();
+  for enzyme in &enzymes {
+    for site in enzyme.find_sites(bytes) {
+      sites.push(format!(
+        r#"{{{},"position":{},"strand":"{}","cut":{}}}"#,
+        enzyme_json(enzyme),
+        site.position,
+        site.strand(),
+        site.cut
+      ));
+    }
+  }
+  format!(r#"{{"sites":[{}],"unknown":{}}}"#, sites.join(","), unknown_json(&unknown))
+}
+
+/// Restriction sites present in one sequence but not at the same place in the other, over the
+/// best placement of seq2 against seq1, with the mismatching positions (in seq1) that make the
+/// difference: candidate digests for genotyping the two alleles
+#[wasm_bindgen]
+pub fn differential_restriction_sites(seq1: &str, seq2: &str, enzymes: &str, options: &ComparisonOptions) -> String {
+  let (enzymes, unknown) = parse_enzymes(enzymes);
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let coordinates = options.coordinates();
+  let mut sites = Vec::new();
+  if !bytes1.is_empty() && !bytes2.is_empty() {
+    let alignment = match options.place(bytes1, bytes2) {
+      Ok(alignment) => alignment,
+      Err(exceeded) => return exceeded.to_json(),
+    };
+    for site in differential_sites(bytes1, bytes2, &alignment, &enzymes) {
+      let mismatches: Vec<String> =
+        site.mismatches.iter().map(|&column| coordinates.start(alignment.offset1 + column).to_string()).collect();
+      sites.push(format!(
+        r#"{{{},"presentIn":"{}","position1":{},"position2":{},"strand":"{}","mismatches":[{}]}}"#,
+        enzyme_json(site.enzyme),
+        if site.in_seq1 { "seq1" } else { "seq2" },
+        coordinates.start(alignment.offset1 + site.column),
+        coordinates.start(alignment.offset2 + site.column),
+        if site.reverse { '-' } else { '+' },
+        mismatches.join(",")
+      ));
+    }
+  }
+  format!(
+    r#"{{"coordinateSystem":"{}","sites":[{}],"unknown":{}}}"#,
+    coordinates.name(),
+    sites.join(","),
+    unknown_json(&unknown)
+  )
+}
@@ -0,0 +1,23 @@
+use gene_compare_core::sketch as core_sketch;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// MinHash Sketches (exported)
+// ============================================================================
+/// MinHash sketch of a nucleotide sequence: the `size` smallest distinct hashes of its
+/// canonical k-mers (k ≤ 32), sorted ascending.
+#[wasm_bindgen]
+pub fn sketch(seq: &str, k: usize, size: usize) -> Vec<u64> {
+  core_sketch::sketch(seq.as_bytes(), k, size)
+}
+
+/// Estimated Jaccard index and Mash distance between two sketches built with the same `k`.
+/// Sketches are compared over the `min(len1, len2)` smallest hashes of their union.
+#[wasm_bindgen]
+pub fn sketch_distance(sketch1: &[u64], sketch2: &[u64], k: usize) -> String {
+  let d = core_sketch::sketch_distance(sketch1, sketch2, k);
+  format!(
+    r#"{{"jaccard":{},"distance":{},"sharedHashes":{},"sketchSize":{}}}"#,
+    d.jaccard, d.distance, d.shared, d.union
+  )
+}
@@ -0,0 +1,36 @@
+use gene_compare_core::kmer::decode_kmer;
+use gene_compare_core::spectrum::{compare_spectra, spectrum};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// k-mer Spectra (exported)
+// ============================================================================
+/// k-mer count vector of a sequence as a JSON object (`{"ACG": 3, ...}`), keys sorted.
+/// With `canonical`, each k-mer is merged with its reverse complement.
+#[wasm_bindgen]
+pub fn kmer_counts(seq: &str, k: usize, canonical: bool) -> String {
+  let parts: Vec<String> = spectrum(seq.as_bytes(), k, canonical)
+    .iter()
+    .map(|(&code, count)| format!(r#""{}":{}"#, decode_kmer(code, k), count))
+    .collect();
+  format!("{{{}}}", parts.join(","))
+}
+
+/// Compare the k-mer spectra of two sequences: Jaccard index and containment over distinct
+/// k-mers, and cosine similarity/distance over the count vectors.
+#[wasm_bindgen]
+pub fn compare_kmer_spectra(seq1: &str, seq2: &str, k: usize, canonical: bool) -> String {
+  let c = compare_spectra(&spectrum(seq1.as_bytes(), k, canonical), &spectrum(seq2.as_bytes(), k, canonical));
+  format!(
+    r#"{{"k":{},"distinct1":{},"distinct2":{},"shared":{},"jaccard":{},"containment1":{},"containment2":{},"cosineSimilarity":{},"cosineDistance":{}}}"#,
+    k,
+    c.distinct1,
+    c.distinct2,
+    c.shared,
+    c.jaccard,
+    c.containment1,
+    c.containment2,
+    c.cosine_similarity,
+    1.0 - c.cosine_similarity
+  )
+}
@@ -0,0 +1,166 @@
+use gene_compare_core::exons::exon_ranges;
+use gene_compare_core::symbols::MISMATCH;
+use wasm_bindgen::prelude::*;
+
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Splice-Site Differences
+// ============================================================================
+// With exons annotated on a genomic seq1, each intron starts with a donor
+// dinucleotide (GT, or the rarer GC) after an exon's end and finishes with an
+// acceptor (AG) before the next exon's start. Mismatches on those bases, or
+// within a few bases of the junction, can change splicing.
+
+#[derive(Clone, Copy, PartialEq)]
+enum SpliceSite {
+  Donor,
+  Acceptor,
+}
+
+impl SpliceSite {
+  fn name(self) -> &'static str {
+    match self {
+      SpliceSite::Donor => "donor",
+      SpliceSite::Acceptor => "acceptor",
+    }
+  }
+
+  fn is_canonical(self, dinucleotide: &[u8]) -> bool {
+    let dinucleotide = dinucleotide.to_ascii_uppercase();
+    match self {
+      SpliceSite::Donor => matches!(dinucleotide.as_slice(), b"GT" | b"GC"),
+      SpliceSite::Acceptor => dinucleotide == b"AG",
+    }
+  }
+}
+
+struct Junction {
+  exon: usize,
+  site: SpliceSite,
+  /// The exon boundary: the donor's exon end (exclusive) or the acceptor's exon start
+  boundary: usize,
+  /// Start of the intronic dinucleotide
+  dinucleotide: usize,
+}
+
+/// Donors after every exon but the last and acceptors before every exon but the first,
+/// where the dinucleotide fits in the sequence
+fn junctions(exons: &[(usize, usize)], len: usize) -> Vec<Junction> {
+  let mut junctions = Vec::new();
+  for (i, &(start, end)) in exons.iter().enumerate() {
+    if i > 0 && start >= 2 {
+      junctions.push(Junction { exon: i + 1, site: SpliceSite::Acceptor, boundary: start, dinucleotide: start - 2 });
+    }
+    if i + 1 < exons.len() && end + 2 <= len {
+      junctions.push(Junction { exon: i + 1, site: SpliceSite::Donor, boundary: end, dinucleotide: end });
+    }
+  }
+  junctions
+}
+
+/// Mismatches over the best placement of seq2 that fall on a splice site of the exons
+/// annotated on seq1 (`exons1`, flat 0-based half-open pairs as from
+/// `parse_exon_annotations`): `canonical` on a donor or acceptor dinucleotide, or
+/// `nearJunction` within `window` bases of the exon boundary. `junctions` gives each site's
+/// dinucleotide in both sequences (null where seq2 does not cover it) and whether it is canonical
+#[wasm_bindgen]
+pub fn splice_site_differences(seq1: &str, seq2: &str, exons1: &[u32], window: usize, options: &ComparisonOptions) -> String {
+  let (bytes1, bytes2, _) = options.normalize(seq1, seq2);
+  let (bytes1, bytes2) = (&bytes1[..], &bytes2[..]);
+  let coordinates = options.coordinates();
+  let junctions = junctions(&exon_ranges(exons1, bytes1.len()), bytes1.len());
+  let alignment = if bytes1.is_empty() || bytes2.is_empty() {
+    None
+  } else {
+    match options.place(bytes1, bytes2) {
+      Ok(alignment) => Some(alignment),
+      Err(exceeded) => return exceeded.to_json(),
+    }
+  };
+
+  // Position in seq2 of a seq1 position inside the overlap
+  let in_seq2 = |position: usize| {
+    alignment
+      .as_ref()
+      .filter(|alignment| (alignment.offset1..alignment.offset1 + alignment.length).contains(&position))
+      .map(|alignment| position - alignment.offset1 + alignment.offset2)
+  };
+  let text = |bytes: &[u8]| serde_json::to_string(&String::from_utf8_lossy(bytes)).unwrap_or_else(|_| "\"\"".to_string());
+
+  let junction_rows: Vec<String> = junctions
+    .iter()
+    .map(|junction| {
+      let dinucleotide1 = &bytes1[junction.dinucleotide..junction.dinucleotide + 2];
+      let dinucleotide2 = in_seq2(junction.dinucleotide)
+        .zip(in_seq2(junction.dinucleotide + 1))
+        .map(|(start, _)| &bytes2[start..start + 2]);
+      format!(
+        r#"{{"exon":{},"site":"{}","boundary":{},"dinucleotide1":{},"dinucleotide2":{},"canonical1":{},"canonical2":{}}}"#,
+        junction.exon,
+        junction.site.name(),
+        match junction.site {
+          SpliceSite::Donor => coordinates.end(junction.boundary),
+          SpliceSite::Acceptor => coordinates.start(junction.boundary),
+        },
+        text(dinucleotide1),
+        dinucleotide2.map_or("null".to_string(), text),
+        junction.site.is_canonical(dinucleotide1),
+        dinucleotide2.map_or("null".to_string(), |dinucleotide| junction.site.is_canonical(dinucleotide).to_string())
+      )
+    })
+    .collect();
+
+  let mut flags = Vec::new();
+  if let Some(alignment) = &alignment {
+    let mask = alignment.mask(bytes1, bytes2);
+    for column in (0..mask.len()).filter(|&column| mask[column] == MISMATCH) {
+      let (position1, position2) = (alignment.offset1 + column, alignment.offset2 + column);
+      for junction in &junctions {
+        let on_dinucleotide = (junction.dinucleotide..junction.dinucleotide + 2).contains(&position1);
+        // Distance from the boundary into the intron (positive) or the exon (negative)
+        let distance = match junction.site {
+          SpliceSite::Donor => position1 as i64 - junction.boundary as i64 + 1,
+          SpliceSite::Acceptor => junction.boundary as i64 - position1 as i64,
+        };
+        let distance = if distance <= 0 { distance - 1 } else { distance };
+        if !on_dinucleotide && distance.unsigned_abs() as usize > window {
+          continue;
+        }
+        flags.push(format!(
+          r#"{{"position1":{},"position2":{},"base1":{},"base2":{},"exon":{},"site":"{}","distance":{},"class":"{}"}}"#,
+          coordinates.start(position1),
+          coordinates.start(position2),
+          text(&bytes1[position1..position1 + 1]),
+          text(&bytes2[position2..position2 + 1]),
+          junction.exon,
+          junction.site.name(),
+          distance,
+          if on_dinucleotide { "canonical" } else { "nearJunction" }
+        ));
+      }
+    }
+  }
+
+  format!(
+    r#"{{"coordinateSystem":"{}","window":{},"junctions":[{}],"flags":[{}]}}"#,
+    coordinates.name(),
+    window,
+    junction_rows.join(","),
+    flags.join(",")
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_support::{pair, restyled};
+
+  #[test]
+  fn mask_symbols_do_not_change_the_flags() {
+    let (seq1, seq2) = pair();
+    let flags = |options: &ComparisonOptions| splice_site_differences(&seq1, &seq2, &[0, 40, 80, 249], 3, options);
+    assert_eq!(flags(&restyled()), flags(&ComparisonOptions::new()));
+    assert!(flags(&restyled()).contains("nearJunction"));
+  }
+}
@@ -0,0 +1,126 @@
+use gene_compare_core::symbols::MISMATCH;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::mapping::result_mask;
+
+// ============================================================================
+// SVG Tracks
+// ============================================================================
+// A result drawn as two horizontal tracks, one per sequence, shifted so the
+// aligned columns line up: the sequence as a grey bar, its conserved blocks
+// in green, and a red tick at each mismatching column. Everything comes from
+// the result JSON, so simple embeds don't need a canvas renderer.
+
+const DEFAULT_WIDTH: u32 = 800;
+const DEFAULT_HEIGHT: u32 = 120;
+/// Room left of the tracks for their labels
+const LABEL_WIDTH: f64 = 48.0;
+
+/// The two sequences' extents, in columns of a shared axis on which the aligned region starts at
+/// `lead` in both
+struct Layout {
+  lead: usize,
+  offsets: [usize; 2],
+  lengths: [usize; 2],
+  total: usize,
+}
+
+impl Layout {
+  fn new(result: &Value) -> Layout {
+    let number = |value: Option<&Value>| value.and_then(Value::as_u64).unwrap_or(0) as usize;
+    let one_based = result.get("coordinateSystem").and_then(Value::as_str) == Some("1-based-inclusive");
+    let offset = |key: &str| number(result.get(key)).saturating_sub(one_based as usize);
+    let length = number(result.get("length"));
+    let offsets = [offset("offset1"), offset("offset2")];
+    // Nucleotide results give the unaligned ends; otherwise only the aligned part is known
+    let sequence_length = |i: usize, key: &str| {
+      let overhang = result.get("overhangs").and_then(|overhangs| overhangs.get(key));
+      let three_prime = number(overhang.and_then(|overhang| overhang.get("threePrimeLength")));
+      offsets[i] + length + three_prime
+    };
+    let lengths = [sequence_length(0, "seq1"), sequence_length(1, "seq2")];
+    let lead = offsets[0].max(offsets[1]);
+    let total = lead + (lengths[0] - offsets[0]).max(lengths[1] - offsets[1]);
+    Layout { lead, offsets, lengths, total: total.max(1) }
+  }
+}
+
+fn escape_xml(text: &str) -> String {
+  text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The tracks of a nucleotide or protein comparison result as a standalone SVG document of
+/// `width` x `height` pixels (800 x 120 when 0): each sequence as a grey bar aligned on the
+/// compared region, conserved blocks in green, and a red tick at every mismatch ('?' in the
+/// mask). Protein results are drawn over the compared residues only. Unreadable results
+/// give an SVG saying so
+#[wasm_bindgen]
+pub fn render_svg(result: &str, width: u32, height: u32) -> String {
+  let width = if width == 0 { DEFAULT_WIDTH } else { width } as f64;
+  let height = if height == 0 { DEFAULT_HEIGHT } else { height } as f64;
+  let mut body = String::new();
+  match serde_json::from_str::<Value>(result).ok().filter(Value::is_object) {
+    Some(result) => {
+      let layout = Layout::new(&result);
+      let scale = (width - LABEL_WIDTH - 8.0).max(1.0) / layout.total as f64;
+      let x = |column: usize| LABEL_WIDTH + column as f64 * scale;
+      let bar_height = height * 0.18;
+      let tops = [height * 0.2, height * 0.62];
+      let mask = result_mask(&result);
+      let blocks = result.get("conservedBlocks").and_then(Value::as_array).cloned().unwrap_or_default();
+      let one_based = result.get("coordinateSystem").and_then(Value::as_str) == Some("1-based-inclusive");
+
+      for (i, top) in tops.iter().enumerate() {
+        let start = layout.lead - layout.offsets[i];
+        body += &format!(
+          "<text x=\"4\" y=\"{:.1}\" font-size=\"12\" dominant-baseline=\"middle\">seq{}</text>\n",
+          top + bar_height / 2.0,
+          i + 1
+        );
+        body += &format!(
+          "<rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"#d0d0d0\"><title>seq{}: {} residues</title></rect>\n",
+          x(start),
+          top,
+          layout.lengths[i] as f64 * scale,
+          bar_height,
+          i + 1,
+          layout.lengths[i]
+        );
+        for block in &blocks {
+          let field = |key: &str| block.get(key).and_then(Value::as_u64).unwrap_or(0) as usize;
+          let (block_start, block_end) = (field("start").saturating_sub(one_based as usize), field("end"));
+          body += &format!(
+            "<rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"#4caf50\"><title>{}</title></rect>\n",
+            x(layout.lead + block_start),
+            top,
+            (block_end.saturating_sub(block_start) as f64 * scale).max(1.0),
+            bar_height,
+            escape_xml(&format!("Conserved block {}-{}", field("start"), block_end))
+          );
+        }
+      }
+      for column in mask.iter().enumerate().filter(|&(_, &symbol)| symbol == MISMATCH).map(|(column, _)| column) {
+        let center = x(layout.lead + column) + scale / 2.0;
+        body += &format!(
+          "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\" stroke=\"#e53935\" stroke-width=\"1\"/>\n",
+          center,
+          tops[0],
+          center,
+          tops[1] + bar_height
+        );
+      }
+    }
+    None => {
+      body += &format!(
+        "<text x=\"4\" y=\"{:.1}\" font-size=\"12\">The comparison result could not be read.</text>\n",
+        height / 2.0
+      );
+    }
+  }
+  format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\">\n{body}</svg>\n",
+    w = width,
+    h = height
+  )
+}
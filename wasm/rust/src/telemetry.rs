@@ -0,0 +1,215 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gene_compare_core::blocks::BlockDetection;
+use gene_compare_core::comparison::{nucleotide_comparison, protein_comparison};
+use gene_compare_core::format::ResultFormat;
+use gene_compare_core::scoring::Scoring;
+use gene_compare_core::translation::{ReadingFrames, Translation};
+use wasm_bindgen::prelude::*;
+
+use crate::progress::Progress;
+
+// ============================================================================
+// Timing and Memory Telemetry
+// ============================================================================
+// Phase durations come from the progress reports each phase already emits at
+// its start and end. Scratch memory is measured by counting live heap bytes
+// in the global allocator; the peak is relative to the heap at the start of
+// the call, so it attributes everything the comparison allocated (concurrent
+// calls on other threads are counted too).
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+impl CountingAllocator {
+  fn grew(size: usize) {
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+  }
+
+  fn shrank(size: usize) {
+    LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+  }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+    if !ptr.is_null() {
+      Self::grew(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc_zeroed(layout);
+    if !ptr.is_null() {
+      Self::grew(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+    Self::shrank(layout.size());
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let new_ptr = System.realloc(ptr, layout, new_size);
+    if !new_ptr.is_null() {
+      Self::shrank(layout.size());
+      Self::grew(new_size);
+    }
+    new_ptr
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Peak heap growth since `start`
+struct ScratchMeter {
+  baseline: usize,
+}
+
+impl ScratchMeter {
+  fn start() -> Self {
+    let baseline = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(baseline, Ordering::Relaxed);
+    ScratchMeter { baseline }
+  }
+
+  fn peak(&self) -> usize {
+    PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(self.baseline)
+  }
+}
+
+/// Monotonic clock in microseconds (`performance.now()` on wasm32-unknown-unknown, where
+/// `Instant` is unavailable; `Instant` natively and on wasm32-wasi)
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) fn now_us() -> f64 {
+  let performance = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("performance")).ok();
+  let now = performance
+    .as_ref()
+    .and_then(|p| js_sys::Reflect::get(p, &JsValue::from_str("now")).ok().map(|f| (p, f)))
+    .and_then(|(p, f)| js_sys::Function::from(f).call0(p).ok())
+    .and_then(|ms| ms.as_f64());
+  now.unwrap_or_else(js_sys::Date::now) * 1000.0
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) fn now_us() -> f64 {
+  use std::sync::OnceLock;
+  use std::time::Instant;
+  static EPOCH: OnceLock<Instant> = OnceLock::new();
+  EPOCH.get_or_init(Instant::now).elapsed().as_secs_f64() * 1e6
+}
+
+/// First and last report time of each phase, in order of first appearance
+#[derive(Default)]
+pub(crate) struct Timings {
+  phases: Vec<(&'static str, f64, f64)>,
+}
+
+impl Timings {
+  pub(crate) fn record(&mut self, phase: &'static str) {
+    let now = now_us();
+    match self.phases.iter_mut().find(|(name, _, _)| *name == phase) {
+      Some(entry) => entry.2 = now,
+      None => self.phases.push((phase, now, now)),
+    }
+  }
+
+  fn json(&self, total_us: f64) -> String {
+    let parts: Vec<String> = self
+      .phases
+      .iter()
+      .map(|(name, start, end)| format!(r#""{}":{}"#, name, (end - start).round()))
+      .chain(std::iter::once(format!(r#""total":{}"#, total_us.round())))
+      .collect();
+    format!("{{{}}}", parts.join(","))
+  }
+}
+
+/// Run `compare` with timing and scratch-memory measurement, appending `timings`
+/// (microseconds per phase plus `total`) and `peakScratchBytes` to its JSON object
+fn profiled(compare: impl FnOnce(&mut Progress) -> String) -> String {
+  let meter = ScratchMeter::start();
+  let start = now_us();
+  let mut progress = Progress::timed();
+  let json = compare(&mut progress);
+  let total = now_us() - start;
+
+  let timings = progress.timings().map(|t| t.json(total)).unwrap_or_default();
+  match json.strip_suffix('}') {
+    Some(body) => format!(r#"{},"timings":{},"peakScratchBytes":{}}}"#, body, timings, meter.peak()),
+    None => json,
+  }
+}
+
+// ============================================================================
+// Profiled Comparisons (exported)
+// ============================================================================
+/// `compare_sequences_full` plus `timings` ({offsetScan, mask, blocks, total} in
+/// microseconds) and `peakScratchBytes`, the heap high-water mark of the call
+#[wasm_bindgen]
+pub fn compare_sequences_profiled(
+  seq1: &str,
+  seq2: &str,
+  segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+  min_sequence_overlap_pct: f64,
+) -> String {
+  profiled(|progress| {
+    nucleotide_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      min_sequence_overlap_pct,
+      &ResultFormat::default(),
+      &Scoring::default(),
+      progress,
+    )
+    .1
+  })
+}
+
+/// `compare_proteins_full` plus `timings` ({translation, mask, blocks, total} in
+/// microseconds) and `peakScratchBytes`
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compare_proteins_profiled(
+  seq1: &str,
+  seq2: &str,
+  nuc_offset1: i32,
+  nuc_offset2: i32,
+  nuc_length: i32,
+  aa_segment_window_length: usize,
+  min_identity: f64,
+  min_significant_length_group: f64,
+) -> String {
+  profiled(|progress| {
+    protein_comparison(
+      seq1.as_bytes(),
+      seq2.as_bytes(),
+      nuc_offset1,
+      nuc_offset2,
+      nuc_length,
+      aa_segment_window_length,
+      min_identity,
+      min_significant_length_group,
+      &ReadingFrames::default(),
+      &Translation::default(),
+      &ResultFormat::default(),
+      &Scoring::default(),
+      BlockDetection::default(),
+      progress,
+    )
+  })
+}
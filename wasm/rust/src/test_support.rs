@@ -0,0 +1,23 @@
+use crate::options::ComparisonOptions;
+
+// ============================================================================
+// Test Fixtures
+// ============================================================================
+// Inputs shared by the unit tests of the modules that read comparison results
+// back, which must give the same answers whatever mask symbols a result uses.
+
+/// 249 bases and a copy with a substitution every 37 bases from position 5
+pub(crate) fn pair() -> (String, String) {
+  let seq1 = "ATGGCTAGCTAGGATCCGATCGATCGGCTAGCTTACGATCGATCGTAGCTAGCTAGCATCGATCGACTGACTAGCTAGCATCG".repeat(3);
+  let seq2 = seq1
+    .bytes()
+    .enumerate()
+    .map(|(i, c)| if i % 37 == 5 { if c == b'A' { 'C' } else { 'A' } } else { c as char })
+    .collect();
+  (seq1, seq2)
+}
+
+/// Options writing `.` for mismatches and `|` for matches instead of the default mask
+pub(crate) fn restyled() -> ComparisonOptions {
+  ComparisonOptions::new().with_mismatch_symbol('.').with_match_symbol(Some('|'))
+}
@@ -0,0 +1,19 @@
+use gene_compare_core::tree::{newick, TreeMethod};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Distance-Based Trees (exported)
+// ============================================================================
+/// Build a tree from a row-major n×n distance matrix (e.g. `distance` from `pairwise_matrix`)
+/// and return it in Newick format. `method` is "nj" (default) or "upgma"; missing labels
+/// default to seq1, seq2, ...
+#[wasm_bindgen]
+pub fn build_tree(distances: &[f64], labels: Vec<String>, method: &str) -> String {
+  let n = (distances.len() as f64).sqrt() as usize;
+  if n == 0 || n * n != distances.len() {
+    return ";".to_string();
+  }
+  let dist: Vec<Vec<f64>> = distances.chunks_exact(n).map(|row| row.to_vec()).collect();
+
+  newick(&dist, &labels, TreeMethod::parse(method))
+}
@@ -0,0 +1,400 @@
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
+// ============================================================================
+// Typed Results
+// ============================================================================
+// Serde mirrors of the JSON result objects. They give the generated .d.ts the
+// full result shape, and the checked exports return them as plain JS objects.
+// Field names and order follow the JSON built in the core crate's comparison module.
+
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct Block {
+  pub start: usize,
+  pub end: usize,
+  pub length: usize,
+  /// Mask over the block: matching residues, '?' at mismatches (or the `maskSymbols`)
+  pub sequence: String,
+}
+
+/// Present when the options restyled the mask
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct MaskStyle {
+  pub mismatch: String,
+  /// Null when matching columns keep the residue
+  #[serde(rename = "match")]
+  pub matched: Option<String>,
+  pub gap: String,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct Diagnostic {
+  /// "info" or "warning"
+  pub level: String,
+  pub code: String,
+  pub message: String,
+  #[tsify(type = "Record<string, number | null>")]
+  pub data: serde_json::Value,
+}
+
+/// Unaligned ends of one sequence: 5' before the aligned region, 3' after it
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Overhang {
+  pub five_prime_length: usize,
+  pub five_prime: String,
+  pub three_prime_length: usize,
+  pub three_prime: String,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct Overhangs {
+  pub seq1: Overhang,
+  pub seq2: Overhang,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizationReport {
+  pub original_length: usize,
+  pub length: usize,
+  pub uppercased: usize,
+  pub whitespace_removed: usize,
+  pub digits_removed: usize,
+  pub uracil_replaced: usize,
+  /// GenBank record text outside the ORIGIN section, in bytes
+  pub genbank_removed: usize,
+  pub poly_a_trimmed: usize,
+}
+
+/// Present when the comparison ran with normalization enabled in its options
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct Normalization {
+  pub seq1: NormalizationReport,
+  pub seq2: NormalizationReport,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, missing_as_null, hashmap_as_object)]
+pub struct NucleotideResult {
+  pub mask: String,
+  pub mismatches: usize,
+  pub length: usize,
+  /// Over the aligned columns
+  pub identity: f64,
+  /// Matching columns over the full length of seq1
+  pub full_length_identity: f64,
+  /// Share of seq1 / seq2 in the aligned overlap
+  pub query_coverage: f64,
+  pub target_coverage: f64,
+  /// Sum of the column scores (see the core crate's scoring module)
+  pub score: i64,
+  /// Score in bits; null when the scoring system has no positive or no negative scores
+  pub bit_score: Option<f64>,
+  /// Expected number of equally good alignments between random sequences of these lengths
+  /// and composition
+  pub evalue: Option<f64>,
+  pub truncated: bool,
+  pub offset1: usize,
+  pub offset2: usize,
+  /// "0-based-half-open" or "1-based-inclusive": how offsets and block positions count
+  pub coordinate_system: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub mask_symbols: Option<MaskStyle>,
+  /// Null for empty inputs
+  pub overhangs: Option<Overhangs>,
+  pub conserved_blocks: Vec<Block>,
+  pub diagnostics: Vec<Diagnostic>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub normalization: Option<Normalization>,
+  /// Present when the options ask for primers
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub primers: Option<Vec<Primer>>,
+  /// Present when the options ask for highlight ranges
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub highlight_ranges: Option<Vec<HighlightRange>>,
+  /// Present for alignments of 100 kb or more, or when the options set overview bins
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub overview: Option<Overview>,
+  /// Present when the options ask for heterozygous calls
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub heterozygous_calls: Option<Vec<HeterozygousCall>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub het_consistent_with_reference: Option<usize>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub het_non_reference: Option<usize>,
+  /// Mismatches that are not heterozygous calls
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub homozygous_mismatches: Option<usize>,
+  /// Present when the options ask for mismatch hotspots
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub mismatch_hotspots: Option<Vec<MismatchHotspot>>,
+  /// Present when the options ask for composition-adjusted identity
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub adjusted_identity: Option<AdjustedIdentity>,
+  /// Present when the options carry known variants
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub mismatch_variants: Option<Vec<MismatchVariant>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub known_mismatches: Option<usize>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub novel_mismatches: Option<usize>,
+  /// Hash of the result without this field (see the core crate's fingerprint module)
+  pub fingerprint: String,
+}
+
+/// `compare_sequences_with_options` result when the best placement falls short of the
+/// options' `min_alignment_identity`
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct NoSignificantAlignment {
+  /// Always true
+  pub no_significant_alignment: bool,
+  pub min_alignment_identity: f64,
+  /// Identity and length of the best placement
+  pub identity: f64,
+  pub length: usize,
+  /// The result the placement would have given
+  pub best_attempt: NucleotideResult,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub normalization: Option<Normalization>,
+  pub fingerprint: String,
+}
+
+/// One of the most divergent windows of the alignment
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct MismatchHotspot {
+  pub start: usize,
+  pub end: usize,
+  /// Mismatch and gap columns
+  pub differences: usize,
+  pub identity: f64,
+}
+
+/// Identity without the columns in homopolymer runs or low-complexity stretches
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustedIdentity {
+  pub identity: f64,
+  /// Aligned columns counted, and those left out
+  pub columns: usize,
+  pub excluded_columns: usize,
+}
+
+/// A primer that matches both sequences exactly, from a conserved block
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Primer {
+  /// "forward" (read from seq1) or "reverse" (reverse complement)
+  pub direction: String,
+  /// 5' to 3'
+  pub sequence: String,
+  pub length: usize,
+  /// First base of the site on the top strand of seq1 / seq2
+  pub start1: usize,
+  pub start2: usize,
+  /// Nearest-neighbor melting temperature, °C
+  pub tm: f64,
+  pub gc: f64,
+  /// Longest run the primer pairs with a copy of itself
+  pub self_complementarity: usize,
+  /// Index into `conservedBlocks`
+  pub block: usize,
+}
+
+/// A run of aligned columns of one kind, or a conserved block
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightRange {
+  /// Columns of the alignment, like block positions
+  pub start: usize,
+  pub end: usize,
+  /// "match", "mismatch", "gap", "masked" (lowercase or N in either sequence), or "block"
+  pub kind: String,
+}
+
+/// Identity of the alignment at a fixed resolution, for zoomed-out views
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Overview {
+  pub bins: usize,
+  /// Mean columns per bin; bins differ by at most one column
+  pub bin_width: f64,
+  pub identity: Vec<f64>,
+}
+
+/// A two-base IUPAC code in seq2 (a heterozygous Sanger call) against seq1's base
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct HeterozygousCall {
+  pub position1: usize,
+  pub position2: usize,
+  /// seq1's base
+  pub reference: String,
+  /// seq2's code and the two bases it stands for
+  pub call: String,
+  pub alleles: Vec<String>,
+  /// "hetConsistentWithReference" (one allele is the reference base) or "hetNonReference"
+  pub class: String,
+}
+
+/// A nucleotide mismatch with the known variant at its seq1 position
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct MismatchVariant {
+  pub position1: usize,
+  pub position2: usize,
+  pub base1: String,
+  pub base2: String,
+  pub known: bool,
+  /// The variant's ID (e.g. an rsID), reference and alternate alleles, and whether seq2's
+  /// base is one of them; null for novel mismatches
+  pub id: Option<String>,
+  pub alleles: Option<Vec<String>>,
+  pub allele_known: Option<bool>,
+}
+
+/// An amino-acid mismatch and the codons that encode it
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct MismatchCodon {
+  /// Position in the protein mask
+  pub position: usize,
+  pub aa1: String,
+  pub aa2: String,
+  /// Where the codon starts in seq1 / seq2
+  pub codon1_start: usize,
+  pub codon1: String,
+  pub codon2_start: usize,
+  pub codon2: String,
+}
+
+/// How often one amino acid was replaced by another in the protein alignment
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Substitution {
+  /// Residue in seq1
+  pub from: String,
+  /// Residue in seq2
+  pub to: String,
+  pub count: usize,
+  pub blosum62: i32,
+  /// BLOSUM62 scores the pair above zero
+  pub conservative: bool,
+}
+
+/// In-frame insertion or deletion in the protein alignment of pre-aligned (gapped) input
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Indel {
+  /// "insertion" (residues only in seq2) or "deletion" (residues only in seq1)
+  #[serde(rename = "type")]
+  pub kind: String,
+  /// First column, in the protein mask
+  pub position: usize,
+  pub length: usize,
+  pub residues: String,
+}
+
+/// Where the two proteins terminate
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct StopCodons {
+  /// Protein mask column of each sequence's first stop, if any
+  pub column1: Option<usize>,
+  pub column2: Option<usize>,
+  /// Where the stop codon starts in seq1 / seq2 (null for proteins compared directly)
+  pub codon1_start: Option<usize>,
+  pub codon2_start: Option<usize>,
+  /// Both stop in the same column
+  pub homologous: bool,
+  /// `column2 - column1` when both stop
+  pub shift: Option<i64>,
+}
+
+/// Run of '-' gaps in pre-aligned input that is not a whole number of codons
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Frameshift {
+  /// 1 or 2
+  pub sequence: u8,
+  /// First gap, in the nucleotide sequence
+  pub position: usize,
+  pub length: usize,
+  /// Protein mask column of the codon the run starts in
+  pub column: usize,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct KozakContext {
+  pub score: u32,
+  pub max_score: u32,
+  /// "strong" (purine at -3 and G at +4), "adequate" (one of them), or "weak"
+  pub strength: String,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(missing_as_null)]
+pub struct FrameDetection {
+  /// "search", "pinned" (frames given), or "cds" (coding regions given)
+  pub method: String,
+  pub start_codon1: Option<usize>,
+  pub start_codon2: Option<usize>,
+  /// Context of each start codon, when chosen by Kozak context
+  pub kozak1: Option<KozakContext>,
+  pub kozak2: Option<KozakContext>,
+  pub inferred_frame1: Option<usize>,
+  pub inferred_frame2: Option<usize>,
+  pub frame_broken: bool,
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, missing_as_null, hashmap_as_object)]
+pub struct ProteinResult {
+  pub aa1: String,
+  pub aa2: String,
+  pub mask: String,
+  pub mismatches: usize,
+  pub length: usize,
+  pub identity: f64,
+  /// Matching codons over the full length of seq1, in bases (residues for protein input)
+  pub full_length_identity: f64,
+  /// Share of seq1 / seq2 covered by the compared codons (residues for protein input)
+  pub query_coverage: f64,
+  pub target_coverage: f64,
+  /// Sum of the column scores (see the core crate's scoring module)
+  pub score: i64,
+  /// Score in bits; null when the scoring system has no positive or no negative scores
+  pub bit_score: Option<f64>,
+  /// Expected number of equally good alignments between random sequences of these lengths
+  /// and composition
+  pub evalue: Option<f64>,
+  pub truncated: bool,
+  /// In codons
+  pub offset1: usize,
+  pub offset2: usize,
+  pub coordinate_system: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub mask_symbols: Option<MaskStyle>,
+  /// Null for direct protein results; mRNA-vs-protein results set only `frame2`, the encoding
+  /// frame of the mRNA (null when nothing aligns)
+  pub frame1: Option<usize>,
+  pub frame2: Option<usize>,
+  pub conserved_blocks: Vec<Block>,
+  /// Null for direct protein and mRNA-vs-protein results
+  pub mismatch_codons: Option<Vec<MismatchCodon>>,
+  pub substitutions: Vec<Substitution>,
+  pub indels: Vec<Indel>,
+  pub frameshifts: Vec<Frameshift>,
+  pub stop_codons: StopCodons,
+  /// Null for direct protein and mRNA-vs-protein results
+  pub frame_detection: Option<FrameDetection>,
+  pub diagnostics: Vec<Diagnostic>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub normalization: Option<Normalization>,
+  pub fingerprint: String,
+}
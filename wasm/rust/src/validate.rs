@@ -0,0 +1,196 @@
+use std::ops::Range;
+
+use gene_compare_core::iupac::base_set;
+use gene_compare_core::normalize::genbank_origin;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Input Validation
+// ============================================================================
+// Reports what is wrong with pasted or uploaded sequence text before it is
+// compared: FASTA headers, whitespace and line numbers, characters invalid for
+// the detected sequence type, and the normalization that would fix them.
+// A pasted GenBank record is checked on its ORIGIN section only, so the record
+// header does not show up as invalid characters. Positions are byte offsets
+// into the original text.
+
+const MAX_REPORTED_POSITIONS: usize = 100;
+const AMINO_ACID_CODES: &[u8] = b"ACDEFGHIKLMNPQRSTVWYBZXJUO*";
+/// Share of nucleotide codes (A, C, G, T, U, N) above which text is taken as DNA or RNA
+const NUCLEOTIDE_FRACTION: f64 = 0.9;
+
+#[derive(Clone, Copy, PartialEq)]
+enum SequenceType {
+  Dna,
+  Rna,
+  Protein,
+  Unknown,
+}
+
+impl SequenceType {
+  fn name(self) -> &'static str {
+    match self {
+      SequenceType::Dna => "dna",
+      SequenceType::Rna => "rna",
+      SequenceType::Protein => "protein",
+      SequenceType::Unknown => "unknown",
+    }
+  }
+
+  fn accepts(self, c: u8) -> bool {
+    match self {
+      SequenceType::Dna | SequenceType::Rna => base_set(c) != 0 || c == b'-',
+      SequenceType::Protein => AMINO_ACID_CODES.contains(&c.to_ascii_uppercase()) || c == b'-',
+      SequenceType::Unknown => c.is_ascii_alphabetic() || c == b'-' || c == b'*',
+    }
+  }
+}
+
+/// Residues of `text[section]` (everything except FASTA header lines and whitespace) and
+/// the number of whitespace characters outside header lines
+fn residues(text: &[u8], section: Range<usize>) -> (Vec<(usize, u8)>, usize) {
+  let mut out = Vec::with_capacity(section.len());
+  let mut whitespace = 0;
+  let mut line_start = true;
+  let mut in_header = false;
+  for (i, &c) in text.iter().enumerate().take(section.end).skip(section.start) {
+    if line_start {
+      in_header = c == b'>' || c == b';';
+    }
+    line_start = c == b'\n';
+    if in_header {
+      continue;
+    }
+    if c.is_ascii_whitespace() {
+      whitespace += 1;
+    } else {
+      out.push((i, c));
+    }
+  }
+  (out, whitespace)
+}
+
+fn detect_type(residues: &[(usize, u8)]) -> SequenceType {
+  let letters: Vec<u8> = residues.iter().map(|&(_, c)| c.to_ascii_uppercase()).filter(u8::is_ascii_alphabetic).collect();
+  if letters.is_empty() {
+    return SequenceType::Unknown;
+  }
+  let count = |set: &[u8]| letters.iter().filter(|c| set.contains(c)).count();
+  let nucleotides = count(b"ACGTUN");
+  if nucleotides as f64 >= NUCLEOTIDE_FRACTION * letters.len() as f64 {
+    return if count(b"U") > count(b"T") { SequenceType::Rna } else { SequenceType::Dna };
+  }
+  if letters.iter().all(|c| AMINO_ACID_CODES.contains(c)) {
+    return SequenceType::Protein;
+  }
+  SequenceType::Unknown
+}
+
+fn json_string(s: &str) -> String {
+  serde_json::to_string(s).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Check sequence text before comparison. Returns the detected `type` (dna, rna, protein,
+/// unknown), GenBank record / FASTA header / whitespace / lowercase contamination, invalid
+/// characters with their positions, `suggestions` for normalizing the text, and the
+/// `normalized` sequence (whose length is `length`).
+#[wasm_bindgen]
+pub fn validate_sequence(seq: &str) -> String {
+  let text = seq.as_bytes();
+  let origin = genbank_origin(text);
+  let (residues, whitespace) = residues(text, origin.clone().unwrap_or(0..text.len()));
+  let sequence_type = detect_type(&residues);
+
+  let headers: Vec<&str> = seq[origin.clone().unwrap_or(0..text.len())]
+    .lines().filter(|line| line.starts_with('>') || line.starts_with(';')).collect();
+  let digits = residues.iter().filter(|(_, c)| c.is_ascii_digit()).count();
+  let lowercase = residues.iter().any(|(_, c)| c.is_ascii_lowercase());
+  let uracil_in_dna = sequence_type == SequenceType::Dna && residues.iter().any(|(_, c)| c.eq_ignore_ascii_case(&b'U'));
+
+  // Invalid characters grouped by character, in order of first appearance
+  let mut invalid: Vec<(u8, Vec<usize>, usize)> = Vec::new();
+  for &(i, c) in residues.iter().filter(|(_, c)| !c.is_ascii_digit() && !sequence_type.accepts(*c)) {
+    match invalid.iter_mut().find(|(ch, _, _)| *ch == c) {
+      Some((_, positions, count)) => {
+        if positions.len() < MAX_REPORTED_POSITIONS {
+          positions.push(i);
+        }
+        *count += 1;
+      }
+      None => invalid.push((c, vec![i], 1)),
+    }
+  }
+
+  let mut suggestions: Vec<&str> = Vec::new();
+  if origin.is_some() {
+    suggestions.push("keep only the GenBank ORIGIN section");
+  }
+  if !headers.is_empty() {
+    suggestions.push("remove FASTA header lines");
+  }
+  if whitespace > 0 {
+    suggestions.push("remove whitespace and line breaks");
+  }
+  if digits > 0 {
+    suggestions.push("remove line numbers");
+  }
+  if lowercase {
+    suggestions.push("convert to uppercase");
+  }
+  if uracil_in_dna {
+    suggestions.push("replace U with T");
+  }
+  if !invalid.is_empty() {
+    suggestions.push(match sequence_type {
+      SequenceType::Protein => "replace invalid characters with X",
+      _ => "replace invalid characters with N",
+    });
+  }
+
+  let normalized: String = residues
+    .iter()
+    .filter(|(_, c)| !c.is_ascii_digit())
+    .map(|&(_, c)| {
+      let c = c.to_ascii_uppercase();
+      if !sequence_type.accepts(c) {
+        if sequence_type == SequenceType::Protein { 'X' } else { 'N' }
+      } else if uracil_in_dna && c == b'U' {
+        'T'
+      } else {
+        c as char
+      }
+    })
+    .collect();
+
+  let invalid_json: Vec<String> = invalid
+    .iter()
+    .map(|(c, positions, count)| {
+      let positions: Vec<String> = positions.iter().map(|p| p.to_string()).collect();
+      format!(
+        r#"{{"character":{},"count":{},"positions":[{}]}}"#,
+        json_string(&(*c as char).to_string()),
+        count,
+        positions.join(",")
+      )
+    })
+    .collect();
+  let headers_json: Vec<String> = headers.iter().map(|h| json_string(h)).collect();
+  let suggestions_json: Vec<String> = suggestions.iter().map(|s| json_string(s)).collect();
+  let valid = origin.is_none()
+    && headers.is_empty() && whitespace == 0 && digits == 0 && invalid.is_empty() && !residues.is_empty();
+
+  format!(
+    r#"{{"valid":{},"type":"{}","length":{},"genbank":{},"fastaHeaders":[{}],"whitespace":{},"digits":{},"lowercase":{},"invalidCharacters":[{}],"suggestions":[{}],"normalized":{}}}"#,
+    valid,
+    sequence_type.name(),
+    normalized.len(),
+    origin.is_some(),
+    headers_json.join(","),
+    whitespace,
+    digits,
+    lowercase,
+    invalid_json.join(","),
+    suggestions_json.join(","),
+    json_string(&normalized)
+  )
+}